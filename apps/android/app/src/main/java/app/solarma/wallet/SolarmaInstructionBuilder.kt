@@ -21,7 +21,15 @@ class SolarmaInstructionBuilder
             // Program ID - matches declare_id! in lib.rs
             val PROGRAM_ID = PublicKey("F54LpWS97bCvkn5PGfUsFi8cU8HyYBZgyozkSkAbAjzP")
 
+            // Anchor reads the program ID in an optional account slot as None
+            private val NONE_ACCOUNT = AccountMeta(PROGRAM_ID, isSigner = false, isWritable = false)
+
             // Anchor discriminators (first 8 bytes of sha256("global:<instruction_name>"))
+            private val DISCRIMINATOR_INITIALIZE =
+                byteArrayOf(
+                    0xaf.toByte(), 0xaf.toByte(), 0x6d.toByte(), 0x1f.toByte(),
+                    0x0d.toByte(), 0x98.toByte(), 0x9b.toByte(), 0xed.toByte(),
+                )
             private val DISCRIMINATOR_CREATE_ALARM =
                 byteArrayOf(
                     0xdd.toByte(), 0x93.toByte(), 0xba.toByte(), 0xee.toByte(),
@@ -56,8 +64,28 @@ class SolarmaInstructionBuilder
                 )
         }
 
+        /**
+         * Build initialize instruction: creates the owner's user profile,
+         * paid by the owner.
+         */
+        fun buildInitialize(owner: PublicKey): SolarmaInstruction {
+            val profilePda = deriveUserProfilePda(owner)
+            val keys =
+                listOf(
+                    AccountMeta(profilePda.address, isSigner = false, isWritable = true),
+                    AccountMeta(owner, isSigner = true, isWritable = false),
+                    AccountMeta(owner, isSigner = true, isWritable = true), // payer
+                    AccountMeta(SYSTEM_PROGRAM_ID, isSigner = false, isWritable = false),
+                )
+            return SolarmaInstruction(PROGRAM_ID, keys, DISCRIMINATOR_INITIALIZE)
+        }
+
         /**
          * Build create_alarm instruction data and accounts.
+         * Reads the global config; the owner also pays, and the optional
+         * charity, savings, index, stats and payer profile accounts are
+         * None. The owner's profile is passed for a Buddy destination,
+         * which the program checks against the profile's blocklist.
          */
         fun buildCreateAlarm(
             owner: PublicKey,
@@ -71,6 +99,13 @@ class SolarmaInstructionBuilder
             // Derive PDAs
             val alarmPda = deriveAlarmPda(owner, alarmId)
             val vaultPda = deriveVaultPda(alarmPda.address)
+            val configPda = deriveConfigPda()
+            val profileAccount =
+                if (penaltyRoute == PenaltyRoute.BUDDY.code && penaltyDestination != null) {
+                    AccountMeta(deriveUserProfilePda(owner).address, isSigner = false, isWritable = true)
+                } else {
+                    NONE_ACCOUNT
+                }
 
             // Log all parameters
             Log.d(TAG, "Building create_alarm:")
@@ -83,7 +118,7 @@ class SolarmaInstructionBuilder
             Log.d(TAG, "  vault PDA=${vaultPda.address.toBase58()}")
 
             // Serialize instruction data
-            val dataSize = 8 + 8 + 8 + 8 + 8 + 1 + 1 + (if (penaltyDestination != null) 32 else 0)
+            val dataSize = 8 + 8 + 8 + 8 + 8 + 1 + 1 + (if (penaltyDestination != null) 32 else 0) + 1
             val buffer = ByteBuffer.allocate(dataSize).order(ByteOrder.LITTLE_ENDIAN)
             buffer.put(DISCRIMINATOR_CREATE_ALARM)
             buffer.putLong(alarmId)
@@ -97,6 +132,7 @@ class SolarmaInstructionBuilder
             } else {
                 buffer.put(0.toByte()) // None
             }
+            buffer.put(0.toByte()) // options: None (config defaults)
 
             // Log instruction data hex
             val dataBytes = buffer.array()
@@ -107,8 +143,16 @@ class SolarmaInstructionBuilder
                 listOf(
                     AccountMeta(alarmPda.address, isSigner = false, isWritable = true),
                     AccountMeta(vaultPda.address, isSigner = false, isWritable = true),
+                    AccountMeta(configPda.address, isSigner = false, isWritable = false),
+                    NONE_ACCOUNT, // charity
+                    NONE_ACCOUNT, // savings_vault
+                    profileAccount, // user_profile
+                    NONE_ACCOUNT, // alarm_index
                     AccountMeta(owner, isSigner = true, isWritable = true),
+                    AccountMeta(owner, isSigner = true, isWritable = true), // payer
                     AccountMeta(SYSTEM_PROGRAM_ID, isSigner = false, isWritable = false),
+                    NONE_ACCOUNT, // daily_stats
+                    NONE_ACCOUNT, // payer_profile
                 )
 
             return SolarmaInstruction(PROGRAM_ID, keys, buffer.array())
@@ -116,6 +160,9 @@ class SolarmaInstructionBuilder
 
         /**
          * Build claim instruction data and accounts.
+         * The owner receives the vault and also signs as `authority`; the
+         * optional profile, leaderboard, buddy, index, stats, destination,
+         * forgiveness and funder accounts are all passed as None.
          */
         fun buildClaim(
             owner: PublicKey,
@@ -129,8 +176,18 @@ class SolarmaInstructionBuilder
                 listOf(
                     AccountMeta(alarmPda, isSigner = false, isWritable = true),
                     AccountMeta(vaultPda.address, isSigner = false, isWritable = true),
-                    AccountMeta(owner, isSigner = true, isWritable = true),
+                    AccountMeta(owner, isSigner = false, isWritable = true),
+                    NONE_ACCOUNT, // user_profile
+                    NONE_ACCOUNT, // leaderboard_entry
+                    NONE_ACCOUNT, // leaderboard_epoch
+                    NONE_ACCOUNT, // buddy
+                    NONE_ACCOUNT, // alarm_index
+                    AccountMeta(owner, isSigner = true, isWritable = false), // authority
                     AccountMeta(SYSTEM_PROGRAM_ID, isSigner = false, isWritable = false),
+                    NONE_ACCOUNT, // daily_stats
+                    NONE_ACCOUNT, // claim_to
+                    NONE_ACCOUNT, // forgiveness_token
+                    NONE_ACCOUNT, // funder
                 )
 
             return SolarmaInstruction(PROGRAM_ID, keys, DISCRIMINATOR_CLAIM)
@@ -138,25 +195,44 @@ class SolarmaInstructionBuilder
 
         /**
          * H3: Build ack_awake instruction — records wake proof completion on-chain.
-         * Only needs alarm PDA + owner signer (no vault, no system program).
+         * The owner signs as `authority`; profile, vault, penalty recipient and
+         * config are None (no proof hash, commitment or soft deadline).
+         * @param proofCompletedAt unix seconds the wake proof was completed
+         * @param proofType completed `ProofType` code (0 = not reported)
          */
         fun buildAckAwake(
             owner: PublicKey,
             alarmPda: PublicKey,
+            proofCompletedAt: Long,
+            proofType: Int = 0,
         ): SolarmaInstruction {
-            Log.d(TAG, "Building ack_awake: alarm=${alarmPda.toBase58()}")
+            Log.d(TAG, "Building ack_awake: alarm=${alarmPda.toBase58()}, completedAt=$proofCompletedAt")
 
             val keys =
                 listOf(
                     AccountMeta(alarmPda, isSigner = false, isWritable = true),
-                    AccountMeta(owner, isSigner = true, isWritable = true),
+                    NONE_ACCOUNT, // user_profile
+                    AccountMeta(owner, isSigner = true, isWritable = true), // authority
+                    NONE_ACCOUNT, // vault
+                    NONE_ACCOUNT, // penalty_recipient
+                    NONE_ACCOUNT, // config
                 )
 
-            return SolarmaInstruction(PROGRAM_ID, keys, DISCRIMINATOR_ACK_AWAKE)
+            // Data: discriminator + proof_hash (None) + proof_completed_at (i64)
+            // + proof_type (u8) + proof_preimage (None)
+            val buffer = ByteBuffer.allocate(8 + 1 + 8 + 1 + 1).order(ByteOrder.LITTLE_ENDIAN)
+            buffer.put(DISCRIMINATOR_ACK_AWAKE)
+            buffer.put(0.toByte())
+            buffer.putLong(proofCompletedAt)
+            buffer.put(proofType.toByte())
+            buffer.put(0.toByte())
+
+            return SolarmaInstruction(PROGRAM_ID, keys, buffer.array())
         }
 
         /**
          * Build snooze instruction data and accounts.
+         * The owner signs as `authority`; the snooze length is the alarm's own.
          * @param expectedSnoozeCount current snooze count for idempotency guard
          */
         fun buildSnooze(
@@ -180,17 +256,21 @@ class SolarmaInstructionBuilder
                     AccountMeta(alarmPda, isSigner = false, isWritable = true),
                     AccountMeta(vaultPda.address, isSigner = false, isWritable = true),
                     AccountMeta(sinkAddress, isSigner = false, isWritable = true),
-                    AccountMeta(owner, isSigner = true, isWritable = true),
+                    AccountMeta(deriveConfigPda().address, isSigner = false, isWritable = false),
+                    NONE_ACCOUNT, // user_profile
+                    AccountMeta(owner, isSigner = true, isWritable = true), // authority
                     AccountMeta(SYSTEM_PROGRAM_ID, isSigner = false, isWritable = false),
                 )
 
             // Instruction data: 8-byte discriminator + 1-byte expected_snooze_count
-            val data = DISCRIMINATOR_SNOOZE + byteArrayOf(expectedSnoozeCount.toByte())
+            // + Option<u8> extension_minutes (None)
+            val data = DISCRIMINATOR_SNOOZE + byteArrayOf(expectedSnoozeCount.toByte(), 0)
             return SolarmaInstruction(PROGRAM_ID, keys, data)
         }
 
         /**
          * Build emergency_refund instruction data and accounts.
+         * Reads the global config; the alarm index is None.
          */
         fun buildEmergencyRefund(
             owner: PublicKey,
@@ -210,9 +290,12 @@ class SolarmaInstructionBuilder
                 listOf(
                     AccountMeta(alarmPda, isSigner = false, isWritable = true),
                     AccountMeta(vaultPda.address, isSigner = false, isWritable = true),
+                    AccountMeta(deriveConfigPda().address, isSigner = false, isWritable = false),
                     AccountMeta(sinkAddress, isSigner = false, isWritable = true),
+                    NONE_ACCOUNT, // alarm_index
                     AccountMeta(owner, isSigner = true, isWritable = true),
                     AccountMeta(SYSTEM_PROGRAM_ID, isSigner = false, isWritable = false),
+                    NONE_ACCOUNT, // funder
                 )
 
             return SolarmaInstruction(PROGRAM_ID, keys, DISCRIMINATOR_EMERGENCY_REFUND)
@@ -220,6 +303,7 @@ class SolarmaInstructionBuilder
 
         /**
          * Build slash instruction data and accounts.
+         * The protocol fee goes to the treasury; every optional account is None.
          */
         fun buildSlash(
             caller: PublicKey,
@@ -240,8 +324,19 @@ class SolarmaInstructionBuilder
                     AccountMeta(alarmPda, isSigner = false, isWritable = true),
                     AccountMeta(vaultPda.address, isSigner = false, isWritable = true),
                     AccountMeta(penaltyRecipient, isSigner = false, isWritable = true),
+                    AccountMeta(deriveConfigPda().address, isSigner = false, isWritable = false),
+                    AccountMeta(deriveTreasuryPda().address, isSigner = false, isWritable = true),
+                    NONE_ACCOUNT, // leaderboard_entry
+                    NONE_ACCOUNT, // leaderboard_epoch
+                    NONE_ACCOUNT, // alarm_index
+                    NONE_ACCOUNT, // user_profile
+                    NONE_ACCOUNT, // referrer
                     AccountMeta(caller, isSigner = true, isWritable = true),
                     AccountMeta(SYSTEM_PROGRAM_ID, isSigner = false, isWritable = false),
+                    NONE_ACCOUNT, // memo_program
+                    NONE_ACCOUNT, // daily_stats
+                    NONE_ACCOUNT, // insurance_pool
+                    NONE_ACCOUNT, // campaign
                 )
 
             return SolarmaInstruction(PROGRAM_ID, keys, DISCRIMINATOR_SLASH)
@@ -268,6 +363,30 @@ class SolarmaInstructionBuilder
             return findProgramDerivedAddress(seeds, PROGRAM_ID)
         }
 
+        /**
+         * Derive the owner's user profile PDA.
+         */
+        fun deriveUserProfilePda(owner: PublicKey): PdaResult {
+            val seeds = listOf("user-profile".toByteArray(), owner.bytes())
+            return findProgramDerivedAddress(seeds, PROGRAM_ID)
+        }
+
+        /**
+         * Derive the global config PDA.
+         */
+        fun deriveConfigPda(): PdaResult {
+            val seeds = listOf("config".toByteArray())
+            return findProgramDerivedAddress(seeds, PROGRAM_ID)
+        }
+
+        /**
+         * Derive the protocol fee treasury PDA.
+         */
+        fun deriveTreasuryPda(): PdaResult {
+            val seeds = listOf("treasury".toByteArray())
+            return findProgramDerivedAddress(seeds, PROGRAM_ID)
+        }
+
         /**
          * Find program derived address with proper on-curve check.
          * Matches Solana's find_program_address algorithm.
@@ -75,6 +75,8 @@ class TransactionBuilder
 
         /**
          * Build create_alarm transaction.
+         * A Buddy alarm needs the owner's profile, so an initialize
+         * instruction is prepended when the profile does not exist yet.
          * Returns serialized transaction ready for MWA signing.
          */
         suspend fun buildCreateAlarmTransaction(
@@ -105,7 +107,17 @@ class TransactionBuilder
                             },
                     )
 
-                buildTransaction(owner, instruction)
+                val profilePda = instructionBuilder.deriveUserProfilePda(owner).address
+                val needsProfile =
+                    penaltyRoute == PenaltyRoute.BUDDY &&
+                        buddyAddress != null &&
+                        !rpcClient.accountExists(profilePda.toBase58()).getOrThrow()
+                if (needsProfile) {
+                    Log.i(TAG, "Owner profile missing, prepending initialize")
+                    buildTransaction(owner, listOf(instructionBuilder.buildInitialize(owner), instruction))
+                } else {
+                    buildTransaction(owner, instruction)
+                }
             }
 
         /**
@@ -143,10 +155,12 @@ class TransactionBuilder
 
         /**
          * H3: Build ack_awake transaction to record wake proof on-chain.
+         * @param proofCompletedAt unix seconds the wake proof was completed
          */
         suspend fun buildAckAwakeTransactionByPubkey(
             owner: PublicKey,
             alarmPda: PublicKey,
+            proofCompletedAt: Long,
         ): ByteArray =
             withContext(Dispatchers.IO) {
                 Log.i(TAG, "Building ack_awake tx: alarmPda=${alarmPda.toBase58()}")
@@ -155,6 +169,7 @@ class TransactionBuilder
                     instructionBuilder.buildAckAwake(
                         owner = owner,
                         alarmPda = alarmPda,
+                        proofCompletedAt = proofCompletedAt,
                     )
 
                 buildTransaction(owner, instruction)
@@ -286,6 +301,15 @@ class TransactionBuilder
         private suspend fun buildTransaction(
             feePayer: PublicKey,
             instruction: SolarmaInstruction,
+        ): ByteArray = buildTransaction(feePayer, listOf(instruction))
+
+        /**
+         * Build and serialize a transaction with compute budget instructions
+         * followed by the given program instructions, in order.
+         */
+        private suspend fun buildTransaction(
+            feePayer: PublicKey,
+            instructions: List<SolarmaInstruction>,
         ): ByteArray {
             // Get recent blockhash
             val blockhash = rpcClient.getLatestBlockhash()
@@ -296,8 +320,7 @@ class TransactionBuilder
                 listOf(
                     buildSetComputeUnitLimitIx(COMPUTE_UNIT_LIMIT),
                     buildSetComputeUnitPriceIx(COMPUTE_UNIT_PRICE),
-                    instruction,
-                )
+                ) + instructions
 
             // Build sorted account list with metadata
             val sortedAccounts = buildSortedAccountMetas(feePayer, allInstructions)
@@ -306,22 +329,24 @@ class TransactionBuilder
                 Log.d(TAG, "  [$i] ${acc.pubkey.toBase58()} signer=${acc.isSigner} writable=${acc.isWritable}")
             }
 
-            // Log instruction accounts order
-            Log.d(TAG, "Instruction accounts order:")
-            instruction.accounts.forEachIndexed { i, meta ->
-                val idx = sortedAccounts.indexOfFirst { it.pubkey.toBase58() == meta.pubkey.toBase58() }
-                Log.d(TAG, "  [$i] -> index $idx (${meta.pubkey.toBase58()})")
-            }
+            for (instruction in instructions) {
+                // Log instruction accounts order
+                Log.d(TAG, "Instruction accounts order:")
+                instruction.accounts.forEachIndexed { i, meta ->
+                    val idx = sortedAccounts.indexOfFirst { it.pubkey.toBase58() == meta.pubkey.toBase58() }
+                    Log.d(TAG, "  [$i] -> index $idx (${meta.pubkey.toBase58()})")
+                }
 
-            // Log instruction data
-            Log.d(TAG, "Instruction data (${instruction.data.size} bytes): ${instruction.data.joinToString("") { "%02x".format(it) }}")
+                // Log instruction data
+                Log.d(TAG, "Instruction data (${instruction.data.size} bytes): ${instruction.data.joinToString("") { "%02x".format(it) }}")
+            }
 
             // Build message with all instructions
             val message = buildMessage(blockhash, sortedAccounts, allInstructions)
 
             // Return message for MWA signing
             val tx = buildUnsignedTransaction(message)
-            Log.i(TAG, "Final TX size: ${tx.size} bytes (3 instructions: 2 compute budget + 1 program)")
+            Log.i(TAG, "Final TX size: ${tx.size} bytes (${allInstructions.size} instructions: 2 compute budget + ${instructions.size} program)")
             Log.d(TAG, "Final TX hex: ${tx.joinToString("") { "%02x".format(it) }}")
             return tx
         }
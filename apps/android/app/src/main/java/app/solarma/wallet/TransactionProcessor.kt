@@ -173,7 +173,8 @@ class TransactionProcessor
                                         Log.w(TAG, "AckAwake skipped: deadline passed for alarm ${alarm.id}")
                                         continue
                                     }
-                                    transactionBuilder.buildAckAwakeTransactionByPubkey(owner, alarmPda)
+                                    // Queued right after the wake proof, so the enqueue time is its completion
+                                    transactionBuilder.buildAckAwakeTransactionByPubkey(owner, alarmPda, tx.createdAt / 1000)
                                 }
                                 "SNOOZE" -> transactionBuilder.buildSnoozeTransactionByPubkey(owner, alarmPda, alarm.snoozeCount)
                                 "SLASH" -> {
@@ -143,7 +143,7 @@ class SolarmaInstructionBuilderExpandedTest {
     fun `ack_awake discriminator matches Anchor convention`() {
         val expected = sha256("global:ack_awake").copyOfRange(0, 8)
         val alarmPda = builder.deriveAlarmPda(owner, 1L).address
-        val ix = builder.buildAckAwake(owner = owner, alarmPda = alarmPda)
+        val ix = builder.buildAckAwake(owner = owner, alarmPda = alarmPda, proofCompletedAt = 0L)
         assertArrayEquals("ack_awake discriminator", expected, ix.data.copyOfRange(0, 8))
     }
 
@@ -214,81 +214,153 @@ class SolarmaInstructionBuilderExpandedTest {
     // =========================================================================
 
     @Test
-    fun `buildCreateAlarm accounts are alarm, vault, owner, system`() {
+    fun `buildCreateAlarm accounts follow the program's CreateAlarm order`() {
         val ix =
             builder.buildCreateAlarm(
                 owner = owner, alarmId = 1L, alarmTime = 0L, deadline = 0L,
                 depositLamports = 0L, penaltyRoute = 0,
             )
 
-        assertEquals(4, ix.accounts.size)
+        assertEquals(12, ix.accounts.size)
         // Alarm PDA
         assertFalse(ix.accounts[0].isSigner)
         assertTrue(ix.accounts[0].isWritable)
         // Vault PDA
         assertFalse(ix.accounts[1].isSigner)
         assertTrue(ix.accounts[1].isWritable)
-        // Owner (signer)
+        // Config PDA (read-only)
+        assertEquals(builder.deriveConfigPda().address, ix.accounts[2].pubkey)
+        assertFalse(ix.accounts[2].isWritable)
+        // Optional accounts are passed as the program ID (None)
+        for (i in listOf(3, 4, 5, 6, 10, 11)) {
+            assertEquals(SolarmaInstructionBuilder.PROGRAM_ID, ix.accounts[i].pubkey)
+        }
+        // Owner signs and pays
+        for (i in listOf(7, 8)) {
+            assertEquals(owner, ix.accounts[i].pubkey)
+            assertTrue(ix.accounts[i].isSigner)
+            assertTrue(ix.accounts[i].isWritable)
+        }
+        // System Program
+        assertFalse(ix.accounts[9].isSigner)
+        assertFalse(ix.accounts[9].isWritable)
+    }
+
+    @Test
+    fun `buildCreateAlarm passes the owner's profile for a Buddy destination`() {
+        val buddy = PublicKey("22222222222222222222222222222222")
+        val ix =
+            builder.buildCreateAlarm(
+                owner = owner, alarmId = 1L, alarmTime = 0L, deadline = 0L,
+                depositLamports = 0L, penaltyRoute = 2, penaltyDestination = buddy,
+            )
+
+        assertEquals(12, ix.accounts.size)
+        assertEquals(builder.deriveUserProfilePda(owner).address, ix.accounts[5].pubkey)
+        assertFalse(ix.accounts[5].isSigner)
+        assertTrue(ix.accounts[5].isWritable)
+    }
+
+    @Test
+    fun `buildInitialize accounts follow the program's Initialize order`() {
+        val ix = builder.buildInitialize(owner)
+
+        assertEquals(4, ix.accounts.size)
+        assertEquals(builder.deriveUserProfilePda(owner).address, ix.accounts[0].pubkey)
+        assertTrue(ix.accounts[0].isWritable)
+        // Owner signs and pays
+        assertTrue(ix.accounts[1].isSigner)
         assertTrue(ix.accounts[2].isSigner)
         assertTrue(ix.accounts[2].isWritable)
-        // System Program
-        assertFalse(ix.accounts[3].isSigner)
-        assertFalse(ix.accounts[3].isWritable)
+        assertEquals(SYSTEM_PROGRAM_ID, ix.accounts[3].pubkey)
+        assertEquals(8, ix.data.size)
     }
 
     @Test
-    fun `buildClaim accounts are alarm, vault, owner, system`() {
+    fun `buildClaim accounts follow the program's Claim order`() {
         val alarmPda = builder.deriveAlarmPda(owner, 1L).address
         val ix = builder.buildClaim(owner = owner, alarmPda = alarmPda)
 
-        assertEquals(4, ix.accounts.size)
-        assertTrue(ix.accounts[2].isSigner)
-        assertFalse(ix.accounts[3].isWritable) // system program
+        assertEquals(14, ix.accounts.size)
+        // Owner receives the vault
+        assertEquals(owner, ix.accounts[2].pubkey)
+        assertFalse(ix.accounts[2].isSigner)
+        assertTrue(ix.accounts[2].isWritable)
+        // Optional accounts are passed as the program ID (None)
+        for (i in listOf(3, 4, 5, 6, 7, 10, 11, 12, 13)) {
+            assertEquals(SolarmaInstructionBuilder.PROGRAM_ID, ix.accounts[i].pubkey)
+            assertFalse(ix.accounts[i].isWritable)
+        }
+        // Owner signs as authority
+        assertEquals(owner, ix.accounts[8].pubkey)
+        assertTrue(ix.accounts[8].isSigner)
+        assertEquals(SYSTEM_PROGRAM_ID, ix.accounts[9].pubkey)
+        assertFalse(ix.accounts[9].isWritable) // system program
     }
 
     @Test
-    fun `buildAckAwake has only 2 accounts`() {
+    fun `buildAckAwake accounts follow the program's AckAwake order`() {
         val alarmPda = builder.deriveAlarmPda(owner, 1L).address
-        val ix = builder.buildAckAwake(owner = owner, alarmPda = alarmPda)
+        val ix = builder.buildAckAwake(owner = owner, alarmPda = alarmPda, proofCompletedAt = 0L)
 
-        assertEquals(2, ix.accounts.size)
+        assertEquals(6, ix.accounts.size)
         // Alarm PDA
         assertFalse(ix.accounts[0].isSigner)
         assertTrue(ix.accounts[0].isWritable)
-        // Owner
-        assertTrue(ix.accounts[1].isSigner)
-        assertTrue(ix.accounts[1].isWritable)
+        // Owner signs as authority
+        assertEquals(owner, ix.accounts[2].pubkey)
+        assertTrue(ix.accounts[2].isSigner)
+        assertTrue(ix.accounts[2].isWritable)
+        // user_profile, vault, penalty_recipient and config are None
+        for (i in listOf(1, 3, 4, 5)) {
+            assertEquals(SolarmaInstructionBuilder.PROGRAM_ID, ix.accounts[i].pubkey)
+        }
     }
 
     @Test
-    fun `buildSnooze has 5 accounts with sink`() {
+    fun `buildSnooze has 7 accounts with sink and config`() {
         val alarmPda = builder.deriveAlarmPda(owner, 1L).address
         val sink = PublicKey("22222222222222222222222222222222")
         val ix = builder.buildSnooze(owner = owner, alarmPda = alarmPda, sinkAddress = sink, expectedSnoozeCount = 5)
 
-        assertEquals(5, ix.accounts.size)
+        assertEquals(7, ix.accounts.size)
         assertEquals(sink, ix.accounts[2].pubkey) // sink address
         assertTrue(ix.accounts[2].isWritable) // sink receives funds
+        assertEquals(builder.deriveConfigPda().address, ix.accounts[3].pubkey)
+        assertEquals(SolarmaInstructionBuilder.PROGRAM_ID, ix.accounts[4].pubkey) // user_profile: None
+        assertEquals(owner, ix.accounts[5].pubkey) // authority
+        assertTrue(ix.accounts[5].isSigner)
     }
 
     @Test
-    fun `buildEmergencyRefund has 5 accounts with sink`() {
+    fun `buildEmergencyRefund has 8 accounts with config and sink`() {
         val alarmPda = builder.deriveAlarmPda(owner, 1L).address
         val sink = PublicKey("22222222222222222222222222222222")
         val ix = builder.buildEmergencyRefund(owner = owner, alarmPda = alarmPda, sinkAddress = sink)
 
-        assertEquals(5, ix.accounts.size)
-        assertEquals(sink, ix.accounts[2].pubkey)
+        assertEquals(8, ix.accounts.size)
+        assertEquals(builder.deriveConfigPda().address, ix.accounts[2].pubkey)
+        assertFalse(ix.accounts[2].isWritable)
+        assertEquals(sink, ix.accounts[3].pubkey)
+        assertEquals(SolarmaInstructionBuilder.PROGRAM_ID, ix.accounts[4].pubkey) // alarm_index: None
+        assertTrue(ix.accounts[5].isSigner) // owner
+        assertEquals(SolarmaInstructionBuilder.PROGRAM_ID, ix.accounts[7].pubkey) // funder: None
     }
 
     @Test
-    fun `buildSlash has 5 accounts with penalty recipient`() {
+    fun `buildSlash has 16 accounts with penalty recipient and treasury`() {
         val alarmPda = builder.deriveAlarmPda(owner, 1L).address
         val recipient = PublicKey("33333333333333333333333333333333")
         val ix = builder.buildSlash(caller = owner, alarmPda = alarmPda, penaltyRecipient = recipient)
 
-        assertEquals(5, ix.accounts.size)
+        assertEquals(16, ix.accounts.size)
         assertEquals(recipient, ix.accounts[2].pubkey)
+        assertEquals(builder.deriveConfigPda().address, ix.accounts[3].pubkey)
+        assertFalse(ix.accounts[3].isWritable)
+        assertEquals(builder.deriveTreasuryPda().address, ix.accounts[4].pubkey)
+        assertTrue(ix.accounts[4].isWritable) // treasury receives the protocol fee
+        assertEquals(owner, ix.accounts[10].pubkey) // caller
+        assertTrue(ix.accounts[10].isSigner)
     }
 
     // =========================================================================
@@ -304,7 +376,7 @@ class SolarmaInstructionBuilderExpandedTest {
             listOf(
                 builder.buildCreateAlarm(owner, 1L, 0L, 0L, 0L, 0),
                 builder.buildClaim(owner, alarmPda),
-                builder.buildAckAwake(owner, alarmPda),
+                builder.buildAckAwake(owner, alarmPda, 0L),
                 builder.buildSnooze(owner, alarmPda, sink, 0),
                 builder.buildEmergencyRefund(owner, alarmPda, sink),
                 builder.buildSlash(owner, alarmPda, sink),
@@ -326,7 +398,7 @@ class SolarmaInstructionBuilderExpandedTest {
 
         for (count in listOf(0, 1, 5, 9, 255)) {
             val ix = builder.buildSnooze(owner, alarmPda, sink, count)
-            assertEquals(9, ix.data.size)
+            assertEquals(10, ix.data.size)
             assertEquals(count.toByte(), ix.data[8])
         }
     }
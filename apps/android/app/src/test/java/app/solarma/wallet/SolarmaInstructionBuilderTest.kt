@@ -57,8 +57,8 @@ class SolarmaInstructionBuilderTest {
         // Verify program ID
         assertEquals(SolarmaInstructionBuilder.PROGRAM_ID, instruction.programId)
 
-        // Verify we have 4 accounts (alarm, vault, owner, system)
-        assertEquals(4, instruction.accounts.size)
+        // alarm, vault, config, 4 optional, owner, payer, system, daily_stats, payer_profile
+        assertEquals(12, instruction.accounts.size)
     }
 
     @Test
@@ -80,7 +80,8 @@ class SolarmaInstructionBuilderTest {
 
         // Data should be longer when destination is included
         // 8 (discriminator) + 8 (alarmId) + 8 (time) + 8 (deadline) + 8 (deposit) + 1 (route) + 1 (Some) + 32 (pubkey)
-        assertEquals(8 + 8 + 8 + 8 + 8 + 1 + 1 + 32, instruction.data.size)
+        // + 1 (options None)
+        assertEquals(8 + 8 + 8 + 8 + 8 + 1 + 1 + 32 + 1, instruction.data.size)
     }
 
     @Test
@@ -96,7 +97,8 @@ class SolarmaInstructionBuilderTest {
 
         // Claim only has discriminator
         assertEquals(8, instruction.data.size)
-        assertEquals(4, instruction.accounts.size)
+        // alarm, vault, owner, 5 optional, authority, system, 4 optional
+        assertEquals(14, instruction.accounts.size)
     }
 
     @Test
@@ -113,11 +115,11 @@ class SolarmaInstructionBuilderTest {
                 expectedSnoozeCount = 0,
             )
 
-        // Snooze has 5 accounts (alarm, vault, sink, owner, system)
-        assertEquals(5, instruction.accounts.size)
+        // Snooze has 7 accounts (alarm, vault, sink, config, user_profile, authority, system)
+        assertEquals(7, instruction.accounts.size)
         assertEquals(sink, instruction.accounts[2].pubkey)
-        // Data: 8-byte discriminator + 1-byte expected_snooze_count
-        assertEquals(9, instruction.data.size)
+        // Data: 8-byte discriminator + 1-byte expected_snooze_count + 1-byte extension None
+        assertEquals(10, instruction.data.size)
     }
 
     @Test
@@ -134,8 +136,8 @@ class SolarmaInstructionBuilderTest {
             )
 
         assertEquals(8, instruction.data.size)
-        assertEquals(5, instruction.accounts.size)
-        assertEquals(sink, instruction.accounts[2].pubkey)
+        assertEquals(8, instruction.accounts.size)
+        assertEquals(sink, instruction.accounts[3].pubkey)
     }
 
     @Test
@@ -152,7 +154,7 @@ class SolarmaInstructionBuilderTest {
             )
 
         assertEquals(8, instruction.data.size)
-        assertEquals(5, instruction.accounts.size)
+        assertEquals(16, instruction.accounts.size)
         assertEquals(recipient, instruction.accounts[2].pubkey)
     }
 }
@@ -3,6 +3,8 @@ package app.solarma.wallet
 import org.junit.Assert.*
 import org.junit.Test
 import org.sol4k.PublicKey
+import java.nio.ByteBuffer
+import java.nio.ByteOrder
 
 /**
  * Deep coverage tests for TransactionBuilder.
@@ -87,7 +89,7 @@ class TransactionBuilderTest {
                         owner, 42L, 1700000000L, 1700001800L, 100_000_000L, 0, null,
                     ),
                 "claim" to instructionBuilder.buildClaim(owner, alarmPda),
-                "ack_awake" to instructionBuilder.buildAckAwake(owner, alarmPda),
+                "ack_awake" to instructionBuilder.buildAckAwake(owner, alarmPda, 1700000060L),
                 "snooze" to instructionBuilder.buildSnooze(owner, alarmPda, sink, 0),
                 "emergency_refund" to instructionBuilder.buildEmergencyRefund(owner, alarmPda, sink),
                 "slash" to instructionBuilder.buildSlash(owner, alarmPda, sink),
@@ -377,8 +379,9 @@ class TransactionBuilderTest {
                 penaltyDestination = treasury,
             )
 
-        // Data includes Option<Pubkey> = Some(treasury) → 1 + 32 bytes extra
-        assertEquals(8 + 8 + 8 + 8 + 8 + 1 + 1 + 32, ix.data.size)
+        // Data includes Option<Pubkey> = Some(treasury) → 1 + 32 bytes extra,
+        // then Option<AlarmOptions> = None
+        assertEquals(8 + 8 + 8 + 8 + 8 + 1 + 1 + 32 + 1, ix.data.size)
     }
 
     @Test
@@ -394,8 +397,8 @@ class TransactionBuilderTest {
                 penaltyDestination = null,
             )
 
-        // No destination: Option<Pubkey> = None → 1 byte only
-        assertEquals(8 + 8 + 8 + 8 + 8 + 1 + 1, ix.data.size)
+        // No destination: Option<Pubkey> = None → 1 byte only, options None → 1 byte
+        assertEquals(8 + 8 + 8 + 8 + 8 + 1 + 1 + 1, ix.data.size)
     }
 
     // =========================================================================
@@ -414,18 +417,24 @@ class TransactionBuilderTest {
             )
 
         // Snooze instruction data: 8-byte discriminator + 1-byte expected_snooze_count
-        assertEquals(9, ix.data.size)
-        assertEquals(3.toByte(), ix.data[8]) // Last byte is the count
+        // + 1-byte extension_minutes None
+        assertEquals(10, ix.data.size)
+        assertEquals(3.toByte(), ix.data[8])
+        assertEquals(0.toByte(), ix.data[9])
     }
 
     @Test
-    fun `snapshot transaction for ack_awake has minimal accounts`() {
+    fun `snapshot transaction for ack_awake encodes proof completion`() {
         val alarmPda = instructionBuilder.deriveAlarmPda(owner, 7L).address
-        val ix = instructionBuilder.buildAckAwake(owner = owner, alarmPda = alarmPda)
-
-        // ack_awake only needs 2 accounts (alarmPda + owner)
-        assertEquals(2, ix.accounts.size)
-        assertEquals(8, ix.data.size) // discriminator only
+        val ix = instructionBuilder.buildAckAwake(owner = owner, alarmPda = alarmPda, proofCompletedAt = 1700000060L)
+
+        // alarm, user_profile, authority, vault, penalty_recipient, config
+        assertEquals(6, ix.accounts.size)
+        // discriminator + proof_hash None + proof_completed_at + proof_type + proof_preimage None
+        assertEquals(8 + 1 + 8 + 1 + 1, ix.data.size)
+        assertEquals(0.toByte(), ix.data[8])
+        val completedAt = ByteBuffer.wrap(ix.data, 9, 8).order(ByteOrder.LITTLE_ENDIAN).long
+        assertEquals(1700000060L, completedAt)
     }
 
     // =========================================================================
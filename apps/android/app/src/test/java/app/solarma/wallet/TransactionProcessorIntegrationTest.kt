@@ -118,7 +118,7 @@ class TransactionProcessorIntegrationTest {
             processor.processPendingTransactionsWithUi(activityResultSender)
 
             verify(transactionDao).updateStatus(eq(100L), eq("FAILED"), argThat { contains("Deadline passed") }, any())
-            verify(transactionBuilder, never()).buildAckAwakeTransactionByPubkey(any(), any())
+            verify(transactionBuilder, never()).buildAckAwakeTransactionByPubkey(any(), any(), any())
         }
 
     // ── ErrorClassifier tests ──
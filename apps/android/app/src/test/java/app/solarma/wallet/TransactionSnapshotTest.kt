@@ -68,9 +68,15 @@ class TransactionSnapshotTest {
                     "ack_awake" -> {
                         val owner = PublicKey(inputs.getString("owner"))
                         val alarmId = inputs.getLong("alarmId")
+                        val proofCompletedAt = inputs.getLong("proofCompletedAt")
 
                         val pda = instructionBuilder.deriveAlarmPda(owner, alarmId)
-                        val ix = instructionBuilder.buildAckAwake(owner = owner, alarmPda = pda.address)
+                        val ix =
+                            instructionBuilder.buildAckAwake(
+                                owner = owner,
+                                alarmPda = pda.address,
+                                proofCompletedAt = proofCompletedAt,
+                            )
                         Quad(owner, ix, pda.address, null)
                     }
 
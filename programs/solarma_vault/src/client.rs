@@ -0,0 +1,544 @@
+//! Instruction builders for off-chain Rust clients (bots, keepers, tests)
+//!
+//! Enabled by the `client` feature. Each function takes the
+//! Anchor-generated `accounts::*` struct plus the instruction arguments and
+//! returns a ready-to-sign `Instruction` for this program, with no IDL
+//! parsing and no `anchor-client`. The returned type is the
+//! `solana_program` `Instruction`, the same type `solana_sdk::instruction`
+//! re-exports. Derive account addresses with [`crate::pda`].
+//!
+//! Optional accounts left as `None` are encoded as the program id, which
+//! Anchor reads as "not provided".
+
+// Builders mirror each handler's argument list.
+#![allow(clippy::too_many_arguments)]
+
+use crate::state;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+
+/// Assemble an instruction for this program from any accounts struct and
+/// instruction data, e.g. for `remaining_accounts` that must be appended.
+pub fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+macro_rules! builders {
+    ($(
+        $(#[$doc:meta])*
+        $name:ident($accounts:ident $(, $arg:ident: $ty:ty)*) => $data:ident;
+    )*) => {
+        $(
+            $(#[$doc])*
+            pub fn $name(accounts: crate::accounts::$accounts $(, $arg: $ty)*) -> Instruction {
+                build(accounts, crate::instruction::$data { $($arg),* })
+            }
+        )*
+    };
+}
+
+builders! {
+    /// Initialize a user profile with optional tag registration
+    initialize(Initialize) => Initialize;
+
+    /// Initialize user profile with a referrer who earns a share of the
+    /// protocol fees this user's alarms generate
+    initialize_with_referrer(Initialize, referrer: Pubkey) => InitializeWithReferrer;
+
+    /// Create a new alarm with optional deposit and per-alarm options
+    create_alarm(
+        CreateAlarm,
+        alarm_id: u64,
+        alarm_time: i64,
+        deadline: i64,
+        deposit_amount: u64,
+        penalty_route: u8,
+        penalty_destination: Option<Pubkey>,
+        options: Option<state::AlarmOptions>
+    ) => CreateAlarm;
+
+    /// Claim the remaining deposit (for acknowledged alarms, with grace)
+    claim(Claim) => Claim;
+
+    /// Snooze the alarm (reduces deposit).
+    /// `expected_snooze_count` — current snooze count (idempotency guard).
+    /// `extension_minutes` — optional 1–15 minute length, priced pro rata.
+    snooze(Snooze, expected_snooze_count: u8, extension_minutes: Option<u8>) => Snooze;
+
+    /// Slash the deposit after deadline (Created only; buddy-only subwindow for Buddy route)
+    slash(Slash) => Slash;
+
+    /// Permissionless sweep after claim grace for acknowledged alarms.
+    sweep_acknowledged(SweepAcknowledged) => SweepAcknowledged;
+
+    /// Emergency refund - owner can cancel before alarm time
+    emergency_refund(EmergencyRefund) => EmergencyRefund;
+
+    /// H3: Record wake proof completion on-chain
+    ack_awake(
+        AckAwake,
+        proof_hash: Option<[u8; 32]>,
+        proof_completed_at: i64,
+        proof_type: u8,
+        proof_preimage: Option<[u8; 32]>
+    ) => AckAwake;
+
+    /// Replace the co-owner keys allowed to ack/snooze/claim (owner only)
+    set_co_owners(SetCoOwners, co_owners: Vec<Pubkey>) => SetCoOwners;
+
+    /// Create a fully-funded alarm for another user (PendingGift until accepted)
+    create_gift_alarm(
+        CreateGiftAlarm,
+        alarm_id: u64,
+        alarm_time: i64,
+        deadline: i64,
+        deposit_amount: u64,
+        penalty_route: u8,
+        penalty_destination: Option<Pubkey>
+    ) => CreateGiftAlarm;
+
+    /// Recipient accepts a pending gift alarm (PendingGift → Created)
+    accept_gift(AcceptGift) => AcceptGift;
+
+    /// Recipient declines a pending gift; funds and rent return to gifter
+    decline_gift(DeclineGift) => DeclineGift;
+
+    /// Permissionless return of an unaccepted gift after alarm time
+    expire_gift(ExpireGift) => ExpireGift;
+
+    /// Offer a matched-deposit bet on an alarm to a designated challenger
+    offer_bet(OfferBet, challenger: Pubkey) => OfferBet;
+
+    /// Challenger matches the owner's deposit into the same vault
+    join_bet(JoinBet) => JoinBet;
+
+    /// Create a recurring alarm series backed by a single escrowed deposit
+    create_series(
+        CreateSeries,
+        series_id: u64,
+        first_alarm_time: i64,
+        window_seconds: i64,
+        interval_seconds: i64,
+        occurrences: u16,
+        deposit_per_occurrence: u64,
+        penalty_route: u8,
+        penalty_destination: Option<Pubkey>
+    ) => CreateSeries;
+
+    /// Spawn the next occurrence of a series as a funded Alarm
+    spawn_next_occurrence(SpawnNextOccurrence, alarm_id: u64) => SpawnNextOccurrence;
+
+    /// Cancel a series and return the unused escrow to the owner
+    cancel_series(CancelSeries) => CancelSeries;
+
+    /// Initialize the global protocol config (program upgrade authority only)
+    init_config(InitConfig, admin: Pubkey) => InitConfig;
+
+    /// Update global protocol parameters (config admin only)
+    update_config(UpdateConfig, update: state::ConfigUpdate) => UpdateConfig;
+
+    /// Register the user's NFC/QR tag hash (first time only)
+    register_tag(RegisterTag, tag_hash: [u8; 32]) => RegisterTag;
+
+    /// Replace the registered tag hash, proving knowledge of the old one
+    rotate_tag(RotateTag, old_hash: [u8; 32], new_hash: [u8; 32]) => RotateTag;
+
+    /// Withdraw accumulated protocol fees from the treasury (config admin, or
+    /// 2 of 3 treasury signers once set; pass the others as signing remaining accounts)
+    withdraw_treasury(WithdrawTreasury, amount: u64) => WithdrawTreasury;
+
+    /// Close a claimed or slashed alarm and return its rent to the owner
+    close_alarm(CloseAlarm) => CloseAlarm;
+
+    /// Register a verified charity for Donate-route alarms (config admin only)
+    add_charity(AddCharity, address: Pubkey, name_hash: [u8; 32]) => AddCharity;
+
+    /// Remove a charity from the registry (config admin only)
+    remove_charity(RemoveCharity) => RemoveCharity;
+
+    /// Create a Buddy-route alarm that activates only once the buddy co-signs
+    create_alarm_pending_buddy(
+        CreateAlarm,
+        alarm_id: u64,
+        alarm_time: i64,
+        deadline: i64,
+        deposit_amount: u64,
+        penalty_destination: Option<Pubkey>,
+        options: Option<state::AlarmOptions>
+    ) => CreateAlarmPendingBuddy;
+
+    /// Buddy co-signs a pending Buddy-route alarm (PendingBuddy → Created)
+    buddy_accept(BuddyAccept) => BuddyAccept;
+
+    /// Owner withdraws a Buddy alarm the buddy has not accepted (no penalty)
+    cancel_buddy_request(CancelBuddyRequest) => CancelBuddyRequest;
+
+    /// Open a group wake challenge with a fixed per-participant stake
+    create_challenge(
+        CreateChallenge,
+        challenge_id: u64,
+        wake_time: i64,
+        deadline: i64,
+        deposit_amount: u64
+    ) => CreateChallenge;
+
+    /// Stake into a challenge before its wake time
+    join_challenge(JoinChallenge) => JoinChallenge;
+
+    /// Record a participant's wake-up before the shared deadline
+    ack_challenge(AckChallenge) => AckChallenge;
+
+    /// Settle one participant after the deadline (permissionless)
+    settle_challenge(SettleChallenge) => SettleChallenge;
+
+    /// Slash the tranches of a progressive alarm that are due (permissionless, repeatable)
+    slash_partial(SlashPartial) => SlashPartial;
+
+    /// Recover a time-decayed share of an unslashed deposit after the deadline
+    claim_late(ClaimLate) => ClaimLate;
+
+    /// Acknowledge wake-up with a server-signed Ed25519 permit (one-time nonce)
+    ack_awake_attested(
+        AckAwakeAttested,
+        nonce: u64,
+        expires_at: i64,
+        proof_hash: [u8; 32]
+    ) => AckAwakeAttested;
+
+    /// Claim an acknowledged alarm with a server-signed Ed25519 permit (one-time nonce)
+    claim_attested(ClaimAttested, nonce: u64, expires_at: i64) => ClaimAttested;
+
+    /// Create the attestation signer PDA (config admin only)
+    init_attestation_config(InitAttestationConfig, key: Pubkey) => InitAttestationConfig;
+
+    /// Rotate the attestation signer; the old key validates for `transition_seconds`
+    set_attestation_key(
+        SetAttestationKey,
+        new_key: Pubkey,
+        transition_seconds: i64
+    ) => SetAttestationKey;
+
+    /// Register additional attestor keys and the M-of-N signature threshold
+    set_attestors(SetAttestors, attestors: Vec<Pubkey>, threshold: u8) => SetAttestors;
+
+    /// Reclaim rent from an expired permit nonce once its alarm is terminal
+    close_permit_nonce(ClosePermitNonce) => ClosePermitNonce;
+
+    /// Move alarm_time and deadline before the alarm fires (owner only, no penalty)
+    reschedule_alarm(RescheduleAlarm, new_alarm_time: i64, new_deadline: i64) => RescheduleAlarm;
+
+    /// Mint an achievement badge once profile counters cross its threshold
+    claim_achievement(ClaimAchievement, badge_id: u8) => ClaimAchievement;
+
+    /// Open the weekly leaderboard epoch PDA (permissionless)
+    init_leaderboard_epoch(InitLeaderboardEpoch, epoch: u64) => InitLeaderboardEpoch;
+
+    /// Open the caller's leaderboard entry for a current or future epoch
+    join_leaderboard(JoinLeaderboard) => JoinLeaderboard;
+
+    /// Freeze an ended leaderboard epoch and publish its totals (permissionless)
+    finalize_epoch(FinalizeEpoch) => FinalizeEpoch;
+
+    /// Register (or clear) a session key allowed to ack and claim for the owner
+    set_delegate(SetDelegate, delegate: Pubkey, expires_at: i64) => SetDelegate;
+
+    /// Let anyone push the claim to the owner once the alarm is acknowledged
+    authorize_auto_claim(AuthorizeAutoClaim) => AuthorizeAutoClaim;
+
+    /// Claim a pre-authorized acknowledged alarm to its owner (permissionless)
+    auto_claim(AutoClaim) => AutoClaim;
+
+    /// Open a head-to-head duel against `opponent`, staking `stake`
+    create_duel(
+        CreateDuel,
+        duel_id: u64,
+        opponent: Pubkey,
+        stake: u64,
+        creator_wake_time: i64,
+        creator_deadline: i64,
+        opponent_wake_time: i64,
+        opponent_deadline: i64
+    ) => CreateDuel;
+
+    /// Match the stake of a duel you were invited to
+    accept_duel(AcceptDuel) => AcceptDuel;
+
+    /// Acknowledge your wake-up in a duel, within your own window
+    ack_duel(AckDuel) => AckDuel;
+
+    /// Pay out both sides of a duel and close it (permissionless)
+    settle_duel(SettleDuel) => SettleDuel;
+
+    /// Create the caller's savings vault for `PenaltyRoute::Savings`
+    init_savings_vault(InitSavingsVault, lock_seconds: i64) => InitSavingsVault;
+
+    /// Withdraw unlocked savings to the owner
+    withdraw_savings(WithdrawSavings) => WithdrawSavings;
+
+    /// Read-only: alarm status and which actions are currently open
+    get_alarm_status(ViewAlarm) => GetAlarmStatus;
+
+    /// Read-only: lamports a claim would return to the owner right now
+    get_claimable_amount(ViewAlarm) => GetClaimableAmount;
+
+    /// Read-only: lamports the next snooze would cost
+    get_next_snooze_cost(ViewAlarm) => GetNextSnoozeCost;
+
+    /// Read-only preflight: run `create_alarm` validation and return the
+    /// first failure (if any) as data. Set `pending_buddy` to check
+    /// `create_alarm_pending_buddy` instead
+    validate_create_alarm_params(
+        ValidateCreateAlarmParams,
+        alarm_time: i64,
+        deadline: i64,
+        deposit_amount: u64,
+        penalty_route: u8,
+        penalty_destination: Option<Pubkey>,
+        options: Option<state::AlarmOptions>,
+        pending_buddy: bool
+    ) => ValidateCreateAlarmParams;
+
+    /// Tag an alarm with a 32-byte label, short URI or hash (owner or co-owner)
+    set_alarm_memo(SetAlarmMemo, memo: [u8; 32]) => SetAlarmMemo;
+
+    /// Create the caller's alarm index for listing live alarms
+    init_alarm_index(InitAlarmIndex) => InitAlarmIndex;
+
+    /// Owner of a Buddy alarm with an arbiter freezes slashing for the
+    /// dispute window (once per alarm, from alarm time on)
+    raise_dispute(RaiseDispute) => RaiseDispute;
+
+    /// Arbiter rules on an open dispute: refund the owner in full, or let
+    /// the penalty route proceed
+    resolve_dispute(ResolveDispute, refund_owner: bool) => ResolveDispute;
+
+    /// Owner registers 2-3 recovery guardians and the address they may
+    /// redirect acknowledged alarms to (empty list disables recovery)
+    set_guardians(SetGuardians, guardians: Vec<Pubkey>, recovery_address: Pubkey) => SetGuardians;
+
+    /// Guardian approves redirecting an acknowledged alarm of an owner who
+    /// lost their key (blocks sweep; 2 approvals start the timelock)
+    approve_recovery(ApproveRecovery) => ApproveRecovery;
+
+    /// Keeper slashes or sweeps up to 10 expired alarms passed as
+    /// `[alarm, vault, recipient, alarm_index]` groups in remaining_accounts; append
+    /// them to the returned instruction's accounts
+    crank_settle(CrankSettle) => CrankSettle;
+
+    /// Owner discards guardian approvals on an alarm
+    cancel_recovery(CancelRecovery) => CancelRecovery;
+
+    /// Guardian pays an acknowledged alarm to the owner's recovery address
+    /// after 2 approvals and the timelock
+    recover_alarm(RecoverAlarm) => RecoverAlarm;
+
+    /// Anyone pins the accounts needed to settle an alarm, so automation
+    /// threads can register `settle_scheduled` once at `trigger_at`
+    schedule_settlement(ScheduleSettlement) => ScheduleSettlement;
+
+    /// Fee payer slashes or sweeps a scheduled alarm with fixed account
+    /// metas (thread-friendly); closes the schedule to its creator
+    settle_scheduled(SettleScheduled) => SettleScheduled;
+
+    /// Schedule creator reclaims the rent of an unused settlement schedule
+    close_settlement_schedule(CloseSettlementSchedule) => CloseSettlementSchedule;
+
+    /// Owner stores a timezone-aware weekly schedule (local weekdays, wake
+    /// time and UTC offset) as the source of truth for recurring alarms
+    create_schedule(
+        CreateSchedule,
+        schedule_id: u64,
+        days_of_week: u8,
+        wake_minute: u16,
+        utc_offset_minutes: i16,
+        window_seconds: i64,
+        deposit_amount: u64,
+        penalty_route: u8,
+        penalty_destination: Option<Pubkey>
+    ) => CreateSchedule;
+
+    /// Owner changes a schedule's weekdays, wake time or UTC offset (e.g. for
+    /// daylight saving)
+    update_schedule(
+        UpdateSchedule,
+        days_of_week: u8,
+        wake_minute: u16,
+        utc_offset_minutes: i16
+    ) => UpdateSchedule;
+
+    /// Owner turns the schedule's next occurrence into a funded Alarm
+    materialize_next_alarm(MaterializeNextAlarm, alarm_id: u64) => MaterializeNextAlarm;
+
+    /// Owner deletes a weekly schedule and reclaims its rent
+    close_schedule(CloseSchedule) => CloseSchedule;
+
+    /// Owner skips an upcoming series occurrence without penalty (at most
+    /// 4 per 30 days); the series ends one interval later instead
+    skip_occurrence(SkipOccurrence, occurrence_time: i64) => SkipOccurrence;
+
+    /// Buddy escrows a deposit matching the owner's on a Buddy-route alarm;
+    /// it is returned once the alarm resolves
+    buddy_stake(StakeAsBuddy) => BuddyStake;
+
+    /// Buddy takes back their stake after the alarm was claimed, slashed or
+    /// closed
+    release_buddy_stake(ReleaseBuddyStake) => ReleaseBuddyStake;
+
+    /// Owner-signed ack submitted by a relayer paying the fee
+    ack_awake_relayed(
+        AckAwakeRelayed,
+        proof_hash: Option<[u8; 32]>,
+        proof_completed_at: i64,
+        proof_type: u8,
+        proof_preimage: Option<[u8; 32]>
+    ) => AckAwakeRelayed;
+
+    /// Owner-signed claim submitted by a relayer paying the fee
+    claim_relayed(ClaimRelayed) => ClaimRelayed;
+
+    /// Register, replace or clear (all default) the 2-of-3 treasury multisig
+    set_treasury_signers(SetTreasurySigners, signers: [Pubkey; 3]) => SetTreasurySigners;
+
+    /// Admin grows the config and treasury PDAs to the current layout after an upgrade
+    migrate_config(MigrateConfig) => MigrateConfig;
+
+    /// Open the per-day protocol stats PDA (permissionless)
+    init_daily_stats(InitDailyStats, day: u64) => InitDailyStats;
+
+    /// Pin a content hash of off-chain wake evidence to an acknowledged alarm (write-once)
+    anchor_proof(AnchorProof, proof_hash: [u8; 32], proof_type: u8) => AnchorProof;
+
+    /// Start a two-phase claim on an alarm above the config threshold
+    request_claim(RequestClaim) => RequestClaim;
+
+    /// Pay out a two-phase claim once its veto delay has passed
+    finalize_claim(FinalizeClaim) => FinalizeClaim;
+
+    /// Buddy or attestation server vetoes a pending two-phase claim
+    flag_claim(FlagClaim) => FlagClaim;
+
+    /// Add a penalty destination to the owner's profile blocklist
+    block_destination(BlockDestination, destination: Pubkey) => BlockDestination;
+
+    /// Remove a penalty destination from the owner's profile blocklist
+    unblock_destination(UnblockDestination, destination: Pubkey) => UnblockDestination;
+
+    /// Settle an alarm nobody slashed, a week after its deadline, for half the bounty
+    sweep_expired(SweepExpired) => SweepExpired;
+
+    /// Read-only quote of the next snooze's cost and new times
+    quote_snooze(ViewAlarm, extension_minutes: Option<u8>) => QuoteSnooze;
+
+    /// Owner buys a later deadline before the alarm fires (fee burned)
+    extend_deadline(ExtendDeadline, extra_seconds: i64) => ExtendDeadline;
+
+    /// Owner claims several acknowledged alarms; pass `[alarm, vault]` pairs as remaining accounts
+    claim_many(ClaimMany) => ClaimMany;
+
+    /// Admin creates the program's lookup table from a recent slot
+    create_lookup_table(CreateLookupTable, recent_slot: u64) => CreateLookupTable;
+
+    /// Admin appends the hot accounts missing from the lookup table
+    extend_lookup_table(ExtendLookupTable) => ExtendLookupTable;
+
+    /// Admin creates the insurance pool funded by a share of every slash
+    init_insurance_pool(InitInsurancePool) => InitInsurancePool;
+
+    /// Recover a slashed deposit from the insurance pool with a server-signed Ed25519 permit (rate-limited)
+    claim_insurance(
+        ClaimInsurance,
+        nonce: u64,
+        expires_at: i64,
+        incident_hash: [u8; 32]
+    ) => ClaimInsurance;
+
+    /// Open a donation matching campaign for a verified charity
+    create_campaign(CreateCampaign, campaign_id: u64) => CreateCampaign;
+
+    /// Add matching funds to a donation campaign
+    fund_campaign(FundCampaign, amount: u64) => FundCampaign;
+
+    /// Ask another user to be your buddy
+    link_buddy(LinkBuddy, buddy: Pubkey) => LinkBuddy;
+
+    /// Accept a pending buddy link
+    accept_buddy_link(AcceptBuddyLink) => AcceptBuddyLink;
+
+    /// Remove a buddy link (owner or buddy)
+    unlink_buddy(UnlinkBuddy) => UnlinkBuddy;
+
+    /// Publish the protocol params PDA on a deployment configured before it existed
+    init_protocol_params(InitProtocolParams) => InitProtocolParams;
+
+    /// Rewrite the protocol params PDA from the current constants
+    sync_protocol_params(SyncProtocolParams) => SyncProtocolParams;
+
+    /// Grow an alarm from an older release to the current layout
+    migrate_alarm(MigrateAlarm) => MigrateAlarm;
+
+    /// Grow a user profile from an older release to the current layout
+    migrate_profile(MigrateProfile) => MigrateProfile;
+
+    /// Owner saves an alarm's time, window, deposit, route and grace/snooze
+    /// options as a reusable template
+    save_template(
+        SaveTemplate,
+        template_id: u64,
+        alarm_time: i64,
+        deadline: i64,
+        deposit_amount: u64,
+        penalty_route: u8,
+        penalty_destination: Option<Pubkey>,
+        options: Option<state::AlarmOptions>
+    ) => SaveTemplate;
+
+    /// Owner creates a funded Alarm from a template, `offset_days` after
+    /// its last alarm time
+    create_alarm_from_template(
+        CreateAlarmFromTemplate,
+        alarm_id: u64,
+        offset_days: u16
+    ) => CreateAlarmFromTemplate;
+
+    /// Owner sets or clears the wallet a claim pays the deposit to, before
+    /// the alarm fires
+    set_claim_destination(SetClaimDestination, claim_to: Option<Pubkey>) => SetClaimDestination;
+
+    /// Owner opens the forgiveness token account credited by wake streaks
+    init_forgiveness_token(InitForgivenessToken) => InitForgivenessToken;
+
+    /// Owner burns a forgiveness token to settle a missed alarm penalty-free
+    forgive_slash(ForgiveSlash) => ForgiveSlash;
+
+    /// Owner claims an acknowledged alarm and rolls its deposit into a new one
+    claim_and_roll(
+        ClaimAndRoll,
+        new_alarm_id: u64,
+        alarm_time: i64,
+        deadline: i64,
+        penalty_route: u8,
+        penalty_destination: Option<Pubkey>,
+        options: Option<state::AlarmOptions>
+    ) => ClaimAndRoll;
+
+    /// Owner moves an alarm's penalty destination before it fires; a new
+    /// buddy co-signs
+    update_penalty_destination(
+        UpdatePenaltyDestination,
+        new_destination: Pubkey
+    ) => UpdatePenaltyDestination;
+
+    /// Record one proof of a multi-proof alarm; the last one acknowledges it
+    ack_awake_partial(
+        AckAwake,
+        proof_hash: Option<[u8; 32]>,
+        proof_completed_at: i64,
+        proof_type: u8,
+        proof_preimage: Option<[u8; 32]>
+    ) => AckAwakePartial;
+}
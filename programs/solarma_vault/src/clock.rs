@@ -0,0 +1,57 @@
+//! Time source for the alarm lifecycle handlers
+//!
+//! Release builds read the `Clock` sysvar. Builds with the `test-clock`
+//! feature (localnet only) also accept a `MockClock` account as the last
+//! remaining account: when it holds a non-zero time, that time replaces
+//! `Clock::unix_timestamp`, so integration tests can jump across alarm
+//! windows with `set_mock_time` instead of sleeping.
+//!
+//! `create_alarm`, `ack_awake` (and `ack_awake_relayed`, `ack_awake_partial`,
+//! `ack_awake_attested`), `snooze`, `claim` (and `finalize_claim`,
+//! `claim_relayed`, `claim_attested`), `slash` (and `sweep_expired`),
+//! `emergency_refund`, `sweep_acknowledged`, `reschedule_alarm`,
+//! `extend_deadline` and the gift and bet instructions read time through
+//! [`get`]; other handlers keep `Clock::get()`.
+
+use anchor_lang::prelude::*;
+
+/// The `Clock` sysvar, with `unix_timestamp` taken from a trailing
+/// `MockClock` when the `test-clock` feature is on and one is passed.
+pub fn get(remaining_accounts: &[AccountInfo]) -> Result<Clock> {
+    #[allow(unused_mut)]
+    let mut clock = Clock::get()?;
+    #[cfg(feature = "test-clock")]
+    if let Some(mock) = mock_clock(remaining_accounts) {
+        if mock.unix_timestamp != 0 {
+            clock.unix_timestamp = mock.unix_timestamp;
+        }
+    }
+    #[cfg(not(feature = "test-clock"))]
+    let _ = remaining_accounts;
+    Ok(clock)
+}
+
+/// `remaining_accounts` without a trailing `MockClock`, for handlers that
+/// read their own accounts from it.
+pub fn without_mock_clock<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> &'a [AccountInfo<'info>] {
+    #[cfg(feature = "test-clock")]
+    if mock_clock(remaining_accounts).is_some() {
+        return &remaining_accounts[..remaining_accounts.len() - 1];
+    }
+    remaining_accounts
+}
+
+/// The trailing account, if it is this program's `MockClock`. Only
+/// `set_mock_time` creates accounts with that discriminator, and only at
+/// its PDA, so owner and discriminator identify it.
+#[cfg(feature = "test-clock")]
+fn mock_clock(remaining_accounts: &[AccountInfo]) -> Option<crate::state::MockClock> {
+    let last = remaining_accounts.last()?;
+    if last.owner != &crate::ID {
+        return None;
+    }
+    let data = last.try_borrow_data().ok()?;
+    crate::state::MockClock::try_deserialize(&mut &data[..]).ok()
+}
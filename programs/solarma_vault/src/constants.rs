@@ -15,25 +15,269 @@ pub const DEFAULT_SNOOZE_PERCENT: u64 = 10;
 /// Maximum snooze count before full penalty
 pub const MAX_SNOOZE_COUNT: u8 = 10;
 
+/// Lower bound for a per-alarm snooze cost percentage
+pub const MIN_SNOOZE_PERCENT: u64 = 1;
+
+/// Upper bound for a per-alarm snooze cost percentage
+pub const MAX_SNOOZE_PERCENT: u64 = 50;
+
+/// Lower bound for a per-alarm snooze extension (1 minute)
+pub const MIN_SNOOZE_EXTENSION_SECONDS: i64 = 60;
+
+/// Upper bound for a per-alarm snooze extension (30 minutes)
+pub const MAX_SNOOZE_EXTENSION_SECONDS: i64 = 1800;
+
+/// Shortest owner-selected snooze (`snooze` with `extension_minutes`)
+pub const MIN_SNOOZE_EXTENSION_MINUTES: u8 = 1;
+
+/// Longest owner-selected snooze (`snooze` with `extension_minutes`)
+pub const MAX_SNOOZE_EXTENSION_MINUTES: u8 = 15;
+
+/// Upper bound for a per-alarm snooze limit (must stay < 64 for shift safety)
+pub const MAX_SNOOZES_LIMIT: u8 = 20;
+
 /// Minimum deposit amount in lamports (0.001 SOL)
 pub const MIN_DEPOSIT_LAMPORTS: u64 = 1_000_000;
 
 /// Emergency refund penalty percent (e.g., 5%)
 pub const EMERGENCY_REFUND_PENALTY_PERCENT: u64 = 5;
 
+/// `extend_deadline` fee, in basis points of the remaining deposit per hour
+/// added (0.5%/h), charged pro rata to the second and burned.
+pub const DEADLINE_EXTENSION_FEE_BPS_PER_HOUR: u64 = 50;
+
 /// Grace period after alarm time before deadline starts (in seconds)
 /// Default: 30 minutes = 1800 seconds
 pub const DEFAULT_GRACE_PERIOD: i64 = 1800;
 
+/// Shortest `deadline - alarm_time` when `GlobalConfig.min_wake_window_seconds`
+/// is 0: anything shorter is practically a guaranteed slash.
+pub const DEFAULT_MIN_WAKE_WINDOW_SECONDS: i64 = 60;
+
+/// Longest `deadline - alarm_time` when `GlobalConfig.max_wake_window_seconds`
+/// is 0 (one day).
+pub const DEFAULT_MAX_WAKE_WINDOW_SECONDS: i64 = 86_400;
+
 /// Default snooze extension (in seconds)
 /// Default: 5 minutes = 300 seconds
 pub const DEFAULT_SNOOZE_EXTENSION_SECONDS: i64 = 300;
 
 /// Additional grace period for claim after deadline (in seconds).
-/// Default: 120 seconds.
+/// Default: 120 seconds; alarms may override it via `AlarmOptions.claim_grace_seconds`.
 pub const CLAIM_GRACE_SECONDS: i64 = 120;
 
+/// Upper bound for a per-alarm claim grace (1 hour).
+pub const MAX_CLAIM_GRACE_SECONDS: i64 = 3600;
+
+/// Upper bound for `AlarmOptions.early_ack_seconds` (30 minutes).
+pub const MAX_EARLY_ACK_SECONDS: i64 = 1800;
+
+/// Layout version written to new and migrated `Alarm` accounts.
+/// 0 means the account predates versioning and may need `migrate_alarm`.
+pub const ALARM_LAYOUT_VERSION: u8 = 1;
+
+/// Layout version written to new and migrated `UserProfile` accounts.
+pub const PROFILE_LAYOUT_VERSION: u8 = 1;
+
+/// Delay between `request_claim` and `finalize_claim` on two-phase alarms,
+/// during which the buddy or attestation server may `flag_claim`.
+pub const CLAIM_FINALIZE_DELAY_SECONDS: i64 = 60;
+
 /// Buddy-only slash window after deadline (in seconds).
 /// For Buddy route, only buddy can slash during this window.
 /// Default: 120 seconds.
 pub const BUDDY_ONLY_SECONDS: i64 = 120;
+
+/// Time after the deadline from which `sweep_expired` may settle an alarm
+/// nobody slashed (7 days).
+pub const SWEEP_EXPIRED_DELAY_SECONDS: i64 = 604_800;
+
+/// How long `raise_dispute` freezes slashing of a Buddy alarm while its
+/// arbiter decides (6 hours).
+pub const DISPUTE_WINDOW_SECONDS: i64 = 21_600;
+
+/// Most alarms one `crank_settle` call takes; each uses four accounts
+/// (alarm, vault, recipient, alarm index), which keeps a full batch within the
+/// transaction account limit and default compute budget.
+pub const MAX_CRANK_ALARMS: usize = 10;
+
+/// Most alarms one `claim_many` call takes, as `[alarm, vault]` pairs;
+/// each claim closes a vault, which bounds a full batch's compute.
+pub const MAX_CLAIM_MANY_ALARMS: usize = 8;
+
+/// Maximum number of co-owner keys (besides the owner) that may
+/// ack, snooze or claim an alarm.
+pub const MAX_CO_OWNERS: usize = 3;
+
+/// Maximum number of recovery guardians on a profile.
+pub const MAX_GUARDIANS: usize = 3;
+
+/// Maximum number of penalty destinations an owner can block on their profile.
+pub const MAX_BLOCKED_DESTINATIONS: usize = 8;
+
+/// Guardian approvals needed to redirect an alarm (2-of-3).
+pub const RECOVERY_THRESHOLD: u32 = 2;
+
+/// Delay between the last needed guardian approval and `recover_alarm`,
+/// during which the owner can still `cancel_recovery` (24 hours).
+pub const RECOVERY_TIMELOCK_SECONDS: i64 = 86_400;
+
+/// Capacity of an owner's `AlarmIndex`.
+pub const MAX_INDEXED_ALARMS: usize = 32;
+
+/// Minimum spacing between recurring series occurrences (daily)
+pub const MIN_SERIES_INTERVAL_SECONDS: i64 = 86_400;
+
+/// Maximum spacing between recurring series occurrences (weekly)
+pub const MAX_SERIES_INTERVAL_SECONDS: i64 = 604_800;
+
+/// Maximum number of occurrences a single series deposit may back
+pub const MAX_SERIES_OCCURRENCES: u16 = 366;
+
+/// Series slots ahead of `next_alarm_time` that `skip_occurrence` can mark
+/// (one bit each in `AlarmSeries.skipped_slots`)
+pub const MAX_SKIP_LOOKAHEAD_SLOTS: u32 = 32;
+
+/// Skips a series owner may request per `SERIES_SKIP_PERIOD_SECONDS`
+pub const MAX_SERIES_SKIPS_PER_PERIOD: u8 = 4;
+
+/// Length of a series skip quota period (30 days)
+pub const SERIES_SKIP_PERIOD_SECONDS: i64 = 2_592_000;
+
+/// Minutes in a day; a schedule's local wake time is below this
+pub const MINUTES_PER_DAY: u16 = 1_440;
+
+/// Westernmost UTC offset a weekly schedule may use (UTC-12:00)
+pub const MIN_UTC_OFFSET_MINUTES: i16 = -720;
+
+/// Easternmost UTC offset a weekly schedule may use (UTC+14:00)
+pub const MAX_UTC_OFFSET_MINUTES: i16 = 840;
+
+/// Upper bound for the protocol fee on slashed deposits (10%)
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 1_000;
+
+/// Upper bound for the slasher bounty (2% of the slashed amount)
+pub const MAX_SLASHER_BOUNTY_BPS: u16 = 200;
+
+/// Slasher bounty applied when the config is first initialized (1%)
+pub const DEFAULT_SLASHER_BOUNTY_BPS: u16 = 100;
+
+/// Maximum destinations for a split penalty
+pub const MAX_PENALTY_SPLITS: usize = 3;
+
+/// Maximum participants in a group wake challenge
+pub const MAX_CHALLENGE_PARTICIPANTS: u32 = 1_000;
+
+/// Basis-point denominator (100% = 10_000 bps)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Shortest interval between progressive slash tranches
+pub const MIN_PARTIAL_SLASH_INTERVAL_SECONDS: i64 = 60;
+
+/// Longest interval between progressive slash tranches (one day)
+pub const MAX_PARTIAL_SLASH_INTERVAL_SECONDS: i64 = 86_400;
+
+/// Late claims within this many seconds after the deadline refund
+/// `LATE_CLAIM_EARLY_REFUND_BPS` of the remaining deposit (1 hour)
+pub const LATE_CLAIM_EARLY_SECONDS: i64 = 3_600;
+
+/// Refund share for an early late claim (50%)
+pub const LATE_CLAIM_EARLY_REFUND_BPS: u16 = 5_000;
+
+/// Late claims are possible until this many seconds after the deadline (6 hours)
+pub const LATE_CLAIM_MAX_SECONDS: i64 = 21_600;
+
+/// Refund share for a late claim after the first hour (25%)
+pub const LATE_CLAIM_LATE_REFUND_BPS: u16 = 2_500;
+
+/// Domain separator prefixed to every signed permit message
+pub const PERMIT_DOMAIN: &[u8] = b"solarma-permit-v1";
+
+/// Permit action tag for `ack_awake_attested`
+pub const PERMIT_ACTION_ACK: u8 = 0;
+
+/// Permit action tag for `claim_attested`
+pub const PERMIT_ACTION_CLAIM: u8 = 1;
+
+/// Permit action tag for `claim_insurance`
+pub const PERMIT_ACTION_INSURANCE: u8 = 2;
+
+/// Base permit action tag for `ack_awake_attested` on an alarm with a
+/// required proof type: the server signs `PERMIT_ACTION_ACK_TYPED + type`
+pub const PERMIT_ACTION_ACK_TYPED: u8 = 0x10;
+
+/// `GlobalConfig.proof_type_registry` bits of every known `ProofType`
+/// except `None` (Steps..=Location)
+pub const KNOWN_PROOF_TYPES_MASK: u16 = 0b1111_1110;
+
+/// Upper bound for `GlobalConfig.insurance_bps`, the slice of each slash
+/// sent to the insurance pool (5%)
+pub const MAX_INSURANCE_BPS: u16 = 500;
+
+/// Upper bound for `Alarm.unclaimed_penalty_bps`, the share of an
+/// acknowledged but unclaimed deposit `sweep_acknowledged` routes like a
+/// penalty (50%: a real ack always gets at least half back)
+pub const MAX_UNCLAIMED_PENALTY_BPS: u16 = 5_000;
+
+/// Shortest gap between two `claim_insurance` payouts to one owner (30 days)
+pub const INSURANCE_CLAIM_COOLDOWN_SECONDS: i64 = 2_592_000;
+
+/// Longest validity window a permit may request (15 minutes)
+pub const MAX_PERMIT_TTL_SECONDS: i64 = 900;
+
+/// Longest window during which a rotated-out attestation key still validates (7 days)
+pub const MAX_ATTESTATION_TRANSITION_SECONDS: i64 = 604_800;
+
+/// Maximum attestor keys registered alongside the current attestation key
+pub const MAX_ATTESTORS: usize = 4;
+
+/// Keys in the optional treasury multisig (`GlobalConfig.treasury_signers`)
+pub const TREASURY_SIGNER_COUNT: usize = 3;
+
+/// Treasury signers that must sign a withdrawal once the multisig is set
+pub const TREASURY_MULTISIG_THRESHOLD: usize = 2;
+
+/// Badge: claimed on 7 consecutive days
+pub const BADGE_WEEK_STREAK: u8 = 0;
+
+/// Badge: 30 alarms claimed
+pub const BADGE_THIRTY_CLAIMS: u8 = 1;
+
+/// Badge: 30 consecutive claim days without snoozing
+pub const BADGE_SNOOZE_FREE_MONTH: u8 = 2;
+
+/// Day streak required for `BADGE_WEEK_STREAK`
+pub const WEEK_STREAK_DAYS: u16 = 7;
+
+/// Claims required for `BADGE_THIRTY_CLAIMS`
+pub const THIRTY_CLAIMS_COUNT: u32 = 30;
+
+/// Snooze-free day streak required for `BADGE_SNOOZE_FREE_MONTH`
+pub const SNOOZE_FREE_MONTH_DAYS: u16 = 30;
+
+/// Day streak that earns a forgiveness token (every multiple of it)
+pub const FORGIVENESS_STREAK_DAYS: u16 = 7;
+
+/// Most unspent forgiveness tokens an owner can hold
+pub const MAX_FORGIVENESS_TOKENS: u8 = 3;
+
+/// How long after the deadline `forgive_slash` stays open (15 minutes)
+pub const FORGIVENESS_WINDOW_SECONDS: i64 = 900;
+
+/// Length of a leaderboard epoch (one week)
+pub const LEADERBOARD_EPOCH_SECONDS: i64 = 604_800;
+
+/// Longest lifetime of a profile delegate (session key)
+pub const MAX_DELEGATE_TTL_SECONDS: i64 = 2_592_000; // 30 days
+
+/// Maximum share of the deposit paid to the buddy on a successful claim
+pub const MAX_BUDDY_REWARD_BPS: u16 = 1_000; // 10%
+
+/// Maximum share of a protocol fee paid to the referee's referrer
+pub const MAX_REFERRAL_FEE_BPS: u16 = 5_000; // 50%
+
+/// Shortest savings lock a user can configure (one week)
+pub const MIN_SAVINGS_LOCK_SECONDS: i64 = 604_800;
+
+/// Longest savings lock a user can configure (one year)
+pub const MAX_SAVINGS_LOCK_SECONDS: i64 = 31_536_000;
@@ -54,4 +54,319 @@ pub enum SolarmaError {
 
     #[msg("Only buddy can slash during buddy-only window")]
     BuddyOnlyWindow,
+
+    #[msg("Signer is not the owner or a co-owner of this alarm")]
+    UnauthorizedSigner,
+
+    #[msg("Too many co-owners (maximum 3)")]
+    TooManyCoOwners,
+
+    #[msg("Invalid co-owner key (empty, owner, or duplicate)")]
+    InvalidCoOwner,
+
+    #[msg("Gift has expired (alarm time reached before acceptance)")]
+    GiftExpired,
+
+    #[msg("Gift has not expired yet")]
+    GiftNotExpired,
+
+    #[msg("Gift recipient must differ from gifter")]
+    InvalidGiftRecipient,
+
+    #[msg("Alarm is not open for a matching bet")]
+    BetUnavailable,
+
+    #[msg("Matched bet alarms cannot be snoozed or refunded")]
+    BetLocked,
+
+    #[msg("Snooze settings are outside protocol bounds")]
+    InvalidSnoozeConfig,
+
+    #[msg("Invalid recurring series configuration")]
+    InvalidSeriesConfig,
+
+    #[msg("Recurring series has no occurrences left")]
+    SeriesExhausted,
+
+    #[msg("Config value is outside protocol bounds")]
+    InvalidConfig,
+
+    #[msg("Signer is not the program upgrade authority")]
+    NotUpgradeAuthority,
+
+    #[msg("Protocol is paused: only claims and refunds are allowed")]
+    ProtocolPaused,
+
+    #[msg("Tag hash must be non-zero and differ from the current tag")]
+    InvalidTagHash,
+
+    #[msg("A tag is already registered; use rotate_tag")]
+    TagAlreadyRegistered,
+
+    #[msg("Tag hash does not match the registered tag")]
+    TagMismatch,
+
+    #[msg("Withdrawal must be non-zero and leave the treasury rent-exempt")]
+    InsufficientTreasury,
+
+    #[msg("Penalty splits must have 2-3 distinct legs summing to 10000 bps, led by the penalty destination")]
+    InvalidPenaltySplit,
+
+    #[msg("Donate destination is not a verified charity")]
+    CharityNotVerified,
+
+    #[msg("Buddy can no longer accept: alarm time has passed")]
+    BuddyAcceptExpired,
+
+    #[msg("Challenge has already started or is full")]
+    ChallengeClosed,
+
+    #[msg("Progressive slash needs 1-10000 bps per 60-86400s interval and no Split route")]
+    InvalidPartialSlashConfig,
+
+    #[msg("No slash tranche is due yet")]
+    NothingToSlash,
+
+    #[msg("This alarm requires a server-attested instruction")]
+    AttestationRequired,
+
+    #[msg("Missing or invalid Ed25519 attestation permit")]
+    InvalidAttestation,
+
+    #[msg("Permit has expired or its validity window is too long")]
+    PermitExpired,
+
+    #[msg("Unknown achievement badge")]
+    InvalidBadge,
+
+    #[msg("Achievement threshold not reached yet")]
+    AchievementLocked,
+
+    #[msg("Leaderboard epoch is over, finalized or not the current epoch")]
+    EpochClosed,
+
+    #[msg("Leaderboard epoch has not ended yet")]
+    EpochNotEnded,
+
+    #[msg("Invalid delegate (owner key, or expiry outside the allowed window)")]
+    InvalidDelegate,
+
+    #[msg("Buddy reward requires the Buddy route and at most 10%")]
+    InvalidBuddyReward,
+
+    #[msg("Duel was already accepted or its accept window has closed")]
+    DuelClosed,
+
+    #[msg("Duel cannot be settled yet")]
+    DuelNotSettleable,
+
+    #[msg("Savings lock must be between 7 and 365 days")]
+    InvalidSavingsLock,
+
+    #[msg("Savings are still locked")]
+    SavingsLocked,
+
+    #[msg("Deposit exceeds the per-alarm or daily deposit limit")]
+    DepositLimitExceeded,
+
+    #[msg("Owner's user profile is required by the config limits or this alarm")]
+    UserProfileRequired,
+
+    #[msg("Claim grace must be between 0 and 3600 seconds")]
+    InvalidClaimGrace,
+
+    #[msg("Acknowledged alarms are never slashed; claim, or sweep after the claim grace")]
+    AlarmAcknowledged,
+
+    #[msg("Too many open alarms; close resolved alarms first")]
+    TooManyOpenAlarms,
+
+    #[msg("Alarm index is full; close resolved alarms first")]
+    AlarmIndexFull,
+
+    #[msg("Unknown wake proof type")]
+    InvalidProofType,
+
+    #[msg("Proof completion time must be between alarm time and now")]
+    InvalidProofTimestamp,
+
+    #[msg("Snooze extension must be between 1 and 15 minutes")]
+    InvalidSnoozeExtension,
+
+    #[msg("Referrer must be a different, non-default key matching the profile")]
+    InvalidReferrer,
+
+    #[msg("Arbiter requires the Buddy route and must not be the owner or the buddy")]
+    InvalidArbiter,
+
+    #[msg("Dispute needs an arbiter, an undisputed alarm, and the alarm time reached")]
+    DisputeUnavailable,
+
+    #[msg("Slashing is frozen while a dispute is open")]
+    DisputeActive,
+
+    #[msg("No open dispute to resolve")]
+    NoActiveDispute,
+
+    #[msg("Guardians must be 2-3 distinct keys other than the owner, with a recovery address")]
+    InvalidGuardians,
+
+    #[msg("Signer is not one of the owner's guardians")]
+    NotGuardian,
+
+    #[msg("A guardian recovery is in progress; recover or cancel it first")]
+    RecoveryPending,
+
+    #[msg("Recovery needs 2 guardian approvals and the timelock to pass")]
+    RecoveryNotReady,
+
+    #[msg("Crank accounts must be 1-10 groups of [alarm, vault, recipient, alarm_index]")]
+    InvalidCrankAccounts,
+
+    #[msg("No alarm was ready to settle")]
+    NothingToSettle,
+
+    #[msg("Only Created or Acknowledged single-recipient alarms can be scheduled")]
+    ScheduleUnavailable,
+
+    #[msg("Deadline minus alarm time is outside the configured wake window")]
+    WakeWindowOutOfBounds,
+
+    #[msg("Schedule needs 1-7 weekdays, a wake time before midnight and a quarter-hour UTC offset from -12:00 to +14:00")]
+    InvalidSchedule,
+
+    #[msg("Occurrence is not one of the series' next 32 future slots, or is already skipped")]
+    SkipUnavailable,
+
+    #[msg("Series skip limit reached for this 30-day period")]
+    SkipLimitReached,
+
+    #[msg("Only the buddy of a funded, unbet Buddy-route alarm can stake, before alarm time")]
+    BuddyStakeUnavailable,
+
+    #[msg("Buddy stake is locked until the alarm is resolved")]
+    BuddyStakeLocked,
+
+    #[msg("expected_open_alarms requires the owner's alarm index")]
+    AlarmIndexRequired,
+
+    #[msg("Owner's open alarms do not match expected_open_alarms (duplicate submit?)")]
+    OpenAlarmsMismatch,
+
+    #[msg("Treasury action needs the admin, or 2 of the 3 treasury signers once set")]
+    TreasuryApprovalsMissing,
+
+    #[msg("Treasury signers must be all unset or 3 distinct non-zero keys")]
+    InvalidTreasurySigners,
+
+    #[msg("Daily stats can only be opened for today or a later day")]
+    StatsDayClosed,
+
+    #[msg("Proof commitment must be non-zero")]
+    InvalidProofCommitment,
+
+    #[msg("Proof preimage missing or does not hash to the alarm's commitment")]
+    ProofPreimageMismatch,
+
+    #[msg("Anchored proof hash must be non-zero and can only be set once")]
+    InvalidAnchoredProof,
+
+    #[msg("Deposit is above the two-phase threshold: use request_claim, then finalize_claim")]
+    ClaimRequestRequired,
+
+    #[msg("Claim request was flagged and cannot be finalized")]
+    ClaimVetoed,
+
+    #[msg("Penalty destination is on the owner's blocklist")]
+    DestinationBlocked,
+
+    #[msg("Destination must be non-zero and not already blocked")]
+    InvalidBlockedDestination,
+
+    #[msg("Destination blocklist is full")]
+    BlocklistFull,
+
+    #[msg("Destination is not on the blocklist")]
+    DestinationNotBlocked,
+
+    #[msg("Early ack lead must be between 0 and 1800 seconds")]
+    InvalidEarlyAck,
+
+    #[msg("Soft deadline must fall strictly between alarm time and deadline")]
+    InvalidSoftDeadline,
+
+    #[msg("Soft deadline passed: pass the vault, config and penalty recipient")]
+    SoftForfeitAccountsRequired,
+
+    #[msg("Claim batch must be 1-8 writable pairs of [alarm, vault]")]
+    InvalidClaimBatch,
+
+    #[msg("The config already records a lookup table")]
+    LookupTableAlreadySet,
+
+    #[msg("Lookup table does not match the config or is not owned by it")]
+    InvalidLookupTable,
+
+    #[msg("Lookup table already holds every hot account")]
+    LookupTableUpToDate,
+
+    #[msg("Insurance is enabled; pass the insurance pool")]
+    InsurancePoolRequired,
+
+    #[msg("This alarm has no insured loss to claim")]
+    NothingToInsure,
+
+    #[msg("Insurance was claimed too recently")]
+    InsuranceCooldown,
+
+    #[msg("Insurance pool has nothing left to pay")]
+    InsurancePoolEmpty,
+
+    #[msg("Campaign only matches Donate-route slashes to its charity")]
+    CampaignMismatch,
+
+    #[msg("Buddy link must name another real key and be pending to accept")]
+    InvalidBuddyLink,
+
+    #[msg("Templates keep only grace and snooze options, and no Split route")]
+    InvalidTemplate,
+
+    #[msg("Claim destination must be another real key, and passed when set")]
+    InvalidClaimDestination,
+
+    #[msg("No forgiveness token left to redeem")]
+    NoForgivenessToken,
+
+    #[msg("Forgiveness is only open for a short window after the deadline")]
+    ForgivenessWindowClosed,
+
+    #[msg("Burn and Savings routes have no penalty destination to change")]
+    PenaltyDestinationFixed,
+
+    #[msg("The new buddy must co-sign a Buddy-route destination change")]
+    BuddyConsentRequired,
+
+    #[msg("Required proof type is unknown or not enabled in the registry")]
+    ProofTypeNotAllowed,
+
+    #[msg("Reported proof type does not match the alarm's required proof")]
+    ProofTypeMismatch,
+
+    #[msg("Multi-proof alarms need 2+ registered proof types, no single required type and no attestation")]
+    InvalidRequiredProofs,
+
+    #[msg("Alarm requires every proof to be recorded with ack_awake_partial")]
+    ProofsIncomplete,
+
+    #[msg("Unclaimed penalty cannot exceed 5000 bps")]
+    InvalidUnclaimedPenalty,
+
+    #[msg("Unclaimed penalty owed: pass the config and penalty recipient")]
+    UnclaimedPenaltyAccountsRequired,
+
+    #[msg("Leaderboard entry does not belong to the alarm owner")]
+    InvalidLeaderboardEntry,
+
+    #[msg("Deposit returns to the sponsor or gifter that funded it: pass it as funder")]
+    InvalidFunder,
 }
@@ -5,18 +5,169 @@
 //! the full alarm lifecycle without parsing account data.
 //!
 //! All alarm-related events include `alarm_id` for client-side correlation.
+//! Every event starts with `version` (the layout revision, see
+//! [`EVENT_VERSION`]) and `timestamp` (cluster unix time at emission), so
+//! indexers can decode and order events without fetching account data.
 
 use anchor_lang::prelude::*;
 
+/// Layout revision carried in every event; bump whenever any event's fields change.
+pub const EVENT_VERSION: u8 = 1;
+
+/// `$accounts.event_authority` (added by `#[event_cpi]`) under the
+/// `event-cpi` feature, `None` otherwise, so settlement code can pass it
+/// around without feature-gating its own signatures.
+macro_rules! event_authority {
+    ($accounts:expr) => {{
+        #[cfg(feature = "event-cpi")]
+        let authority = Some(&$accounts.event_authority);
+        #[cfg(not(feature = "event-cpi"))]
+        let authority: Option<&AccountInfo> = None;
+        authority
+    }};
+}
+pub(crate) use event_authority;
+
+/// Emit a settlement event (`AlarmSlashed` / `AlarmClaimed`) with `emit!`
+/// and, under the `event-cpi` feature, also as a self-CPI signed by
+/// `event_authority`. Inner instructions survive RPC log truncation; the
+/// log line is kept so log-parsing indexers work during the transition.
+pub(crate) fn emit_settlement<E: anchor_lang::Event>(
+    event_authority: Option<&AccountInfo>,
+    event: E,
+) -> Result<()> {
+    emit!(event);
+    #[cfg(feature = "event-cpi")]
+    if let Some(authority) = event_authority {
+        emit_self_cpi(authority, &event)?;
+    }
+    #[cfg(not(feature = "event-cpi"))]
+    let _ = event_authority;
+    Ok(())
+}
+
+/// The self-CPI `emit_cpi!` builds, without needing `ctx` in scope.
+#[cfg(feature = "event-cpi")]
+fn emit_self_cpi<E: anchor_lang::Event>(event_authority: &AccountInfo, event: &E) -> Result<()> {
+    use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+    use anchor_lang::solana_program::program::invoke_signed;
+
+    let (_, bump) = crate::pda::event_authority_address();
+    let data = [anchor_lang::event::EVENT_IX_TAG_LE, &event.data()].concat();
+    let ix = Instruction::new_with_bytes(
+        crate::ID,
+        &data,
+        vec![AccountMeta::new_readonly(event_authority.key(), true)],
+    );
+    invoke_signed(
+        &ix,
+        std::slice::from_ref(event_authority),
+        &[&[b"__event_authority", &[bump]]],
+    )?;
+    Ok(())
+}
+
 /// Emitted when a user profile is initialized
 #[event]
 pub struct ProfileInitialized {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+}
+
+/// Emitted when a profile is created with a referrer
+#[event]
+pub struct ReferralRegistered {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub referrer: Pubkey,
+}
+
+/// Emitted when part of a protocol fee is paid to the referee's referrer
+#[event]
+pub struct ReferralRewardPaid {
+    pub version: u8,
+    pub timestamp: i64,
+    pub referrer: Pubkey,
+    pub referee: Pubkey,
+    pub alarm: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when the owner sets or clears their profile delegate
+#[event]
+pub struct DelegateUpdated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub expires_at: i64,
+}
+
+/// Emitted when the owner sets or clears their recovery guardians
+#[event]
+pub struct GuardiansUpdated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub recovery_address: Pubkey,
+}
+
+/// Emitted when the owner blocks or unblocks a penalty destination
+#[event]
+pub struct DestinationBlocklistUpdated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+    /// `true` for `block_destination`, `false` for `unblock_destination`
+    pub blocked: bool,
+}
+
+/// Emitted when a guardian approves redirecting an acknowledged alarm
+#[event]
+pub struct RecoveryApproved {
+    pub version: u8,
+    pub timestamp: i64,
     pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub guardian: Pubkey,
+    /// Distinct guardians that have approved so far
+    pub approvals: u8,
+    /// When `recover_alarm` opens (0 = threshold not reached)
+    pub unlocks_at: i64,
+}
+
+/// Emitted when the owner cancels a guardian recovery
+#[event]
+pub struct RecoveryCancelled {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+}
+
+/// Emitted when guardians redirect an acknowledged alarm's deposit
+#[event]
+pub struct AlarmRecovered {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub recovery_address: Pubkey,
+    pub amount: u64,
 }
 
 /// Emitted when a new alarm + vault is created
 #[event]
 pub struct AlarmCreated {
+    pub version: u8,
+    pub timestamp: i64,
     pub owner: Pubkey,
     pub alarm: Pubkey,
     pub alarm_id: u64,
@@ -26,18 +177,35 @@ pub struct AlarmCreated {
     pub penalty_route: u8,
 }
 
+/// Emitted when `claim_and_roll` moves a claimed deposit into a new alarm
+#[event]
+pub struct AlarmRolled {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub from_alarm: Pubkey,
+    pub to_alarm: Pubkey,
+    pub amount: u64,
+}
+
 /// Emitted when an alarm is successfully claimed
 #[event]
 pub struct AlarmClaimed {
+    pub version: u8,
+    pub timestamp: i64,
     pub owner: Pubkey,
     pub alarm: Pubkey,
     pub alarm_id: u64,
     pub returned_amount: u64,
+    /// Thank-you share sent to the buddy (0 unless `buddy_reward_bps` is set)
+    pub buddy_reward: u64,
 }
 
 /// Emitted when an alarm is snoozed
 #[event]
 pub struct AlarmSnoozed {
+    pub version: u8,
+    pub timestamp: i64,
     pub owner: Pubkey,
     pub alarm: Pubkey,
     pub alarm_id: u64,
@@ -46,21 +214,111 @@ pub struct AlarmSnoozed {
     pub remaining: u64,
     pub new_alarm_time: i64,
     pub new_deadline: i64,
+    /// Account that received `cost` (the burn sink or the penalty destination)
+    pub penalty_recipient: Pubkey,
+}
+
+/// Emitted after `AlarmSnoozed` when the rent-exempt guard charged less
+/// than the quoted snooze cost; `AlarmSnoozed.cost` equals `charged`
+#[event]
+pub struct SnoozeCostCapped {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub snooze_count: u8,
+    /// Cost quoted by the alarm's snooze model
+    pub requested: u64,
+    /// Cost actually moved out of the vault
+    pub charged: u64,
+}
+
+/// Emitted when an ack after the soft deadline forfeits part of the deposit
+#[event]
+pub struct SoftDeadlineForfeited {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub forfeited: u64,
+    pub remaining: u64,
+    /// Account that received `forfeited` (the burn sink or the penalty destination)
+    pub penalty_recipient: Pubkey,
 }
 
 /// Emitted when an alarm is slashed after deadline
 #[event]
 pub struct AlarmSlashed {
+    pub version: u8,
+    pub timestamp: i64,
     pub alarm: Pubkey,
     pub alarm_id: u64,
     pub penalty_recipient: Pubkey,
     pub slashed_amount: u64,
     pub caller: Pubkey,
+    /// Portion of `slashed_amount` paid to the caller
+    pub caller_bounty: u64,
+    /// Portion of `slashed_amount` paid to the protocol treasury
+    pub protocol_fee: u64,
+    /// `PenaltyRoute` the slashed funds followed
+    pub penalty_route: u8,
+}
+
+/// Emitted once per `crank_settle` batch, after the per-alarm
+/// `AlarmSlashed` / `AlarmClaimed` events
+#[event]
+pub struct CrankSettled {
+    pub version: u8,
+    pub timestamp: i64,
+    pub keeper: Pubkey,
+    pub slashed: u16,
+    pub swept: u16,
+    /// Groups not ready to settle (or already settled by another keeper)
+    pub skipped: u16,
+    /// Slasher bounties paid to the keeper across the batch
+    pub total_bounty: u64,
+}
+
+/// Emitted when a `SettlementSchedule` is created for an alarm
+#[event]
+pub struct SettlementScheduled {
+    pub version: u8,
+    pub timestamp: i64,
+    pub alarm: Pubkey,
+    pub schedule: Pubkey,
+    pub penalty_recipient: Pubkey,
+    pub trigger_at: i64,
+}
+
+/// Emitted when the buddy escrows a matching stake on an alarm
+#[event]
+pub struct BuddyStaked {
+    pub version: u8,
+    pub timestamp: i64,
+    pub alarm: Pubkey,
+    pub buddy: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when a buddy stake is returned after the alarm resolved
+#[event]
+pub struct BuddyStakeReleased {
+    pub version: u8,
+    pub timestamp: i64,
+    pub alarm: Pubkey,
+    pub buddy: Pubkey,
+    pub amount: u64,
+    /// The owner was slashed, so the buddy also received the deposit
+    pub owner_slashed: bool,
 }
 
 /// Emitted when an emergency refund is executed
 #[event]
 pub struct EmergencyRefundExecuted {
+    pub version: u8,
+    pub timestamp: i64,
     pub owner: Pubkey,
     pub alarm: Pubkey,
     pub alarm_id: u64,
@@ -72,8 +330,800 @@ pub struct EmergencyRefundExecuted {
 /// Emitted when a wake proof is acknowledged on-chain (H3)
 #[event]
 pub struct WakeAcknowledged {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    /// Reported proof completion time (0 for attested acks)
+    pub proof_completed_at: i64,
+    /// `ProofType` as u8 (`None` for attested acks)
+    pub proof_type: u8,
+}
+
+/// Emitted alongside the usual event when a relayer paid the fee for an
+/// owner-signed `ack_awake_relayed` / `claim_relayed`
+#[event]
+pub struct RelayedSubmission {
+    pub version: u8,
+    pub timestamp: i64,
+    pub alarm: Pubkey,
+    /// Owner, co-owner or delegate who signed
+    pub authority: Pubkey,
+    /// Fee payer that submitted the transaction
+    pub relayer: Pubkey,
+    /// `PERMIT_ACTION_ACK` or `PERMIT_ACTION_CLAIM`
+    pub action: u8,
+}
+
+/// Emitted when the owner replaces the co-owner key list of an alarm
+#[event]
+pub struct CoOwnersUpdated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub co_owners: Vec<Pubkey>,
+}
+
+/// Emitted when a funded gift alarm is created for a recipient
+#[event]
+pub struct GiftCreated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub gifter: Pubkey,
+    pub recipient: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub alarm_time: i64,
+    pub deadline: i64,
+    pub deposit_amount: u64,
+}
+
+/// Emitted when the recipient accepts a gift alarm and becomes responsible for it
+#[event]
+pub struct GiftAccepted {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub gifter: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+}
+
+/// Emitted when a gift is declined or expires and funds return to the gifter
+#[event]
+pub struct GiftReturned {
+    pub version: u8,
+    pub timestamp: i64,
+    pub gifter: Pubkey,
+    pub recipient: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    /// Total lamports returned to gifter (deposit + alarm and vault rent)
+    pub returned_amount: u64,
+    /// `true` when returned by expiry, `false` when declined
+    pub expired: bool,
+}
+
+/// Emitted when the owner offers a matched-deposit bet to a challenger
+#[event]
+pub struct BetOffered {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub challenger: Pubkey,
+}
+
+/// Emitted when the challenger matches the owner's deposit
+#[event]
+pub struct BetMatched {
+    pub version: u8,
+    pub timestamp: i64,
+    pub challenger: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub stake: u64,
+    /// Total pot held in the vault (owner deposit + challenger stake)
+    pub pot: u64,
+}
+
+/// Emitted when a recurring alarm series is created and funded
+#[event]
+pub struct SeriesCreated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub series: Pubkey,
+    pub series_id: u64,
+    pub first_alarm_time: i64,
+    pub interval_seconds: i64,
+    pub occurrences: u16,
+    pub deposit_per_occurrence: u64,
+}
+
+/// Emitted when the next occurrence of a series is spawned as an Alarm
+#[event]
+pub struct OccurrenceSpawned {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub series: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub alarm_time: i64,
+    pub deadline: i64,
+    pub deposit_amount: u64,
+    pub remaining_occurrences: u16,
+}
+
+/// Emitted when a weekly schedule is created or its timing changes
+#[event]
+pub struct ScheduleUpdated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub schedule: Pubkey,
+    pub schedule_id: u64,
+    pub days_of_week: u8,
+    pub wake_minute: u16,
+    pub utc_offset_minutes: i16,
+}
+
+/// Emitted when the next occurrence of a weekly schedule becomes an Alarm
+#[event]
+pub struct ScheduleMaterialized {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub schedule: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub alarm_time: i64,
+    pub deadline: i64,
+    pub deposit_amount: u64,
+}
+
+/// Emitted when an alarm template is saved
+#[event]
+pub struct TemplateSaved {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub template: Pubkey,
+    pub template_id: u64,
+    pub deposit_amount: u64,
+    pub penalty_route: u8,
+}
+
+/// Emitted when `create_alarm_from_template` clones a template into an Alarm
+#[event]
+pub struct TemplateUsed {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub template: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub alarm_time: i64,
+    pub deadline: i64,
+    pub deposit_amount: u64,
+}
+
+/// Emitted when the owner skips an upcoming series occurrence
+#[event]
+pub struct OccurrenceSkipped {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub series: Pubkey,
+    pub occurrence_time: i64,
+    pub skips_in_period: u8,
+}
+
+/// Emitted when a series is cancelled and its unused escrow returned
+#[event]
+pub struct SeriesCancelled {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub series: Pubkey,
+    pub series_id: u64,
+    /// Total lamports returned to owner (unused escrow + rent)
+    pub refunded_amount: u64,
+}
+
+/// Emitted when the global protocol config is initialized
+#[event]
+pub struct ConfigInitialized {
+    pub version: u8,
+    pub timestamp: i64,
+    pub admin: Pubkey,
+    pub config: Pubkey,
+}
+
+/// Emitted when the admin updates the global protocol config
+#[event]
+pub struct ConfigUpdated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub admin: Pubkey,
+    pub config: Pubkey,
+    pub protocol_fee_bps: u16,
+    pub paused: bool,
+}
+
+/// Emitted when a user registers their first NFC/QR tag
+#[event]
+pub struct TagRegistered {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub tag_hash: [u8; 32],
+}
+
+/// Emitted when a user replaces their registered tag
+#[event]
+pub struct TagRotated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub old_hash: [u8; 32],
+    pub new_hash: [u8; 32],
+}
+
+/// Emitted when the treasury authority withdraws accumulated protocol fees
+#[event]
+pub struct TreasuryWithdrawn {
+    pub version: u8,
+    pub timestamp: i64,
+    /// Submitting signer: the admin, or a treasury signer under the multisig
+    pub admin: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when the treasury multisig is set, replaced or cleared
+#[event]
+pub struct TreasurySignersUpdated {
+    pub version: u8,
+    pub timestamp: i64,
+    /// All default = admin-only treasury
+    pub signers: [Pubkey; 3],
+}
+
+/// Emitted when `migrate_config` brings the config and treasury PDAs to the
+/// current layout
+#[event]
+pub struct ConfigMigrated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub config_size: u32,
+    pub treasury_size: u32,
+}
+
+/// Emitted when `migrate_alarm` or `migrate_profile` brings an account to
+/// the current layout
+#[event]
+pub struct AccountMigrated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub account: Pubkey,
+    pub size: u32,
+    pub layout_version: u8,
+}
+
+/// Emitted when a resolved alarm account is closed and its rent reclaimed
+#[event]
+pub struct AlarmClosed {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+}
+
+/// Emitted when the admin registers a verified charity
+#[event]
+pub struct CharityAdded {
+    pub version: u8,
+    pub timestamp: i64,
+    pub charity: Pubkey,
+    pub address: Pubkey,
+    pub name_hash: [u8; 32],
+}
+
+/// Emitted when the admin removes a charity from the registry
+#[event]
+pub struct CharityRemoved {
+    pub version: u8,
+    pub timestamp: i64,
+    pub charity: Pubkey,
+    pub address: Pubkey,
+}
+
+/// Emitted when a buddy co-signs a pending Buddy-route alarm
+#[event]
+pub struct BuddyAccepted {
+    pub version: u8,
+    pub timestamp: i64,
+    pub buddy: Pubkey,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+}
+
+/// Emitted when a buddy link is requested, accepted or removed
+#[event]
+pub struct BuddyLinkUpdated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub buddy: Pubkey,
+    pub link: Pubkey,
+    /// Whether the link is accepted; false on request and on removal
+    pub active: bool,
+    /// Set when `unlink_buddy` closed the link
+    pub removed: bool,
+}
+
+/// Emitted when the owner of a Buddy alarm freezes slashing for the arbiter
+#[event]
+pub struct DisputeRaised {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub arbiter: Pubkey,
+    pub dispute_until: i64,
+}
+
+/// Emitted when the arbiter rules on a dispute
+#[event]
+pub struct DisputeResolved {
+    pub version: u8,
+    pub timestamp: i64,
+    pub arbiter: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    /// `true` = deposit returned to the owner; `false` = penalty route proceeds
+    pub refunded_owner: bool,
+    /// Lamports returned to the owner (0 when the penalty stands)
+    pub refunded_amount: u64,
+}
+
+/// Emitted when the owner withdraws an alarm the buddy never accepted
+#[event]
+pub struct BuddyRequestCancelled {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub returned_amount: u64,
+}
+
+/// Emitted when a group wake challenge is created
+#[event]
+pub struct ChallengeCreated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub creator: Pubkey,
+    pub challenge: Pubkey,
+    pub challenge_id: u64,
+    pub wake_time: i64,
+    pub deadline: i64,
+    pub deposit_amount: u64,
+}
+
+/// Emitted when a user stakes into a challenge
+#[event]
+pub struct ChallengeJoined {
+    pub version: u8,
+    pub timestamp: i64,
+    pub user: Pubkey,
+    pub challenge: Pubkey,
+    pub participant_count: u32,
+    pub total_pot: u64,
+}
+
+/// Emitted when a participant acknowledges before the shared deadline
+#[event]
+pub struct ChallengeAcked {
+    pub version: u8,
+    pub timestamp: i64,
+    pub user: Pubkey,
+    pub challenge: Pubkey,
+}
+
+/// Emitted when a participant is settled after the deadline
+#[event]
+pub struct ChallengeSettled {
+    pub version: u8,
+    pub timestamp: i64,
+    pub user: Pubkey,
+    pub challenge: Pubkey,
+    pub acked: bool,
+    /// Lamports paid to the user (0 for failed participants)
+    pub payout: u64,
+    /// Lamports sent to the burn sink (only when nobody acknowledged)
+    pub burned: u64,
+}
+
+/// Emitted when a tranche of a progressive alarm is slashed
+#[event]
+pub struct PartialSlashed {
+    pub version: u8,
+    pub timestamp: i64,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub penalty_recipient: Pubkey,
+    pub slashed_amount: u64,
+    /// Cumulative lamports slashed from this alarm so far
+    pub total_slashed: u64,
+    pub remaining_amount: u64,
+    pub caller: Pubkey,
+    /// Portion of `slashed_amount` paid to the caller
+    pub caller_bounty: u64,
+    /// Portion of `slashed_amount` paid to the protocol treasury
+    pub protocol_fee: u64,
+}
+
+/// Emitted when the owner recovers a deposit after the deadline
+#[event]
+pub struct LateClaimed {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    /// Lamports returned to the owner (deposit + rent)
+    pub returned_amount: u64,
+    /// Lamports routed to the penalty recipient by this claim
+    pub forfeited_amount: u64,
+}
+
+/// Emitted when the attestation signer is set or rotated
+#[event]
+pub struct AttestationKeyUpdated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub admin: Pubkey,
+    pub previous_key: Pubkey,
+    pub current_key: Pubkey,
+    /// Unix timestamp until which `previous_key` still validates
+    pub previous_valid_until: i64,
+}
+
+/// Emitted when the attestor set or signature threshold changes
+#[event]
+pub struct AttestorsUpdated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub admin: Pubkey,
+    pub attestors: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+/// Emitted when a spent permit nonce is closed
+#[event]
+pub struct PermitNonceClosed {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub nonce: u64,
+}
+
+/// Emitted when an alarm is moved before it fires
+#[event]
+pub struct AlarmRescheduled {
+    pub version: u8,
+    pub timestamp: i64,
     pub owner: Pubkey,
     pub alarm: Pubkey,
     pub alarm_id: u64,
+    pub old_alarm_time: i64,
+    pub old_deadline: i64,
+    pub new_alarm_time: i64,
+    pub new_deadline: i64,
+}
+
+/// Emitted when an owner buys a later deadline before the alarm fires
+#[event]
+pub struct DeadlineExtended {
+    pub version: u8,
     pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub old_deadline: i64,
+    pub new_deadline: i64,
+    /// Lamports burned, after the rent-exempt cap
+    pub fee: u64,
+    pub remaining: u64,
+}
+
+/// Emitted when the program's lookup table is created or extended
+#[event]
+pub struct LookupTableUpdated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub lookup_table: Pubkey,
+    /// Addresses appended by this call (0 on create)
+    pub added: u8,
+}
+
+/// Emitted when the insurance pool pays an owner back for an attested outage
+#[event]
+pub struct InsuranceClaimed {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub amount: u64,
+    /// Loss still claimable after this payout
+    pub remaining_loss: u64,
+    /// Attestation server's hash of the incident record
+    pub incident_hash: [u8; 32],
+}
+
+/// Emitted when a donation matching campaign is created
+#[event]
+pub struct CampaignCreated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub sponsor: Pubkey,
+    pub campaign: Pubkey,
+    pub campaign_id: u64,
+    pub charity: Pubkey,
+}
+
+/// Emitted when matching funds are added to a campaign
+#[event]
+pub struct CampaignFunded {
+    pub version: u8,
+    pub timestamp: i64,
+    pub campaign: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub total_funded: u64,
+}
+
+/// Emitted when a campaign matches a Donate-route slash
+#[event]
+pub struct DonationMatched {
+    pub version: u8,
+    pub timestamp: i64,
+    pub campaign: Pubkey,
+    pub alarm: Pubkey,
+    pub charity: Pubkey,
+    /// Lamports paid from the campaign on top of the donation
+    pub amount: u64,
+    pub total_matched: u64,
+}
+
+/// Emitted when an achievement badge is minted
+#[event]
+pub struct AchievementUnlocked {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub achievement: Pubkey,
+    pub badge_id: u8,
+    pub unlocked_at: i64,
+}
+
+/// Emitted when a wake streak earns the owner a forgiveness token
+#[event]
+pub struct ForgivenessEarned {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub streak: u16,
+    pub balance: u8,
+}
+
+/// Emitted when a forgiveness token turns a missed alarm into a full refund
+#[event]
+pub struct SlashForgiven {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub returned_amount: u64,
+    /// Tokens left after this one was burned
+    pub balance: u8,
+}
+
+/// Emitted when a day's `DailyStats` PDA is opened
+#[event]
+pub struct DailyStatsOpened {
+    pub version: u8,
+    pub timestamp: i64,
+    pub day: u64,
+}
+
+/// Emitted when a leaderboard epoch is frozen
+#[event]
+pub struct EpochFinalized {
+    pub version: u8,
+    pub timestamp: i64,
+    pub epoch: u64,
+    pub participants: u32,
+    pub total_claims: u32,
+    pub total_slashes: u32,
+    pub finalized_by: Pubkey,
+}
+
+/// Emitted when the owner pre-authorizes a permissionless claim
+#[event]
+pub struct AutoClaimAuthorized {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub authorized_by: Pubkey,
+}
+
+/// Emitted when a duel is opened
+#[event]
+pub struct DuelCreated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub creator: Pubkey,
+    pub opponent: Pubkey,
+    pub duel: Pubkey,
+    pub duel_id: u64,
+    pub stake: u64,
+}
+
+/// Emitted when the opponent matches the stake
+#[event]
+pub struct DuelAccepted {
+    pub version: u8,
+    pub timestamp: i64,
+    pub opponent: Pubkey,
+    pub duel: Pubkey,
+}
+
+/// Emitted when either side acknowledges their wake-up
+#[event]
+pub struct DuelAcked {
+    pub version: u8,
+    pub timestamp: i64,
+    pub user: Pubkey,
+    pub duel: Pubkey,
+}
+
+/// Emitted when a duel is paid out and closed
+#[event]
+pub struct DuelSettled {
+    pub version: u8,
+    pub timestamp: i64,
+    pub duel: Pubkey,
+    pub creator_payout: u64,
+    pub opponent_payout: u64,
+}
+
+/// Emitted when a penalty lands in a savings vault
+#[event]
+pub struct SavingsCredited {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub savings_vault: Pubkey,
+    pub amount: u64,
+    pub unlock_at: i64,
+}
+
+/// Emitted when the owner withdraws unlocked savings
+#[event]
+pub struct SavingsWithdrawn {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when a two-phase claim is requested
+#[event]
+pub struct ClaimRequested {
+    pub version: u8,
+    pub timestamp: i64,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    /// Owner, co-owner or delegate who requested
+    pub authority: Pubkey,
+    /// Earliest `finalize_claim` time
+    pub finalize_after: i64,
+}
+
+/// Emitted when the buddy or attestation server vetoes a pending claim
+#[event]
+pub struct ClaimFlagged {
+    pub version: u8,
+    pub timestamp: i64,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub flagger: Pubkey,
+}
+
+/// Emitted when wake evidence is anchored to an alarm by hash
+#[event]
+pub struct ProofAnchored {
+    pub version: u8,
+    pub timestamp: i64,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub proof_hash: [u8; 32],
+    pub proof_type: u8,
+}
+
+/// Emitted when the owner sets or clears an alarm's claim destination
+#[event]
+pub struct ClaimDestinationUpdated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    /// New destination (None = the owner)
+    pub claim_to: Option<Pubkey>,
+}
+
+/// Emitted when `ack_awake_partial` records one stage of a multi-proof alarm
+#[event]
+pub struct ProofStageCompleted {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub proof_type: u8,
+    pub completed_proofs: u16,
+    pub required_proofs: u16,
+}
+
+/// Emitted when `sweep_acknowledged` routes part of an unclaimed deposit
+/// like a penalty before returning the rest to the owner
+#[event]
+pub struct UnclaimedDepositPenalized {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub penalty: u64,
+    /// Account that received `penalty` (the burn sink or the penalty destination)
+    pub penalty_recipient: Pubkey,
+}
+
+/// Emitted when the owner moves an alarm's penalty destination
+#[event]
+pub struct PenaltyDestinationUpdated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub owner: Pubkey,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub old_destination: Option<Pubkey>,
+    pub new_destination: Pubkey,
+}
+
+/// Emitted when an alarm's memo is set or cleared
+#[event]
+pub struct AlarmMemoUpdated {
+    pub version: u8,
+    pub timestamp: i64,
+    pub alarm: Pubkey,
+    pub alarm_id: u64,
+    pub memo: [u8; 32],
 }
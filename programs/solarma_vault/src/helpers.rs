@@ -5,10 +5,31 @@
 //! coverage reflects actual domain-level correctness.
 
 use crate::constants::{
-    BUDDY_ONLY_SECONDS, CLAIM_GRACE_SECONDS, DEFAULT_SNOOZE_PERCENT,
-    EMERGENCY_REFUND_PENALTY_PERCENT, MAX_SNOOZE_COUNT, MIN_DEPOSIT_LAMPORTS,
+    BADGE_SNOOZE_FREE_MONTH, BADGE_THIRTY_CLAIMS, BADGE_WEEK_STREAK, BPS_DENOMINATOR,
+    BUDDY_ONLY_SECONDS, CLAIM_FINALIZE_DELAY_SECONDS, CLAIM_GRACE_SECONDS,
+    DEADLINE_EXTENSION_FEE_BPS_PER_HOUR, DEFAULT_MAX_WAKE_WINDOW_SECONDS,
+    DEFAULT_MIN_WAKE_WINDOW_SECONDS, DEFAULT_SNOOZE_EXTENSION_SECONDS, DEFAULT_SNOOZE_PERCENT,
+    DISPUTE_WINDOW_SECONDS, EMERGENCY_REFUND_PENALTY_PERCENT, FORGIVENESS_STREAK_DAYS,
+    FORGIVENESS_WINDOW_SECONDS, INSURANCE_CLAIM_COOLDOWN_SECONDS, KNOWN_PROOF_TYPES_MASK,
+    LATE_CLAIM_EARLY_REFUND_BPS, LATE_CLAIM_EARLY_SECONDS, LATE_CLAIM_LATE_REFUND_BPS,
+    LATE_CLAIM_MAX_SECONDS, LEADERBOARD_EPOCH_SECONDS, MAX_ATTESTATION_TRANSITION_SECONDS,
+    MAX_ATTESTORS, MAX_BUDDY_REWARD_BPS, MAX_CLAIM_GRACE_SECONDS, MAX_CLAIM_MANY_ALARMS,
+    MAX_CO_OWNERS, MAX_CRANK_ALARMS, MAX_DELEGATE_TTL_SECONDS, MAX_EARLY_ACK_SECONDS,
+    MAX_GUARDIANS, MAX_INSURANCE_BPS, MAX_PARTIAL_SLASH_INTERVAL_SECONDS, MAX_PENALTY_SPLITS,
+    MAX_PERMIT_TTL_SECONDS, MAX_PROTOCOL_FEE_BPS, MAX_REFERRAL_FEE_BPS, MAX_SAVINGS_LOCK_SECONDS,
+    MAX_SERIES_INTERVAL_SECONDS, MAX_SERIES_OCCURRENCES, MAX_SERIES_SKIPS_PER_PERIOD,
+    MAX_SKIP_LOOKAHEAD_SLOTS, MAX_SLASHER_BOUNTY_BPS, MAX_SNOOZES_LIMIT, MAX_SNOOZE_COUNT,
+    MAX_SNOOZE_EXTENSION_MINUTES, MAX_SNOOZE_EXTENSION_SECONDS, MAX_SNOOZE_PERCENT,
+    MAX_UNCLAIMED_PENALTY_BPS, MAX_UTC_OFFSET_MINUTES, MINUTES_PER_DAY, MIN_DEPOSIT_LAMPORTS,
+    MIN_PARTIAL_SLASH_INTERVAL_SECONDS, MIN_SAVINGS_LOCK_SECONDS, MIN_SERIES_INTERVAL_SECONDS,
+    MIN_SNOOZE_EXTENSION_MINUTES, MIN_SNOOZE_EXTENSION_SECONDS, MIN_SNOOZE_PERCENT,
+    MIN_UTC_OFFSET_MINUTES, PERMIT_ACTION_ACK, PERMIT_ACTION_ACK_TYPED, PERMIT_DOMAIN,
+    RECOVERY_THRESHOLD, RECOVERY_TIMELOCK_SECONDS, SERIES_SKIP_PERIOD_SECONDS,
+    SNOOZE_FREE_MONTH_DAYS, SWEEP_EXPIRED_DELAY_SECONDS, THIRTY_CLAIMS_COUNT,
+    TREASURY_MULTISIG_THRESHOLD, WEEK_STREAK_DAYS,
 };
-use crate::state::PenaltyRoute;
+use crate::state::{AlarmStatus, PenaltyRoute, SnoozeModel};
+use solana_program::hash::hash;
 
 // =========================================================================
 // Snooze cost arithmetic
@@ -20,8 +41,21 @@ use crate::state::PenaltyRoute;
 ///
 /// Returns `None` on overflow.
 pub fn snooze_cost(remaining_amount: u64, snooze_count: u8) -> Option<u64> {
+    snooze_cost_with_percent(remaining_amount, snooze_count, DEFAULT_SNOOZE_PERCENT)
+}
+
+/// Calculate the raw snooze cost for a per-alarm percentage.
+///
+/// Formula: `remaining * snooze_percent / 100 * 2^snooze_count`
+///
+/// Returns `None` on overflow.
+pub fn snooze_cost_with_percent(
+    remaining_amount: u64,
+    snooze_count: u8,
+    snooze_percent: u64,
+) -> Option<u64> {
     let base = remaining_amount
-        .checked_mul(DEFAULT_SNOOZE_PERCENT)?
+        .checked_mul(snooze_percent)?
         .checked_div(100)?;
 
     let multiplier = 1u64.checked_shl(snooze_count as u32)?;
@@ -29,9 +63,120 @@ pub fn snooze_cost(remaining_amount: u64, snooze_count: u8) -> Option<u64> {
     Some(cost.min(remaining_amount))
 }
 
+/// Linear pricing: each snooze adds one base step.
+///
+/// Formula: `remaining * snooze_percent / 100 * (snooze_count + 1)`, capped at remaining.
+///
+/// Returns `None` on overflow.
+pub fn snooze_cost_linear(
+    remaining_amount: u64,
+    snooze_count: u8,
+    snooze_percent: u64,
+) -> Option<u64> {
+    let base = remaining_amount
+        .checked_mul(snooze_percent)?
+        .checked_div(100)?;
+    let cost = base.checked_mul(u64::from(snooze_count) + 1)?;
+    Some(cost.min(remaining_amount))
+}
+
+/// Flat pricing: every snooze costs the base step.
+///
+/// Formula: `remaining * snooze_percent / 100`, capped at remaining.
+///
+/// Returns `None` on overflow.
+pub fn snooze_cost_flat(remaining_amount: u64, snooze_percent: u64) -> Option<u64> {
+    let base = remaining_amount
+        .checked_mul(snooze_percent)?
+        .checked_div(100)?;
+    Some(base.min(remaining_amount))
+}
+
+/// Raw snooze cost under the alarm's pricing model.
+pub fn snooze_cost_for_model(
+    model: SnoozeModel,
+    remaining_amount: u64,
+    snooze_count: u8,
+    snooze_percent: u64,
+) -> Option<u64> {
+    match model {
+        SnoozeModel::Exponential => {
+            snooze_cost_with_percent(remaining_amount, snooze_count, snooze_percent)
+        }
+        SnoozeModel::Linear => snooze_cost_linear(remaining_amount, snooze_count, snooze_percent),
+        SnoozeModel::Flat => snooze_cost_flat(remaining_amount, snooze_percent),
+    }
+}
+
+/// Snooze cost for an owner-selected extension, priced pro rata against
+/// the default 5-minute snooze at the default percentage.
+///
+/// Formula: `snooze_cost * extension_minutes * 60 / 300`, capped at remaining.
+///
+/// Returns `None` on overflow.
+pub fn snooze_cost_scaled(
+    remaining_amount: u64,
+    snooze_count: u8,
+    extension_minutes: u8,
+) -> Option<u64> {
+    snooze_cost_scaled_for(
+        remaining_amount,
+        snooze_count,
+        &SnoozeParams::default(),
+        SnoozeModel::Exponential,
+        extension_minutes,
+    )
+}
+
+/// Snooze cost for an owner-selected extension, priced pro rata against
+/// the alarm's own `percent`, `extension_seconds` and pricing model.
+///
+/// Returns `None` on overflow or a zero `extension_seconds`.
+pub fn snooze_cost_scaled_for(
+    remaining_amount: u64,
+    snooze_count: u8,
+    params: &SnoozeParams,
+    model: SnoozeModel,
+    extension_minutes: u8,
+) -> Option<u64> {
+    let full = snooze_cost_for_model(model, remaining_amount, snooze_count, params.percent)?;
+    let scaled = (full as u128)
+        .checked_mul(u128::from(extension_minutes) * 60)?
+        .checked_div(u128::try_from(params.extension_seconds).ok()?)?;
+    Some(u64::try_from(scaled).ok()?.min(remaining_amount))
+}
+
+/// Validate an owner-selected snooze length.
+pub fn validate_snooze_extension_minutes(extension_minutes: u8) -> bool {
+    (MIN_SNOOZE_EXTENSION_MINUTES..=MAX_SNOOZE_EXTENSION_MINUTES).contains(&extension_minutes)
+}
+
+/// Exponent for the next snooze's cost, or `None` while it is still
+/// covered by the alarm's free budget. The curve restarts at `2^0` once
+/// the free snoozes are used up.
+pub fn paid_snooze_index(snooze_count: u8, free_snoozes: u8) -> Option<u8> {
+    snooze_count.checked_sub(free_snoozes)
+}
+
+/// Whether snooze costs go to the alarm's `penalty_destination` rather
+/// than the burn sink. Every route except Burn has a destination.
+pub fn routes_snooze_to_destination(route: PenaltyRoute) -> bool {
+    route != PenaltyRoute::Burn
+}
+
+/// The free budget may not exceed the alarm's snooze limit.
+pub fn validate_free_snoozes(free_snoozes: u8, max_snoozes: u8) -> bool {
+    free_snoozes <= max_snoozes
+}
+
 /// Returns `true` when further snoozes should be blocked.
 pub fn is_max_snooze(snooze_count: u8) -> bool {
-    snooze_count >= MAX_SNOOZE_COUNT
+    is_max_snooze_for(snooze_count, MAX_SNOOZE_COUNT)
+}
+
+/// Returns `true` when further snoozes should be blocked for a per-alarm limit.
+pub fn is_max_snooze_for(snooze_count: u8, max_snoozes: u8) -> bool {
+    snooze_count >= max_snoozes
 }
 
 // =========================================================================
@@ -49,10 +194,60 @@ pub fn emergency_penalty(remaining_amount: u64) -> Option<u64> {
         .checked_div(100)
 }
 
+/// Fee for extending a deadline by `extra_seconds`:
+/// `remaining * DEADLINE_EXTENSION_FEE_BPS_PER_HOUR * extra_seconds / (3600 * 10_000)`,
+/// capped at `remaining`.
+///
+/// Returns `None` on overflow or a negative `extra_seconds`.
+pub fn deadline_extension_fee(remaining_amount: u64, extra_seconds: i64) -> Option<u64> {
+    let fee = (remaining_amount as u128)
+        .checked_mul(DEADLINE_EXTENSION_FEE_BPS_PER_HOUR as u128)?
+        .checked_mul(u128::try_from(extra_seconds).ok()?)?
+        .checked_div(3600 * BPS_DENOMINATOR as u128)?;
+    Some(u64::try_from(fee).unwrap_or(u64::MAX).min(remaining_amount))
+}
+
 // =========================================================================
 // Alarm creation validation (pure)
 // =========================================================================
 
+/// Per-alarm snooze economics, with defaults already applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SnoozeParams {
+    /// Cost percentage of the remaining deposit for the first snooze
+    pub percent: u64,
+    /// Seconds added to alarm_time and deadline per snooze
+    pub extension_seconds: i64,
+    /// Maximum number of snoozes allowed
+    pub max_snoozes: u8,
+}
+
+impl Default for SnoozeParams {
+    fn default() -> Self {
+        Self {
+            percent: DEFAULT_SNOOZE_PERCENT,
+            extension_seconds: DEFAULT_SNOOZE_EXTENSION_SECONDS,
+            max_snoozes: MAX_SNOOZE_COUNT,
+        }
+    }
+}
+
+/// Validate per-alarm snooze economics against protocol bounds.
+pub fn validate_snooze_params(params: &SnoozeParams) -> Result<(), &'static str> {
+    if !(MIN_SNOOZE_PERCENT..=MAX_SNOOZE_PERCENT).contains(&params.percent) {
+        return Err("invalid_snooze_percent");
+    }
+    if !(MIN_SNOOZE_EXTENSION_SECONDS..=MAX_SNOOZE_EXTENSION_SECONDS)
+        .contains(&params.extension_seconds)
+    {
+        return Err("invalid_snooze_extension");
+    }
+    if params.max_snoozes > MAX_SNOOZES_LIMIT {
+        return Err("invalid_max_snoozes");
+    }
+    Ok(())
+}
+
 /// Validate alarm parameters without requiring Anchor context.
 /// Returns `Ok(())` or a string describing the violation.
 pub fn validate_alarm_params(
@@ -62,6 +257,7 @@ pub fn validate_alarm_params(
     deposit_amount: u64,
     penalty_route: u8,
     penalty_destination: bool, // whether Some
+    snooze: &SnoozeParams,
 ) -> Result<(), &'static str> {
     if alarm_time <= current_time {
         return Err("alarm_time_in_past");
@@ -74,11 +270,18 @@ pub fn validate_alarm_params(
     }
     let route = PenaltyRoute::try_from(penalty_route).map_err(|_| "invalid_penalty_route")?;
     if deposit_amount > 0
-        && (route == PenaltyRoute::Donate || route == PenaltyRoute::Buddy)
+        && matches!(
+            route,
+            PenaltyRoute::Donate
+                | PenaltyRoute::Buddy
+                | PenaltyRoute::Split
+                | PenaltyRoute::Savings
+        )
         && !penalty_destination
     {
         return Err("penalty_destination_required");
     }
+    validate_snooze_params(snooze)?;
     Ok(())
 }
 
@@ -95,33 +298,185 @@ pub fn is_claim_window(alarm_time: i64, deadline: i64, current_time: i64) -> boo
 
 /// Compute claim deadline including post-deadline claim grace.
 pub fn claim_deadline_with_grace(deadline: i64) -> Option<i64> {
-    deadline.checked_add(CLAIM_GRACE_SECONDS)
+    claim_deadline_with_grace_for(deadline, CLAIM_GRACE_SECONDS)
+}
+
+/// Claim deadline for an alarm with its own `claim_grace_seconds`.
+pub fn claim_deadline_with_grace_for(deadline: i64, grace_seconds: i64) -> Option<i64> {
+    deadline.checked_add(grace_seconds)
+}
+
+/// Validate a per-alarm claim grace: `0..=MAX_CLAIM_GRACE_SECONDS`.
+pub fn validate_claim_grace(grace_seconds: i64) -> bool {
+    (0..=MAX_CLAIM_GRACE_SECONDS).contains(&grace_seconds)
+}
+
+/// Validate a per-alarm early-ack lead: `0..=MAX_EARLY_ACK_SECONDS`.
+pub fn validate_early_ack(early_ack_seconds: i64) -> bool {
+    (0..=MAX_EARLY_ACK_SECONDS).contains(&early_ack_seconds)
+}
+
+/// Validate a soft deadline: unset (0) or strictly between `alarm_time`
+/// and `deadline`.
+pub fn validate_soft_deadline(alarm_time: i64, soft_deadline: i64, deadline: i64) -> bool {
+    soft_deadline == 0 || (alarm_time < soft_deadline && soft_deadline < deadline)
+}
+
+/// Lamports forfeited by acknowledging at `now` after the soft deadline:
+/// `remaining` scaled linearly from 0 at `soft` to all of it at `hard`.
+/// No soft deadline (0) or an ack before it forfeits nothing.
+pub fn soft_penalty(soft: i64, hard: i64, now: i64, remaining: u64) -> u64 {
+    if soft == 0 || now <= soft {
+        return 0;
+    }
+    if now >= hard {
+        return remaining;
+    }
+    let elapsed = (now - soft) as u128;
+    let span = (hard - soft) as u128;
+    (remaining as u128 * elapsed / span) as u64
+}
+
+/// First second an alarm may be acknowledged and claimed: `alarm_time`
+/// less its early-ack lead. Refund and snooze windows still use `alarm_time`.
+pub fn wake_window_start(alarm_time: i64, early_ack_seconds: i64) -> i64 {
+    alarm_time.saturating_sub(early_ack_seconds)
 }
 
 /// Check whether claim is valid for acknowledged alarms.
 ///
 /// Valid when `current_time >= alarm_time AND current_time <= deadline + CLAIM_GRACE_SECONDS`.
 pub fn is_claim_window_with_grace(alarm_time: i64, deadline: i64, current_time: i64) -> bool {
+    is_claim_window_with_grace_for(alarm_time, deadline, CLAIM_GRACE_SECONDS, current_time)
+}
+
+/// [`is_claim_window_with_grace`] for an alarm with its own grace.
+pub fn is_claim_window_with_grace_for(
+    alarm_time: i64,
+    deadline: i64,
+    grace_seconds: i64,
+    current_time: i64,
+) -> bool {
     if current_time < alarm_time {
         return false;
     }
-    let Some(claim_deadline) = claim_deadline_with_grace(deadline) else {
+    let Some(claim_deadline) = claim_deadline_with_grace_for(deadline, grace_seconds) else {
         return false;
     };
     current_time <= claim_deadline
 }
 
+/// Claim gate shared by `claim`, `claim_attested` and `auto_claim`.
+///
+/// - `Acknowledged`: from `alarm_time` through `deadline + grace_seconds`
+///   (inclusive), so a slow ACK-to-claim round trip still succeeds.
+/// - `Created`: the strict deadline applies (`current_time < deadline`), and
+///   the alarm must be acknowledged first, so this is `not_acknowledged`
+///   inside the window and `deadline_passed` after it.
+/// - Any other status cannot be claimed.
+pub fn check_claim_window(
+    status: AlarmStatus,
+    alarm_time: i64,
+    deadline: i64,
+    grace_seconds: i64,
+    current_time: i64,
+) -> Result<(), &'static str> {
+    let cutoff = match status {
+        AlarmStatus::Acknowledged => {
+            claim_deadline_with_grace_for(deadline, grace_seconds).ok_or("overflow")?
+        }
+        AlarmStatus::Created => deadline.checked_sub(1).ok_or("overflow")?,
+        _ => return Err("invalid_state"),
+    };
+    if current_time < alarm_time {
+        return Err("too_early");
+    }
+    if current_time > cutoff {
+        return Err("deadline_passed");
+    }
+    if status != AlarmStatus::Acknowledged {
+        return Err("not_acknowledged");
+    }
+    Ok(())
+}
+
+/// Alarms whose deposit reaches the config threshold (0 = off) claim in
+/// two phases: `request_claim`, then `finalize_claim` after a veto delay.
+pub fn requires_two_phase_claim(deposit_amount: u64, threshold: u64) -> bool {
+    threshold > 0 && deposit_amount >= threshold
+}
+
+/// Gate the shared claim body on the two-phase protocol.
+///
+/// Plain claims are refused for two-phase alarms. Finalizing needs an
+/// unflagged request at least `CLAIM_FINALIZE_DELAY_SECONDS` old.
+pub fn check_claim_phase(
+    two_phase: bool,
+    finalizing: bool,
+    requested_at: i64,
+    flagged: bool,
+    current_time: i64,
+) -> Result<(), &'static str> {
+    if !finalizing {
+        return if two_phase {
+            Err("request_required")
+        } else {
+            Ok(())
+        };
+    }
+    if requested_at == 0 {
+        return Err("not_requested");
+    }
+    if flagged {
+        return Err("flagged");
+    }
+    let ready_at = requested_at
+        .checked_add(CLAIM_FINALIZE_DELAY_SECONDS)
+        .ok_or("overflow")?;
+    if current_time < ready_at {
+        return Err("too_early");
+    }
+    Ok(())
+}
+
+/// `flag_claim` may veto a pending request only before it can be finalized.
+pub fn is_claim_veto_open(requested_at: i64, flagged: bool, current_time: i64) -> bool {
+    requested_at != 0
+        && !flagged
+        && requested_at
+            .checked_add(CLAIM_FINALIZE_DELAY_SECONDS)
+            .is_some_and(|ready_at| current_time < ready_at)
+}
+
 /// Check whether sweep is valid for acknowledged alarms.
 ///
 /// Valid only strictly after claim grace expires:
 /// `current_time > deadline + CLAIM_GRACE_SECONDS`.
 pub fn is_sweep_window(deadline: i64, current_time: i64) -> bool {
-    let Some(claim_deadline) = claim_deadline_with_grace(deadline) else {
+    is_sweep_window_for(deadline, CLAIM_GRACE_SECONDS, current_time)
+}
+
+/// [`is_sweep_window`] for an alarm with its own grace.
+pub fn is_sweep_window_for(deadline: i64, grace_seconds: i64, current_time: i64) -> bool {
+    let Some(claim_deadline) = claim_deadline_with_grace_for(deadline, grace_seconds) else {
         return false;
     };
     current_time > claim_deadline
 }
 
+/// `sweep_expired` opens strictly after `deadline + SWEEP_EXPIRED_DELAY_SECONDS`.
+pub fn is_sweep_expired_window(deadline: i64, current_time: i64) -> bool {
+    deadline
+        .checked_add(SWEEP_EXPIRED_DELAY_SECONDS)
+        .is_some_and(|opens_at| current_time > opens_at)
+}
+
+/// The late-settlement bounty is half the slasher bounty, so keepers still
+/// do better slashing on time.
+pub fn sweep_expired_bounty_bps(slasher_bounty_bps: u16) -> u16 {
+    slasher_bounty_bps / 2
+}
+
 /// Check whether a slash is valid (after deadline).
 pub fn is_slash_window(deadline: i64, current_time: i64) -> bool {
     current_time >= deadline
@@ -140,6 +495,155 @@ pub fn is_buddy_only_window(deadline: i64, current_time: i64) -> bool {
     current_time < buddy_only_end
 }
 
+/// Post-deadline resolution an alarm is open to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Resolution {
+    /// Forfeit through the penalty route (`slash`)
+    Slash,
+    /// Still inside the owner's claim window; nobody else may resolve it
+    Claim,
+    /// Return to the owner without penalty (`sweep_acknowledged`)
+    Sweep,
+}
+
+/// Single rule for who may resolve an alarm once its deadline passes.
+///
+/// - `Created`: slashable from `deadline`. On the Buddy route (unless a
+///   bet is matched) only the buddy may slash during the buddy-only window.
+/// - `Acknowledged`: never slashable. The owner side claims through
+///   `deadline + grace_seconds`; after that anyone may sweep it back to the
+///   owner. The buddy-only window does not apply, because the owner keeps
+///   the funds whoever resolves it.
+pub fn post_deadline_resolution(
+    status: AlarmStatus,
+    buddy_window_applies: bool,
+    caller_is_buddy: bool,
+    deadline: i64,
+    grace_seconds: i64,
+    current_time: i64,
+) -> Result<Resolution, &'static str> {
+    match status {
+        AlarmStatus::Created => {
+            if !is_slash_window(deadline, current_time) {
+                return Err("deadline_not_passed");
+            }
+            if buddy_window_applies
+                && !caller_is_buddy
+                && is_buddy_only_window(deadline, current_time)
+            {
+                return Err("buddy_only_window");
+            }
+            Ok(Resolution::Slash)
+        }
+        AlarmStatus::Acknowledged => {
+            if is_sweep_window_for(deadline, grace_seconds, current_time) {
+                Ok(Resolution::Sweep)
+            } else {
+                Ok(Resolution::Claim)
+            }
+        }
+        _ => Err("invalid_state"),
+    }
+}
+
+// =========================================================================
+// Buddy disputes
+// =========================================================================
+
+/// Validate an optional dispute arbiter. Only Buddy-route alarms may name
+/// one, and it must be a third party: neither the owner nor the buddy.
+pub fn validate_arbiter(
+    arbiter: &[u8; 32],
+    owner: &[u8; 32],
+    buddy: Option<&[u8; 32]>,
+    route: u8,
+) -> Result<(), &'static str> {
+    if route != PenaltyRoute::Buddy as u8 {
+        return Err("buddy_route_required");
+    }
+    if arbiter == owner || buddy == Some(arbiter) || *arbiter == [0u8; 32] {
+        return Err("arbiter_not_third_party");
+    }
+    Ok(())
+}
+
+/// End of the slash freeze opened by `raise_dispute` at `current_time`.
+///
+/// An alarm can be disputed once (`dispute_until == 0`), only while it is
+/// still `Created`, only if it names an arbiter, and only from `alarm_time`
+/// on — the failure being disputed has to have happened.
+pub fn dispute_window_end(
+    status: AlarmStatus,
+    has_arbiter: bool,
+    dispute_until: i64,
+    alarm_time: i64,
+    current_time: i64,
+) -> Result<i64, &'static str> {
+    if status != AlarmStatus::Created {
+        return Err("invalid_state");
+    }
+    if !has_arbiter || dispute_until != 0 || current_time < alarm_time {
+        return Err("dispute_unavailable");
+    }
+    current_time
+        .checked_add(DISPUTE_WINDOW_SECONDS)
+        .ok_or("overflow")
+}
+
+/// Check whether slashing is frozen by an open dispute.
+///
+/// The freeze lasts until the arbiter rules or `dispute_until` passes,
+/// whichever comes first; an arbiter who never rules cannot block the
+/// penalty forever.
+pub fn is_dispute_active(dispute_until: i64, current_time: i64) -> bool {
+    current_time < dispute_until
+}
+
+// =========================================================================
+// Keeper crank
+// =========================================================================
+
+/// Number of alarms in a `crank_settle` batch of `account_count`
+/// remaining accounts: a non-empty multiple of four (alarm, vault,
+/// recipient, alarm index), at most `MAX_CRANK_ALARMS` groups.
+pub fn crank_batch_size(account_count: usize) -> Option<usize> {
+    let alarms = account_count / 4;
+    (alarms * 4 == account_count && (1..=MAX_CRANK_ALARMS).contains(&alarms)).then_some(alarms)
+}
+
+/// Number of alarms in a `claim_many` batch of `account_count` remaining
+/// accounts: a non-empty multiple of two (alarm, vault), at most
+/// `MAX_CLAIM_MANY_ALARMS` pairs.
+pub fn claim_many_batch_size(account_count: usize) -> Option<usize> {
+    let alarms = account_count / 2;
+    (alarms * 2 == account_count && (1..=MAX_CLAIM_MANY_ALARMS).contains(&alarms)).then_some(alarms)
+}
+
+/// Trigger time stored on a `SettlementSchedule`: the first moment
+/// `settle_scheduled` can settle the alarm whatever its status is by then,
+/// i.e. past the claim grace (sweep) and past the buddy-only window
+/// (slash). `None` on overflow.
+pub fn settlement_trigger_time(
+    deadline: i64,
+    grace_seconds: i64,
+    buddy_window_applies: bool,
+) -> Option<i64> {
+    let sweep_at = claim_deadline_with_grace_for(deadline, grace_seconds)?.checked_add(1)?;
+    let slash_at = if buddy_window_applies {
+        deadline.checked_add(BUDDY_ONLY_SECONDS)?
+    } else {
+        deadline
+    };
+    Some(sweep_at.max(slash_at))
+}
+
+/// Check whether a pending gift has expired (recipient can no longer accept).
+///
+/// Gifts expire once the alarm time is reached: `current_time >= alarm_time`.
+pub fn is_gift_expired(alarm_time: i64, current_time: i64) -> bool {
+    current_time >= alarm_time
+}
+
 /// Check whether an emergency refund is valid (before alarm time).
 pub fn is_refund_window(alarm_time: i64, current_time: i64) -> bool {
     current_time < alarm_time
@@ -156,8 +660,8 @@ pub fn is_snooze_window(alarm_time: i64, deadline: i64, current_time: i64) -> bo
 
 /// Validate the penalty recipient address matches the expected target.
 ///
-/// For Burn route → must match BURN_SINK.
-/// For Donate/Buddy → must match `penalty_destination`.
+/// For Burn route → must match `burn_sink` (the config's effective sink).
+/// For Donate/Buddy/Split/Savings → must match `penalty_destination`.
 pub fn validate_penalty_recipient(
     route: u8,
     recipient: &[u8; 32],
@@ -171,7 +675,12 @@ pub fn validate_penalty_recipient(
                 return Err("invalid_penalty_recipient");
             }
         }
-        PenaltyRoute::Donate | PenaltyRoute::Buddy => {
+        // Split: the primary (first) leg is the close target.
+        // Savings: the destination is the owner's SavingsVault PDA.
+        PenaltyRoute::Donate
+        | PenaltyRoute::Buddy
+        | PenaltyRoute::Split
+        | PenaltyRoute::Savings => {
             let dest = penalty_destination.ok_or("penalty_destination_not_set")?;
             if recipient != dest {
                 return Err("invalid_penalty_recipient");
@@ -209,3 +718,1402 @@ pub fn cap_at_rent_exempt(desired: u64, current_lamports: u64, min_balance: u64)
     let available = current_lamports.saturating_sub(min_balance);
     desired.min(available)
 }
+
+// =========================================================================
+// Co-owner authorization
+// =========================================================================
+
+/// Check whether `signer` may act on an alarm as its owner or a co-owner.
+///
+/// Empty co-owner slots are all-zero keys and never match.
+pub fn is_authorized_signer(signer: &[u8; 32], owner: &[u8; 32], co_owners: &[[u8; 32]]) -> bool {
+    if signer == owner {
+        return true;
+    }
+    *signer != [0u8; 32] && co_owners.contains(signer)
+}
+
+/// Check whether `signer` is a profile's delegate and the delegation is live.
+///
+/// An all-zero delegate means none is registered and never matches.
+pub fn is_delegate_active(
+    signer: &[u8; 32],
+    delegate: &[u8; 32],
+    expires_at: i64,
+    current_time: i64,
+) -> bool {
+    *delegate != [0u8; 32] && signer == delegate && current_time < expires_at
+}
+
+/// Validate a delegate registration. An all-zero delegate clears it and
+/// skips the expiry check.
+///
+/// Otherwise the delegate must differ from the owner and expire within
+/// `MAX_DELEGATE_TTL_SECONDS` from now.
+pub fn validate_delegate(
+    owner: &[u8; 32],
+    delegate: &[u8; 32],
+    expires_at: i64,
+    current_time: i64,
+) -> Result<(), &'static str> {
+    if *delegate == [0u8; 32] {
+        return Ok(());
+    }
+    if delegate == owner {
+        return Err("delegate_is_owner");
+    }
+    let max_expiry = current_time
+        .checked_add(MAX_DELEGATE_TTL_SECONDS)
+        .ok_or("overflow")?;
+    if expires_at <= current_time || expires_at > max_expiry {
+        return Err("invalid_expiry");
+    }
+    Ok(())
+}
+
+/// Validate a co-owner list supplied by the owner.
+///
+/// At most `MAX_CO_OWNERS` entries; each must be non-empty, distinct,
+/// and different from the owner.
+pub fn validate_co_owners(owner: &[u8; 32], co_owners: &[[u8; 32]]) -> Result<(), &'static str> {
+    if co_owners.len() > MAX_CO_OWNERS {
+        return Err("too_many_co_owners");
+    }
+    for (i, key) in co_owners.iter().enumerate() {
+        if *key == [0u8; 32] || key == owner || co_owners[..i].contains(key) {
+            return Err("invalid_co_owner");
+        }
+    }
+    Ok(())
+}
+
+// =========================================================================
+// Social recovery
+// =========================================================================
+
+/// Validate a guardian list and the recovery address it may redirect to.
+///
+/// An empty list disables recovery. Otherwise there must be between
+/// `RECOVERY_THRESHOLD` and `MAX_GUARDIANS` distinct, non-empty guardians
+/// other than the owner, and a non-empty recovery address other than the
+/// owner (whose key is presumed lost when recovery runs).
+pub fn validate_guardians(
+    owner: &[u8; 32],
+    guardians: &[[u8; 32]],
+    recovery_address: &[u8; 32],
+) -> Result<(), &'static str> {
+    if guardians.is_empty() {
+        return Ok(());
+    }
+    if guardians.len() > MAX_GUARDIANS || (guardians.len() as u32) < RECOVERY_THRESHOLD {
+        return Err("invalid_guardian_count");
+    }
+    for (i, key) in guardians.iter().enumerate() {
+        if *key == [0u8; 32] || key == owner || guardians[..i].contains(key) {
+            return Err("invalid_guardian");
+        }
+    }
+    if *recovery_address == [0u8; 32] || recovery_address == owner {
+        return Err("invalid_recovery_address");
+    }
+    Ok(())
+}
+
+/// Slot of `signer` in a profile's guardian list. Empty slots never match.
+pub fn guardian_slot(signer: &[u8; 32], guardians: &[[u8; 32]]) -> Option<usize> {
+    if *signer == [0u8; 32] {
+        return None;
+    }
+    guardians.iter().position(|g| g == signer)
+}
+
+/// Slot of `destination` in a profile's blocklist. Empty slots never match.
+pub fn blocklist_slot(destination: &[u8; 32], blocked: &[[u8; 32]]) -> Option<usize> {
+    if *destination == [0u8; 32] {
+        return None;
+    }
+    blocked.iter().position(|b| b == destination)
+}
+
+/// Free slot for a new blocklist entry; the key must be non-zero and not
+/// already listed.
+pub fn blocklist_insert_slot(
+    destination: &[u8; 32],
+    blocked: &[[u8; 32]],
+) -> Result<usize, &'static str> {
+    if *destination == [0u8; 32] || blocklist_slot(destination, blocked).is_some() {
+        return Err("invalid_destination");
+    }
+    blocked
+        .iter()
+        .position(|b| *b == [0u8; 32])
+        .ok_or("blocklist_full")
+}
+
+/// Check an alarm's penalty destinations against the owner's blocklist
+/// (`None` when the profile was not passed). Buddy and Split destinations
+/// cannot be checked without it, so they require the profile.
+pub fn check_destination_blocklist(
+    penalty_route: u8,
+    destinations: &[[u8; 32]],
+    blocked: Option<&[[u8; 32]]>,
+) -> Result<(), &'static str> {
+    match blocked {
+        Some(blocked) => {
+            if destinations
+                .iter()
+                .any(|d| blocklist_slot(d, blocked).is_some())
+            {
+                return Err("blocked");
+            }
+        }
+        None => {
+            let checked_route = penalty_route == PenaltyRoute::Buddy as u8
+                || penalty_route == PenaltyRoute::Split as u8;
+            if checked_route && !destinations.is_empty() {
+                return Err("profile_required");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Record the approval of guardian `slot` on an alarm.
+///
+/// `approvals` is a bitmask over guardian slots. The timelock starts once,
+/// when the approval count first reaches `RECOVERY_THRESHOLD`; repeated
+/// approvals change nothing. Returns the new `(approvals, unlocks_at)`, or
+/// `None` on overflow.
+pub fn apply_recovery_approval(
+    approvals: u8,
+    unlocks_at: i64,
+    slot: usize,
+    current_time: i64,
+) -> Option<(u8, i64)> {
+    let approvals = approvals | 1u8.checked_shl(slot as u32)?;
+    let unlocks_at = if unlocks_at == 0 && approvals.count_ones() >= RECOVERY_THRESHOLD {
+        current_time.checked_add(RECOVERY_TIMELOCK_SECONDS)?
+    } else {
+        unlocks_at
+    };
+    Some((approvals, unlocks_at))
+}
+
+/// Check whether guardians may redirect an alarm: the threshold was reached
+/// (`unlocks_at != 0`) and the timelock has passed.
+pub fn is_recovery_unlocked(unlocks_at: i64, current_time: i64) -> bool {
+    unlocks_at != 0 && current_time >= unlocks_at
+}
+
+// =========================================================================
+// Matched-deposit bets
+// =========================================================================
+
+/// Total pot of a bet alarm: owner's remaining deposit plus challenger stake.
+///
+/// Returns `None` on overflow.
+pub fn bet_pot(remaining_amount: u64, challenger_stake: u64) -> Option<u64> {
+    remaining_amount.checked_add(challenger_stake)
+}
+
+/// Check whether a challenger may still join a bet.
+///
+/// Joining requires an untouched deposit (no snoozes) and must happen
+/// strictly before alarm time, while the outcome is still unknown.
+pub fn can_join_bet(alarm_time: i64, snooze_count: u8, current_time: i64) -> bool {
+    snooze_count == 0 && current_time < alarm_time
+}
+
+// =========================================================================
+// Recurring series
+// =========================================================================
+
+/// Validate recurring series parameters.
+///
+/// The wake window must fit strictly inside one interval so that
+/// consecutive occurrences never overlap.
+pub fn validate_series_params(
+    first_alarm_time: i64,
+    window_seconds: i64,
+    interval_seconds: i64,
+    occurrences: u16,
+    deposit_per_occurrence: u64,
+    current_time: i64,
+) -> Result<(), &'static str> {
+    if first_alarm_time <= current_time {
+        return Err("alarm_time_in_past");
+    }
+    if !(MIN_SERIES_INTERVAL_SECONDS..=MAX_SERIES_INTERVAL_SECONDS).contains(&interval_seconds) {
+        return Err("invalid_series_interval");
+    }
+    if window_seconds <= 0 || window_seconds >= interval_seconds {
+        return Err("invalid_deadline");
+    }
+    if occurrences == 0 || occurrences > MAX_SERIES_OCCURRENCES {
+        return Err("invalid_series_occurrences");
+    }
+    if deposit_per_occurrence > 0 && deposit_per_occurrence < MIN_DEPOSIT_LAMPORTS {
+        return Err("deposit_too_small");
+    }
+    Ok(())
+}
+
+/// Total escrow required to back every occurrence of a series.
+///
+/// Returns `None` on overflow.
+pub fn series_total_deposit(deposit_per_occurrence: u64, occurrences: u16) -> Option<u64> {
+    deposit_per_occurrence.checked_mul(u64::from(occurrences))
+}
+
+/// Slot index of a series occurrence relative to `next_alarm_time` (0 =
+/// the next slot), if `occurrence_time` is a future slot within the
+/// `MAX_SKIP_LOOKAHEAD_SLOTS` tracked by `AlarmSeries.skipped_slots`.
+pub fn series_slot_index(
+    next_alarm_time: i64,
+    interval_seconds: i64,
+    occurrence_time: i64,
+    current_time: i64,
+) -> Option<u32> {
+    if interval_seconds <= 0 || occurrence_time <= current_time {
+        return None;
+    }
+    let offset = occurrence_time.checked_sub(next_alarm_time)?;
+    if offset < 0 || offset % interval_seconds != 0 {
+        return None;
+    }
+    u32::try_from(offset / interval_seconds)
+        .ok()
+        .filter(|&index| index < MAX_SKIP_LOOKAHEAD_SLOTS)
+}
+
+/// Count one skip against the series quota and return the new
+/// `(skip_period, skips_in_period)`; the count resets every
+/// `SERIES_SKIP_PERIOD_SECONDS`.
+pub fn apply_skip_quota(
+    skip_period: i64,
+    skips_in_period: u8,
+    current_time: i64,
+) -> Result<(i64, u8), &'static str> {
+    let period = current_time.div_euclid(SERIES_SKIP_PERIOD_SECONDS);
+    let used = if period == skip_period {
+        skips_in_period
+    } else {
+        0
+    };
+    if used >= MAX_SERIES_SKIPS_PER_PERIOD {
+        return Err("skip_limit_reached");
+    }
+    Ok((period, used + 1))
+}
+
+/// Next series slot to spawn: missed slots roll forward as in
+/// `next_occurrence_time`, then skipped slots are passed over.
+///
+/// Returns the alarm time and `skipped_slots` re-based so that bit 0 is the
+/// returned (unskipped) slot. `None` on overflow or a non-positive interval.
+pub fn next_unskipped_occurrence(
+    next_alarm_time: i64,
+    interval_seconds: i64,
+    skipped_slots: u32,
+    current_time: i64,
+) -> Option<(i64, u32)> {
+    let mut slot = next_occurrence_time(next_alarm_time, interval_seconds, current_time)?;
+    let rolled = slot.checked_sub(next_alarm_time)? / interval_seconds;
+    let mut mask = u32::try_from(rolled)
+        .ok()
+        .and_then(|r| skipped_slots.checked_shr(r))
+        .unwrap_or(0);
+    while mask & 1 == 1 {
+        slot = slot.checked_add(interval_seconds)?;
+        mask >>= 1;
+    }
+    Some((slot, mask))
+}
+
+/// Roll a series schedule forward to the first slot strictly after `current_time`.
+///
+/// Slots whose alarm time already passed are skipped without penalty.
+/// Returns `None` on overflow or a non-positive interval.
+pub fn next_occurrence_time(
+    next_alarm_time: i64,
+    interval_seconds: i64,
+    current_time: i64,
+) -> Option<i64> {
+    if interval_seconds <= 0 {
+        return None;
+    }
+    if next_alarm_time > current_time {
+        return Some(next_alarm_time);
+    }
+    let behind = current_time.checked_sub(next_alarm_time)?;
+    let skipped = behind.checked_div(interval_seconds)?.checked_add(1)?;
+    next_alarm_time.checked_add(skipped.checked_mul(interval_seconds)?)
+}
+
+// =========================================================================
+// Weekly schedules
+// =========================================================================
+
+/// Validate a weekly schedule: at least one day in the 7-bit `days_of_week`
+/// mask (bit 0 = Monday … bit 6 = Sunday), a local wake time before
+/// midnight, and a UTC offset in whole quarter hours from UTC-12:00 to
+/// UTC+14:00.
+pub fn validate_weekly_schedule(
+    days_of_week: u8,
+    wake_minute: u16,
+    utc_offset_minutes: i16,
+) -> Result<(), &'static str> {
+    if days_of_week == 0 || days_of_week >= 1 << 7 {
+        return Err("invalid_days_of_week");
+    }
+    if wake_minute >= MINUTES_PER_DAY {
+        return Err("invalid_wake_minute");
+    }
+    if !(MIN_UTC_OFFSET_MINUTES..=MAX_UTC_OFFSET_MINUTES).contains(&utc_offset_minutes)
+        || utc_offset_minutes % 15 != 0
+    {
+        return Err("invalid_utc_offset");
+    }
+    Ok(())
+}
+
+/// First UTC time strictly after `after` that falls on one of the
+/// schedule's local weekdays at its local wake time.
+///
+/// Local time is `utc + utc_offset_minutes`; 1970-01-01 was a Thursday.
+/// Returns `None` for an empty mask or on overflow.
+pub fn next_weekly_occurrence(
+    days_of_week: u8,
+    wake_minute: u16,
+    utc_offset_minutes: i16,
+    after: i64,
+) -> Option<i64> {
+    const DAY: i64 = 86_400;
+    let offset = i64::from(utc_offset_minutes) * 60;
+    let local_day = after.checked_add(offset)?.div_euclid(DAY);
+    // Today's slot may already be past, so a full week ahead is needed
+    (0..=7).find_map(|k| {
+        let day = local_day.checked_add(k)?;
+        let weekday = (day + 3).rem_euclid(7);
+        if days_of_week & (1 << weekday) == 0 {
+            return None;
+        }
+        let utc = day
+            .checked_mul(DAY)?
+            .checked_add(i64::from(wake_minute) * 60)?
+            .checked_sub(offset)?;
+        (utc > after).then_some(utc)
+    })
+}
+
+// =========================================================================
+// Global config
+// =========================================================================
+
+/// Validate config values set by the admin.
+pub fn validate_config_params(
+    protocol_fee_bps: u16,
+    slasher_bounty_bps: u16,
+    default_snooze: &SnoozeParams,
+) -> Result<(), &'static str> {
+    if protocol_fee_bps > MAX_PROTOCOL_FEE_BPS {
+        return Err("invalid_protocol_fee");
+    }
+    if slasher_bounty_bps > MAX_SLASHER_BOUNTY_BPS {
+        return Err("invalid_slasher_bounty");
+    }
+    validate_snooze_params(default_snooze)
+}
+
+/// Effective `(min, max)` wake window from the config values, where 0
+/// selects `DEFAULT_MIN_WAKE_WINDOW_SECONDS` / `DEFAULT_MAX_WAKE_WINDOW_SECONDS`.
+pub fn wake_window_bounds(min_seconds: i64, max_seconds: i64) -> (i64, i64) {
+    let min = if min_seconds == 0 {
+        DEFAULT_MIN_WAKE_WINDOW_SECONDS
+    } else {
+        min_seconds
+    };
+    let max = if max_seconds == 0 {
+        DEFAULT_MAX_WAKE_WINDOW_SECONDS
+    } else {
+        max_seconds
+    };
+    (min, max)
+}
+
+/// Validate admin wake window bounds: non-negative, and the effective
+/// minimum no larger than the effective maximum.
+pub fn validate_wake_window_bounds(min_seconds: i64, max_seconds: i64) -> bool {
+    let (min, max) = wake_window_bounds(min_seconds, max_seconds);
+    min_seconds >= 0 && max_seconds >= 0 && min <= max
+}
+
+/// Check that `deadline - alarm_time` lies within the `(min, max)` wake
+/// window returned by `wake_window_bounds`. Callers check
+/// `deadline > alarm_time` first.
+pub fn validate_wake_window(
+    alarm_time: i64,
+    deadline: i64,
+    (min, max): (i64, i64),
+) -> Result<(), &'static str> {
+    let window = deadline
+        .checked_sub(alarm_time)
+        .ok_or("wake_window_out_of_bounds")?;
+    if !(min..=max).contains(&window) {
+        return Err("wake_window_out_of_bounds");
+    }
+    Ok(())
+}
+
+/// Validate the insurance pool's share of each slash.
+pub fn validate_insurance_bps(insurance_bps: u16) -> bool {
+    insurance_bps <= MAX_INSURANCE_BPS
+}
+
+/// Validate the share of an acknowledged but unclaimed deposit that
+/// `sweep_acknowledged` routes like a penalty.
+pub fn validate_unclaimed_penalty_bps(bps: u16) -> bool {
+    bps <= MAX_UNCLAIMED_PENALTY_BPS
+}
+
+/// Whether an owner may file `claim_insurance` again: never paid before,
+/// or `INSURANCE_CLAIM_COOLDOWN_SECONDS` since the last payout.
+pub fn is_insurance_cooldown_over(last_claim_at: i64, current_time: i64) -> bool {
+    last_claim_at == 0
+        || current_time >= last_claim_at.saturating_add(INSURANCE_CLAIM_COOLDOWN_SECONDS)
+}
+
+/// Whether `migrate_alarm` / `migrate_profile` must rewrite an account:
+/// it was shorter than the current layout or carries an older version.
+pub fn needs_migration(data_len: usize, size: usize, layout_version: u8, current: u8) -> bool {
+    data_len < size || layout_version < current
+}
+
+/// Validate the referrer's share of protocol fees.
+pub fn validate_referral_fee(referral_fee_bps: u16) -> bool {
+    referral_fee_bps <= MAX_REFERRAL_FEE_BPS
+}
+
+/// Alarm time of an alarm cloned from a template: `offset_days` whole days
+/// after the template's `alarm_time`. Returns `None` on overflow.
+pub fn template_alarm_time(alarm_time: i64, offset_days: u16) -> Option<i64> {
+    alarm_time.checked_add(i64::from(offset_days).checked_mul(86_400)?)
+}
+
+/// Whether a claim that moved the day streak from `old_streak` to
+/// `new_streak` earns a forgiveness token: the streak grew onto a multiple
+/// of `FORGIVENESS_STREAK_DAYS`. Same-day claims leave the streak alone and
+/// earn nothing.
+pub fn forgiveness_earned(old_streak: u16, new_streak: u16) -> bool {
+    new_streak > old_streak && new_streak.is_multiple_of(FORGIVENESS_STREAK_DAYS)
+}
+
+/// Check whether `forgive_slash` is open: valid for
+/// `deadline <= current_time < deadline + FORGIVENESS_WINDOW_SECONDS`.
+pub fn is_forgiveness_window(deadline: i64, current_time: i64) -> bool {
+    if current_time < deadline {
+        return false;
+    }
+    let Some(window_end) = deadline.checked_add(FORGIVENESS_WINDOW_SECONDS) else {
+        return false;
+    };
+    current_time < window_end
+}
+
+/// Whether `update_penalty_destination` may change an alarm's destination.
+/// Burn has none and Savings must stay the owner's savings vault; a buddy
+/// who already staked is locked in until the alarm resolves.
+pub fn check_penalty_destination_mutable(route: u8, buddy_stake: u64) -> Result<(), &'static str> {
+    match PenaltyRoute::try_from(route) {
+        Ok(PenaltyRoute::Donate) | Ok(PenaltyRoute::Split) => Ok(()),
+        Ok(PenaltyRoute::Buddy) if buddy_stake == 0 => Ok(()),
+        Ok(PenaltyRoute::Buddy) => Err("buddy_staked"),
+        _ => Err("fixed_route"),
+    }
+}
+
+/// A proof type registry may only name known types other than `None`.
+pub fn validate_proof_type_registry(registry: u16) -> bool {
+    registry & !KNOWN_PROOF_TYPES_MASK == 0
+}
+
+/// Check a `required_proof_type` option: a known type other than `None`,
+/// enabled in `registry` (0 = every known type).
+pub fn validate_required_proof_type(proof_type: u8, registry: u16) -> Result<(), &'static str> {
+    let bit = 1u16.checked_shl(u32::from(proof_type)).unwrap_or(0);
+    if bit & KNOWN_PROOF_TYPES_MASK == 0 {
+        return Err("unknown_proof_type");
+    }
+    if registry != 0 && registry & bit == 0 {
+        return Err("not_registered");
+    }
+    Ok(())
+}
+
+/// Check a `required_proofs` option: two or more known types other than
+/// `None`, each enabled in `registry` (0 = every known type).
+pub fn validate_required_proofs(mask: u16, registry: u16) -> Result<(), &'static str> {
+    if mask & !KNOWN_PROOF_TYPES_MASK != 0 || mask.count_ones() < 2 {
+        return Err("invalid_mask");
+    }
+    if registry != 0 && mask & !registry != 0 {
+        return Err("not_registered");
+    }
+    Ok(())
+}
+
+/// Record a completed `proof_type` stage against the alarm's required
+/// proofs. Returns the new completed mask; a type the alarm does not
+/// require is an error, and repeating a stage changes nothing.
+pub fn record_proof_stage(
+    required: u16,
+    completed: u16,
+    proof_type: u8,
+) -> Result<u16, &'static str> {
+    let bit = 1u16.checked_shl(u32::from(proof_type)).unwrap_or(0);
+    if bit == 0 || required & bit == 0 {
+        return Err("not_required");
+    }
+    Ok(completed | bit)
+}
+
+/// Whether an ack reporting `reported` satisfies the alarm's
+/// `required_proof_type` (0 = any proof).
+pub fn proof_type_satisfies(required: u8, reported: u8) -> bool {
+    required == 0 || reported == required
+}
+
+/// Permit action an attested ack must carry: `PERMIT_ACTION_ACK`, or
+/// `PERMIT_ACTION_ACK_TYPED + required` when the alarm requires a proof
+/// type, so the server's signature covers the verified type.
+pub fn ack_permit_action(required_proof_type: u8) -> u8 {
+    if required_proof_type == 0 {
+        PERMIT_ACTION_ACK
+    } else {
+        PERMIT_ACTION_ACK_TYPED.saturating_add(required_proof_type)
+    }
+}
+
+/// A claim destination must be a real key other than the owner.
+pub fn validate_claim_to(owner: &[u8; 32], claim_to: &[u8; 32]) -> bool {
+    claim_to != owner && claim_to.iter().any(|&b| b != 0)
+}
+
+/// A linked buddy must be a real key other than the owner.
+pub fn validate_buddy_link(owner: &[u8; 32], buddy: &[u8; 32]) -> bool {
+    buddy != owner && buddy.iter().any(|&b| b != 0)
+}
+
+/// A referrer must be a real key other than the referee.
+pub fn validate_referrer(owner: &[u8; 32], referrer: &[u8; 32]) -> bool {
+    referrer != owner && referrer.iter().any(|&b| b != 0)
+}
+
+/// Check a new deposit against the config limits (0 = unlimited) and
+/// return the owner's updated `(deposit_day, deposited_today)`.
+///
+/// The daily total resets at each UTC day boundary.
+pub fn apply_deposit_limits(
+    deposit_amount: u64,
+    max_deposit: u64,
+    daily_cap: u64,
+    deposit_day: i64,
+    deposited_today: u64,
+    current_time: i64,
+) -> Result<(i64, u64), &'static str> {
+    if max_deposit > 0 && deposit_amount > max_deposit {
+        return Err("deposit_too_large");
+    }
+    let today = current_time.div_euclid(86_400);
+    let so_far = if today == deposit_day {
+        deposited_today
+    } else {
+        0
+    };
+    let total = so_far.checked_add(deposit_amount).ok_or("overflow")?;
+    if daily_cap > 0 && total > daily_cap {
+        return Err("daily_cap_exceeded");
+    }
+    Ok((today, total))
+}
+
+/// Open-alarm count after creating one more, enforcing `max_open` (0 = unlimited).
+pub fn next_open_alarm_count(open_count: u16, max_open: u16) -> Result<u16, &'static str> {
+    if max_open > 0 && open_count >= max_open {
+        return Err("too_many_open_alarms");
+    }
+    open_count.checked_add(1).ok_or("overflow")
+}
+
+/// Treasury signers are either all unset (admin-only treasury) or
+/// distinct, non-zero keys.
+pub fn validate_treasury_signers(signers: &[[u8; 32]]) -> bool {
+    let zero = [0u8; 32];
+    if signers.iter().all(|s| *s == zero) {
+        return true;
+    }
+    signers
+        .iter()
+        .enumerate()
+        .all(|(i, s)| *s != zero && !signers[..i].contains(s))
+}
+
+/// Whether the keys in `signed` may move treasury funds: the admin while no
+/// treasury signers are set, otherwise `TREASURY_MULTISIG_THRESHOLD` of them.
+pub fn check_treasury_authority(
+    admin: &[u8; 32],
+    treasury_signers: &[[u8; 32]],
+    signed: &[[u8; 32]],
+) -> Result<(), &'static str> {
+    let zero = [0u8; 32];
+    if treasury_signers.iter().all(|s| *s == zero) {
+        if !signed.contains(admin) {
+            return Err("not_admin");
+        }
+        return Ok(());
+    }
+    let approvals = treasury_signers
+        .iter()
+        .filter(|s| **s != zero && signed.contains(s))
+        .count();
+    if approvals < TREASURY_MULTISIG_THRESHOLD {
+        return Err("approvals_missing");
+    }
+    Ok(())
+}
+
+/// Lamports an account needs on top of `lamports` to stay rent-exempt
+/// after growing to a size whose minimum balance is `min_balance`.
+pub fn rent_topup(lamports: u64, min_balance: u64) -> u64 {
+    min_balance.saturating_sub(lamports)
+}
+
+// =========================================================================
+// NFC/QR tag registration
+// =========================================================================
+
+/// A tag hash must not be all zeroes (the client's "unset" sentinel).
+pub fn validate_tag_hash(tag_hash: &[u8; 32]) -> Result<(), &'static str> {
+    if tag_hash.iter().all(|&b| b == 0) {
+        return Err("invalid_tag_hash");
+    }
+    Ok(())
+}
+
+/// Rotation requires the caller to prove knowledge of the current hash.
+pub fn validate_tag_rotation(
+    current: Option<[u8; 32]>,
+    old_hash: &[u8; 32],
+    new_hash: &[u8; 32],
+) -> Result<(), &'static str> {
+    if current.as_ref() != Some(old_hash) {
+        return Err("tag_mismatch");
+    }
+    if old_hash == new_hash {
+        return Err("invalid_tag_hash");
+    }
+    validate_tag_hash(new_hash)
+}
+
+/// If a tag is registered, the supplied proof hash must match it.
+pub fn verify_tag_proof(
+    registered: Option<[u8; 32]>,
+    proof_hash: Option<[u8; 32]>,
+) -> Result<(), &'static str> {
+    match registered {
+        None => Ok(()),
+        Some(tag) if proof_hash == Some(tag) => Ok(()),
+        Some(_) => Err("tag_mismatch"),
+    }
+}
+
+/// An alarm created with a proof commitment (non-zero) can only be
+/// acknowledged by revealing a preimage whose SHA-256 equals it.
+pub fn verify_proof_commitment(
+    commitment: &[u8; 32],
+    preimage: Option<[u8; 32]>,
+) -> Result<(), &'static str> {
+    if commitment.iter().all(|&b| b == 0) {
+        return Ok(());
+    }
+    match preimage {
+        Some(preimage) if hash(&preimage).to_bytes() == *commitment => Ok(()),
+        Some(_) => Err("preimage_mismatch"),
+        None => Err("preimage_required"),
+    }
+}
+
+/// Evidence can be anchored once an alarm is acknowledged (or claimed), a
+/// single time, and must be a non-zero hash.
+pub fn validate_proof_anchor(
+    status: AlarmStatus,
+    anchored: &[u8; 32],
+    proof_hash: &[u8; 32],
+) -> Result<(), &'static str> {
+    if !matches!(status, AlarmStatus::Acknowledged | AlarmStatus::Claimed) {
+        return Err("invalid_state");
+    }
+    if anchored.iter().any(|&b| b != 0) {
+        return Err("already_anchored");
+    }
+    if proof_hash.iter().all(|&b| b == 0) {
+        return Err("invalid_proof_hash");
+    }
+    Ok(())
+}
+
+/// A reported proof completion time must fall inside the ring window:
+/// no earlier than its start (see [`wake_window_start`]) and no later than
+/// the ack itself.
+pub fn validate_proof_completed_at(
+    proof_completed_at: i64,
+    window_start: i64,
+    current_time: i64,
+) -> bool {
+    (window_start..=current_time).contains(&proof_completed_at)
+}
+
+/// Basis-point share of `amount`, rounded down.
+pub fn bps_of(amount: u64, bps: u16) -> Option<u64> {
+    let share = (amount as u128)
+        .checked_mul(bps as u128)?
+        .checked_div(BPS_DENOMINATOR as u128)?;
+    u64::try_from(share).ok()
+}
+
+// =========================================================================
+// Split penalties
+// =========================================================================
+
+/// Validate split legs: 2..=MAX legs, non-zero shares summing to 100%,
+/// distinct non-default destinations, and the first leg is `primary`.
+pub fn validate_penalty_splits(
+    primary: &[u8; 32],
+    splits: &[([u8; 32], u16)],
+) -> Result<(), &'static str> {
+    if splits.len() < 2 || splits.len() > MAX_PENALTY_SPLITS {
+        return Err("invalid_split_count");
+    }
+    if &splits[0].0 != primary {
+        return Err("invalid_split_primary");
+    }
+    let mut total: u64 = 0;
+    for (i, (dest, bps)) in splits.iter().enumerate() {
+        if *bps == 0 || *dest == [0u8; 32] {
+            return Err("invalid_split_leg");
+        }
+        if splits[..i].iter().any(|(d, _)| d == dest) {
+            return Err("invalid_split_leg");
+        }
+        total += u64::from(*bps);
+    }
+    if total != BPS_DENOMINATOR {
+        return Err("invalid_split_total");
+    }
+    Ok(())
+}
+
+/// Amount owed to each leg. The first leg absorbs rounding dust so the
+/// shares always sum to `amount`.
+pub fn split_shares(amount: u64, bps: &[u16]) -> Option<Vec<u64>> {
+    if bps.is_empty() {
+        return None;
+    }
+
+    let mut shares = Vec::with_capacity(bps.len());
+    shares.push(0);
+    let mut rest: u64 = 0;
+    for b in bps.iter().skip(1) {
+        let share = bps_of(amount, *b)?;
+        rest = rest.checked_add(share)?;
+        shares.push(share);
+    }
+    *shares.first_mut()? = amount.checked_sub(rest)?;
+    Some(shares)
+}
+
+// =========================================================================
+// Charity registry
+// =========================================================================
+
+/// Donate-route alarms must point at a registered charity when the config
+/// requires it. `registered` is the address of the supplied `Charity` PDA.
+pub fn is_charity_verified(
+    required: bool,
+    penalty_route: u8,
+    destination: Option<&[u8; 32]>,
+    registered: Option<&[u8; 32]>,
+) -> bool {
+    if !required || PenaltyRoute::try_from(penalty_route) != Ok(PenaltyRoute::Donate) {
+        return true;
+    }
+    matches!((destination, registered), (Some(d), Some(r)) if d == r)
+}
+
+// =========================================================================
+// Group wake challenges
+// =========================================================================
+
+/// Joining closes at the shared wake time or when the challenge is full.
+pub fn can_join_challenge(
+    wake_time: i64,
+    participant_count: u32,
+    max_participants: u32,
+    current_time: i64,
+) -> bool {
+    current_time < wake_time && participant_count < max_participants
+}
+
+/// Payout for the next winner. Winners split the whole pot equally; the
+/// last winner receives any rounding dust so the pot drains exactly.
+pub fn challenge_winner_share(
+    total_pot: u64,
+    paid_out: u64,
+    acked_count: u32,
+    winners_paid: u32,
+) -> Option<u64> {
+    if acked_count == 0 || winners_paid >= acked_count {
+        return None;
+    }
+    if winners_paid + 1 == acked_count {
+        return total_pot.checked_sub(paid_out);
+    }
+    total_pot.checked_div(u64::from(acked_count))
+}
+
+// =========================================================================
+// Head-to-head duels
+// =========================================================================
+
+/// The opponent may accept until the earlier of the two wake times.
+pub fn can_accept_duel(
+    accepted: bool,
+    creator_wake_time: i64,
+    opponent_wake_time: i64,
+    current_time: i64,
+) -> bool {
+    !accepted && current_time < creator_wake_time.min(opponent_wake_time)
+}
+
+/// Whether a duel can be settled. An unaccepted duel settles (refund)
+/// once the accept window closes; an accepted one once both sides acked
+/// or both deadlines passed.
+pub fn can_settle_duel(
+    accepted: bool,
+    creator_acked: bool,
+    opponent_acked: bool,
+    accept_closes_at: i64,
+    last_deadline: i64,
+    current_time: i64,
+) -> bool {
+    if !accepted {
+        return current_time >= accept_closes_at;
+    }
+    (creator_acked && opponent_acked) || current_time >= last_deadline
+}
+
+/// Payouts `(creator, opponent)` at settlement. The sole waker takes both
+/// stakes; if both woke or both failed, each side gets its stake back.
+pub fn duel_payouts(
+    stake: u64,
+    accepted: bool,
+    creator_acked: bool,
+    opponent_acked: bool,
+) -> Option<(u64, u64)> {
+    if !accepted {
+        return Some((stake, 0));
+    }
+    let pot = stake.checked_mul(2)?;
+    Some(match (creator_acked, opponent_acked) {
+        (true, false) => (pot, 0),
+        (false, true) => (0, pot),
+        _ => (stake, stake),
+    })
+}
+
+// =========================================================================
+// Progressive slashing
+// =========================================================================
+
+/// Validate a progressive slash schedule. `(0, 0)` means all-or-nothing;
+/// otherwise both values must be in range and the route cannot be Split.
+pub fn validate_partial_slash(
+    bps: u16,
+    interval_seconds: i64,
+    route: u8,
+) -> Result<(), &'static str> {
+    if bps == 0 && interval_seconds == 0 {
+        return Ok(());
+    }
+    if bps == 0 || u64::from(bps) > BPS_DENOMINATOR {
+        return Err("invalid_partial_slash_bps");
+    }
+    if !(MIN_PARTIAL_SLASH_INTERVAL_SECONDS..=MAX_PARTIAL_SLASH_INTERVAL_SECONDS)
+        .contains(&interval_seconds)
+    {
+        return Err("invalid_partial_slash_interval");
+    }
+    if route == PenaltyRoute::Split as u8 {
+        return Err("split_route_unsupported");
+    }
+    Ok(())
+}
+
+/// Validate the buddy's thank-you share. Only Buddy-route alarms may set
+/// one, capped at `MAX_BUDDY_REWARD_BPS`.
+pub fn validate_buddy_reward(bps: u16, route: u8) -> Result<(), &'static str> {
+    if bps == 0 {
+        return Ok(());
+    }
+    if route != PenaltyRoute::Buddy as u8 {
+        return Err("buddy_route_required");
+    }
+    if bps > MAX_BUDDY_REWARD_BPS {
+        return Err("buddy_reward_too_high");
+    }
+    Ok(())
+}
+
+/// Lamports that may be slashed now under a progressive schedule.
+///
+/// One tranche of `bps` of the post-deadline deposit (`remaining +
+/// already_slashed`) becomes due when the deadline passes, and another at
+/// the start of every following interval, capped at 100%. Returns the
+/// portion not yet slashed.
+pub fn partial_slash_due(
+    remaining: u64,
+    already_slashed: u64,
+    bps: u16,
+    interval_seconds: i64,
+    deadline: i64,
+    current_time: i64,
+) -> Option<u64> {
+    if current_time < deadline || bps == 0 {
+        return Some(0);
+    }
+    if interval_seconds <= 0 {
+        return None;
+    }
+    let base = remaining.checked_add(already_slashed)?;
+    let tranches = (current_time.checked_sub(deadline)? / interval_seconds).checked_add(1)?;
+    let cumulative_bps = (tranches as u64)
+        .checked_mul(u64::from(bps))?
+        .min(BPS_DENOMINATOR);
+    let cumulative = bps_of(base, cumulative_bps as u16)?;
+    Some(cumulative.saturating_sub(already_slashed).min(remaining))
+}
+
+// =========================================================================
+// Late claims
+// =========================================================================
+
+/// Refund owed to an owner claiming an all-or-nothing alarm after the
+/// deadline: 50% of `remaining` within the first hour, 25% until six
+/// hours, nothing afterwards. Before the deadline the whole amount is owed.
+pub fn late_claim_refund(deadline: i64, current_time: i64, remaining: u64) -> u64 {
+    let bps = match current_time.saturating_sub(deadline) {
+        elapsed if elapsed < 0 => return remaining,
+        elapsed if elapsed < LATE_CLAIM_EARLY_SECONDS => LATE_CLAIM_EARLY_REFUND_BPS,
+        elapsed if elapsed < LATE_CLAIM_MAX_SECONDS => LATE_CLAIM_LATE_REFUND_BPS,
+        _ => return 0,
+    };
+    // bps ≤ 10_000, so the share always fits in u64
+    bps_of(remaining, bps).unwrap_or(0)
+}
+
+// =========================================================================
+// Attestation permits
+// =========================================================================
+
+/// Bytes the attestation server signs for a permit:
+/// `PERMIT_DOMAIN || action || alarm || nonce (le) || expires_at (le) || proof_hash`.
+pub fn permit_message(
+    action: u8,
+    alarm: &[u8; 32],
+    nonce: u64,
+    expires_at: i64,
+    proof_hash: &[u8; 32],
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(PERMIT_DOMAIN.len() + 1 + 32 + 8 + 8 + 32);
+    message.extend_from_slice(PERMIT_DOMAIN);
+    message.push(action);
+    message.extend_from_slice(alarm);
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message.extend_from_slice(&expires_at.to_le_bytes());
+    message.extend_from_slice(proof_hash);
+    message
+}
+
+/// A permit must still be valid and may not outlive `MAX_PERMIT_TTL_SECONDS`.
+pub fn validate_permit_window(expires_at: i64, current_time: i64) -> Result<(), &'static str> {
+    if current_time > expires_at {
+        return Err("permit_expired");
+    }
+    if expires_at.saturating_sub(current_time) > MAX_PERMIT_TTL_SECONDS {
+        return Err("permit_ttl_too_long");
+    }
+    Ok(())
+}
+
+/// Extract `(pubkey, message)` from Ed25519 program instruction data that
+/// carries exactly one signature with all data inline in the same
+/// instruction. Returns `None` for any other layout.
+pub fn parse_ed25519_instruction(data: &[u8]) -> Option<([u8; 32], &[u8])> {
+    const HEADER: usize = 2;
+    const OFFSETS: usize = 14;
+    if data.len() < HEADER + OFFSETS || data[0] != 1 {
+        return None;
+    }
+    let read_u16 = |at: usize| -> usize { u16::from_le_bytes([data[at], data[at + 1]]) as usize };
+    let o = HEADER;
+    let (signature_ix, pubkey_offset, pubkey_ix) =
+        (read_u16(o + 2), read_u16(o + 4), read_u16(o + 6));
+    let (message_offset, message_size, message_ix) =
+        (read_u16(o + 8), read_u16(o + 10), read_u16(o + 12));
+    // Signature, key and message must all live in this instruction
+    let inline = u16::MAX as usize;
+    if signature_ix != inline || pubkey_ix != inline || message_ix != inline {
+        return None;
+    }
+    let pubkey: [u8; 32] = data
+        .get(pubkey_offset..pubkey_offset.checked_add(32)?)?
+        .try_into()
+        .ok()?;
+    let message = data.get(message_offset..message_offset.checked_add(message_size)?)?;
+    Some((pubkey, message))
+}
+
+/// Whether `signer` may attest permits: the current key always, the
+/// previous key only until the end of its transition window.
+pub fn is_attestation_key_valid(
+    signer: &[u8; 32],
+    current_key: &[u8; 32],
+    previous_key: &[u8; 32],
+    previous_valid_until: i64,
+    current_time: i64,
+) -> bool {
+    if *signer == [0u8; 32] {
+        return false;
+    }
+    signer == current_key || (signer == previous_key && current_time <= previous_valid_until)
+}
+
+/// A new attestation key must be non-zero and different from the current
+/// one; the overlap window is bounded by `MAX_ATTESTATION_TRANSITION_SECONDS`.
+pub fn validate_attestation_rotation(
+    current_key: &[u8; 32],
+    new_key: &[u8; 32],
+    transition_seconds: i64,
+) -> Result<(), &'static str> {
+    if *new_key == [0u8; 32] || new_key == current_key {
+        return Err("invalid_attestation_key");
+    }
+    if !(0..=MAX_ATTESTATION_TRANSITION_SECONDS).contains(&transition_seconds) {
+        return Err("invalid_transition_window");
+    }
+    Ok(())
+}
+
+/// Count distinct attestations among `signers`. The current key and its
+/// rotated-out predecessor share one slot, so a rotation never lets one
+/// server count twice; every registered attestor counts once.
+pub fn count_attestations(
+    signers: &[[u8; 32]],
+    current_key: &[u8; 32],
+    previous_key: &[u8; 32],
+    previous_valid_until: i64,
+    attestors: &[[u8; 32]],
+    current_time: i64,
+) -> usize {
+    let primary = signers.iter().any(|s| {
+        is_attestation_key_valid(
+            s,
+            current_key,
+            previous_key,
+            previous_valid_until,
+            current_time,
+        )
+    });
+    let extra = attestors
+        .iter()
+        .filter(|a| **a != [0u8; 32] && signers.contains(a))
+        .count();
+    usize::from(primary) + extra
+}
+
+/// Attestors must be distinct, non-zero and differ from the current key;
+/// the threshold must be reachable (`1..=attestors + 1`).
+pub fn validate_attestor_set(
+    current_key: &[u8; 32],
+    attestors: &[[u8; 32]],
+    threshold: u8,
+) -> Result<(), &'static str> {
+    if attestors.len() > MAX_ATTESTORS {
+        return Err("too_many_attestors");
+    }
+    for (i, key) in attestors.iter().enumerate() {
+        if *key == [0u8; 32] || key == current_key || attestors[..i].contains(key) {
+            return Err("invalid_attestor");
+        }
+    }
+    if threshold == 0 || usize::from(threshold) > attestors.len() + 1 {
+        return Err("invalid_threshold");
+    }
+    Ok(())
+}
+
+// =========================================================================
+// Achievements
+// =========================================================================
+
+/// Per-profile claim counters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProfileStats {
+    pub total_claims: u32,
+    pub current_streak: u16,
+    pub best_streak: u16,
+    pub clean_streak: u16,
+    pub best_clean_streak: u16,
+    /// UTC day index of the last claim
+    pub last_claim_day: i64,
+}
+
+/// Record a claim at `current_time` for an alarm snoozed `snooze_count`
+/// times. Streaks count UTC days: another claim the same day only bumps
+/// `total_claims`, the next day extends the streak, a gap restarts it.
+/// Any snoozed claim resets the snooze-free streak.
+pub fn record_claim(stats: &ProfileStats, current_time: i64, snooze_count: u8) -> ProfileStats {
+    let day = current_time.div_euclid(86_400);
+    let mut next = *stats;
+    next.total_claims = stats.total_claims.saturating_add(1);
+
+    let first_claim = stats.total_claims == 0;
+    if first_claim || day > stats.last_claim_day {
+        let consecutive = !first_claim && day == stats.last_claim_day + 1;
+        next.current_streak = if consecutive {
+            stats.current_streak.saturating_add(1)
+        } else {
+            1
+        };
+        next.clean_streak = if consecutive {
+            stats.clean_streak.saturating_add(1)
+        } else {
+            1
+        };
+        next.last_claim_day = day;
+    }
+    if snooze_count > 0 {
+        next.clean_streak = 0;
+    }
+    next.best_streak = next.best_streak.max(next.current_streak);
+    next.best_clean_streak = next.best_clean_streak.max(next.clean_streak);
+    next
+}
+
+/// UTC day index of `timestamp` (`None` before 1970).
+pub fn stats_day_of(timestamp: i64) -> Option<u64> {
+    u64::try_from(timestamp.div_euclid(86_400)).ok()
+}
+
+/// Daily stats can be opened for today or any later day.
+pub fn is_stats_day_open(day: u64, current_time: i64) -> bool {
+    stats_day_of(current_time).is_some_and(|today| day >= today)
+}
+
+/// Whether `badge_id` is unlocked by `stats`; unknown badges are an error.
+pub fn badge_unlocked(badge_id: u8, stats: &ProfileStats) -> Result<bool, &'static str> {
+    match badge_id {
+        BADGE_WEEK_STREAK => Ok(stats.best_streak >= WEEK_STREAK_DAYS),
+        BADGE_THIRTY_CLAIMS => Ok(stats.total_claims >= THIRTY_CLAIMS_COUNT),
+        BADGE_SNOOZE_FREE_MONTH => Ok(stats.best_clean_streak >= SNOOZE_FREE_MONTH_DAYS),
+        _ => Err("unknown_badge"),
+    }
+}
+
+// =========================================================================
+// Leaderboard epochs
+// =========================================================================
+
+/// Epoch index containing `timestamp`, or `None` before the Unix epoch.
+pub fn epoch_of(timestamp: i64) -> Option<u64> {
+    u64::try_from(timestamp.div_euclid(LEADERBOARD_EPOCH_SECONDS)).ok()
+}
+
+/// Whether entries for `epoch` may still be opened or updated at `current_time`.
+pub fn is_epoch_open(epoch: u64, finalized: bool, current_time: i64) -> bool {
+    !finalized && epoch_of(current_time).is_some_and(|now| epoch >= now)
+}
+
+/// An epoch can be finalized once its last second has passed.
+pub fn can_finalize_epoch(epoch: u64, finalized: bool, current_time: i64) -> bool {
+    !finalized && epoch_of(current_time).is_some_and(|now| now > epoch)
+}
+
+// =========================================================================
+// Savings route
+// =========================================================================
+
+/// Validate a user-chosen savings lock.
+pub fn validate_savings_lock(lock_seconds: i64) -> bool {
+    (MIN_SAVINGS_LOCK_SECONDS..=MAX_SAVINGS_LOCK_SECONDS).contains(&lock_seconds)
+}
+
+/// New unlock time after a penalty lands: the lock restarts from now but
+/// never moves earlier.
+pub fn savings_unlock_at(unlock_at: i64, lock_seconds: i64, current_time: i64) -> Option<i64> {
+    Some(unlock_at.max(current_time.checked_add(lock_seconds)?))
+}
+
+// =========================================================================
+// Alarm index
+// =========================================================================
+
+/// Add `alarm_id` to an owner's index. Already-listed ids are left alone.
+pub fn index_alarm(
+    alarm_ids: &mut Vec<u64>,
+    alarm_id: u64,
+    capacity: usize,
+) -> Result<(), &'static str> {
+    if alarm_ids.contains(&alarm_id) {
+        return Ok(());
+    }
+    if alarm_ids.len() >= capacity {
+        return Err("index_full");
+    }
+    alarm_ids.push(alarm_id);
+    Ok(())
+}
+
+/// Check a create's `expected_open_alarms` against the owner's index
+/// length, so a retried submit fails once the first one has landed.
+pub fn check_expected_open_alarms(
+    expected: Option<u16>,
+    indexed: Option<usize>,
+) -> Result<(), &'static str> {
+    let Some(expected) = expected else {
+        return Ok(());
+    };
+    let indexed = indexed.ok_or("index_required")?;
+    if indexed != usize::from(expected) {
+        return Err("open_alarms_mismatch");
+    }
+    Ok(())
+}
+
+/// Drop `alarm_id` from an owner's index, keeping creation order.
+/// Returns whether it was listed.
+pub fn unindex_alarm(alarm_ids: &mut Vec<u64>, alarm_id: u64) -> bool {
+    match alarm_ids.iter().position(|&id| id == alarm_id) {
+        Some(i) => {
+            alarm_ids.remove(i);
+            true
+        }
+        None => false,
+    }
+}
+
+// =========================================================================
+// Settlement memos
+// =========================================================================
+
+/// Short, wallet-friendly name of a penalty route.
+pub fn penalty_route_name(route: u8) -> &'static str {
+    match route {
+        0 => "burn",
+        1 => "donate",
+        2 => "buddy",
+        3 => "split",
+        4 => "savings",
+        _ => "unknown",
+    }
+}
+
+/// Compact JSON payload recorded through the SPL Memo program when a
+/// deposit is settled, e.g.
+/// `{"solarma":"slash","alarm_id":7,"amount":100,"route":"buddy"}`.
+pub fn settlement_memo(action: &str, alarm_id: u64, amount: u64, route: &str) -> String {
+    format!(
+        "{{\"solarma\":\"{}\",\"alarm_id\":{},\"amount\":{},\"route\":\"{}\"}}",
+        action, alarm_id, amount, route
+    )
+}
+
+// =========================================================================
+// Address lookup table
+// =========================================================================
+
+/// Entries of `wanted` not yet in `existing`, in order and without
+/// repeats: what `extend_lookup_table` still has to append.
+pub fn missing_lookup_addresses(existing: &[[u8; 32]], wanted: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    let mut missing: Vec<[u8; 32]> = Vec::new();
+    for address in wanted {
+        if !existing.contains(address) && !missing.contains(address) {
+            missing.push(*address);
+        }
+    }
+    missing
+}
+
+// =========================================================================
+// Read-only views
+// =========================================================================
+
+/// Lamports the owner would receive from a claim: the whole vault balance
+/// (deposit + rent) less the buddy's thank-you share.
+pub fn claimable_amount(
+    vault_lamports: u64,
+    remaining_amount: u64,
+    buddy_reward_bps: u16,
+) -> Option<u64> {
+    let reward = bps_of(remaining_amount, buddy_reward_bps)?;
+    vault_lamports.checked_sub(reward)
+}
+
+/// Cost of the next snooze after the rent-exempt cap, or 0 while it is
+/// free or once `max_snoozes` is reached.
+pub fn next_snooze_cost(
+    remaining_amount: u64,
+    snooze_count: u8,
+    params: &SnoozeParams,
+    model: SnoozeModel,
+    free_snoozes: u8,
+    vault_lamports: u64,
+    min_balance: u64,
+) -> Option<u64> {
+    quoted_snooze_cost(
+        remaining_amount,
+        snooze_count,
+        params,
+        model,
+        free_snoozes,
+        None,
+        vault_lamports,
+        min_balance,
+    )
+}
+
+/// [`next_snooze_cost`] for a snooze of `extension_minutes` (priced as in
+/// `snooze`), or of the alarm's own length when `None`.
+#[allow(clippy::too_many_arguments)]
+pub fn quoted_snooze_cost(
+    remaining_amount: u64,
+    snooze_count: u8,
+    params: &SnoozeParams,
+    model: SnoozeModel,
+    free_snoozes: u8,
+    extension_minutes: Option<u8>,
+    vault_lamports: u64,
+    min_balance: u64,
+) -> Option<u64> {
+    if is_max_snooze_for(snooze_count, params.max_snoozes) {
+        return Some(0);
+    }
+    let Some(index) = paid_snooze_index(snooze_count, free_snoozes) else {
+        return Some(0);
+    };
+    let cost = match extension_minutes {
+        Some(minutes) => snooze_cost_scaled_for(remaining_amount, index, params, model, minutes)?,
+        None => snooze_cost_for_model(model, remaining_amount, index, params.percent)?,
+    };
+    Some(cap_at_rent_exempt(cost, vault_lamports, min_balance))
+}
@@ -0,0 +1,39 @@
+//! Accept buddy link instruction - the buddy co-signs a pending link
+
+use crate::error::SolarmaError;
+use crate::state::BuddyLink;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptBuddyLink<'info> {
+    #[account(
+        mut,
+        has_one = buddy @ SolarmaError::UnauthorizedSigner,
+        constraint = !buddy_link.active @ SolarmaError::InvalidBuddyLink,
+        seeds = [b"buddy-link", buddy_link.owner.as_ref(), buddy.key().as_ref()],
+        bump = buddy_link.bump
+    )]
+    pub buddy_link: Account<'info, BuddyLink>,
+
+    /// Buddy agreeing to the link
+    pub buddy: Signer<'info>,
+}
+
+pub fn process_accept_buddy_link(ctx: Context<AcceptBuddyLink>) -> Result<()> {
+    let link_key = ctx.accounts.buddy_link.key();
+    let link = &mut ctx.accounts.buddy_link;
+    link.active = true;
+
+    emit!(crate::events::BuddyLinkUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: link.owner,
+        buddy: link.buddy,
+        link: link_key,
+        active: true,
+        removed: false,
+    });
+
+    msg!("Buddy {} accepted link from {}", link.buddy, link.owner);
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//! Accept duel instruction - the invited opponent matches the stake
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Duel, GlobalConfig};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+pub struct AcceptDuel<'info> {
+    #[account(
+        mut,
+        has_one = opponent,
+        seeds = [b"duel", duel.creator.as_ref(), &duel.duel_id.to_le_bytes()],
+        bump = duel.bump
+    )]
+    pub duel: Account<'info, Duel>,
+
+    /// Global config - new deposits are rejected while paused
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ SolarmaError::ProtocolPaused
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub opponent: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_accept_duel(ctx: Context<AcceptDuel>) -> Result<()> {
+    let clock = Clock::get()?;
+    let duel = &ctx.accounts.duel;
+    require!(
+        helpers::can_accept_duel(
+            duel.accepted,
+            duel.creator_wake_time,
+            duel.opponent_wake_time,
+            clock.unix_timestamp,
+        ),
+        SolarmaError::DuelClosed
+    );
+
+    let stake = duel.stake;
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.opponent.to_account_info(),
+                to: ctx.accounts.duel.to_account_info(),
+            },
+        ),
+        stake,
+    )?;
+
+    let duel = &mut ctx.accounts.duel;
+    duel.accepted = true;
+
+    emit!(crate::events::DuelAccepted {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        opponent: duel.opponent,
+        duel: duel.key(),
+    });
+
+    msg!("Duel {} accepted by {}", duel.duel_id, duel.opponent);
+    Ok(())
+}
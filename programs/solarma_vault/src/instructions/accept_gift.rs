@@ -0,0 +1,45 @@
+//! Accept gift instruction - recipient takes ownership of a pending gift alarm
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Alarm, AlarmStatus};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptGift<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::PendingGift @ SolarmaError::InvalidAlarmState
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Gift recipient (recorded as alarm owner at creation)
+    pub owner: Signer<'info>,
+}
+
+pub fn process_accept_gift(ctx: Context<AcceptGift>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let owner_key = ctx.accounts.owner.key();
+    let alarm = &mut ctx.accounts.alarm;
+    let clock = crate::clock::get(ctx.remaining_accounts)?;
+
+    require!(
+        !helpers::is_gift_expired(alarm.alarm_time, clock.unix_timestamp),
+        SolarmaError::GiftExpired
+    );
+
+    alarm.status = AlarmStatus::Created;
+
+    emit!(crate::events::GiftAccepted {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: owner_key,
+        gifter: alarm.gifter.unwrap_or_default(),
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+    });
+
+    msg!("Gift alarm {} accepted by {}", alarm.alarm_id, owner_key);
+    Ok(())
+}
@@ -1,61 +1,270 @@
 //! AckAwake instruction - record the owner's wake-up acknowledgement on-chain (H3)
 //!
-//! This instruction is called by the owner after completing the wake proof
+//! This instruction is called by the owner (or a co-owner) after completing the wake proof
 //! on the client side. It transitions the alarm from Created → Acknowledged.
 //! The wake proof itself is verified client-side — this instruction does not
 //! carry or verify a proof payload; it records only the owner's signed
 //! acknowledgement. This reduces the race window between claim and slash, and
 //! prevents bots from slashing before the claim transaction reaches finality.
+//!
+//! If the owner's `UserProfile` is passed and has a registered tag, the
+//! supplied `proof_hash` must match it. The profile also authorizes its
+//! active delegate to sign in place of the owner.
+//!
+//! An alarm created with a `proof_commitment` additionally requires
+//! `proof_preimage`, whose SHA-256 must equal the commitment. Only the owner
+//! knows the preimage (e.g. a hash of the NFC tag UID + salt), so this is an
+//! on-chain wake-proof check that needs no attestation server.
+//!
+//! An alarm created with `early_ack_seconds` opens ack (and claim) that
+//! many seconds before `alarm_time`, for owners who wake up early.
+//!
+//! The client also reports which proof was completed (`proof_type`) and
+//! when (`proof_completed_at`, to the second). Both are stored on the alarm
+//! for analytics and disputes; only their ranges are checked.
+//!
+//! An alarm with a `soft_deadline` forfeits part of its deposit when
+//! acknowledged after it: nothing at the soft deadline, rising linearly to
+//! all of it at the hard `deadline` (`helpers::soft_penalty`). The forfeit
+//! goes where a snooze cost would, judged at the on-chain clock, so every
+//! claim path (and the sweep) later returns only what is left.
 
 use crate::error::SolarmaError;
-use crate::state::{Alarm, AlarmStatus};
+use crate::helpers;
+use crate::instructions::snooze::{pay_penalty_sink, penalty_sink};
+use crate::state::{Alarm, AlarmStatus, GlobalConfig, ProofType, UserProfile, Vault};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct AckAwake<'info> {
     #[account(
         mut,
-        has_one = owner,
-        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState
+        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState,
+        constraint = !alarm.require_attestation @ SolarmaError::AttestationRequired
     )]
     pub alarm: Account<'info, Alarm>,
 
+    /// Owner's profile; when present, its registered tag is enforced and
+    /// its delegate may sign. Required for alarms with `tag_required`
+    #[account(
+        seeds = [b"user-profile", alarm.owner.as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Owner, co-owner or profile delegate acknowledging the wake proof
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    /// Vault PDA; needed to take a forfeit after the soft deadline
+    #[account(mut, seeds = [b"vault", alarm.key().as_ref()], bump = alarm.vault_bump)]
+    pub vault: Option<Account<'info, Vault>>,
+
+    /// Receives a soft-deadline forfeit, as it would a snooze cost
+    /// CHECK: Validated against the alarm's penalty route in `take_soft_forfeit`
+    #[account(mut)]
+    pub penalty_recipient: Option<UncheckedAccount<'info>>,
+
+    /// Global config supplying the burn sink for a forfeit
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Option<Account<'info, GlobalConfig>>,
 }
 
-pub fn process_ack_awake(ctx: Context<AckAwake>) -> Result<()> {
-    let alarm_key = ctx.accounts.alarm.key();
-    let owner_key = ctx.accounts.owner.key();
-    let alarm = &mut ctx.accounts.alarm;
-    let clock = Clock::get()?;
+/// Take the soft-deadline forfeit for an ack at `current_time` out of the
+/// vault and lower `remaining_amount` by it. Returns the amount forfeited;
+/// the optional accounts are only required when it is non-zero.
+pub(crate) fn take_soft_forfeit(
+    alarm: &mut Account<Alarm>,
+    vault: Option<&Account<Vault>>,
+    penalty_recipient: Option<&UncheckedAccount>,
+    config: Option<&Account<GlobalConfig>>,
+    current_time: i64,
+) -> Result<u64> {
+    let penalty = helpers::soft_penalty(
+        alarm.soft_deadline,
+        alarm.deadline,
+        current_time,
+        alarm.remaining_amount,
+    );
+    if penalty == 0 {
+        return Ok(0);
+    }
+    let (Some(vault), Some(recipient), Some(config)) = (vault, penalty_recipient, config) else {
+        return err!(SolarmaError::SoftForfeitAccountsRequired);
+    };
 
-    // Can only acknowledge after alarm time (i.e., alarm has fired)
+    // C1: never take the vault below its rent-exempt minimum
+    let vault_info = vault.to_account_info();
+    let min_balance = Rent::get()?.minimum_balance(vault_info.data_len());
+    let forfeited = helpers::cap_at_rent_exempt(penalty, vault_info.lamports(), min_balance);
+    let burn_sink = config.effective_burn_sink();
+    pay_penalty_sink(
+        alarm,
+        &vault_info,
+        &recipient.to_account_info(),
+        burn_sink,
+        forfeited,
+        current_time,
+    )?;
+    alarm.remaining_amount = alarm
+        .remaining_amount
+        .checked_sub(forfeited)
+        .ok_or(SolarmaError::Overflow)?;
+
+    emit!(crate::events::SoftDeadlineForfeited {
+        version: crate::events::EVENT_VERSION,
+        timestamp: current_time,
+        owner: alarm.owner,
+        alarm: alarm.key(),
+        alarm_id: alarm.alarm_id,
+        forfeited,
+        remaining: alarm.remaining_amount,
+        penalty_recipient: penalty_sink(alarm, burn_sink)?,
+    });
+    Ok(forfeited)
+}
+
+pub fn process_ack_awake(
+    ctx: Context<AckAwake>,
+    proof_hash: Option<[u8; 32]>,
+    proof_completed_at: i64,
+    proof_type: u8,
+    proof_preimage: Option<[u8; 32]>,
+) -> Result<()> {
+    acknowledge(
+        ctx.accounts,
+        ctx.remaining_accounts,
+        proof_hash,
+        proof_completed_at,
+        proof_type,
+        proof_preimage,
+    )
+}
+
+/// Ack body shared by `ack_awake` and `ack_awake_relayed`, which nests
+/// these accounts alongside its fee payer.
+pub(crate) fn acknowledge(
+    accounts: &mut AckAwake,
+    remaining_accounts: &[AccountInfo],
+    proof_hash: Option<[u8; 32]>,
+    proof_completed_at: i64,
+    proof_type: u8,
+    proof_preimage: Option<[u8; 32]>,
+) -> Result<()> {
+    let clock = crate::clock::get(remaining_accounts)?;
+    check_ack(
+        accounts,
+        proof_hash,
+        proof_completed_at,
+        proof_type,
+        proof_preimage,
+        clock.unix_timestamp,
+    )?;
+    // Multi-proof alarms collect every stage through `ack_awake_partial`
     require!(
-        clock.unix_timestamp >= alarm.alarm_time,
-        SolarmaError::TooEarly
+        accounts.alarm.required_proofs == 0,
+        SolarmaError::ProofsIncomplete
     );
+    finish_ack(
+        accounts,
+        proof_completed_at,
+        proof_type,
+        clock.unix_timestamp,
+    )
+}
+
+/// Every check an ack (or an `ack_awake_partial` stage) makes before
+/// touching state.
+pub(crate) fn check_ack(
+    accounts: &AckAwake,
+    proof_hash: Option<[u8; 32]>,
+    proof_completed_at: i64,
+    proof_type: u8,
+    proof_preimage: Option<[u8; 32]>,
+    current_time: i64,
+) -> Result<()> {
+    let authority_key = accounts.authority.key();
+    let user_profile = accounts.user_profile.as_deref();
+    let alarm = &accounts.alarm;
+
+    require!(
+        alarm.is_authorized(&authority_key)
+            || user_profile.is_some_and(|p| p.is_active_delegate(&authority_key, current_time)),
+        SolarmaError::UnauthorizedSigner
+    );
+
+    // Can only acknowledge once the alarm has fired, or within its early-ack lead
+    let window_start = helpers::wake_window_start(alarm.alarm_time, alarm.early_ack_seconds);
+    require!(current_time >= window_start, SolarmaError::TooEarly);
 
     // Can only acknowledge before deadline
+    require!(current_time < alarm.deadline, SolarmaError::DeadlinePassed);
+
+    // Registered tag must match the scanned proof; an alarm created with a
+    // tag registered cannot skip the check by omitting the profile
+    require!(
+        user_profile.is_some() || !alarm.tag_required,
+        SolarmaError::UserProfileRequired
+    );
+    if let Some(profile) = user_profile {
+        helpers::verify_tag_proof(profile.tag_hash, proof_hash)
+            .map_err(|_| SolarmaError::TagMismatch)?;
+    }
+
+    // A committed alarm only opens with the matching preimage
+    helpers::verify_proof_commitment(&alarm.proof_commitment, proof_preimage)
+        .map_err(|_| SolarmaError::ProofPreimageMismatch)?;
+
+    ProofType::try_from(proof_type).map_err(|_| SolarmaError::InvalidProofType)?;
+    require!(
+        helpers::proof_type_satisfies(alarm.required_proof_type, proof_type),
+        SolarmaError::ProofTypeMismatch
+    );
     require!(
-        clock.unix_timestamp < alarm.deadline,
-        SolarmaError::DeadlinePassed
+        helpers::validate_proof_completed_at(proof_completed_at, window_start, current_time),
+        SolarmaError::InvalidProofTimestamp
     );
+    Ok(())
+}
+
+/// Take any soft-deadline forfeit and move the alarm to Acknowledged.
+pub(crate) fn finish_ack(
+    accounts: &mut AckAwake,
+    proof_completed_at: i64,
+    proof_type: u8,
+    current_time: i64,
+) -> Result<()> {
+    let alarm_key = accounts.alarm.key();
+    let authority_key = accounts.authority.key();
+    let alarm = &mut accounts.alarm;
+
+    take_soft_forfeit(
+        alarm,
+        accounts.vault.as_ref(),
+        accounts.penalty_recipient.as_ref(),
+        accounts.config.as_ref(),
+        current_time,
+    )?;
 
     // Transition to Acknowledged
     alarm.status = AlarmStatus::Acknowledged;
+    alarm.proof_completed_at = proof_completed_at;
+    alarm.proof_type = proof_type;
 
     emit!(crate::events::WakeAcknowledged {
-        owner: owner_key,
+        version: crate::events::EVENT_VERSION,
+        timestamp: current_time,
+        owner: alarm.owner,
         alarm: alarm_key,
         alarm_id: alarm.alarm_id,
-        timestamp: clock.unix_timestamp,
+        proof_completed_at,
+        proof_type,
     });
 
     msg!(
         "Alarm acknowledged by {} at timestamp {}",
-        owner_key,
-        clock.unix_timestamp
+        authority_key,
+        current_time
     );
     Ok(())
 }
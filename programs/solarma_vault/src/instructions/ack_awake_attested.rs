@@ -0,0 +1,200 @@
+//! AckAwakeAttested instruction - record a wake-up whose proof was verified
+//! server-side.
+//!
+//! The transaction must carry Ed25519 program instructions, before this
+//! one, in which `AttestationConfig.threshold` distinct attestation keys
+//! sign a permit binding the alarm, nonce, expiry and proof hash. Each
+//! permit nonce can be used once: consuming it creates a `PermitNonce` PDA.
+//!
+//! Alarms with a `required_proof_type` only accept a permit signed for that
+//! type (see `helpers::ack_permit_action`), so the server, not the client,
+//! vouches for which proof was completed.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::ack_awake::take_soft_forfeit;
+use crate::state::{Alarm, AlarmStatus, AttestationConfig, GlobalConfig, PermitNonce, Vault};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use solana_program::ed25519_program;
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct AckAwakeAttested<'info> {
+    #[account(
+        mut,
+        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.is_authorized(&authority.key()) @ SolarmaError::UnauthorizedSigner
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Anti-replay marker; `init` fails if the nonce was already used
+    #[account(
+        init,
+        payer = authority,
+        space = PermitNonce::SIZE,
+        seeds = [b"permit", alarm.key().as_ref(), &nonce.to_le_bytes()],
+        bump
+    )]
+    pub permit_nonce: Account<'info, PermitNonce>,
+
+    /// Registered attestation signer(s)
+    #[account(seeds = [b"attestation"], bump = attestation_config.bump)]
+    pub attestation_config: Account<'info, AttestationConfig>,
+
+    /// Instructions sysvar, used to read the preceding Ed25519 instruction
+    /// CHECK: Address is checked against the sysvar ID
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    /// Owner or co-owner acknowledging the wake proof; pays for the nonce PDA
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Vault PDA; needed to take a forfeit after the soft deadline
+    #[account(mut, seeds = [b"vault", alarm.key().as_ref()], bump = alarm.vault_bump)]
+    pub vault: Option<Account<'info, Vault>>,
+
+    /// Receives a soft-deadline forfeit, as it would a snooze cost
+    /// CHECK: Validated against the alarm's penalty route in `take_soft_forfeit`
+    #[account(mut)]
+    pub penalty_recipient: Option<UncheckedAccount<'info>>,
+
+    /// Global config supplying the burn sink for a forfeit
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Option<Account<'info, GlobalConfig>>,
+}
+
+/// Check that the Ed25519 instructions earlier in this transaction carry
+/// at least `threshold` distinct valid attestations of the expected permit.
+pub(crate) fn verify_permit(
+    instructions: &AccountInfo,
+    attestation: &AttestationConfig,
+    message: &[u8],
+    current_time: i64,
+) -> Result<()> {
+    let current = load_current_index_checked(instructions)?;
+    let mut signers: Vec<[u8; 32]> = Vec::new();
+    for index in 0..usize::from(current) {
+        let ix = load_instruction_at_checked(index, instructions)?;
+        if ix.program_id != ed25519_program::ID {
+            continue;
+        }
+        if let Some((signer, signed)) = helpers::parse_ed25519_instruction(&ix.data) {
+            if signed == message {
+                signers.push(signer);
+            }
+        }
+    }
+
+    let attestors = attestation.attestors.map(|k| k.to_bytes());
+    let count = helpers::count_attestations(
+        &signers,
+        &attestation.current_key.to_bytes(),
+        &attestation.previous_key.to_bytes(),
+        attestation.previous_valid_until,
+        &attestors,
+        current_time,
+    );
+    require!(
+        count >= usize::from(attestation.threshold.max(1)),
+        SolarmaError::InvalidAttestation
+    );
+    Ok(())
+}
+
+/// Validate the permit window and signature, then record the nonce.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn consume_permit(
+    permit_nonce: &mut Account<PermitNonce>,
+    permit_bump: u8,
+    instructions: &AccountInfo,
+    attestation: &AttestationConfig,
+    action: u8,
+    alarm: &Pubkey,
+    nonce: u64,
+    expires_at: i64,
+    proof_hash: &[u8; 32],
+    current_time: i64,
+) -> Result<()> {
+    helpers::validate_permit_window(expires_at, current_time)
+        .map_err(|_| SolarmaError::PermitExpired)?;
+    let message = helpers::permit_message(action, &alarm.to_bytes(), nonce, expires_at, proof_hash);
+    verify_permit(instructions, attestation, &message, current_time)?;
+
+    permit_nonce.alarm = *alarm;
+    permit_nonce.nonce = nonce;
+    permit_nonce.expires_at = expires_at;
+    permit_nonce.bump = permit_bump;
+    Ok(())
+}
+
+pub fn process_ack_awake_attested(
+    ctx: Context<AckAwakeAttested>,
+    nonce: u64,
+    expires_at: i64,
+    proof_hash: [u8; 32],
+) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let authority_key = ctx.accounts.authority.key();
+    let clock = crate::clock::get(ctx.remaining_accounts)?;
+
+    consume_permit(
+        &mut ctx.accounts.permit_nonce,
+        ctx.bumps.permit_nonce,
+        &ctx.accounts.instructions,
+        &ctx.accounts.attestation_config,
+        helpers::ack_permit_action(ctx.accounts.alarm.required_proof_type),
+        &alarm_key,
+        nonce,
+        expires_at,
+        &proof_hash,
+        clock.unix_timestamp,
+    )?;
+
+    let alarm = &mut ctx.accounts.alarm;
+
+    // Same time window as ack_awake
+    require!(
+        clock.unix_timestamp
+            >= helpers::wake_window_start(alarm.alarm_time, alarm.early_ack_seconds),
+        SolarmaError::TooEarly
+    );
+    require!(
+        clock.unix_timestamp < alarm.deadline,
+        SolarmaError::DeadlinePassed
+    );
+
+    take_soft_forfeit(
+        alarm,
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.penalty_recipient.as_ref(),
+        ctx.accounts.config.as_ref(),
+        clock.unix_timestamp,
+    )?;
+
+    alarm.status = AlarmStatus::Acknowledged;
+    // A typed permit vouches for the required proof
+    alarm.proof_type = alarm.required_proof_type;
+
+    emit!(crate::events::WakeAcknowledged {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: alarm.owner,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        proof_completed_at: alarm.proof_completed_at,
+        proof_type: alarm.proof_type,
+    });
+
+    msg!(
+        "Alarm acknowledged (attested, nonce {}) by {}",
+        nonce,
+        authority_key
+    );
+    Ok(())
+}
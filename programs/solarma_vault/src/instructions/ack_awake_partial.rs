@@ -0,0 +1,67 @@
+//! AckAwakePartial instruction - record one stage of a multi-proof wake-up
+//! (e.g. an NFC scan, then 50 steps)
+//!
+//! Alarms created with `AlarmOptions.required_proofs` cannot use
+//! `ack_awake`. Each call records one `proof_type` from the required
+//! bitmask, with every check `ack_awake` makes, and the call that completes
+//! the set moves the alarm to Acknowledged (taking any soft-deadline
+//! forfeit then). Stages are kept across snoozes and must all land before
+//! the deadline.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::ack_awake::{check_ack, finish_ack, AckAwake};
+use anchor_lang::prelude::*;
+
+pub fn process_ack_awake_partial(
+    ctx: Context<AckAwake>,
+    proof_hash: Option<[u8; 32]>,
+    proof_completed_at: i64,
+    proof_type: u8,
+    proof_preimage: Option<[u8; 32]>,
+) -> Result<()> {
+    let clock = crate::clock::get(ctx.remaining_accounts)?;
+    check_ack(
+        ctx.accounts,
+        proof_hash,
+        proof_completed_at,
+        proof_type,
+        proof_preimage,
+        clock.unix_timestamp,
+    )?;
+
+    let alarm_key = ctx.accounts.alarm.key();
+    let alarm = &mut ctx.accounts.alarm;
+    let completed =
+        helpers::record_proof_stage(alarm.required_proofs, alarm.completed_proofs, proof_type)
+            .map_err(|_| SolarmaError::ProofTypeMismatch)?;
+    alarm.completed_proofs = completed;
+
+    emit!(crate::events::ProofStageCompleted {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: alarm.owner,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        proof_type,
+        completed_proofs: completed,
+        required_proofs: alarm.required_proofs,
+    });
+    msg!(
+        "Alarm {} proof {} recorded ({:#b} of {:#b})",
+        alarm.alarm_id,
+        proof_type,
+        completed,
+        alarm.required_proofs
+    );
+
+    if completed == alarm.required_proofs {
+        finish_ack(
+            ctx.accounts,
+            proof_completed_at,
+            proof_type,
+            clock.unix_timestamp,
+        )?;
+    }
+    Ok(())
+}
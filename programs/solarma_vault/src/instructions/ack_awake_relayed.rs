@@ -0,0 +1,55 @@
+//! AckAwakeRelayed instruction - `ack_awake` submitted by a fee-paying relayer
+//!
+//! Early-morning users often hold no SOL for fees. The owner (or co-owner,
+//! or profile delegate) signs the ack transaction, usually against a durable
+//! nonce so it does not expire, and a relayer adds its signature as fee payer
+//! and submits it. The relayer's key carries no authority: every check is
+//! exactly `ack_awake`'s, made against `ack.authority`.
+
+use crate::constants::PERMIT_ACTION_ACK;
+// Glob: the nested `AckAwake` needs its Anchor-generated modules in scope
+use crate::instructions::ack_awake::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AckAwakeRelayed<'info> {
+    /// The `ack_awake` accounts, unchanged
+    pub ack: AckAwake<'info>,
+
+    /// Fee payer submitting the owner-signed transaction
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+}
+
+pub fn process_ack_awake_relayed(
+    ctx: Context<AckAwakeRelayed>,
+    proof_hash: Option<[u8; 32]>,
+    proof_completed_at: i64,
+    proof_type: u8,
+    proof_preimage: Option<[u8; 32]>,
+) -> Result<()> {
+    let alarm_key = ctx.accounts.ack.alarm.key();
+    let authority_key = ctx.accounts.ack.authority.key();
+    let relayer_key = ctx.accounts.relayer.key();
+
+    acknowledge(
+        &mut ctx.accounts.ack,
+        ctx.remaining_accounts,
+        proof_hash,
+        proof_completed_at,
+        proof_type,
+        proof_preimage,
+    )?;
+
+    emit!(crate::events::RelayedSubmission {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        alarm: alarm_key,
+        authority: authority_key,
+        relayer: relayer_key,
+        action: PERMIT_ACTION_ACK,
+    });
+
+    msg!("Ack relayed by {}", relayer_key);
+    Ok(())
+}
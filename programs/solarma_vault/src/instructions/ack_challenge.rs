@@ -0,0 +1,66 @@
+//! Ack challenge instruction - participant records their wake-up
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Challenge, ChallengeParticipant};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AckChallenge<'info> {
+    #[account(
+        mut,
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.challenge_id.to_le_bytes()],
+        bump = challenge.bump
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        mut,
+        has_one = challenge,
+        has_one = user,
+        seeds = [b"participant", challenge.key().as_ref(), user.key().as_ref()],
+        bump = participant.bump,
+        constraint = !participant.acked @ SolarmaError::InvalidAlarmState
+    )]
+    pub participant: Account<'info, ChallengeParticipant>,
+
+    pub user: Signer<'info>,
+}
+
+pub fn process_ack_challenge(ctx: Context<AckChallenge>) -> Result<()> {
+    let clock = Clock::get()?;
+    let challenge = &mut ctx.accounts.challenge;
+
+    require!(
+        clock.unix_timestamp >= challenge.wake_time,
+        SolarmaError::TooEarly
+    );
+    require!(
+        helpers::is_claim_window(
+            challenge.wake_time,
+            challenge.deadline,
+            clock.unix_timestamp
+        ),
+        SolarmaError::DeadlinePassed
+    );
+
+    ctx.accounts.participant.acked = true;
+    challenge.acked_count = challenge
+        .acked_count
+        .checked_add(1)
+        .ok_or(SolarmaError::Overflow)?;
+
+    emit!(crate::events::ChallengeAcked {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        user: ctx.accounts.user.key(),
+        challenge: challenge.key(),
+    });
+
+    msg!(
+        "Challenge {} acknowledged by {}",
+        challenge.challenge_id,
+        ctx.accounts.user.key()
+    );
+    Ok(())
+}
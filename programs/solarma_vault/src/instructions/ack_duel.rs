@@ -0,0 +1,61 @@
+//! Ack duel instruction - either side records their wake-up within their
+//! own window
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::Duel;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AckDuel<'info> {
+    #[account(
+        mut,
+        seeds = [b"duel", duel.creator.as_ref(), &duel.duel_id.to_le_bytes()],
+        bump = duel.bump,
+        constraint = duel.accepted @ SolarmaError::InvalidAlarmState
+    )]
+    pub duel: Account<'info, Duel>,
+
+    /// Creator or opponent
+    pub user: Signer<'info>,
+}
+
+pub fn process_ack_duel(ctx: Context<AckDuel>) -> Result<()> {
+    let clock = Clock::get()?;
+    let user_key = ctx.accounts.user.key();
+    let duel = &mut ctx.accounts.duel;
+
+    let (wake_time, deadline, acked) = if user_key == duel.creator {
+        (
+            duel.creator_wake_time,
+            duel.creator_deadline,
+            &mut duel.creator_acked,
+        )
+    } else if user_key == duel.opponent {
+        (
+            duel.opponent_wake_time,
+            duel.opponent_deadline,
+            &mut duel.opponent_acked,
+        )
+    } else {
+        return err!(SolarmaError::UnauthorizedSigner);
+    };
+
+    require!(!*acked, SolarmaError::InvalidAlarmState);
+    require!(clock.unix_timestamp >= wake_time, SolarmaError::TooEarly);
+    require!(
+        helpers::is_claim_window(wake_time, deadline, clock.unix_timestamp),
+        SolarmaError::DeadlinePassed
+    );
+    *acked = true;
+
+    emit!(crate::events::DuelAcked {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        user: user_key,
+        duel: duel.key(),
+    });
+
+    msg!("Duel {} acknowledged by {}", duel.duel_id, user_key);
+    Ok(())
+}
@@ -0,0 +1,47 @@
+//! Add charity instruction - admin registers a verified donation target
+
+use crate::state::{Charity, GlobalConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct AddCharity<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Charity::SIZE,
+        seeds = [b"charity", address.as_ref()],
+        bump
+    )]
+    pub charity: Account<'info, Charity>,
+
+    #[account(has_one = admin, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_add_charity(
+    ctx: Context<AddCharity>,
+    address: Pubkey,
+    name_hash: [u8; 32],
+) -> Result<()> {
+    let charity = &mut ctx.accounts.charity;
+    charity.address = address;
+    charity.name_hash = name_hash;
+    charity.bump = ctx.bumps.charity;
+
+    emit!(crate::events::CharityAdded {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        charity: charity.key(),
+        address,
+        name_hash,
+    });
+
+    msg!("Charity {} added to registry", address);
+    Ok(())
+}
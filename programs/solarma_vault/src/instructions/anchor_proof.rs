@@ -0,0 +1,55 @@
+//! Anchor proof instruction - owner or co-owner pins a content hash of the
+//! wake evidence (camera photo, sensor trace) to an acknowledged alarm
+//!
+//! Only the 32-byte hash goes on-chain; the blob stays with the owner and
+//! can be produced in a later dispute. The hash is write-once so anchored
+//! evidence cannot be swapped after the fact.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Alarm, ProofType};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AnchorProof<'info> {
+    #[account(
+        mut,
+        constraint = alarm.is_authorized(&authority.key()) @ SolarmaError::UnauthorizedSigner
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Owner or co-owner
+    pub authority: Signer<'info>,
+}
+
+pub fn process_anchor_proof(
+    ctx: Context<AnchorProof>,
+    proof_hash: [u8; 32],
+    proof_type: u8,
+) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let alarm = &mut ctx.accounts.alarm;
+
+    helpers::validate_proof_anchor(alarm.status, &alarm.anchored_proof_hash, &proof_hash).map_err(
+        |e| match e {
+            "invalid_state" => SolarmaError::InvalidAlarmState,
+            _ => SolarmaError::InvalidAnchoredProof,
+        },
+    )?;
+    ProofType::try_from(proof_type).map_err(|_| SolarmaError::InvalidProofType)?;
+
+    alarm.anchored_proof_hash = proof_hash;
+    alarm.anchored_proof_type = proof_type;
+
+    emit!(crate::events::ProofAnchored {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        proof_hash,
+        proof_type,
+    });
+
+    msg!("Proof anchored on alarm {}", alarm.alarm_id);
+    Ok(())
+}
@@ -0,0 +1,72 @@
+//! Approve recovery instruction - a guardian votes to redirect an
+//! acknowledged alarm of an owner who lost their key
+//!
+//! The first approval already blocks `sweep_acknowledged`, so the deposit
+//! is not swept to the lost key while the other guardians gather. Once
+//! `RECOVERY_THRESHOLD` guardians have approved, `recover_alarm` opens after
+//! `RECOVERY_TIMELOCK_SECONDS`; until then the owner can `cancel_recovery`.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Alarm, AlarmStatus, UserProfile};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ApproveRecovery<'info> {
+    #[account(
+        mut,
+        constraint = alarm.status == AlarmStatus::Acknowledged @ SolarmaError::InvalidAlarmState
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Owner's profile holding the guardian list
+    #[account(
+        seeds = [b"user-profile", alarm.owner.as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    pub guardian: Signer<'info>,
+}
+
+pub fn process_approve_recovery(ctx: Context<ApproveRecovery>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let guardian_key = ctx.accounts.guardian.key();
+    let clock = Clock::get()?;
+
+    let slot = ctx
+        .accounts
+        .user_profile
+        .guardian_slot(&guardian_key)
+        .ok_or(SolarmaError::NotGuardian)?;
+
+    let alarm = &mut ctx.accounts.alarm;
+    let (approvals, unlocks_at) = helpers::apply_recovery_approval(
+        alarm.recovery_approvals,
+        alarm.recovery_unlocks_at,
+        slot,
+        clock.unix_timestamp,
+    )
+    .ok_or(SolarmaError::Overflow)?;
+    alarm.recovery_approvals = approvals;
+    alarm.recovery_unlocks_at = unlocks_at;
+
+    emit!(crate::events::RecoveryApproved {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: alarm.owner,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        guardian: guardian_key,
+        approvals: approvals.count_ones() as u8,
+        unlocks_at,
+    });
+
+    msg!(
+        "Guardian {} approved recovery of alarm {} ({} approvals)",
+        guardian_key,
+        alarm.alarm_id,
+        approvals.count_ones()
+    );
+    Ok(())
+}
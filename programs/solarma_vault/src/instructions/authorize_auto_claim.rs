@@ -0,0 +1,48 @@
+//! Authorize auto-claim instruction - the owner (or a co-owner) lets anyone
+//! push the claim through once the alarm is acknowledged.
+//!
+//! Meant to be sent with the ACK (same transaction or just before it), so
+//! a keeper can finish the claim if the phone dies after the wake proof.
+//! Funds still only go to the owner; see `auto_claim`.
+
+use crate::error::SolarmaError;
+use crate::state::{Alarm, AlarmStatus};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AuthorizeAutoClaim<'info> {
+    #[account(
+        mut,
+        constraint = matches!(alarm.status, AlarmStatus::Created | AlarmStatus::Acknowledged)
+            @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.is_authorized(&authority.key()) @ SolarmaError::UnauthorizedSigner,
+        // Attested alarms only release funds against a server permit.
+        constraint = !alarm.require_attestation @ SolarmaError::AttestationRequired,
+        // Two-phase alarms only release funds after the veto window.
+        constraint = !alarm.two_phase_claim @ SolarmaError::ClaimRequestRequired
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Owner or co-owner
+    pub authority: Signer<'info>,
+}
+
+pub fn process_authorize_auto_claim(ctx: Context<AuthorizeAutoClaim>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let authority_key = ctx.accounts.authority.key();
+    let alarm = &mut ctx.accounts.alarm;
+
+    alarm.auto_claim_authorized = true;
+
+    emit!(crate::events::AutoClaimAuthorized {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: alarm.owner,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        authorized_by: authority_key,
+    });
+
+    msg!("Auto-claim authorized for alarm {}", alarm.alarm_id);
+    Ok(())
+}
@@ -0,0 +1,116 @@
+//! Auto claim instruction - permissionless claim of an acknowledged alarm
+//! whose owner called `authorize_auto_claim`.
+//!
+//! Same window as `claim` (alarm time through deadline + grace), so a
+//! keeper does not have to wait for `sweep_acknowledged`. The vault is
+//! always closed to the owner, so sponsored alarms are refused.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::claim::pay_buddy_reward;
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::state::{Alarm, AlarmIndex, AlarmStatus, Vault};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct AutoClaim<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::Acknowledged @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.auto_claim_authorized @ SolarmaError::UnauthorizedSigner,
+        constraint = !alarm.two_phase_claim @ SolarmaError::ClaimRequestRequired,
+        constraint = alarm.sponsor().is_none() @ SolarmaError::InvalidFunder
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA holding the deposit - closed and funds returned to owner
+    #[account(
+        mut,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump = alarm.vault_bump,
+        close = owner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Alarm owner account, always receives the funds
+    /// CHECK: Key is verified by `alarm.has_one = owner`
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// Any signer can push a pre-authorized claim
+    pub caller: Signer<'info>,
+
+    /// Buddy receiving the thank-you share; required when `buddy_reward_bps > 0`
+    /// CHECK: Validated against alarm.penalty_destination in `pay_buddy_reward`
+    #[account(mut)]
+    pub buddy: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Owner's alarm index; the claimed alarm is removed from it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", alarm.owner.as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+}
+
+pub fn process_auto_claim(ctx: Context<AutoClaim>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let owner_key = ctx.accounts.owner.key();
+    let caller_key = ctx.accounts.caller.key();
+    let alarm = &mut ctx.accounts.alarm;
+    let clock = Clock::get()?;
+
+    // Acknowledged alarms claim from the wake window start through deadline + grace
+    helpers::check_claim_window(
+        alarm.status,
+        helpers::wake_window_start(alarm.alarm_time, alarm.early_ack_seconds),
+        alarm.deadline,
+        alarm.claim_grace_seconds,
+        clock.unix_timestamp,
+    )
+    .map_err(|e| match e {
+        "too_early" => SolarmaError::TooEarly,
+        "deadline_passed" => SolarmaError::DeadlinePassed,
+        "overflow" => SolarmaError::Overflow,
+        _ => SolarmaError::InvalidAlarmState,
+    })?;
+
+    let buddy_reward = pay_buddy_reward(
+        alarm,
+        &ctx.accounts.vault.to_account_info(),
+        ctx.accounts.buddy.as_ref().map(|b| b.as_ref()),
+    )?;
+
+    // The `close = owner` constraint returns deposit + rent to the owner
+    let vault_lamports = ctx.accounts.vault.to_account_info().lamports();
+
+    crate::events::emit_settlement(
+        crate::events::event_authority!(ctx.accounts),
+        crate::events::AlarmClaimed {
+            version: crate::events::EVENT_VERSION,
+            timestamp: clock.unix_timestamp,
+            owner: owner_key,
+            alarm: alarm_key,
+            alarm_id: alarm.alarm_id,
+            returned_amount: vault_lamports,
+            buddy_reward,
+        },
+    )?;
+
+    alarm.status = AlarmStatus::Claimed;
+    alarm.remaining_amount = 0;
+    remove_from_alarm_index(ctx.accounts.alarm_index.as_deref_mut(), alarm.alarm_id);
+
+    msg!(
+        "Auto-claim by {}: returned {} lamports to owner {}",
+        caller_key,
+        vault_lamports,
+        owner_key
+    );
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! Block destination instruction - owner adds a penalty destination to the
+//! profile blocklist
+//!
+//! `create_alarm` (and `materialize_next_alarm`) refuse a blocked
+//! `penalty_destination` or split leg whenever the owner's profile is
+//! passed, so a compromised client cannot quietly route future penalties to
+//! an attacker-controlled "buddy". Existing alarms are not affected.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::UserProfile;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct BlockDestination<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_block_destination(
+    ctx: Context<BlockDestination>,
+    destination: Pubkey,
+) -> Result<()> {
+    let profile = &mut ctx.accounts.user_profile;
+    let blocked = profile.blocked_destinations.map(|k| k.to_bytes());
+    let slot =
+        helpers::blocklist_insert_slot(&destination.to_bytes(), &blocked).map_err(|e| match e {
+            "blocklist_full" => SolarmaError::BlocklistFull,
+            _ => SolarmaError::InvalidBlockedDestination,
+        })?;
+    profile.blocked_destinations[slot] = destination;
+
+    emit!(crate::events::DestinationBlocklistUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: profile.owner,
+        destination,
+        blocked: true,
+    });
+
+    msg!("Destination {} blocked", destination);
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! Buddy accept instruction - the penalty destination co-signs a Buddy alarm
+//!
+//! Gives the buddy an on-chain record that they agreed to the arrangement
+//! and prevents owners from pointing penalties at strangers' wallets.
+
+use crate::error::SolarmaError;
+use crate::state::{Alarm, AlarmStatus};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct BuddyAccept<'info> {
+    #[account(
+        mut,
+        constraint = alarm.status == AlarmStatus::PendingBuddy @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.penalty_destination == Some(buddy.key()) @ SolarmaError::UnauthorizedSigner
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Penalty destination agreeing to act as buddy
+    pub buddy: Signer<'info>,
+}
+
+pub fn process_buddy_accept(ctx: Context<BuddyAccept>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let alarm = &mut ctx.accounts.alarm;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp < alarm.alarm_time,
+        SolarmaError::BuddyAcceptExpired
+    );
+
+    alarm.status = AlarmStatus::Created;
+
+    emit!(crate::events::BuddyAccepted {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        buddy: ctx.accounts.buddy.key(),
+        owner: alarm.owner,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+    });
+
+    msg!(
+        "Buddy {} accepted alarm {}",
+        ctx.accounts.buddy.key(),
+        alarm.alarm_id
+    );
+    Ok(())
+}
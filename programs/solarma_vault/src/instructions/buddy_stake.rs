@@ -0,0 +1,92 @@
+//! Buddy stake instruction - the buddy of a Buddy-route alarm escrows a
+//! deposit matching the owner's
+//!
+//! The stake sits in its own `BuddyStake` PDA, not in the vault, so every
+//! claim, sweep and slash path is unchanged. Once the alarm is resolved the
+//! buddy takes the stake back with `release_buddy_stake`. If the owner was
+//! slashed, the buddy has also received the owner's deposit through the
+//! Buddy route, so a staked buddy ends up with both stakes.
+//!
+//! Bets are excluded both ways: a bet would send the slash to the
+//! challenger instead of the buddy.
+
+use crate::error::SolarmaError;
+use crate::state::{Alarm, AlarmStatus, BuddyStake, PenaltyRoute};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+pub struct StakeAsBuddy<'info> {
+    #[account(
+        mut,
+        constraint = alarm.penalty_route == PenaltyRoute::Buddy as u8
+            @ SolarmaError::BuddyStakeUnavailable,
+        constraint = alarm.penalty_destination == Some(buddy.key())
+            @ SolarmaError::BuddyStakeUnavailable,
+        constraint = matches!(alarm.status, AlarmStatus::Created | AlarmStatus::PendingBuddy)
+            @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.challenger.is_none() @ SolarmaError::BuddyStakeUnavailable
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    #[account(
+        init,
+        payer = buddy,
+        space = BuddyStake::SIZE,
+        seeds = [b"buddy-stake", alarm.key().as_ref()],
+        bump
+    )]
+    pub buddy_stake: Account<'info, BuddyStake>,
+
+    #[account(mut)]
+    pub buddy: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_buddy_stake(ctx: Context<StakeAsBuddy>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let buddy_key = ctx.accounts.buddy.key();
+    let clock = Clock::get()?;
+
+    let alarm = &ctx.accounts.alarm;
+    require!(
+        clock.unix_timestamp < alarm.alarm_time,
+        SolarmaError::BuddyStakeUnavailable
+    );
+    let amount = alarm.remaining_amount;
+    require!(amount > 0, SolarmaError::BuddyStakeUnavailable);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.buddy.to_account_info(),
+                to: ctx.accounts.buddy_stake.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let stake = &mut ctx.accounts.buddy_stake;
+    stake.alarm = alarm_key;
+    stake.buddy = buddy_key;
+    stake.amount = amount;
+    stake.bump = ctx.bumps.buddy_stake;
+    ctx.accounts.alarm.buddy_stake = amount;
+
+    emit!(crate::events::BuddyStaked {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        alarm: alarm_key,
+        buddy: buddy_key,
+        amount,
+    });
+
+    msg!(
+        "Buddy staked {} on alarm {}",
+        amount,
+        ctx.accounts.alarm.alarm_id
+    );
+    Ok(())
+}
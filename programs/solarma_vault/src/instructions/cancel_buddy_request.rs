@@ -0,0 +1,51 @@
+//! Cancel buddy request instruction - owner withdraws an unaccepted Buddy alarm
+//!
+//! Until the buddy co-signs, the alarm is inert, so the owner can close
+//! it and the vault at any time without penalty.
+
+use crate::error::SolarmaError;
+use crate::state::{Alarm, AlarmStatus, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelBuddyRequest<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::PendingBuddy @ SolarmaError::InvalidAlarmState,
+        close = owner
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA holding the deposit - closed to owner
+    #[account(
+        mut,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump = alarm.vault_bump,
+        close = owner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn process_cancel_buddy_request(ctx: Context<CancelBuddyRequest>) -> Result<()> {
+    let returned = ctx.accounts.alarm.remaining_amount;
+
+    emit!(crate::events::BuddyRequestCancelled {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: ctx.accounts.owner.key(),
+        alarm: ctx.accounts.alarm.key(),
+        alarm_id: ctx.accounts.alarm.alarm_id,
+        returned_amount: returned,
+    });
+
+    msg!(
+        "Pending buddy alarm {} cancelled: returned {} lamports",
+        ctx.accounts.alarm.alarm_id,
+        returned
+    );
+    Ok(())
+}
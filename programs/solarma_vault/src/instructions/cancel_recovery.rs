@@ -0,0 +1,41 @@
+//! Cancel recovery instruction - the owner, still holding their key,
+//! discards guardian approvals on an alarm
+//!
+//! Works until `recover_alarm` runs, including after the timelock; the
+//! timelock exists so the owner has time to notice and call this.
+
+use crate::error::SolarmaError;
+use crate::state::{Alarm, AlarmStatus};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::Acknowledged @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.recovery_approvals != 0 @ SolarmaError::RecoveryNotReady
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let alarm = &mut ctx.accounts.alarm;
+
+    alarm.recovery_approvals = 0;
+    alarm.recovery_unlocks_at = 0;
+
+    emit!(crate::events::RecoveryCancelled {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: alarm.owner,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+    });
+
+    msg!("Recovery of alarm {} cancelled by owner", alarm.alarm_id);
+    Ok(())
+}
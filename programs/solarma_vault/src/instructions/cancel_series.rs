@@ -0,0 +1,44 @@
+//! Cancel series instruction - owner stops a series and recovers unused escrow
+//!
+//! Occurrences that were already spawned are independent alarms and are
+//! not affected.
+
+use crate::state::AlarmSeries;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelSeries<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"series", owner.key().as_ref(), &series.series_id.to_le_bytes()],
+        bump = series.bump,
+        close = owner
+    )]
+    pub series: Account<'info, AlarmSeries>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn process_cancel_series(ctx: Context<CancelSeries>) -> Result<()> {
+    let series = &ctx.accounts.series;
+    let refunded = series.to_account_info().lamports();
+
+    emit!(crate::events::SeriesCancelled {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: series.owner,
+        series: series.key(),
+        series_id: series.series_id,
+        refunded_amount: refunded,
+    });
+
+    msg!(
+        "Series {} cancelled: {} occurrences unspawned, returned {} lamports",
+        series.series_id,
+        series.remaining_occurrences,
+        refunded
+    );
+    Ok(())
+}
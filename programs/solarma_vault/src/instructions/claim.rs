@@ -1,17 +1,29 @@
 //! Claim instruction - return deposit to user after wake acknowledgement.
+//!
+//! Buddy-route alarms with `buddy_reward_bps` first send that share of the
+//! deposit to the buddy (see `pay_buddy_reward`).
 
 use crate::error::SolarmaError;
 use crate::helpers;
-use crate::state::{Alarm, AlarmStatus, Vault};
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::instructions::init_daily_stats::{record_daily_stat, DailyStat};
+use crate::instructions::init_forgiveness_token::credit_forgiveness;
+use crate::instructions::join_leaderboard::record_leaderboard_result;
+use crate::state::{
+    Alarm, AlarmIndex, AlarmStatus, DailyStats, ForgivenessToken, LeaderboardEntry,
+    LeaderboardEpoch, UserProfile, Vault,
+};
 use anchor_lang::prelude::*;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct Claim<'info> {
     #[account(
         mut,
         has_one = owner,
         // Claim is allowed only after wake acknowledgment.
-        constraint = alarm.status == AlarmStatus::Acknowledged @ SolarmaError::InvalidAlarmState
+        constraint = alarm.status == AlarmStatus::Acknowledged @ SolarmaError::InvalidAlarmState,
+        constraint = !alarm.require_attestation @ SolarmaError::AttestationRequired
     )]
     pub alarm: Account<'info, Alarm>,
 
@@ -24,53 +36,291 @@ pub struct Claim<'info> {
     )]
     pub vault: Account<'info, Vault>,
 
+    /// Alarm owner account; receives the funds, or only the vault rent
+    /// when `alarm.claim_to` is set
+    /// CHECK: Key is verified by `alarm.has_one = owner`
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub owner: UncheckedAccount<'info>,
+
+    /// Owner's profile; when present, claim counters for achievements are
+    /// updated and its delegate may sign
+    #[account(
+        mut,
+        seeds = [b"user-profile", alarm.owner.as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Owner's leaderboard entry for the current epoch; pass with `leaderboard_epoch`
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"leaderboard".as_ref(), &leaderboard_entry.epoch.to_le_bytes(), alarm.owner.as_ref()],
+        bump = leaderboard_entry.bump
+    )]
+    pub leaderboard_entry: Option<Account<'info, LeaderboardEntry>>,
+
+    /// Current leaderboard epoch; pass with `leaderboard_entry`
+    #[account(
+        mut,
+        seeds = [b"epoch".as_ref(), &leaderboard_epoch.epoch.to_le_bytes()],
+        bump = leaderboard_epoch.bump
+    )]
+    pub leaderboard_epoch: Option<Account<'info, LeaderboardEpoch>>,
+
+    /// Buddy receiving the thank-you share; required when `buddy_reward_bps > 0`
+    /// CHECK: Validated against alarm.penalty_destination in `pay_buddy_reward`
+    #[account(mut)]
+    pub buddy: Option<UncheckedAccount<'info>>,
+
+    /// Owner's alarm index; the claimed alarm is removed from it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", alarm.owner.as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+
+    /// Owner, co-owner or profile delegate signing the claim
+    pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
-}
 
-pub fn process_claim(ctx: Context<Claim>) -> Result<()> {
-    let alarm_key = ctx.accounts.alarm.key();
-    let owner_key = ctx.accounts.owner.key();
-    let alarm = &mut ctx.accounts.alarm;
-    let clock = Clock::get()?;
+    /// Today's protocol counters; the claim is counted on it
+    #[account(mut, seeds = [b"daily-stats".as_ref(), &daily_stats.day.to_le_bytes()], bump = daily_stats.bump)]
+    pub daily_stats: Option<Account<'info, DailyStats>>,
+
+    /// Receives the deposit; required when `alarm.claim_to` is set
+    /// CHECK: Validated against alarm.claim_to in `pay_claim_destination`
+    #[account(mut)]
+    pub claim_to: Option<UncheckedAccount<'info>>,
+
+    /// Owner's forgiveness tokens; credited when the claim extends the
+    /// streak onto a multiple of `FORGIVENESS_STREAK_DAYS` (needs `user_profile`)
+    #[account(
+        mut,
+        seeds = [b"forgiveness", alarm.owner.as_ref()],
+        bump = forgiveness_token.bump
+    )]
+    pub forgiveness_token: Option<Account<'info, ForgivenessToken>>,
 
-    // CRITICAL: Cannot claim BEFORE alarm time (wake proof not complete)
+    /// Sponsor that funded the deposit; required for sponsored alarms
+    /// (`Alarm::sponsor`), and receives the deposit instead of the owner
+    /// CHECK: Validated against alarm.payer in `return_deposit_to_funder`
+    #[account(mut)]
+    pub funder: Option<UncheckedAccount<'info>>,
+}
+
+/// Move the buddy's share of the deposit out of the vault before it is
+/// closed to the owner. Returns the amount paid (0 when no reward is set).
+pub(crate) fn pay_buddy_reward(
+    alarm: &Alarm,
+    vault: &AccountInfo,
+    buddy: Option<&AccountInfo>,
+) -> Result<u64> {
+    if alarm.buddy_reward_bps == 0 {
+        return Ok(0);
+    }
+    let buddy = buddy.ok_or(SolarmaError::InvalidPenaltyRecipient)?;
     require!(
-        clock.unix_timestamp >= alarm.alarm_time,
-        SolarmaError::TooEarly
+        Some(buddy.key()) == alarm.penalty_destination,
+        SolarmaError::InvalidPenaltyRecipient
     );
 
-    let claim_deadline =
-        helpers::claim_deadline_with_grace(alarm.deadline).ok_or(SolarmaError::Overflow)?;
+    let reward = helpers::bps_of(alarm.remaining_amount, alarm.buddy_reward_bps)
+        .ok_or(SolarmaError::Overflow)?;
+    if reward > 0 {
+        **vault.try_borrow_mut_lamports()? -= reward;
+        **buddy.try_borrow_mut_lamports()? += reward;
+    }
+    Ok(reward)
+}
+
+/// Move the deposit (every lamport above the vault's rent) to the alarm's
+/// claim destination before the vault is closed to the owner. Returns the
+/// amount moved (0 when no destination is set).
+pub(crate) fn pay_claim_destination(
+    alarm: &Alarm,
+    vault: &AccountInfo,
+    claim_to: Option<&AccountInfo>,
+) -> Result<u64> {
+    let Some(expected) = alarm.claim_to else {
+        return Ok(0);
+    };
+    let claim_to = claim_to.ok_or(SolarmaError::InvalidClaimDestination)?;
+    require_keys_eq!(
+        claim_to.key(),
+        expected,
+        SolarmaError::InvalidClaimDestination
+    );
+
+    move_deposit(vault, claim_to)
+}
+
+/// Move the deposit back to the sponsor or gifter that funded it
+/// (`funder`, from `Alarm::sponsor` or `Alarm::deposit_funder`) before the
+/// vault is closed to the owner. Returns the amount moved (0 when the owner
+/// funded the deposit).
+pub(crate) fn return_deposit_to_funder(
+    funder: Option<Pubkey>,
+    vault: &AccountInfo,
+    funder_account: Option<&AccountInfo>,
+) -> Result<u64> {
+    let Some(expected) = funder else {
+        return Ok(0);
+    };
+    let funder_account = funder_account.ok_or(SolarmaError::InvalidFunder)?;
+    require_keys_eq!(funder_account.key(), expected, SolarmaError::InvalidFunder);
+    move_deposit(vault, funder_account)
+}
+
+/// Move every lamport above the vault's rent to `to`.
+fn move_deposit(vault: &AccountInfo, to: &AccountInfo) -> Result<u64> {
+    let min_balance = Rent::get()?.minimum_balance(vault.data_len());
+    let amount = vault.lamports().saturating_sub(min_balance);
+    if amount > 0 {
+        **vault.try_borrow_mut_lamports()? -= amount;
+        **to.try_borrow_mut_lamports()? += amount;
+    }
+    Ok(amount)
+}
+
+pub fn process_claim(ctx: Context<Claim>) -> Result<()> {
+    settle_claim(ctx.accounts, ctx.remaining_accounts, false)
+}
+
+/// Claim body shared by `claim`, `claim_relayed` and `finalize_claim`, which
+/// nest these accounts. Two-phase alarms only settle with `finalizing`, and
+/// then the claim window is judged at the request time.
+pub(crate) fn settle_claim(
+    accounts: &mut Claim,
+    remaining_accounts: &[AccountInfo],
+    finalizing: bool,
+) -> Result<()> {
+    let alarm_key = accounts.alarm.key();
+    let owner_key = accounts.owner.key();
+    let authority_key = accounts.authority.key();
+    let alarm = &mut accounts.alarm;
+    let clock = crate::clock::get(remaining_accounts)?;
 
-    // CRITICAL: Claim is allowed through deadline + grace (inclusive).
     require!(
-        clock.unix_timestamp <= claim_deadline,
-        SolarmaError::DeadlinePassed
+        alarm.is_authorized(&authority_key)
+            || accounts
+                .user_profile
+                .as_ref()
+                .is_some_and(|p| p.is_active_delegate(&authority_key, clock.unix_timestamp)),
+        SolarmaError::UnauthorizedSigner
     );
 
+    helpers::check_claim_phase(
+        alarm.two_phase_claim,
+        finalizing,
+        alarm.claim_requested_at,
+        alarm.claim_flagged,
+        clock.unix_timestamp,
+    )
+    .map_err(|e| match e {
+        "flagged" => SolarmaError::ClaimVetoed,
+        "too_early" => SolarmaError::TooEarly,
+        "overflow" => SolarmaError::Overflow,
+        _ => SolarmaError::ClaimRequestRequired,
+    })?;
+    let claimed_at = if finalizing {
+        alarm.claim_requested_at
+    } else {
+        clock.unix_timestamp
+    };
+
+    // CRITICAL: Cannot claim before the wake window opens (wake proof not complete).
+    // Acknowledged alarms may claim through deadline + grace (inclusive).
+    helpers::check_claim_window(
+        alarm.status,
+        helpers::wake_window_start(alarm.alarm_time, alarm.early_ack_seconds),
+        alarm.deadline,
+        alarm.claim_grace_seconds,
+        claimed_at,
+    )
+    .map_err(|e| match e {
+        "too_early" => SolarmaError::TooEarly,
+        "deadline_passed" => SolarmaError::DeadlinePassed,
+        "overflow" => SolarmaError::Overflow,
+        _ => SolarmaError::InvalidAlarmState,
+    })?;
+
+    let buddy_reward = pay_buddy_reward(
+        alarm,
+        &accounts.vault.to_account_info(),
+        accounts.buddy.as_ref().map(|b| b.as_ref()),
+    )?;
+
     // The `close = owner` constraint automatically transfers all lamports
-    // (rent + remaining deposit) back to owner when vault account is closed
-    let vault_lamports = ctx.accounts.vault.to_account_info().lamports();
+    // (rent + remaining deposit) back to owner when vault account is closed,
+    // after the deposit went to the sponsor or claim destination if one is set
+    let vault_lamports = accounts.vault.to_account_info().lamports();
+    return_deposit_to_funder(
+        alarm.sponsor(),
+        &accounts.vault.to_account_info(),
+        accounts.funder.as_ref().map(|f| f.as_ref()),
+    )?;
+    pay_claim_destination(
+        alarm,
+        &accounts.vault.to_account_info(),
+        accounts.claim_to.as_ref().map(|c| c.as_ref()),
+    )?;
 
-    emit!(crate::events::AlarmClaimed {
-        owner: owner_key,
-        alarm: alarm_key,
-        alarm_id: alarm.alarm_id,
-        returned_amount: vault_lamports,
-    });
+    crate::events::emit_settlement(
+        crate::events::event_authority!(accounts),
+        crate::events::AlarmClaimed {
+            version: crate::events::EVENT_VERSION,
+            timestamp: clock.unix_timestamp,
+            owner: owner_key,
+            alarm: alarm_key,
+            alarm_id: alarm.alarm_id,
+            returned_amount: vault_lamports,
+            buddy_reward,
+        },
+    )?;
 
     msg!(
         "Claimed {} lamports back to owner (deposit + rent)",
         vault_lamports
     );
 
+    if let Some(profile) = accounts.user_profile.as_mut() {
+        let old_streak = profile.current_streak;
+        let stats =
+            helpers::record_claim(&profile.stats(), clock.unix_timestamp, alarm.snooze_count);
+        profile.set_stats(&stats);
+        credit_forgiveness(
+            accounts.forgiveness_token.as_mut(),
+            old_streak,
+            stats.current_streak,
+            clock.unix_timestamp,
+        );
+    }
+
+    record_leaderboard_result(
+        accounts.leaderboard_entry.as_mut(),
+        accounts.leaderboard_epoch.as_mut(),
+        true,
+        alarm.remaining_amount,
+        clock.unix_timestamp,
+    )?;
+
     // Mark as claimed (terminal state)
     alarm.status = AlarmStatus::Claimed;
     alarm.remaining_amount = 0;
+    remove_from_alarm_index(accounts.alarm_index.as_deref_mut(), alarm.alarm_id);
+    record_daily_stat(
+        accounts.daily_stats.as_mut(),
+        DailyStat::Claimed,
+        clock.unix_timestamp,
+    );
 
-    msg!("Alarm claimed successfully by {}", owner_key);
+    msg!(
+        "Alarm claimed successfully by {} for owner {}",
+        authority_key,
+        owner_key
+    );
     Ok(())
 }
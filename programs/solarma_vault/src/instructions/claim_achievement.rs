@@ -0,0 +1,57 @@
+//! Claim achievement instruction - mint a badge PDA once the owner's
+//! profile counters cross the badge threshold
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Achievement, UserProfile};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(badge_id: u8)]
+pub struct ClaimAchievement<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = Achievement::SIZE,
+        seeds = [b"achievement", owner.key().as_ref(), &[badge_id]],
+        bump
+    )]
+    pub achievement: Account<'info, Achievement>,
+
+    #[account(
+        has_one = owner,
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_claim_achievement(ctx: Context<ClaimAchievement>, badge_id: u8) -> Result<()> {
+    let unlocked = helpers::badge_unlocked(badge_id, &ctx.accounts.user_profile.stats())
+        .map_err(|_| SolarmaError::InvalidBadge)?;
+    require!(unlocked, SolarmaError::AchievementLocked);
+
+    let clock = Clock::get()?;
+    let achievement = &mut ctx.accounts.achievement;
+    achievement.owner = ctx.accounts.owner.key();
+    achievement.badge_id = badge_id;
+    achievement.unlocked_at = clock.unix_timestamp;
+    achievement.bump = ctx.bumps.achievement;
+
+    emit!(crate::events::AchievementUnlocked {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: achievement.owner,
+        achievement: achievement.key(),
+        badge_id,
+        unlocked_at: clock.unix_timestamp,
+    });
+
+    msg!("Badge {} unlocked for {}", badge_id, achievement.owner);
+    Ok(())
+}
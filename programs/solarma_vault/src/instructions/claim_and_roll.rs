@@ -0,0 +1,357 @@
+//! Claim and roll instruction - claim an acknowledged alarm and put its
+//! deposit straight into the owner's next alarm
+//!
+//! The old alarm passes every `claim` check and the new one every
+//! `create_alarm` check, with the rolled deposit as its `deposit_amount`.
+//! The deposit moves vault to vault, so the owner signs once and only pays
+//! the new accounts' rent; the old vault's rent comes back to them.
+//!
+//! Like `claim_many`, alarms that need accounts only their own paths take
+//! are refused: attested and two-phase alarms, and alarms with a sponsor
+//! or claim destination. Leaderboard results are only recorded by `claim`,
+//! and `deposit_from_payer` is ignored (the owner pays the rent).
+
+use crate::constants::ALARM_LAYOUT_VERSION;
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::claim::{
+    pay_buddy_reward, pay_claim_destination, return_deposit_to_funder,
+};
+use crate::instructions::create_alarm::validate_create_alarm;
+use crate::instructions::init_alarm_index::{add_to_alarm_index, remove_from_alarm_index};
+use crate::instructions::init_daily_stats::{record_daily_stat, DailyStat};
+use crate::instructions::init_forgiveness_token::credit_forgiveness;
+use crate::state::{
+    Alarm, AlarmIndex, AlarmOptions, AlarmStatus, Charity, DailyStats, ForgivenessToken,
+    GlobalConfig, SavingsVault, UserProfile, Vault,
+};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(new_alarm_id: u64)]
+pub struct ClaimAndRoll<'info> {
+    /// Acknowledged alarm being claimed
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::Acknowledged @ SolarmaError::InvalidAlarmState,
+        constraint = !alarm.require_attestation @ SolarmaError::AttestationRequired
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Old vault - the deposit moves to `new_vault`, the rent goes to owner
+    #[account(
+        mut,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump = alarm.vault_bump,
+        close = owner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = Alarm::SIZE,
+        seeds = [b"alarm", owner.key().as_ref(), &new_alarm_id.to_le_bytes()],
+        bump
+    )]
+    pub new_alarm: Account<'info, Alarm>,
+
+    /// Vault PDA for the new alarm - INITIALIZED here
+    #[account(
+        init,
+        payer = owner,
+        space = Vault::SIZE,
+        seeds = [b"vault", new_alarm.key().as_ref()],
+        bump
+    )]
+    pub new_vault: Account<'info, Vault>,
+
+    /// Global config supplying default alarm parameters; rejects rolls while
+    /// paused (a plain `claim` still works)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ SolarmaError::ProtocolPaused
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Registry entry for a Donate destination (needed when verified charities are required)
+    pub charity: Option<Account<'info, Charity>>,
+
+    /// Owner's savings vault (required for the Savings route)
+    #[account(
+        seeds = [b"savings", owner.key().as_ref()],
+        bump = savings_vault.bump
+    )]
+    pub savings_vault: Option<Account<'info, SavingsVault>>,
+
+    /// Owner's profile; claim counters are updated and deposit limits and
+    /// open alarms are tracked as in `create_alarm`
+    #[account(
+        mut,
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Owner's alarm index; the claimed alarm leaves it and the new one joins
+    #[account(
+        mut,
+        seeds = [b"alarm-index", owner.key().as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+
+    /// Buddy receiving the thank-you share; required when `buddy_reward_bps > 0`
+    /// CHECK: Validated against alarm.penalty_destination in `pay_buddy_reward`
+    #[account(mut)]
+    pub buddy: Option<UncheckedAccount<'info>>,
+
+    /// Owner's forgiveness tokens, credited as in `claim`
+    #[account(
+        mut,
+        seeds = [b"forgiveness", owner.key().as_ref()],
+        bump = forgiveness_token.bump
+    )]
+    pub forgiveness_token: Option<Account<'info, ForgivenessToken>>,
+
+    /// Today's protocol counters; the claim and the new alarm are counted on it
+    #[account(mut, seeds = [b"daily-stats".as_ref(), &daily_stats.day.to_le_bytes()], bump = daily_stats.bump)]
+    pub daily_stats: Option<Account<'info, DailyStats>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_claim_and_roll(
+    ctx: Context<ClaimAndRoll>,
+    new_alarm_id: u64,
+    alarm_time: i64,
+    deadline: i64,
+    penalty_route: u8,
+    penalty_destination: Option<Pubkey>,
+    options: Option<AlarmOptions>,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    let alarm_key = accounts.alarm.key();
+    let new_alarm_key = accounts.new_alarm.key();
+    let owner_key = accounts.owner.key();
+    let options = options.unwrap_or_default();
+    let clock = Clock::get()?;
+
+    // Same checks as `claim`
+    let alarm = &mut accounts.alarm;
+    helpers::check_claim_phase(
+        alarm.two_phase_claim,
+        false,
+        alarm.claim_requested_at,
+        alarm.claim_flagged,
+        clock.unix_timestamp,
+    )
+    .map_err(|e| match e {
+        "flagged" => SolarmaError::ClaimVetoed,
+        "too_early" => SolarmaError::TooEarly,
+        "overflow" => SolarmaError::Overflow,
+        _ => SolarmaError::ClaimRequestRequired,
+    })?;
+    helpers::check_claim_window(
+        alarm.status,
+        helpers::wake_window_start(alarm.alarm_time, alarm.early_ack_seconds),
+        alarm.deadline,
+        alarm.claim_grace_seconds,
+        clock.unix_timestamp,
+    )
+    .map_err(|e| match e {
+        "too_early" => SolarmaError::TooEarly,
+        "deadline_passed" => SolarmaError::DeadlinePassed,
+        "overflow" => SolarmaError::Overflow,
+        _ => SolarmaError::InvalidAlarmState,
+    })?;
+
+    // The rolled alarm keeps the tag requirement even if the profile is omitted
+    let tag_required = alarm.tag_required;
+
+    let vault_info = accounts.vault.to_account_info();
+    let buddy_reward = pay_buddy_reward(
+        alarm,
+        &vault_info,
+        accounts.buddy.as_ref().map(|b| b.as_ref()),
+    )?;
+    // No sponsor or destination account here, so alarms sending the
+    // deposit elsewhere are refused
+    return_deposit_to_funder(alarm.sponsor(), &vault_info, None)?;
+    pay_claim_destination(alarm, &vault_info, None)?;
+
+    // Everything above the old vault's rent is the rolled deposit
+    let min_balance = Rent::get()?.minimum_balance(vault_info.data_len());
+    let deposit_amount = vault_info.lamports().saturating_sub(min_balance);
+
+    let validated = validate_create_alarm(
+        &accounts.config,
+        &owner_key,
+        accounts.charity.as_deref(),
+        accounts.savings_vault.as_ref().map(|s| s.key()),
+        accounts.user_profile.as_deref(),
+        accounts.user_profile.as_deref(),
+        alarm_time,
+        deadline,
+        deposit_amount,
+        penalty_route,
+        penalty_destination,
+        &options,
+        AlarmStatus::Created,
+        clock.unix_timestamp,
+    )?;
+
+    if let Some(profile) = accounts.user_profile.as_mut() {
+        let old_streak = profile.current_streak;
+        let stats =
+            helpers::record_claim(&profile.stats(), clock.unix_timestamp, alarm.snooze_count);
+        profile.set_stats(&stats);
+        credit_forgiveness(
+            accounts.forgiveness_token.as_mut(),
+            old_streak,
+            stats.current_streak,
+            clock.unix_timestamp,
+        );
+        if let Some((day, total)) = validated.deposit_usage {
+            profile.deposit_day = day;
+            profile.deposited_today = total;
+        }
+        if let Some(count) = validated.open_alarm_count {
+            profile.open_alarm_count = count;
+        }
+    }
+
+    crate::events::emit_settlement(
+        crate::events::event_authority!(accounts),
+        crate::events::AlarmClaimed {
+            version: crate::events::EVENT_VERSION,
+            timestamp: clock.unix_timestamp,
+            owner: owner_key,
+            alarm: alarm_key,
+            alarm_id: alarm.alarm_id,
+            returned_amount: vault_info.lamports(),
+            buddy_reward,
+        },
+    )?;
+
+    // Mark as claimed (terminal state); `close = owner` returns the old vault's rent
+    alarm.status = AlarmStatus::Claimed;
+    alarm.remaining_amount = 0;
+    let old_alarm_id = alarm.alarm_id;
+    remove_from_alarm_index(accounts.alarm_index.as_deref_mut(), old_alarm_id);
+    helpers::check_expected_open_alarms(
+        options.expected_open_alarms,
+        accounts
+            .alarm_index
+            .as_ref()
+            .map(|index| index.alarm_ids.len()),
+    )
+    .map_err(|e| match e {
+        "index_required" => SolarmaError::AlarmIndexRequired,
+        _ => SolarmaError::OpenAlarmsMismatch,
+    })?;
+    add_to_alarm_index(accounts.alarm_index.as_deref_mut(), new_alarm_id)?;
+    record_daily_stat(
+        accounts.daily_stats.as_mut(),
+        DailyStat::Claimed,
+        clock.unix_timestamp,
+    );
+    record_daily_stat(
+        accounts.daily_stats.as_mut(),
+        DailyStat::AlarmCreated,
+        clock.unix_timestamp,
+    );
+
+    let proof_commitment = options.proof_commitment.unwrap_or_default();
+    if options.proof_commitment.is_some() {
+        helpers::validate_tag_hash(&proof_commitment)
+            .map_err(|_| SolarmaError::InvalidProofCommitment)?;
+    }
+
+    // Both vaults are program-owned, so the deposit moves without a CPI
+    if deposit_amount > 0 {
+        **vault_info.try_borrow_mut_lamports()? -= deposit_amount;
+        **accounts
+            .new_vault
+            .to_account_info()
+            .try_borrow_mut_lamports()? += deposit_amount;
+    }
+
+    let new_vault = &mut accounts.new_vault;
+    new_vault.alarm = new_alarm_key;
+    new_vault.bump = ctx.bumps.new_vault;
+
+    let new_alarm = &mut accounts.new_alarm;
+    new_alarm.owner = owner_key;
+    new_alarm.alarm_id = new_alarm_id;
+    new_alarm.alarm_time = alarm_time;
+    new_alarm.deadline = deadline;
+    new_alarm.initial_amount = deposit_amount;
+    new_alarm.remaining_amount = deposit_amount;
+    new_alarm.penalty_route = penalty_route;
+    new_alarm.penalty_destination = penalty_destination;
+    new_alarm.snooze_count = 0;
+    new_alarm.status = AlarmStatus::Created;
+    new_alarm.bump = ctx.bumps.new_alarm;
+    new_alarm.vault_bump = ctx.bumps.new_vault;
+    new_alarm.layout_version = ALARM_LAYOUT_VERSION;
+    new_alarm.set_snooze_params(&validated.snooze_params);
+    new_alarm.free_snoozes = validated.free_snoozes;
+    new_alarm.snooze_model = validated.snooze_model;
+    new_alarm.penalty_splits = validated.penalty_splits;
+    new_alarm.partial_slash_bps = validated.partial_slash_bps;
+    new_alarm.partial_slash_interval_seconds = validated.partial_slash_interval_seconds;
+    new_alarm.require_attestation = options.require_attestation.unwrap_or(false);
+    new_alarm.buddy_reward_bps = validated.buddy_reward_bps;
+    new_alarm.arbiter = validated.arbiter;
+    new_alarm.memo = options.memo.unwrap_or_default();
+    new_alarm.claim_to = options.claim_to;
+    new_alarm.required_proof_type = options.required_proof_type.unwrap_or(0);
+    new_alarm.required_proofs = options.required_proofs.unwrap_or(0);
+    new_alarm.claim_grace_seconds = validated.claim_grace_seconds;
+    new_alarm.early_ack_seconds = validated.early_ack_seconds;
+    new_alarm.soft_deadline = validated.soft_deadline;
+    new_alarm.unclaimed_penalty_bps = validated.unclaimed_penalty_bps;
+    new_alarm.open_alarm_counted = validated.open_alarm_count.is_some();
+    new_alarm.tag_required = tag_required || validated.tag_required;
+    new_alarm.payer = owner_key;
+    new_alarm.proof_commitment = proof_commitment;
+    new_alarm.two_phase_claim = helpers::requires_two_phase_claim(
+        deposit_amount,
+        accounts.config.two_phase_claim_threshold,
+    );
+
+    emit!(crate::events::AlarmCreated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: owner_key,
+        alarm: new_alarm_key,
+        alarm_id: new_alarm_id,
+        alarm_time,
+        deadline,
+        deposit_amount,
+        penalty_route,
+    });
+    emit!(crate::events::AlarmRolled {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: owner_key,
+        from_alarm: alarm_key,
+        to_alarm: new_alarm_key,
+        amount: deposit_amount,
+    });
+
+    msg!(
+        "Alarm {} claimed and rolled {} lamports into alarm {}",
+        old_alarm_id,
+        deposit_amount,
+        new_alarm_id
+    );
+    Ok(())
+}
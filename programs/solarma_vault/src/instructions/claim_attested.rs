@@ -0,0 +1,195 @@
+//! ClaimAttested instruction - claim that also requires a server-signed
+//! permit, so funds only leave the vault after the wake proof was verified
+//! server-side.
+//!
+//! Same rules as `claim`, plus an Ed25519 permit (action
+//! `PERMIT_ACTION_CLAIM`, zero proof hash) consumed through a `PermitNonce`
+//! PDA, as in `ack_awake_attested`.
+
+use crate::constants::PERMIT_ACTION_CLAIM;
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::ack_awake_attested::consume_permit;
+use crate::instructions::claim::{
+    pay_buddy_reward, pay_claim_destination, return_deposit_to_funder,
+};
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::state::{
+    Alarm, AlarmIndex, AlarmStatus, AttestationConfig, PermitNonce, UserProfile, Vault,
+};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ClaimAttested<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::Acknowledged @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.is_authorized(&authority.key()) @ SolarmaError::UnauthorizedSigner
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA holding the deposit - closed and funds returned to owner
+    #[account(
+        mut,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump = alarm.vault_bump,
+        close = owner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Anti-replay marker; `init` fails if the nonce was already used
+    #[account(
+        init,
+        payer = authority,
+        space = PermitNonce::SIZE,
+        seeds = [b"permit", alarm.key().as_ref(), &nonce.to_le_bytes()],
+        bump
+    )]
+    pub permit_nonce: Account<'info, PermitNonce>,
+
+    /// Registered attestation signer(s)
+    #[account(seeds = [b"attestation"], bump = attestation_config.bump)]
+    pub attestation_config: Account<'info, AttestationConfig>,
+
+    /// Alarm owner account; receives the funds, or only the vault rent
+    /// when `alarm.claim_to` is set
+    /// CHECK: Key is verified by `alarm.has_one = owner`
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// Instructions sysvar, used to read the preceding Ed25519 instruction
+    /// CHECK: Address is checked against the sysvar ID
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    /// Owner's profile; when present, claim counters for achievements are updated
+    #[account(
+        mut,
+        seeds = [b"user-profile", alarm.owner.as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Owner or co-owner signing the claim; pays for the nonce PDA
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Buddy receiving the thank-you share; required when `buddy_reward_bps > 0`
+    /// CHECK: Validated against alarm.penalty_destination in `pay_buddy_reward`
+    #[account(mut)]
+    pub buddy: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Receives the deposit; required when `alarm.claim_to` is set
+    /// CHECK: Validated against alarm.claim_to in `pay_claim_destination`
+    #[account(mut)]
+    pub claim_to: Option<UncheckedAccount<'info>>,
+
+    /// Owner's alarm index; the claimed alarm is removed from it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", alarm.owner.as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+
+    /// Sponsor that funded the deposit; required for sponsored alarms
+    /// (`Alarm::sponsor`), and receives the deposit instead of the owner
+    /// CHECK: Validated against alarm.payer in `return_deposit_to_funder`
+    #[account(mut)]
+    pub funder: Option<UncheckedAccount<'info>>,
+}
+
+pub fn process_claim_attested(
+    ctx: Context<ClaimAttested>,
+    nonce: u64,
+    expires_at: i64,
+) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let owner_key = ctx.accounts.owner.key();
+    let clock = crate::clock::get(ctx.remaining_accounts)?;
+
+    consume_permit(
+        &mut ctx.accounts.permit_nonce,
+        ctx.bumps.permit_nonce,
+        &ctx.accounts.instructions,
+        &ctx.accounts.attestation_config,
+        PERMIT_ACTION_CLAIM,
+        &alarm_key,
+        nonce,
+        expires_at,
+        &[0u8; 32],
+        clock.unix_timestamp,
+    )?;
+
+    let alarm = &mut ctx.accounts.alarm;
+
+    // Same window as claim: wake window start through deadline + grace
+    helpers::check_claim_window(
+        alarm.status,
+        helpers::wake_window_start(alarm.alarm_time, alarm.early_ack_seconds),
+        alarm.deadline,
+        alarm.claim_grace_seconds,
+        clock.unix_timestamp,
+    )
+    .map_err(|e| match e {
+        "too_early" => SolarmaError::TooEarly,
+        "deadline_passed" => SolarmaError::DeadlinePassed,
+        "overflow" => SolarmaError::Overflow,
+        _ => SolarmaError::InvalidAlarmState,
+    })?;
+
+    let buddy_reward = pay_buddy_reward(
+        alarm,
+        &ctx.accounts.vault.to_account_info(),
+        ctx.accounts.buddy.as_ref().map(|b| b.as_ref()),
+    )?;
+
+    // The `close = owner` constraint returns deposit + rent to the owner,
+    // or only the rent once the sponsor or claim destination took the deposit
+    let vault_lamports = ctx.accounts.vault.to_account_info().lamports();
+    return_deposit_to_funder(
+        alarm.sponsor(),
+        &ctx.accounts.vault.to_account_info(),
+        ctx.accounts.funder.as_ref().map(|f| f.as_ref()),
+    )?;
+    pay_claim_destination(
+        alarm,
+        &ctx.accounts.vault.to_account_info(),
+        ctx.accounts.claim_to.as_ref().map(|c| c.as_ref()),
+    )?;
+
+    crate::events::emit_settlement(
+        crate::events::event_authority!(ctx.accounts),
+        crate::events::AlarmClaimed {
+            version: crate::events::EVENT_VERSION,
+            timestamp: clock.unix_timestamp,
+            owner: owner_key,
+            alarm: alarm_key,
+            alarm_id: alarm.alarm_id,
+            returned_amount: vault_lamports,
+            buddy_reward,
+        },
+    )?;
+
+    if let Some(profile) = ctx.accounts.user_profile.as_mut() {
+        let stats =
+            helpers::record_claim(&profile.stats(), clock.unix_timestamp, alarm.snooze_count);
+        profile.set_stats(&stats);
+    }
+
+    alarm.status = AlarmStatus::Claimed;
+    alarm.remaining_amount = 0;
+    remove_from_alarm_index(ctx.accounts.alarm_index.as_deref_mut(), alarm.alarm_id);
+
+    msg!(
+        "Alarm claimed (attested, nonce {}) for owner {}",
+        nonce,
+        owner_key
+    );
+    Ok(())
+}
@@ -0,0 +1,136 @@
+//! ClaimInsurance instruction - an owner whose alarm was slashed because of
+//! an infrastructure failure recovers the lost deposit from the insurance
+//! pool
+//!
+//! The attestation server signs an Ed25519 permit (action
+//! `PERMIT_ACTION_INSURANCE`, proof hash = hash of its incident record)
+//! consumed through a `PermitNonce` PDA, as in `ack_awake_attested`. The
+//! payout is the alarm's `insured_loss`, capped at what the pool holds above
+//! rent; an unpaid rest stays claimable. One payout per owner every
+//! `INSURANCE_CLAIM_COOLDOWN_SECONDS`, tracked on the profile.
+
+use crate::constants::PERMIT_ACTION_INSURANCE;
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::ack_awake_attested::consume_permit;
+use crate::state::{
+    Alarm, AlarmStatus, AttestationConfig, InsurancePool, PermitNonce, UserProfile,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ClaimInsurance<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::Slashed @ SolarmaError::InvalidAlarmState
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Owner's profile; carries the per-user cooldown
+    #[account(
+        mut,
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    /// Insurance pool paying the claim
+    #[account(mut, seeds = [b"insurance"], bump = insurance_pool.bump)]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    /// Anti-replay marker; `init` fails if the nonce was already used
+    #[account(
+        init,
+        payer = owner,
+        space = PermitNonce::SIZE,
+        seeds = [b"permit", alarm.key().as_ref(), &nonce.to_le_bytes()],
+        bump
+    )]
+    pub permit_nonce: Account<'info, PermitNonce>,
+
+    /// Registered attestation signer(s)
+    #[account(seeds = [b"attestation"], bump = attestation_config.bump)]
+    pub attestation_config: Account<'info, AttestationConfig>,
+
+    /// Instructions sysvar, used to read the preceding Ed25519 instruction
+    /// CHECK: Address is checked against the sysvar ID
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    /// Alarm owner; receives the payout and pays for the nonce PDA
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_claim_insurance(
+    ctx: Context<ClaimInsurance>,
+    nonce: u64,
+    expires_at: i64,
+    incident_hash: [u8; 32],
+) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let clock = Clock::get()?;
+
+    consume_permit(
+        &mut ctx.accounts.permit_nonce,
+        ctx.bumps.permit_nonce,
+        &ctx.accounts.instructions,
+        &ctx.accounts.attestation_config,
+        PERMIT_ACTION_INSURANCE,
+        &alarm_key,
+        nonce,
+        expires_at,
+        &incident_hash,
+        clock.unix_timestamp,
+    )?;
+
+    let alarm = &mut ctx.accounts.alarm;
+    require!(alarm.insured_loss > 0, SolarmaError::NothingToInsure);
+    let profile = &mut ctx.accounts.user_profile;
+    require!(
+        helpers::is_insurance_cooldown_over(profile.last_insurance_claim_at, clock.unix_timestamp),
+        SolarmaError::InsuranceCooldown
+    );
+
+    // The pool keeps its rent-exempt minimum
+    let pool_info = ctx.accounts.insurance_pool.to_account_info();
+    let min_balance = Rent::get()?.minimum_balance(pool_info.data_len());
+    let payout = helpers::cap_at_rent_exempt(alarm.insured_loss, pool_info.lamports(), min_balance);
+    require!(payout > 0, SolarmaError::InsurancePoolEmpty);
+
+    **pool_info.try_borrow_mut_lamports()? -= payout;
+    **ctx.accounts.owner.try_borrow_mut_lamports()? += payout;
+
+    let pool = &mut ctx.accounts.insurance_pool;
+    pool.total_paid = pool
+        .total_paid
+        .checked_add(payout)
+        .ok_or(SolarmaError::Overflow)?;
+    alarm.insured_loss = alarm
+        .insured_loss
+        .checked_sub(payout)
+        .ok_or(SolarmaError::Overflow)?;
+    profile.last_insurance_claim_at = clock.unix_timestamp;
+
+    emit!(crate::events::InsuranceClaimed {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: alarm.owner,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        amount: payout,
+        remaining_loss: alarm.insured_loss,
+        incident_hash,
+    });
+
+    msg!(
+        "Insurance paid {} lamports for alarm {}",
+        payout,
+        alarm.alarm_id
+    );
+    Ok(())
+}
@@ -0,0 +1,246 @@
+//! Claim late instruction - recover part of an unacknowledged deposit after
+//! the deadline, as long as it has not been slashed yet.
+//!
+//! All-or-nothing alarms refund a time-decayed share (see
+//! `helpers::late_claim_refund`). Progressive alarms forfeit the tranches
+//! already due and refund the rest, so claiming late never dodges accrued
+//! penalties. The forfeited portion is routed per `penalty_route`.
+//! Sponsored alarms are refused: their deposit is not the owner's.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::instructions::init_savings_vault::credit_savings;
+use crate::instructions::initialize::pay_referral_reward;
+use crate::state::{
+    Alarm, AlarmIndex, AlarmStatus, GlobalConfig, PenaltyRoute, Treasury, UserProfile, Vault,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ClaimLate<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.is_authorized(&authority.key()) @ SolarmaError::UnauthorizedSigner
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA holding the deposit - closed and the rest returned to owner
+    #[account(
+        mut,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump = alarm.vault_bump,
+        close = owner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Alarm owner account, always receives the refund
+    /// CHECK: Key is verified by `alarm.has_one = owner`
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// Penalty destination for the forfeited portion (first leg for Split)
+    /// CHECK: Validated against alarm.penalty_destination or the config's burn sink
+    #[account(mut)]
+    pub penalty_recipient: UncheckedAccount<'info>,
+
+    /// Global config - read for the protocol fee only; claims ignore the pause flag
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Treasury PDA receiving the protocol fee on the forfeited portion
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Owner's profile; when it names a referrer, `referral_fee_bps` of the
+    /// protocol fee goes to `referrer`
+    #[account(
+        seeds = [b"user-profile", alarm.owner.as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Referrer named by `user_profile`; required when it earns a share
+    /// CHECK: Validated against user_profile.referrer in `pay_referral_reward`
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Owner or co-owner signing the claim
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Owner's alarm index; the claimed alarm is removed from it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", alarm.owner.as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+}
+
+pub fn process_claim_late(ctx: Context<ClaimLate>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let owner_key = ctx.accounts.owner.key();
+    let recipient_key = ctx.accounts.penalty_recipient.key();
+    let clock = Clock::get()?;
+
+    require!(
+        ctx.accounts.alarm.challenger_stake == 0,
+        SolarmaError::BetLocked
+    );
+    // The refund is the sponsor's, and this path only pays the owner
+    require!(
+        ctx.accounts.alarm.sponsor().is_none(),
+        SolarmaError::InvalidFunder
+    );
+    require!(
+        clock.unix_timestamp >= ctx.accounts.alarm.deadline,
+        SolarmaError::DeadlineNotPassed
+    );
+
+    let alarm = &ctx.accounts.alarm;
+    helpers::validate_penalty_recipient(
+        alarm.penalty_route,
+        &recipient_key.to_bytes(),
+        &ctx.accounts.config.effective_burn_sink().to_bytes(),
+        alarm.penalty_destination.map(|d| d.to_bytes()).as_ref(),
+    )
+    .map_err(|e| match e {
+        "invalid_penalty_route" => SolarmaError::InvalidPenaltyRoute,
+        "penalty_destination_not_set" => SolarmaError::PenaltyDestinationNotSet,
+        _ => SolarmaError::InvalidPenaltyRecipient,
+    })?;
+
+    let forfeited = if alarm.is_progressive() {
+        // Settle tranches that came due since the last slash_partial
+        helpers::partial_slash_due(
+            alarm.remaining_amount,
+            alarm.partial_slashed,
+            alarm.partial_slash_bps,
+            alarm.partial_slash_interval_seconds,
+            alarm.deadline,
+            clock.unix_timestamp,
+        )
+        .ok_or(SolarmaError::Overflow)?
+    } else {
+        let refund = helpers::late_claim_refund(
+            alarm.deadline,
+            clock.unix_timestamp,
+            alarm.remaining_amount,
+        );
+        require!(refund > 0, SolarmaError::DeadlinePassed);
+        alarm
+            .remaining_amount
+            .checked_sub(refund)
+            .ok_or(SolarmaError::Overflow)?
+    };
+
+    if forfeited > 0 {
+        let fee = helpers::bps_of(forfeited, ctx.accounts.config.protocol_fee_bps)
+            .ok_or(SolarmaError::Overflow)?;
+        let distributable = forfeited.checked_sub(fee).ok_or(SolarmaError::Overflow)?;
+
+        // Split route: legs 2..n come from remaining_accounts (in order)
+        let route = PenaltyRoute::try_from(alarm.penalty_route)
+            .map_err(|_| SolarmaError::InvalidPenaltyRoute)?;
+        let splits = alarm.active_splits();
+        let shares = if route == PenaltyRoute::Split {
+            let bps: Vec<u16> = splits.iter().map(|s| s.bps).collect();
+            helpers::split_shares(distributable, &bps).ok_or(SolarmaError::InvalidPenaltySplit)?
+        } else {
+            vec![distributable]
+        };
+        require!(
+            ctx.remaining_accounts.len() == shares.len() - 1,
+            SolarmaError::InvalidPenaltyRecipient
+        );
+
+        let referral = pay_referral_reward(
+            &ctx.accounts.vault.to_account_info(),
+            ctx.accounts.user_profile.as_deref(),
+            ctx.accounts.referrer.as_deref(),
+            fee,
+            ctx.accounts.config.referral_fee_bps,
+            alarm_key,
+        )?;
+        let treasury_fee = fee.checked_sub(referral).ok_or(SolarmaError::Overflow)?;
+
+        **ctx
+            .accounts
+            .vault
+            .to_account_info()
+            .try_borrow_mut_lamports()? -= forfeited - referral;
+        **ctx
+            .accounts
+            .treasury
+            .to_account_info()
+            .try_borrow_mut_lamports()? += treasury_fee;
+        **ctx
+            .accounts
+            .penalty_recipient
+            .to_account_info()
+            .try_borrow_mut_lamports()? += shares[0];
+        for ((leg, share), account) in splits
+            .iter()
+            .zip(shares.iter())
+            .skip(1)
+            .zip(ctx.remaining_accounts.iter())
+        {
+            require!(
+                account.key() == leg.destination && account.is_writable,
+                SolarmaError::InvalidPenaltyRecipient
+            );
+            **account.try_borrow_mut_lamports()? += *share;
+        }
+
+        if route == PenaltyRoute::Savings {
+            credit_savings(
+                &ctx.accounts.penalty_recipient.to_account_info(),
+                &alarm.owner,
+                shares[0],
+                clock.unix_timestamp,
+            )?;
+        }
+
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.total_collected = treasury
+            .total_collected
+            .checked_add(treasury_fee)
+            .ok_or(SolarmaError::Overflow)?;
+    }
+
+    // The `close = owner` constraint returns the rest (deposit + rent)
+    let returned = ctx.accounts.vault.to_account_info().lamports();
+
+    let alarm = &mut ctx.accounts.alarm;
+    if alarm.is_progressive() {
+        alarm.partial_slashed = alarm
+            .partial_slashed
+            .checked_add(forfeited)
+            .ok_or(SolarmaError::Overflow)?;
+    }
+
+    emit!(crate::events::LateClaimed {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: owner_key,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        returned_amount: returned,
+        forfeited_amount: forfeited,
+    });
+
+    msg!(
+        "Late claim: {} lamports returned, {} forfeited",
+        returned,
+        forfeited
+    );
+
+    alarm.status = AlarmStatus::Claimed;
+    alarm.remaining_amount = 0;
+    remove_from_alarm_index(ctx.accounts.alarm_index.as_deref_mut(), alarm.alarm_id);
+    Ok(())
+}
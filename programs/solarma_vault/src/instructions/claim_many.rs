@@ -0,0 +1,201 @@
+//! Claim many instruction - the owner claims several acknowledged alarms
+//! (e.g. a nap and the morning alarm) in one transaction
+//!
+//! `remaining_accounts` holds up to `MAX_CLAIM_MANY_ALARMS` writable
+//! `[alarm, vault]` pairs. Each alarm must pass the checks `claim` makes and
+//! its vault is closed to the owner. Unlike `crank_settle`, nothing is
+//! skipped: one unclaimable alarm fails the whole batch.
+//!
+//! Alarms that need accounts only `claim` takes are refused: attested and
+//! two-phase alarms (use their own paths), buddy-reward alarms (the buddy
+//! account) and sponsored alarms (the funder account). Leaderboard results
+//! are only recorded by `claim`.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::claim::{
+    pay_buddy_reward, pay_claim_destination, return_deposit_to_funder,
+};
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::instructions::init_daily_stats::{record_daily_stat, DailyStat};
+use crate::instructions::init_forgiveness_token::credit_forgiveness;
+use crate::state::{
+    Alarm, AlarmIndex, AlarmStatus, DailyStats, ForgivenessToken, UserProfile, Vault,
+};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct ClaimMany<'info> {
+    /// Owner of every alarm in the batch; receives each vault
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Owner's profile; claim counters for achievements are updated
+    #[account(
+        mut,
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Owner's alarm index; claimed alarms are removed from it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", owner.key().as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+
+    /// Today's protocol counters; each claim is counted on it
+    #[account(mut, seeds = [b"daily-stats".as_ref(), &daily_stats.day.to_le_bytes()], bump = daily_stats.bump)]
+    pub daily_stats: Option<Account<'info, DailyStats>>,
+
+    /// Owner's forgiveness tokens; credited as in `claim` whenever a claim in
+    /// the batch extends the streak onto a multiple of `FORGIVENESS_STREAK_DAYS`
+    /// (needs `user_profile`)
+    #[account(
+        mut,
+        seeds = [b"forgiveness", owner.key().as_ref()],
+        bump = forgiveness_token.bump
+    )]
+    pub forgiveness_token: Option<Account<'info, ForgivenessToken>>,
+}
+
+pub fn process_claim_many<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimMany<'info>>,
+) -> Result<()> {
+    let count = helpers::claim_many_batch_size(ctx.remaining_accounts.len())
+        .ok_or(SolarmaError::InvalidClaimBatch)?;
+    let clock = Clock::get()?;
+
+    let mut total_returned = 0u64;
+    for pair in ctx.remaining_accounts.chunks_exact(2) {
+        let returned = claim_pair(ctx.accounts, pair, clock.unix_timestamp)?;
+        total_returned = total_returned
+            .checked_add(returned)
+            .ok_or(SolarmaError::Overflow)?;
+    }
+
+    msg!(
+        "Claimed {} alarms, {} lamports back to owner {}",
+        count,
+        total_returned,
+        ctx.accounts.owner.key()
+    );
+    Ok(())
+}
+
+/// Claim one `[alarm, vault]` pair and close its vault to the owner.
+/// Returns the lamports sent back.
+fn claim_pair<'info>(
+    accounts: &mut ClaimMany<'info>,
+    pair: &'info [AccountInfo<'info>],
+    current_time: i64,
+) -> Result<u64> {
+    let [alarm_info, vault_info] = pair else {
+        return err!(SolarmaError::InvalidClaimBatch);
+    };
+    require!(
+        alarm_info.is_writable && vault_info.is_writable,
+        SolarmaError::InvalidClaimBatch
+    );
+    let mut alarm = Account::<Alarm>::try_from(alarm_info)?;
+    require_keys_eq!(
+        alarm.owner,
+        accounts.owner.key(),
+        SolarmaError::UnauthorizedSigner
+    );
+    let expected_vault = Pubkey::create_program_address(
+        &[b"vault", alarm_info.key.as_ref(), &[alarm.vault_bump]],
+        &crate::ID,
+    )
+    .map_err(|_| SolarmaError::InvalidClaimBatch)?;
+    require_keys_eq!(
+        vault_info.key(),
+        expected_vault,
+        SolarmaError::InvalidClaimBatch
+    );
+    let vault = Account::<Vault>::try_from(vault_info)?;
+
+    // Same checks as `claim`
+    require!(
+        alarm.status == AlarmStatus::Acknowledged,
+        SolarmaError::InvalidAlarmState
+    );
+    require!(
+        !alarm.require_attestation,
+        SolarmaError::AttestationRequired
+    );
+    helpers::check_claim_phase(
+        alarm.two_phase_claim,
+        false,
+        alarm.claim_requested_at,
+        alarm.claim_flagged,
+        current_time,
+    )
+    .map_err(|e| match e {
+        "flagged" => SolarmaError::ClaimVetoed,
+        "too_early" => SolarmaError::TooEarly,
+        "overflow" => SolarmaError::Overflow,
+        _ => SolarmaError::ClaimRequestRequired,
+    })?;
+    helpers::check_claim_window(
+        alarm.status,
+        helpers::wake_window_start(alarm.alarm_time, alarm.early_ack_seconds),
+        alarm.deadline,
+        alarm.claim_grace_seconds,
+        current_time,
+    )
+    .map_err(|e| match e {
+        "too_early" => SolarmaError::TooEarly,
+        "deadline_passed" => SolarmaError::DeadlinePassed,
+        "overflow" => SolarmaError::Overflow,
+        _ => SolarmaError::InvalidAlarmState,
+    })?;
+    // No buddy, sponsor or destination account here, so alarms owing a
+    // buddy share or sending the deposit elsewhere are refused
+    pay_buddy_reward(&alarm, vault_info, None)?;
+    return_deposit_to_funder(alarm.sponsor(), vault_info, None)?;
+    pay_claim_destination(&alarm, vault_info, None)?;
+
+    let returned = vault_info.lamports();
+    crate::events::emit_settlement(
+        crate::events::event_authority!(accounts),
+        crate::events::AlarmClaimed {
+            version: crate::events::EVENT_VERSION,
+            timestamp: current_time,
+            owner: alarm.owner,
+            alarm: alarm.key(),
+            alarm_id: alarm.alarm_id,
+            returned_amount: returned,
+            buddy_reward: 0,
+        },
+    )?;
+
+    if let Some(profile) = accounts.user_profile.as_mut() {
+        let old_streak = profile.current_streak;
+        let stats = helpers::record_claim(&profile.stats(), current_time, alarm.snooze_count);
+        profile.set_stats(&stats);
+        credit_forgiveness(
+            accounts.forgiveness_token.as_mut(),
+            old_streak,
+            stats.current_streak,
+            current_time,
+        );
+    }
+
+    // Mark as claimed (terminal state)
+    alarm.status = AlarmStatus::Claimed;
+    alarm.remaining_amount = 0;
+    remove_from_alarm_index(accounts.alarm_index.as_deref_mut(), alarm.alarm_id);
+    record_daily_stat(
+        accounts.daily_stats.as_mut(),
+        DailyStat::Claimed,
+        current_time,
+    );
+
+    alarm.exit(&crate::ID)?;
+    vault.close(accounts.owner.to_account_info())?;
+    Ok(returned)
+}
@@ -0,0 +1,40 @@
+//! ClaimRelayed instruction - `claim` submitted by a fee-paying relayer
+//!
+//! Same flow as `ack_awake_relayed`: the owner, co-owner or profile delegate
+//! signs, the relayer pays the fee. Funds still only go to the owner (and
+//! the buddy's thank-you share); the relayer receives nothing.
+
+use crate::constants::PERMIT_ACTION_CLAIM;
+// Glob: the nested `Claim` needs its Anchor-generated modules in scope
+use crate::instructions::claim::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ClaimRelayed<'info> {
+    /// The `claim` accounts, unchanged
+    pub claim: Claim<'info>,
+
+    /// Fee payer submitting the owner-signed transaction
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+}
+
+pub fn process_claim_relayed(ctx: Context<ClaimRelayed>) -> Result<()> {
+    let alarm_key = ctx.accounts.claim.alarm.key();
+    let authority_key = ctx.accounts.claim.authority.key();
+    let relayer_key = ctx.accounts.relayer.key();
+
+    settle_claim(&mut ctx.accounts.claim, ctx.remaining_accounts, false)?;
+
+    emit!(crate::events::RelayedSubmission {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        alarm: alarm_key,
+        authority: authority_key,
+        relayer: relayer_key,
+        action: PERMIT_ACTION_CLAIM,
+    });
+
+    msg!("Claim relayed by {}", relayer_key);
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! Close alarm instruction - reclaim rent from a resolved alarm
+//!
+//! The vault is already closed by claim/slash/refund/sweep, so a terminal
+//! alarm holds only its own rent. Closing it frees that rent for the owner,
+//! and frees a `create_alarm` slot on the owner's profile. Alarms counted
+//! in `open_alarm_count` can only be closed with the profile, so the slot
+//! is never lost.
+
+use crate::error::SolarmaError;
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::state::{Alarm, AlarmIndex, UserProfile};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseAlarm<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status.is_terminal() @ SolarmaError::InvalidAlarmState,
+        close = owner
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Owner's profile; its open-alarm count is released (required when
+    /// `alarm.open_alarm_counted`)
+    #[account(
+        mut,
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Owner's alarm index; drops alarms that were settled without it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", owner.key().as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn process_close_alarm(ctx: Context<CloseAlarm>) -> Result<()> {
+    let alarm = &ctx.accounts.alarm;
+
+    // Only alarms that took a slot at creation give one back
+    if alarm.open_alarm_counted {
+        let Some(profile) = ctx.accounts.user_profile.as_mut() else {
+            return err!(SolarmaError::UserProfileRequired);
+        };
+        profile.open_alarm_count = profile.open_alarm_count.saturating_sub(1);
+    }
+
+    remove_from_alarm_index(ctx.accounts.alarm_index.as_deref_mut(), alarm.alarm_id);
+
+    emit!(crate::events::AlarmClosed {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: alarm.owner,
+        alarm: alarm.key(),
+        alarm_id: alarm.alarm_id,
+    });
+
+    msg!("Alarm {} closed, rent returned to owner", alarm.alarm_id);
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! Close permit nonce instruction - reclaim rent from a spent permit
+//!
+//! A nonce can only be replayed while its permit is valid and the alarm is
+//! still live, so once `expires_at` has passed and the alarm is terminal
+//! the anti-replay marker is no longer needed. Close nonces before
+//! `close_alarm`, which removes the alarm account they are checked against.
+
+use crate::error::SolarmaError;
+use crate::state::{Alarm, PermitNonce};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ClosePermitNonce<'info> {
+    #[account(
+        mut,
+        constraint = permit_nonce.alarm == alarm.key() @ SolarmaError::InvalidAlarmState,
+        seeds = [b"permit", alarm.key().as_ref(), &permit_nonce.nonce.to_le_bytes()],
+        bump = permit_nonce.bump,
+        close = owner
+    )]
+    pub permit_nonce: Account<'info, PermitNonce>,
+
+    #[account(
+        has_one = owner,
+        constraint = alarm.status.is_terminal() @ SolarmaError::InvalidAlarmState
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn process_close_permit_nonce(ctx: Context<ClosePermitNonce>) -> Result<()> {
+    let permit = &ctx.accounts.permit_nonce;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp > permit.expires_at,
+        SolarmaError::TooEarly
+    );
+
+    emit!(crate::events::PermitNonceClosed {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: ctx.accounts.owner.key(),
+        alarm: permit.alarm,
+        nonce: permit.nonce,
+    });
+
+    msg!(
+        "Permit nonce {} closed, rent returned to owner",
+        permit.nonce
+    );
+    Ok(())
+}
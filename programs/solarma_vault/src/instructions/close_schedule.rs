@@ -0,0 +1,27 @@
+//! Close schedule instruction - owner deletes a weekly schedule and
+//! reclaims its rent
+//!
+//! Alarms already materialized are independent and are not affected.
+
+use crate::state::Schedule;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseSchedule<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"wake-schedule", owner.key().as_ref(), &schedule.schedule_id.to_le_bytes()],
+        bump = schedule.bump,
+        close = owner
+    )]
+    pub schedule: Account<'info, Schedule>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn process_close_schedule(ctx: Context<CloseSchedule>) -> Result<()> {
+    msg!("Schedule {} closed", ctx.accounts.schedule.schedule_id);
+    Ok(())
+}
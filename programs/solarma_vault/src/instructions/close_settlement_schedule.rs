@@ -0,0 +1,25 @@
+//! Close settlement schedule instruction - the creator reclaims the rent of
+//! a schedule that is no longer needed
+//!
+//! Use it after the alarm was claimed, refunded or settled without the
+//! schedule, or to re-create a schedule whose slash recipient changed.
+
+use crate::state::SettlementSchedule;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseSettlementSchedule<'info> {
+    #[account(mut, has_one = creator, close = creator)]
+    pub schedule: Account<'info, SettlementSchedule>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+pub fn process_close_settlement_schedule(ctx: Context<CloseSettlementSchedule>) -> Result<()> {
+    msg!(
+        "Settlement schedule for alarm {} closed",
+        ctx.accounts.schedule.alarm
+    );
+    Ok(())
+}
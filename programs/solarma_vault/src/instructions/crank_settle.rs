@@ -0,0 +1,324 @@
+//! Crank settle instruction - a keeper resolves many expired alarms in one
+//! transaction (permissionless)
+//!
+//! `remaining_accounts` holds up to `MAX_CRANK_ALARMS` groups of
+//! `[alarm, vault, recipient, alarm_index]`, all writable. `alarm_index` is
+//! the owner's `AlarmIndex`, which drops the settled alarm, or the program
+//! id when the owner keeps none. Each group is settled the way
+//! `slash` or `sweep_acknowledged` would settle it alone, so a keeper need
+//! not send one transaction per alarm:
+//!
+//! - unacknowledged alarms past their deadline are slashed to `recipient`
+//!   (the route's destination, or the challenger of a matched bet) and
+//!   the keeper earns the usual slasher bounty;
+//! - acknowledged alarms past their claim grace are swept to `recipient`
+//!   (the owner) with no bounty, as in `sweep_acknowledged`.
+//!
+//! Groups that are not ready or that need accounts this instruction does
+//! not take are skipped rather than failing the batch. Racing keepers
+//! therefore do not revert each other. Skipped cases are Split-route and
+//! progressive alarms, pending buddy/gift alarms, open disputes and
+//! recoveries, slashes while the protocol is paused, slashes owing an
+//! insurance share when the insurance pool is not passed, and sweeps owing
+//! an unclaimed penalty (only `sweep_acknowledged` takes its recipient). Referral shares
+//! are only paid by `slash`, which takes the owner's profile.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::instructions::init_insurance_pool::collect_insurance_share;
+use crate::instructions::init_savings_vault::credit_savings;
+use crate::state::{
+    Alarm, AlarmIndex, AlarmStatus, GlobalConfig, InsurancePool, PenaltyRoute, Treasury, Vault,
+};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct CrankSettle<'info> {
+    /// Global config - slashes are skipped while the protocol is paused
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Treasury PDA receiving the protocol fee of each slash
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Keeper running the crank; receives the slasher bounties
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// Insurance pool; without it slashes are skipped while `config.insurance_bps` is set
+    #[account(mut, seeds = [b"insurance"], bump = insurance_pool.bump)]
+    pub insurance_pool: Option<Account<'info, InsurancePool>>,
+}
+
+/// How one `[alarm, vault, recipient, alarm_index]` group was settled.
+pub(crate) enum Settlement {
+    Slashed { bounty: u64 },
+    Swept,
+    Skipped,
+}
+
+pub fn process_crank_settle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CrankSettle<'info>>,
+) -> Result<()> {
+    helpers::crank_batch_size(ctx.remaining_accounts.len())
+        .ok_or(SolarmaError::InvalidCrankAccounts)?;
+    let clock = Clock::get()?;
+
+    let (mut slashed, mut swept, mut skipped) = (0u16, 0u16, 0u16);
+    let mut total_bounty = 0u64;
+    for group in ctx.remaining_accounts.chunks_exact(4) {
+        match settle_group(ctx.accounts, group, clock.unix_timestamp)? {
+            Settlement::Slashed { bounty } => {
+                slashed += 1;
+                total_bounty = total_bounty
+                    .checked_add(bounty)
+                    .ok_or(SolarmaError::Overflow)?;
+            }
+            Settlement::Swept => swept += 1,
+            Settlement::Skipped => skipped += 1,
+        }
+    }
+    require!(slashed + swept > 0, SolarmaError::NothingToSettle);
+
+    emit!(crate::events::CrankSettled {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        keeper: ctx.accounts.keeper.key(),
+        slashed,
+        swept,
+        skipped,
+        total_bounty,
+    });
+
+    msg!(
+        "Crank settled {} slashed, {} swept, {} skipped",
+        slashed,
+        swept,
+        skipped
+    );
+    Ok(())
+}
+
+fn settle_group<'info>(
+    accounts: &mut CrankSettle<'info>,
+    group: &'info [AccountInfo<'info>],
+    current_time: i64,
+) -> Result<Settlement> {
+    let [alarm_info, vault_info, recipient, index_info] = group else {
+        return err!(SolarmaError::InvalidCrankAccounts);
+    };
+    let has_index = index_info.key != &crate::ID;
+    if !(alarm_info.is_writable
+        && vault_info.is_writable
+        && recipient.is_writable
+        && (!has_index || index_info.is_writable))
+    {
+        return Ok(Settlement::Skipped);
+    }
+    // Already closed (or never an alarm): another keeper got here first
+    let Ok(mut alarm) = Account::<Alarm>::try_from(alarm_info) else {
+        return Ok(Settlement::Skipped);
+    };
+    let expected_vault = Pubkey::create_program_address(
+        &[b"vault", alarm_info.key.as_ref(), &[alarm.vault_bump]],
+        &crate::ID,
+    )
+    .map_err(|_| SolarmaError::InvalidCrankAccounts)?;
+    require_keys_eq!(
+        vault_info.key(),
+        expected_vault,
+        SolarmaError::InvalidCrankAccounts
+    );
+    let Ok(vault) = Account::<Vault>::try_from(vault_info) else {
+        return Ok(Settlement::Skipped);
+    };
+
+    let settlement = match alarm.status {
+        AlarmStatus::Created => slash_expired(
+            &accounts.config,
+            &mut accounts.treasury,
+            accounts.insurance_pool.as_mut(),
+            &accounts.keeper,
+            &mut alarm,
+            &vault,
+            recipient,
+            crate::events::event_authority!(accounts),
+            current_time,
+        )?,
+        AlarmStatus::Acknowledged => sweep_expired(
+            &mut alarm,
+            &vault,
+            recipient,
+            crate::events::event_authority!(accounts),
+            current_time,
+        )?,
+        _ => Settlement::Skipped,
+    };
+    if !matches!(settlement, Settlement::Skipped) {
+        if has_index {
+            let mut index = Account::<AlarmIndex>::try_from(index_info)
+                .map_err(|_| SolarmaError::InvalidCrankAccounts)?;
+            require_keys_eq!(index.owner, alarm.owner, SolarmaError::InvalidCrankAccounts);
+            remove_from_alarm_index(Some(&mut index), alarm.alarm_id);
+            index.exit(&crate::ID)?;
+        }
+        alarm.exit(&crate::ID)?;
+        vault.close(recipient.clone())?;
+    }
+    Ok(settlement)
+}
+
+/// Slash an unacknowledged alarm past its deadline to `recipient`, paying
+/// the protocol fee, insurance share and `keeper`'s bounty out of the vault. Returns
+/// `Skipped` when `slash` would refuse it or needs accounts not passed here.
+/// The caller closes the vault to `recipient` on success.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn slash_expired<'info>(
+    config: &GlobalConfig,
+    treasury: &mut Account<'info, Treasury>,
+    insurance_pool: Option<&mut Account<'info, InsurancePool>>,
+    keeper: &AccountInfo<'info>,
+    alarm: &mut Account<'info, Alarm>,
+    vault: &Account<'info, Vault>,
+    recipient: &AccountInfo<'info>,
+    event_authority: Option<&AccountInfo<'info>>,
+    current_time: i64,
+) -> Result<Settlement> {
+    let keeper_key = keeper.key();
+    let Ok(route) = PenaltyRoute::try_from(alarm.penalty_route) else {
+        return Ok(Settlement::Skipped);
+    };
+    let is_bet = alarm.challenger_stake > 0;
+    if config.paused
+        || alarm.is_progressive()
+        || (route == PenaltyRoute::Split && !is_bet)
+        || helpers::is_dispute_active(alarm.dispute_until, current_time)
+        || (config.insurance_bps > 0 && insurance_pool.is_none())
+    {
+        return Ok(Settlement::Skipped);
+    }
+    let resolution = helpers::post_deadline_resolution(
+        alarm.status,
+        route == PenaltyRoute::Buddy && !is_bet,
+        Some(keeper_key) == alarm.penalty_destination,
+        alarm.deadline,
+        alarm.claim_grace_seconds,
+        current_time,
+    );
+    if resolution != Ok(helpers::Resolution::Slash) {
+        return Ok(Settlement::Skipped);
+    }
+
+    let recipient_ok = if is_bet {
+        alarm.challenger == Some(recipient.key())
+    } else {
+        helpers::validate_penalty_recipient(
+            alarm.penalty_route,
+            &recipient.key().to_bytes(),
+            &config.effective_burn_sink().to_bytes(),
+            alarm.penalty_destination.map(|d| d.to_bytes()).as_ref(),
+        )
+        .is_ok()
+    };
+    if !recipient_ok {
+        return Ok(Settlement::Skipped);
+    }
+
+    let slashed = helpers::bet_pot(alarm.remaining_amount, alarm.challenger_stake)
+        .ok_or(SolarmaError::Overflow)?;
+    let fee = helpers::bps_of(slashed, config.protocol_fee_bps).ok_or(SolarmaError::Overflow)?;
+    let bounty =
+        helpers::bps_of(slashed, config.slasher_bounty_bps).ok_or(SolarmaError::Overflow)?;
+
+    **vault.to_account_info().try_borrow_mut_lamports()? -= fee + bounty;
+    **treasury.to_account_info().try_borrow_mut_lamports()? += fee;
+    **keeper.try_borrow_mut_lamports()? += bounty;
+    treasury.total_collected = treasury
+        .total_collected
+        .checked_add(fee)
+        .ok_or(SolarmaError::Overflow)?;
+    let insurance = collect_insurance_share(
+        &vault.to_account_info(),
+        insurance_pool,
+        slashed,
+        config.insurance_bps,
+    )?;
+
+    // Savings route: extend the owner's lock for the incoming penalty
+    if route == PenaltyRoute::Savings && !is_bet {
+        let credited = slashed
+            .checked_sub(fee)
+            .and_then(|v| v.checked_sub(bounty))
+            .and_then(|v| v.checked_sub(insurance))
+            .ok_or(SolarmaError::Overflow)?;
+        credit_savings(recipient, &alarm.owner, credited, current_time)?;
+    }
+
+    crate::events::emit_settlement(
+        event_authority,
+        crate::events::AlarmSlashed {
+            version: crate::events::EVENT_VERSION,
+            timestamp: current_time,
+            alarm: alarm.key(),
+            alarm_id: alarm.alarm_id,
+            penalty_recipient: recipient.key(),
+            slashed_amount: slashed,
+            caller: keeper_key,
+            caller_bounty: bounty,
+            protocol_fee: fee,
+            penalty_route: alarm.penalty_route,
+        },
+    )?;
+
+    alarm.status = AlarmStatus::Slashed;
+    alarm.insured_loss = alarm.remaining_amount;
+    alarm.remaining_amount = 0;
+    Ok(Settlement::Slashed { bounty })
+}
+
+/// Sweep an acknowledged alarm past its claim grace back to `recipient`,
+/// which must be the owner. Returns `Skipped` when `sweep_acknowledged`
+/// would refuse it. The caller closes the vault to `recipient` on success.
+pub(crate) fn sweep_expired<'info>(
+    alarm: &mut Account<'info, Alarm>,
+    vault: &Account<'info, Vault>,
+    recipient: &AccountInfo<'info>,
+    event_authority: Option<&AccountInfo<'info>>,
+    current_time: i64,
+) -> Result<Settlement> {
+    let resolution = helpers::post_deadline_resolution(
+        alarm.status,
+        false,
+        false,
+        alarm.deadline,
+        alarm.claim_grace_seconds,
+        current_time,
+    );
+    if resolution != Ok(helpers::Resolution::Sweep)
+        || alarm.recovery_approvals != 0
+        || alarm.unclaimed_penalty_bps != 0
+        || recipient.key() != alarm.owner
+    {
+        return Ok(Settlement::Skipped);
+    }
+
+    crate::events::emit_settlement(
+        event_authority,
+        crate::events::AlarmClaimed {
+            version: crate::events::EVENT_VERSION,
+            timestamp: current_time,
+            owner: alarm.owner,
+            alarm: alarm.key(),
+            alarm_id: alarm.alarm_id,
+            returned_amount: vault.to_account_info().lamports(),
+            buddy_reward: 0,
+        },
+    )?;
+
+    alarm.status = AlarmStatus::Claimed;
+    alarm.remaining_amount = 0;
+    Ok(Settlement::Swept)
+}
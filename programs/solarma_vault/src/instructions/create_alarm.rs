@@ -1,8 +1,16 @@
 //! Create alarm instruction - with deposit support
 
-use crate::constants::MIN_DEPOSIT_LAMPORTS;
+use crate::constants::{
+    ALARM_LAYOUT_VERSION, CLAIM_GRACE_SECONDS, MAX_PENALTY_SPLITS, MIN_DEPOSIT_LAMPORTS,
+};
 use crate::error::SolarmaError;
-use crate::state::{Alarm, AlarmStatus, PenaltyRoute, Vault};
+use crate::helpers;
+use crate::instructions::init_alarm_index::add_to_alarm_index;
+use crate::instructions::init_daily_stats::{record_daily_stat, DailyStat};
+use crate::state::{
+    Alarm, AlarmIndex, AlarmOptions, AlarmStatus, Charity, DailyStats, GlobalConfig, PenaltyRoute,
+    PenaltySplit, SavingsVault, SnoozeModel, UserProfile, Vault,
+};
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 
@@ -11,7 +19,7 @@ use anchor_lang::system_program;
 pub struct CreateAlarm<'info> {
     #[account(
         init,
-        payer = owner,
+        payer = payer,
         space = Alarm::SIZE,
         // Seeds include alarm_id to avoid collisions when same user
         // creates multiple alarms at similar times
@@ -23,19 +31,73 @@ pub struct CreateAlarm<'info> {
     /// Vault PDA that holds the deposit - INITIALIZED here
     #[account(
         init,
-        payer = owner,
+        payer = payer,
         space = Vault::SIZE,
         seeds = [b"vault", alarm.key().as_ref()],
         bump
     )]
     pub vault: Account<'info, Vault>,
 
+    /// Global config supplying default alarm parameters; rejects new alarms while paused
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ SolarmaError::ProtocolPaused
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Registry entry for a Donate destination (needed when verified charities are required)
+    pub charity: Option<Account<'info, Charity>>,
+
+    /// Owner's savings vault (required for the Savings route)
+    #[account(
+        seeds = [b"savings", owner.key().as_ref()],
+        bump = savings_vault.bump
+    )]
+    pub savings_vault: Option<Account<'info, SavingsVault>>,
+
+    /// Owner's profile, tracking deposits against `config.daily_deposit_cap_lamports`
+    /// and open alarms against `config.max_open_alarms`
+    #[account(
+        mut,
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Owner's alarm index; the new alarm is listed on it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", owner.key().as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+
     #[account(mut)]
     pub owner: Signer<'info>,
 
+    /// Pays the alarm and vault rent (and the deposit with
+    /// `AlarmOptions.deposit_from_payer`); the owner itself, or a sponsor
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     pub system_program: Program<'info, System>,
+
+    /// Today's protocol counters; the new alarm is counted on it
+    #[account(mut, seeds = [b"daily-stats".as_ref(), &daily_stats.day.to_le_bytes()], bump = daily_stats.bump)]
+    pub daily_stats: Option<Account<'info, DailyStats>>,
+
+    /// Sponsor's profile; a `deposit_from_payer` deposit from a payer other
+    /// than the owner counts against its daily cap instead of the owner's
+    #[account(
+        mut,
+        seeds = [b"user-profile", payer.key().as_ref()],
+        bump = payer_profile.bump
+    )]
+    pub payer_profile: Option<Account<'info, UserProfile>>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn process_create_alarm(
     ctx: Context<CreateAlarm>,
     alarm_id: u64,
@@ -44,20 +106,306 @@ pub fn process_create_alarm(
     deposit_amount: u64,
     penalty_route: u8,
     penalty_destination: Option<Pubkey>,
+    options: Option<AlarmOptions>,
+) -> Result<()> {
+    init_alarm(
+        ctx,
+        alarm_id,
+        alarm_time,
+        deadline,
+        deposit_amount,
+        penalty_route,
+        penalty_destination,
+        options,
+        AlarmStatus::Created,
+    )
+}
+
+/// Buddy-route alarm that stays inert until the buddy co-signs `buddy_accept`.
+pub fn process_create_alarm_pending_buddy(
+    ctx: Context<CreateAlarm>,
+    alarm_id: u64,
+    alarm_time: i64,
+    deadline: i64,
+    deposit_amount: u64,
+    penalty_destination: Option<Pubkey>,
+    options: Option<AlarmOptions>,
 ) -> Result<()> {
+    init_alarm(
+        ctx,
+        alarm_id,
+        alarm_time,
+        deadline,
+        deposit_amount,
+        PenaltyRoute::Buddy as u8,
+        penalty_destination,
+        options,
+        AlarmStatus::PendingBuddy,
+    )
+}
+
+/// Parameters derived by `validate_create_alarm`, ready to write.
+pub(crate) struct ValidatedAlarm {
+    pub snooze_params: helpers::SnoozeParams,
+    pub free_snoozes: u8,
+    pub snooze_model: u8,
+    pub penalty_splits: [PenaltySplit; MAX_PENALTY_SPLITS],
+    pub partial_slash_bps: u16,
+    pub partial_slash_interval_seconds: i64,
+    pub buddy_reward_bps: u16,
+    pub arbiter: Option<Pubkey>,
+    pub claim_grace_seconds: i64,
+    pub early_ack_seconds: i64,
+    pub soft_deadline: i64,
+    pub unclaimed_penalty_bps: u16,
+    /// New `(deposit_day, deposited_today)` for the owner's profile, when deposit limits apply
+    pub deposit_usage: Option<(i64, u64)>,
+    /// New `open_alarm_count` for the owner's profile, when one is passed
+    pub open_alarm_count: Option<u16>,
+    /// The owner's profile has a registered tag every ack must scan
+    pub tag_required: bool,
+}
+
+/// Check `deposit_amount` against the operator limits (0 = unlimited) and
+/// return the depositor's new `(deposit_day, deposited_today)`. The daily
+/// total lives on the profile of whoever funds the alarm, so it is
+/// required while a daily cap is set. `None` when no limit is configured.
+pub(crate) fn check_deposit_limits(
+    config: &GlobalConfig,
+    profile: Option<&UserProfile>,
+    deposit_amount: u64,
+    current_time: i64,
+) -> Result<Option<(i64, u64)>> {
+    if config.max_deposit_lamports == 0 && config.daily_deposit_cap_lamports == 0 {
+        return Ok(None);
+    }
+    let (deposit_day, deposited_today) = match profile {
+        Some(profile) => (profile.deposit_day, profile.deposited_today),
+        None if config.daily_deposit_cap_lamports > 0 => {
+            return err!(SolarmaError::UserProfileRequired)
+        }
+        None => (0, 0),
+    };
+    let usage = helpers::apply_deposit_limits(
+        deposit_amount,
+        config.max_deposit_lamports,
+        config.daily_deposit_cap_lamports,
+        deposit_day,
+        deposited_today,
+        current_time,
+    )
+    .map_err(|e| match e {
+        "overflow" => SolarmaError::Overflow,
+        _ => SolarmaError::DepositLimitExceeded,
+    })?;
+    Ok(Some(usage))
+}
+
+/// Refuse penalty destinations on the owner's blocklist. Buddy and Split
+/// destinations need the owner's profile, so leaving it out cannot skip
+/// the check.
+pub(crate) fn check_destinations_not_blocked(
+    profile: Option<&UserProfile>,
+    penalty_route: u8,
+    destinations: &[Pubkey],
+) -> Result<()> {
+    let destinations: Vec<[u8; 32]> = destinations.iter().map(|d| d.to_bytes()).collect();
+    let blocked = profile.map(|p| p.blocked_destinations.map(|k| k.to_bytes()));
+    helpers::check_destination_blocklist(
+        penalty_route,
+        &destinations,
+        blocked.as_ref().map(|b| &b[..]),
+    )
+    .map_err(|e| match e {
+        "profile_required" => SolarmaError::UserProfileRequired,
+        _ => SolarmaError::DestinationBlocked,
+    })?;
+    Ok(())
+}
+
+/// Every check `create_alarm` makes before touching state, in order.
+/// Shared with `validate_create_alarm_params` so preflight and the real
+/// instruction cannot drift apart.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn validate_create_alarm(
+    config: &GlobalConfig,
+    owner: &Pubkey,
+    charity: Option<&Charity>,
+    savings_vault: Option<Pubkey>,
+    user_profile: Option<&UserProfile>,
+    funder_profile: Option<&UserProfile>,
+    alarm_time: i64,
+    deadline: i64,
+    deposit_amount: u64,
+    penalty_route: u8,
+    penalty_destination: Option<Pubkey>,
+    options: &AlarmOptions,
+    status: AlarmStatus,
+    current_time: i64,
+) -> Result<ValidatedAlarm> {
+    require!(!config.paused, SolarmaError::ProtocolPaused);
+
     // Validate penalty route
     let route =
         PenaltyRoute::try_from(penalty_route).map_err(|_| SolarmaError::InvalidPenaltyRoute)?;
 
     // Validate times
-    let clock = Clock::get()?;
+    require!(alarm_time > current_time, SolarmaError::AlarmTimeInPast);
+    require!(deadline > alarm_time, SolarmaError::InvalidDeadline);
+    helpers::validate_wake_window(alarm_time, deadline, config.wake_window_bounds())
+        .map_err(|_| SolarmaError::WakeWindowOutOfBounds)?;
+
+    // Validate per-alarm snooze economics
+    let snooze_params = options.snooze_params(&config.default_snooze_params());
+    helpers::validate_snooze_params(&snooze_params)
+        .map_err(|_| SolarmaError::InvalidSnoozeConfig)?;
+    let free_snoozes = options.free_snoozes.unwrap_or(0);
     require!(
-        alarm_time > clock.unix_timestamp,
-        SolarmaError::AlarmTimeInPast
+        helpers::validate_free_snoozes(free_snoozes, snooze_params.max_snoozes),
+        SolarmaError::InvalidSnoozeConfig
     );
-    require!(deadline > alarm_time, SolarmaError::InvalidDeadline);
+    let snooze_model = options
+        .snooze_model
+        .unwrap_or(SnoozeModel::Exponential as u8);
+    SnoozeModel::try_from(snooze_model).map_err(|_| SolarmaError::InvalidSnoozeConfig)?;
+
+    // Validate split legs (Split route only)
+    let mut penalty_splits = [PenaltySplit::default(); MAX_PENALTY_SPLITS];
+    let splits = options.penalty_splits.as_deref().unwrap_or(&[]);
+    if route == PenaltyRoute::Split {
+        let primary = penalty_destination.ok_or(SolarmaError::PenaltyDestinationRequired)?;
+        let legs: Vec<([u8; 32], u16)> = splits
+            .iter()
+            .map(|s| (s.destination.to_bytes(), s.bps))
+            .collect();
+        helpers::validate_penalty_splits(&primary.to_bytes(), &legs)
+            .map_err(|_| SolarmaError::InvalidPenaltySplit)?;
+        penalty_splits[..splits.len()].copy_from_slice(splits);
+    } else {
+        require!(splits.is_empty(), SolarmaError::InvalidPenaltySplit);
+    }
+
+    // Owner-blocked recipients can never be routed to
+    let destinations: Vec<Pubkey> = penalty_destination
+        .into_iter()
+        .chain(splits.iter().map(|s| s.destination))
+        .collect();
+    check_destinations_not_blocked(user_profile, penalty_route, &destinations)?;
+
+    // Validate the progressive slash schedule (unset = all-or-nothing)
+    let partial_slash_bps = options.partial_slash_bps.unwrap_or(0);
+    let partial_slash_interval_seconds = options.partial_slash_interval_seconds.unwrap_or(0);
+    helpers::validate_partial_slash(
+        partial_slash_bps,
+        partial_slash_interval_seconds,
+        penalty_route,
+    )
+    .map_err(|_| SolarmaError::InvalidPartialSlashConfig)?;
+
+    let buddy_reward_bps = options.buddy_reward_bps.unwrap_or(0);
+    helpers::validate_buddy_reward(buddy_reward_bps, penalty_route)
+        .map_err(|_| SolarmaError::InvalidBuddyReward)?;
+
+    if let Some(arbiter) = options.arbiter {
+        helpers::validate_arbiter(
+            &arbiter.to_bytes(),
+            &owner.to_bytes(),
+            penalty_destination.map(|d| d.to_bytes()).as_ref(),
+            penalty_route,
+        )
+        .map_err(|_| SolarmaError::InvalidArbiter)?;
+    }
+
+    let claim_grace_seconds = options.claim_grace_seconds.unwrap_or(CLAIM_GRACE_SECONDS);
+    require!(
+        helpers::validate_claim_grace(claim_grace_seconds),
+        SolarmaError::InvalidClaimGrace
+    );
+
+    let early_ack_seconds = options.early_ack_seconds.unwrap_or(0);
+    require!(
+        helpers::validate_early_ack(early_ack_seconds),
+        SolarmaError::InvalidEarlyAck
+    );
+
+    let soft_deadline = options.soft_deadline.unwrap_or(0);
+    require!(
+        helpers::validate_soft_deadline(alarm_time, soft_deadline, deadline),
+        SolarmaError::InvalidSoftDeadline
+    );
+
+    if let Some(proof_type) = options.required_proof_type {
+        helpers::validate_required_proof_type(proof_type, config.proof_type_registry)
+            .map_err(|_| SolarmaError::ProofTypeNotAllowed)?;
+    }
+
+    if let Some(mask) = options.required_proofs {
+        helpers::validate_required_proofs(mask, config.proof_type_registry)
+            .map_err(|_| SolarmaError::InvalidRequiredProofs)?;
+        require!(
+            options.required_proof_type.is_none() && options.require_attestation != Some(true),
+            SolarmaError::InvalidRequiredProofs
+        );
+    }
+
+    let unclaimed_penalty_bps = options
+        .unclaimed_penalty_bps
+        .unwrap_or(config.default_unclaimed_penalty_bps);
+    require!(
+        helpers::validate_unclaimed_penalty_bps(unclaimed_penalty_bps),
+        SolarmaError::InvalidUnclaimedPenalty
+    );
+
+    if let Some(claim_to) = options.claim_to {
+        require!(
+            helpers::validate_claim_to(&owner.to_bytes(), &claim_to.to_bytes()),
+            SolarmaError::InvalidClaimDestination
+        );
+    }
+
+    // Donate destinations must be vetted when the registry is enforced
+    let registered = charity.map(|c| c.address.to_bytes());
+    require!(
+        helpers::is_charity_verified(
+            config.require_verified_charity,
+            penalty_route,
+            penalty_destination.map(|d| d.to_bytes()).as_ref(),
+            registered.as_ref(),
+        ),
+        SolarmaError::CharityNotVerified
+    );
+
+    // Savings penalties can only go to the owner's own savings vault
+    if route == PenaltyRoute::Savings {
+        let savings = savings_vault.ok_or(SolarmaError::PenaltyDestinationRequired)?;
+        require!(
+            penalty_destination == Some(savings),
+            SolarmaError::InvalidPenaltyRecipient
+        );
+    }
+
+    // Anti-spam: cap alarms that are still holding rent
+    if config.max_open_alarms > 0 {
+        require!(user_profile.is_some(), SolarmaError::UserProfileRequired);
+    }
+    let open_alarm_count = user_profile
+        .map(|p| helpers::next_open_alarm_count(p.open_alarm_count, config.max_open_alarms))
+        .transpose()
+        .map_err(|e| match e {
+            "overflow" => SolarmaError::Overflow,
+            _ => SolarmaError::TooManyOpenAlarms,
+        })?;
+
+    // The buddy must be known up front to co-sign
+    if status == AlarmStatus::PendingBuddy {
+        require!(
+            penalty_destination.is_some(),
+            SolarmaError::PenaltyDestinationRequired
+        );
+    }
 
     // Validate deposit if provided
+    let mut deposit_usage = None;
     if deposit_amount > 0 {
         require!(
             deposit_amount >= MIN_DEPOSIT_LAMPORTS,
@@ -72,12 +420,118 @@ pub fn process_create_alarm(
             );
         }
 
+        deposit_usage = check_deposit_limits(config, funder_profile, deposit_amount, current_time)?;
+    }
+
+    Ok(ValidatedAlarm {
+        snooze_params,
+        free_snoozes,
+        snooze_model,
+        penalty_splits,
+        partial_slash_bps,
+        partial_slash_interval_seconds,
+        buddy_reward_bps,
+        arbiter: options.arbiter,
+        claim_grace_seconds,
+        early_ack_seconds,
+        soft_deadline,
+        unclaimed_penalty_bps,
+        deposit_usage,
+        open_alarm_count,
+        tag_required: user_profile.is_some_and(|p| p.tag_hash.is_some()),
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn init_alarm(
+    ctx: Context<CreateAlarm>,
+    alarm_id: u64,
+    alarm_time: i64,
+    deadline: i64,
+    deposit_amount: u64,
+    penalty_route: u8,
+    penalty_destination: Option<Pubkey>,
+    options: Option<AlarmOptions>,
+    status: AlarmStatus,
+) -> Result<()> {
+    let options = options.unwrap_or_default();
+    let clock = crate::clock::get(ctx.remaining_accounts)?;
+    // A sponsor's deposit counts against the sponsor's daily cap
+    let deposit_from_payer = options.deposit_from_payer.unwrap_or(false);
+    let sponsor_funded = deposit_from_payer && ctx.accounts.payer.key() != ctx.accounts.owner.key();
+    let funder_profile = if sponsor_funded {
+        ctx.accounts.payer_profile.as_deref()
+    } else {
+        ctx.accounts.user_profile.as_deref()
+    };
+    let validated = validate_create_alarm(
+        &ctx.accounts.config,
+        &ctx.accounts.owner.key(),
+        ctx.accounts.charity.as_deref(),
+        ctx.accounts.savings_vault.as_ref().map(|s| s.key()),
+        ctx.accounts.user_profile.as_deref(),
+        funder_profile,
+        alarm_time,
+        deadline,
+        deposit_amount,
+        penalty_route,
+        penalty_destination,
+        &options,
+        status,
+        clock.unix_timestamp,
+    )?;
+
+    let deposit_profile = if sponsor_funded {
+        ctx.accounts.payer_profile.as_mut()
+    } else {
+        ctx.accounts.user_profile.as_mut()
+    };
+    if let (Some(profile), Some((day, total))) = (deposit_profile, validated.deposit_usage) {
+        profile.deposit_day = day;
+        profile.deposited_today = total;
+    }
+    if let (Some(profile), Some(count)) = (
+        ctx.accounts.user_profile.as_mut(),
+        validated.open_alarm_count,
+    ) {
+        profile.open_alarm_count = count;
+    }
+    helpers::check_expected_open_alarms(
+        options.expected_open_alarms,
+        ctx.accounts
+            .alarm_index
+            .as_ref()
+            .map(|index| index.alarm_ids.len()),
+    )
+    .map_err(|e| match e {
+        "index_required" => SolarmaError::AlarmIndexRequired,
+        _ => SolarmaError::OpenAlarmsMismatch,
+    })?;
+    add_to_alarm_index(ctx.accounts.alarm_index.as_deref_mut(), alarm_id)?;
+    record_daily_stat(
+        ctx.accounts.daily_stats.as_mut(),
+        DailyStat::AlarmCreated,
+        clock.unix_timestamp,
+    );
+
+    let proof_commitment = options.proof_commitment.unwrap_or_default();
+    if options.proof_commitment.is_some() {
+        helpers::validate_tag_hash(&proof_commitment)
+            .map_err(|_| SolarmaError::InvalidProofCommitment)?;
+    }
+
+    if deposit_amount > 0 {
+        let source = if deposit_from_payer {
+            ctx.accounts.payer.to_account_info()
+        } else {
+            ctx.accounts.owner.to_account_info()
+        };
         // Transfer SOL to vault
         system_program::transfer(
             CpiContext::new(
                 ctx.accounts.system_program.to_account_info(),
                 system_program::Transfer {
-                    from: ctx.accounts.owner.to_account_info(),
+                    from: source,
                     to: ctx.accounts.vault.to_account_info(),
                 },
             ),
@@ -101,11 +555,40 @@ pub fn process_create_alarm(
     alarm.penalty_route = penalty_route;
     alarm.penalty_destination = penalty_destination;
     alarm.snooze_count = 0;
-    alarm.status = AlarmStatus::Created;
+    alarm.status = status;
     alarm.bump = ctx.bumps.alarm;
     alarm.vault_bump = ctx.bumps.vault;
+    alarm.layout_version = ALARM_LAYOUT_VERSION;
+    alarm.set_snooze_params(&validated.snooze_params);
+    alarm.free_snoozes = validated.free_snoozes;
+    alarm.snooze_model = validated.snooze_model;
+    alarm.penalty_splits = validated.penalty_splits;
+    alarm.partial_slash_bps = validated.partial_slash_bps;
+    alarm.partial_slash_interval_seconds = validated.partial_slash_interval_seconds;
+    alarm.require_attestation = options.require_attestation.unwrap_or(false);
+    alarm.buddy_reward_bps = validated.buddy_reward_bps;
+    alarm.arbiter = validated.arbiter;
+    alarm.memo = options.memo.unwrap_or_default();
+    alarm.claim_to = options.claim_to;
+    alarm.required_proof_type = options.required_proof_type.unwrap_or(0);
+    alarm.required_proofs = options.required_proofs.unwrap_or(0);
+    alarm.claim_grace_seconds = validated.claim_grace_seconds;
+    alarm.early_ack_seconds = validated.early_ack_seconds;
+    alarm.soft_deadline = validated.soft_deadline;
+    alarm.unclaimed_penalty_bps = validated.unclaimed_penalty_bps;
+    alarm.open_alarm_counted = validated.open_alarm_count.is_some();
+    alarm.tag_required = validated.tag_required;
+    alarm.payer = ctx.accounts.payer.key();
+    alarm.deposit_from_payer = deposit_from_payer;
+    alarm.proof_commitment = proof_commitment;
+    alarm.two_phase_claim = helpers::requires_two_phase_claim(
+        deposit_amount,
+        ctx.accounts.config.two_phase_claim_threshold,
+    );
 
     emit!(crate::events::AlarmCreated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
         owner: ctx.accounts.owner.key(),
         alarm: ctx.accounts.alarm.key(),
         alarm_id,
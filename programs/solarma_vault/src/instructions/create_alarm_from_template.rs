@@ -0,0 +1,200 @@
+//! Create alarm from template instruction - clone a saved template into a
+//! regular Alarm + Vault pair `offset_days` after the template's alarm time
+//!
+//! The owner pays the template's deposit now, and the alarm goes through
+//! every `create_alarm` check (pause, wake window, deposit limits,
+//! open-alarm cap, charity registry, savings vault) with the template's
+//! grace and snooze options. The template's `alarm_time` then moves to the
+//! new alarm's, so a daily routine always passes `offset_days = 1`.
+
+use crate::constants::ALARM_LAYOUT_VERSION;
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::create_alarm::validate_create_alarm;
+use crate::instructions::init_alarm_index::add_to_alarm_index;
+use crate::state::{
+    Alarm, AlarmIndex, AlarmStatus, AlarmTemplate, Charity, GlobalConfig, SavingsVault,
+    UserProfile, Vault,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+#[instruction(alarm_id: u64)]
+pub struct CreateAlarmFromTemplate<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"alarm-template", owner.key().as_ref(), &template.template_id.to_le_bytes()],
+        bump = template.bump
+    )]
+    pub template: Account<'info, AlarmTemplate>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = Alarm::SIZE,
+        seeds = [b"alarm", owner.key().as_ref(), &alarm_id.to_le_bytes()],
+        bump
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA for the new alarm - INITIALIZED here
+    #[account(
+        init,
+        payer = owner,
+        space = Vault::SIZE,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Registry entry for a Donate destination (needed when verified charities are required)
+    pub charity: Option<Account<'info, Charity>>,
+
+    /// Owner's savings vault (required for the Savings route)
+    #[account(
+        seeds = [b"savings", owner.key().as_ref()],
+        bump = savings_vault.bump
+    )]
+    pub savings_vault: Option<Account<'info, SavingsVault>>,
+
+    /// Owner's profile, tracking deposit limits and open alarms as in `create_alarm`
+    #[account(
+        mut,
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Owner's alarm index; the new alarm is listed on it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", owner.key().as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_create_alarm_from_template(
+    ctx: Context<CreateAlarmFromTemplate>,
+    alarm_id: u64,
+    offset_days: u16,
+) -> Result<()> {
+    let template_key = ctx.accounts.template.key();
+    let clock = Clock::get()?;
+
+    let template = &ctx.accounts.template;
+    let alarm_time = helpers::template_alarm_time(template.alarm_time, offset_days)
+        .ok_or(SolarmaError::Overflow)?;
+    let deadline = alarm_time
+        .checked_add(template.window_seconds)
+        .ok_or(SolarmaError::Overflow)?;
+    let deposit_amount = template.deposit_amount;
+    let penalty_route = template.penalty_route;
+    let penalty_destination = template.penalty_destination;
+    let options = template.options();
+
+    let validated = validate_create_alarm(
+        &ctx.accounts.config,
+        &ctx.accounts.owner.key(),
+        ctx.accounts.charity.as_deref(),
+        ctx.accounts.savings_vault.as_ref().map(|s| s.key()),
+        ctx.accounts.user_profile.as_deref(),
+        ctx.accounts.user_profile.as_deref(),
+        alarm_time,
+        deadline,
+        deposit_amount,
+        penalty_route,
+        penalty_destination,
+        &options,
+        AlarmStatus::Created,
+        clock.unix_timestamp,
+    )?;
+
+    if let Some(profile) = ctx.accounts.user_profile.as_mut() {
+        if let Some((day, total)) = validated.deposit_usage {
+            profile.deposit_day = day;
+            profile.deposited_today = total;
+        }
+        if let Some(count) = validated.open_alarm_count {
+            profile.open_alarm_count = count;
+        }
+    }
+    add_to_alarm_index(ctx.accounts.alarm_index.as_deref_mut(), alarm_id)?;
+    ctx.accounts.template.alarm_time = alarm_time;
+
+    if deposit_amount > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            deposit_amount,
+        )?;
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.alarm = ctx.accounts.alarm.key();
+    vault.bump = ctx.bumps.vault;
+
+    let alarm = &mut ctx.accounts.alarm;
+    alarm.owner = ctx.accounts.owner.key();
+    alarm.alarm_id = alarm_id;
+    alarm.alarm_time = alarm_time;
+    alarm.deadline = deadline;
+    alarm.initial_amount = deposit_amount;
+    alarm.remaining_amount = deposit_amount;
+    alarm.penalty_route = penalty_route;
+    alarm.penalty_destination = penalty_destination;
+    alarm.snooze_count = 0;
+    alarm.status = AlarmStatus::Created;
+    alarm.bump = ctx.bumps.alarm;
+    alarm.vault_bump = ctx.bumps.vault;
+    alarm.layout_version = ALARM_LAYOUT_VERSION;
+    alarm.set_snooze_params(&validated.snooze_params);
+    alarm.free_snoozes = validated.free_snoozes;
+    alarm.snooze_model = validated.snooze_model;
+    alarm.claim_grace_seconds = validated.claim_grace_seconds;
+    alarm.early_ack_seconds = validated.early_ack_seconds;
+    alarm.soft_deadline = validated.soft_deadline;
+    alarm.unclaimed_penalty_bps = validated.unclaimed_penalty_bps;
+    alarm.open_alarm_counted = validated.open_alarm_count.is_some();
+    alarm.tag_required = validated.tag_required;
+    alarm.payer = ctx.accounts.owner.key();
+    alarm.two_phase_claim = helpers::requires_two_phase_claim(
+        deposit_amount,
+        ctx.accounts.config.two_phase_claim_threshold,
+    );
+
+    emit!(crate::events::TemplateUsed {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: alarm.owner,
+        template: template_key,
+        alarm: alarm.key(),
+        alarm_id,
+        alarm_time,
+        deadline,
+        deposit_amount,
+    });
+
+    msg!(
+        "Template {} created alarm {} at {}",
+        ctx.accounts.template.template_id,
+        alarm_id,
+        alarm_time
+    );
+    Ok(())
+}
@@ -0,0 +1,106 @@
+//! Create campaign instruction - open a donation matching campaign for a
+//! verified charity
+//!
+//! Sponsors top it up with `fund_campaign`. While it holds funds above its
+//! rent-exempt minimum, `slash` matches each Donate-route penalty to the
+//! charity 1:1. Funding is a pledge: lamports only ever leave as matches.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Campaign, Charity, GlobalConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64)]
+pub struct CreateCampaign<'info> {
+    #[account(
+        init,
+        payer = sponsor,
+        space = Campaign::SIZE,
+        seeds = [b"campaign", sponsor.key().as_ref(), &campaign_id.to_le_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    /// Verified charity whose donations the campaign matches
+    #[account(seeds = [b"charity", charity.address.as_ref()], bump = charity.bump)]
+    pub charity: Account<'info, Charity>,
+
+    /// Global config - new deposits are rejected while paused
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ SolarmaError::ProtocolPaused
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pay a 1:1 match of `donated` from `campaign` to `recipient`, capped at
+/// the campaign's balance above its rent-exempt minimum. Returns the amount
+/// matched; an exhausted campaign matches nothing rather than failing.
+pub(crate) fn match_donation(
+    campaign: &mut Account<Campaign>,
+    recipient: &AccountInfo,
+    alarm: Pubkey,
+    donated: u64,
+) -> Result<u64> {
+    require!(
+        recipient.key() == campaign.charity,
+        SolarmaError::CampaignMismatch
+    );
+    let campaign_info = campaign.to_account_info();
+    let min_balance = Rent::get()?.minimum_balance(Campaign::SIZE);
+    let matched = helpers::cap_at_rent_exempt(donated, campaign_info.lamports(), min_balance);
+    if matched == 0 {
+        return Ok(0);
+    }
+    **campaign_info.try_borrow_mut_lamports()? -= matched;
+    **recipient.try_borrow_mut_lamports()? += matched;
+    campaign.total_matched = campaign
+        .total_matched
+        .checked_add(matched)
+        .ok_or(SolarmaError::Overflow)?;
+
+    emit!(crate::events::DonationMatched {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        campaign: campaign.key(),
+        alarm,
+        charity: campaign.charity,
+        amount: matched,
+        total_matched: campaign.total_matched,
+    });
+    Ok(matched)
+}
+
+pub fn process_create_campaign(ctx: Context<CreateCampaign>, campaign_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.sponsor = ctx.accounts.sponsor.key();
+    campaign.campaign_id = campaign_id;
+    campaign.charity = ctx.accounts.charity.address;
+    campaign.total_funded = 0;
+    campaign.total_matched = 0;
+    campaign.bump = ctx.bumps.campaign;
+
+    emit!(crate::events::CampaignCreated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        sponsor: campaign.sponsor,
+        campaign: campaign.key(),
+        campaign_id,
+        charity: campaign.charity,
+    });
+
+    msg!(
+        "Campaign {} created for charity {}",
+        campaign_id,
+        campaign.charity
+    );
+    Ok(())
+}
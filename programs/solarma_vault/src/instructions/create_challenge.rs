@@ -0,0 +1,96 @@
+//! Create challenge instruction - open a group wake challenge pool
+//!
+//! Participants stake the same deposit, must ACK between `wake_time` and
+//! the shared `deadline`, and failed stakes are split among the winners.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Challenge, GlobalConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(challenge_id: u64)]
+pub struct CreateChallenge<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Challenge::SIZE,
+        seeds = [b"challenge", creator.key().as_ref(), &challenge_id.to_le_bytes()],
+        bump
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// Global config - new deposits are rejected while paused
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ SolarmaError::ProtocolPaused
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_create_challenge(
+    ctx: Context<CreateChallenge>,
+    challenge_id: u64,
+    wake_time: i64,
+    deadline: i64,
+    deposit_amount: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Same time/deposit rules as a single alarm; stakes are mandatory
+    require!(deposit_amount > 0, SolarmaError::DepositTooSmall);
+    helpers::validate_alarm_params(
+        wake_time,
+        deadline,
+        clock.unix_timestamp,
+        deposit_amount,
+        0,
+        false,
+        &helpers::SnoozeParams::default(),
+    )
+    .map_err(|e| match e {
+        "alarm_time_in_past" => SolarmaError::AlarmTimeInPast,
+        "invalid_deadline" => SolarmaError::InvalidDeadline,
+        _ => SolarmaError::DepositTooSmall,
+    })?;
+
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.creator = ctx.accounts.creator.key();
+    challenge.challenge_id = challenge_id;
+    challenge.wake_time = wake_time;
+    challenge.deadline = deadline;
+    challenge.deposit_amount = deposit_amount;
+    challenge.total_pot = 0;
+    challenge.paid_out = 0;
+    challenge.participant_count = 0;
+    challenge.acked_count = 0;
+    challenge.settled_count = 0;
+    challenge.winners_paid = 0;
+    challenge.bump = ctx.bumps.challenge;
+
+    emit!(crate::events::ChallengeCreated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        creator: challenge.creator,
+        challenge: challenge.key(),
+        challenge_id,
+        wake_time,
+        deadline,
+        deposit_amount,
+    });
+
+    msg!(
+        "Challenge {} created: wake={}, deadline={}, stake={}",
+        challenge_id,
+        wake_time,
+        deadline,
+        deposit_amount
+    );
+    Ok(())
+}
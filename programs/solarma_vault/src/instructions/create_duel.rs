@@ -0,0 +1,132 @@
+//! Create duel instruction - open a head-to-head wake bet
+//!
+//! The creator names the opponent and stakes `stake`; each side has its
+//! own wake window. Whoever acknowledges while the other fails takes
+//! both stakes; if both wake or both fail, stakes are returned.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Duel, GlobalConfig};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+#[instruction(duel_id: u64)]
+pub struct CreateDuel<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Duel::SIZE,
+        seeds = [b"duel", creator.key().as_ref(), &duel_id.to_le_bytes()],
+        bump
+    )]
+    pub duel: Account<'info, Duel>,
+
+    /// Global config - new deposits are rejected while paused
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ SolarmaError::ProtocolPaused
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+fn validate_window(wake_time: i64, deadline: i64, stake: u64, now: i64) -> Result<()> {
+    helpers::validate_alarm_params(
+        wake_time,
+        deadline,
+        now,
+        stake,
+        0,
+        false,
+        &helpers::SnoozeParams::default(),
+    )
+    .map_err(|e| match e {
+        "alarm_time_in_past" => SolarmaError::AlarmTimeInPast,
+        "invalid_deadline" => SolarmaError::InvalidDeadline,
+        _ => SolarmaError::DepositTooSmall,
+    })?;
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process_create_duel(
+    ctx: Context<CreateDuel>,
+    duel_id: u64,
+    opponent: Pubkey,
+    stake: u64,
+    creator_wake_time: i64,
+    creator_deadline: i64,
+    opponent_wake_time: i64,
+    opponent_deadline: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let creator_key = ctx.accounts.creator.key();
+
+    require!(stake > 0, SolarmaError::DepositTooSmall);
+    require!(
+        opponent != creator_key && opponent != Pubkey::default(),
+        SolarmaError::UnauthorizedSigner
+    );
+    validate_window(
+        creator_wake_time,
+        creator_deadline,
+        stake,
+        clock.unix_timestamp,
+    )?;
+    validate_window(
+        opponent_wake_time,
+        opponent_deadline,
+        stake,
+        clock.unix_timestamp,
+    )?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.duel.to_account_info(),
+            },
+        ),
+        stake,
+    )?;
+
+    let duel = &mut ctx.accounts.duel;
+    duel.creator = creator_key;
+    duel.opponent = opponent;
+    duel.duel_id = duel_id;
+    duel.stake = stake;
+    duel.creator_wake_time = creator_wake_time;
+    duel.creator_deadline = creator_deadline;
+    duel.opponent_wake_time = opponent_wake_time;
+    duel.opponent_deadline = opponent_deadline;
+    duel.accepted = false;
+    duel.creator_acked = false;
+    duel.opponent_acked = false;
+    duel.bump = ctx.bumps.duel;
+
+    emit!(crate::events::DuelCreated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        creator: creator_key,
+        opponent,
+        duel: duel.key(),
+        duel_id,
+        stake,
+    });
+
+    msg!(
+        "Duel {} created by {} against {}: stake={}",
+        duel_id,
+        creator_key,
+        opponent,
+        stake
+    );
+    Ok(())
+}
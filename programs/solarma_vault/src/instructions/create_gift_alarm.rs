@@ -0,0 +1,215 @@
+//! Create gift alarm instruction - gifter funds an alarm for another user
+//!
+//! The alarm is created under the recipient's PDA namespace in the
+//! `PendingGift` state. The recipient must `accept_gift` before the alarm
+//! time to take responsibility for waking; otherwise the gifter gets the
+//! funds back via `decline_gift` or `expire_gift`.
+//!
+//! The deposit counts against the gifter's limits: `max_deposit_lamports`
+//! always applies, and the daily cap is tracked on the gifter's profile.
+//! A Buddy destination is checked against the recipient's blocklist, so
+//! their profile must be passed.
+
+use crate::constants::{ALARM_LAYOUT_VERSION, CLAIM_GRACE_SECONDS};
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::create_alarm::{check_deposit_limits, check_destinations_not_blocked};
+use crate::state::{Alarm, AlarmStatus, Charity, GlobalConfig, PenaltyRoute, UserProfile, Vault};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+#[instruction(alarm_id: u64)]
+pub struct CreateGiftAlarm<'info> {
+    #[account(
+        init,
+        payer = gifter,
+        space = Alarm::SIZE,
+        seeds = [b"alarm", recipient.key().as_ref(), &alarm_id.to_le_bytes()],
+        bump
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA that holds the gifted deposit - INITIALIZED here
+    #[account(
+        init,
+        payer = gifter,
+        space = Vault::SIZE,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Recipient who becomes the alarm owner on acceptance
+    /// CHECK: Any account may receive a gift; it only has to sign to accept
+    #[account(
+        constraint = recipient.key() != gifter.key() @ SolarmaError::InvalidGiftRecipient
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Global config - new deposits are rejected while paused
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ SolarmaError::ProtocolPaused
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Registry entry for a Donate destination (needed when verified charities are required)
+    pub charity: Option<Account<'info, Charity>>,
+
+    #[account(mut)]
+    pub gifter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Gifter's profile; the gift is added to their daily deposit total
+    /// (required while `config.daily_deposit_cap_lamports` is set)
+    #[account(
+        mut,
+        seeds = [b"user-profile", gifter.key().as_ref()],
+        bump = gifter_profile.bump
+    )]
+    pub gifter_profile: Option<Account<'info, UserProfile>>,
+
+    /// Recipient's profile; a Buddy destination must not be on its blocklist
+    #[account(
+        seeds = [b"user-profile", recipient.key().as_ref()],
+        bump = recipient_profile.bump
+    )]
+    pub recipient_profile: Option<Account<'info, UserProfile>>,
+}
+
+pub fn process_create_gift_alarm(
+    ctx: Context<CreateGiftAlarm>,
+    alarm_id: u64,
+    alarm_time: i64,
+    deadline: i64,
+    deposit_amount: u64,
+    penalty_route: u8,
+    penalty_destination: Option<Pubkey>,
+) -> Result<()> {
+    let clock = crate::clock::get(ctx.remaining_accounts)?;
+
+    // Gifts must be fully funded
+    require!(deposit_amount > 0, SolarmaError::DepositTooSmall);
+    let snooze_params = helpers::SnoozeParams::default();
+    helpers::validate_alarm_params(
+        alarm_time,
+        deadline,
+        clock.unix_timestamp,
+        deposit_amount,
+        penalty_route,
+        penalty_destination.is_some(),
+        &snooze_params,
+    )
+    .map_err(|e| match e {
+        "alarm_time_in_past" => SolarmaError::AlarmTimeInPast,
+        "invalid_deadline" => SolarmaError::InvalidDeadline,
+        "deposit_too_small" => SolarmaError::DepositTooSmall,
+        "invalid_penalty_route" => SolarmaError::InvalidPenaltyRoute,
+        "penalty_destination_required" => SolarmaError::PenaltyDestinationRequired,
+        _ => SolarmaError::InvalidSnoozeConfig,
+    })?;
+    helpers::validate_wake_window(
+        alarm_time,
+        deadline,
+        ctx.accounts.config.wake_window_bounds(),
+    )
+    .map_err(|_| SolarmaError::WakeWindowOutOfBounds)?;
+    // Split legs and savings vaults can only be configured through create_alarm
+    require!(
+        !matches!(
+            PenaltyRoute::try_from(penalty_route),
+            Ok(PenaltyRoute::Split | PenaltyRoute::Savings)
+        ),
+        SolarmaError::InvalidPenaltyRoute
+    );
+    // Donate destinations must be vetted when the registry is enforced
+    let registered = ctx.accounts.charity.as_ref().map(|c| c.address.to_bytes());
+    require!(
+        helpers::is_charity_verified(
+            ctx.accounts.config.require_verified_charity,
+            penalty_route,
+            penalty_destination.map(|d| d.to_bytes()).as_ref(),
+            registered.as_ref(),
+        ),
+        SolarmaError::CharityNotVerified
+    );
+    check_destinations_not_blocked(
+        ctx.accounts.recipient_profile.as_deref(),
+        penalty_route,
+        penalty_destination.as_slice(),
+    )?;
+    let deposit_usage = check_deposit_limits(
+        &ctx.accounts.config,
+        ctx.accounts.gifter_profile.as_deref(),
+        deposit_amount,
+        clock.unix_timestamp,
+    )?;
+    if let (Some(profile), Some((day, total))) =
+        (ctx.accounts.gifter_profile.as_mut(), deposit_usage)
+    {
+        profile.deposit_day = day;
+        profile.deposited_today = total;
+    }
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.gifter.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        deposit_amount,
+    )?;
+
+    let vault = &mut ctx.accounts.vault;
+    vault.alarm = ctx.accounts.alarm.key();
+    vault.bump = ctx.bumps.vault;
+
+    let alarm = &mut ctx.accounts.alarm;
+    alarm.owner = ctx.accounts.recipient.key();
+    alarm.alarm_id = alarm_id;
+    alarm.alarm_time = alarm_time;
+    alarm.deadline = deadline;
+    alarm.initial_amount = deposit_amount;
+    alarm.remaining_amount = deposit_amount;
+    alarm.penalty_route = penalty_route;
+    alarm.penalty_destination = penalty_destination;
+    alarm.snooze_count = 0;
+    alarm.status = AlarmStatus::PendingGift;
+    alarm.bump = ctx.bumps.alarm;
+    alarm.vault_bump = ctx.bumps.vault;
+    alarm.layout_version = ALARM_LAYOUT_VERSION;
+    alarm.set_snooze_params(&snooze_params);
+    alarm.claim_grace_seconds = CLAIM_GRACE_SECONDS;
+    alarm.gifter = Some(ctx.accounts.gifter.key());
+    alarm.payer = ctx.accounts.gifter.key();
+    alarm.deposit_from_payer = true;
+    alarm.two_phase_claim = helpers::requires_two_phase_claim(
+        deposit_amount,
+        ctx.accounts.config.two_phase_claim_threshold,
+    );
+
+    emit!(crate::events::GiftCreated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        gifter: ctx.accounts.gifter.key(),
+        recipient: ctx.accounts.recipient.key(),
+        alarm: ctx.accounts.alarm.key(),
+        alarm_id,
+        alarm_time,
+        deadline,
+        deposit_amount,
+    });
+
+    msg!(
+        "Gift alarm {} created for {}: deposit={}",
+        alarm_id,
+        ctx.accounts.recipient.key(),
+        deposit_amount
+    );
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! Create lookup table instruction - admin opens the program's Address
+//! Lookup Table and records it in the config for discovery
+//!
+//! The config PDA is the table's authority, so only this program can extend
+//! it (see `extend_lookup_table`). `recent_slot` must be a slot the lookup
+//! table program still finds in `SlotHashes`; the table address is derived
+//! from it and the config PDA.
+
+use crate::error::SolarmaError;
+use crate::state::GlobalConfig;
+use anchor_lang::prelude::*;
+use solana_address_lookup_table_interface::instruction::{
+    create_lookup_table, derive_lookup_table_address,
+};
+use solana_address_lookup_table_interface::program::ID as LOOKUP_TABLE_PROGRAM_ID;
+use solana_program::program::invoke_signed;
+
+#[derive(Accounts)]
+pub struct CreateLookupTable<'info> {
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Table to create, derived from the config PDA and `recent_slot`
+    /// CHECK: Address checked in the handler; created by the lookup table program
+    #[account(mut)]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    /// Config admin; pays the table's rent
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Address constrained to the lookup table program
+    #[account(address = LOOKUP_TABLE_PROGRAM_ID)]
+    pub lookup_table_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_create_lookup_table(
+    ctx: Context<CreateLookupTable>,
+    recent_slot: u64,
+) -> Result<()> {
+    let config_key = ctx.accounts.config.key();
+    require_keys_eq!(
+        ctx.accounts.config.lookup_table,
+        Pubkey::default(),
+        SolarmaError::LookupTableAlreadySet
+    );
+    let (table_key, _) = derive_lookup_table_address(&config_key, recent_slot);
+    require_keys_eq!(
+        ctx.accounts.lookup_table.key(),
+        table_key,
+        SolarmaError::InvalidLookupTable
+    );
+
+    let (ix, _) = create_lookup_table(config_key, ctx.accounts.admin.key(), recent_slot);
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.lookup_table.to_account_info(),
+            ctx.accounts.config.to_account_info(),
+            ctx.accounts.admin.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[&[b"config", &[ctx.accounts.config.bump]]],
+    )?;
+
+    ctx.accounts.config.lookup_table = table_key;
+
+    emit!(crate::events::LookupTableUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        lookup_table: table_key,
+        added: 0,
+    });
+
+    msg!("Lookup table created: {}", table_key);
+    Ok(())
+}
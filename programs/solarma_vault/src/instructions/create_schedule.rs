@@ -0,0 +1,104 @@
+//! Create schedule instruction - store a timezone-aware weekly wake
+//! schedule on-chain
+//!
+//! The schedule keeps local weekdays, a local wake time and the UTC offset,
+//! so every device reads the same recurring alarm without a server. No
+//! deposit is taken here: `materialize_next_alarm` funds each occurrence
+//! and runs the full `create_alarm` checks at that point.
+
+use crate::constants::MIN_DEPOSIT_LAMPORTS;
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{GlobalConfig, PenaltyRoute, Schedule};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(schedule_id: u64)]
+pub struct CreateSchedule<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = Schedule::SIZE,
+        seeds = [b"wake-schedule", owner.key().as_ref(), &schedule_id.to_le_bytes()],
+        bump
+    )]
+    pub schedule: Account<'info, Schedule>,
+
+    /// Global config bounding the wake window
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process_create_schedule(
+    ctx: Context<CreateSchedule>,
+    schedule_id: u64,
+    days_of_week: u8,
+    wake_minute: u16,
+    utc_offset_minutes: i16,
+    window_seconds: i64,
+    deposit_amount: u64,
+    penalty_route: u8,
+    penalty_destination: Option<Pubkey>,
+) -> Result<()> {
+    helpers::validate_weekly_schedule(days_of_week, wake_minute, utc_offset_minutes)
+        .map_err(|_| SolarmaError::InvalidSchedule)?;
+    helpers::validate_wake_window(0, window_seconds, ctx.accounts.config.wake_window_bounds())
+        .map_err(|_| SolarmaError::WakeWindowOutOfBounds)?;
+
+    // Split legs can only be configured through create_alarm options
+    let route =
+        PenaltyRoute::try_from(penalty_route).map_err(|_| SolarmaError::InvalidPenaltyRoute)?;
+    require!(
+        route != PenaltyRoute::Split,
+        SolarmaError::InvalidPenaltyRoute
+    );
+    if deposit_amount > 0 {
+        require!(
+            deposit_amount >= MIN_DEPOSIT_LAMPORTS,
+            SolarmaError::DepositTooSmall
+        );
+        require!(
+            route == PenaltyRoute::Burn || penalty_destination.is_some(),
+            SolarmaError::PenaltyDestinationRequired
+        );
+    }
+
+    let schedule = &mut ctx.accounts.schedule;
+    schedule.owner = ctx.accounts.owner.key();
+    schedule.schedule_id = schedule_id;
+    schedule.days_of_week = days_of_week;
+    schedule.wake_minute = wake_minute;
+    schedule.utc_offset_minutes = utc_offset_minutes;
+    schedule.window_seconds = window_seconds;
+    schedule.deposit_amount = deposit_amount;
+    schedule.penalty_route = penalty_route;
+    schedule.penalty_destination = penalty_destination;
+    schedule.last_alarm_time = 0;
+    schedule.bump = ctx.bumps.schedule;
+
+    emit!(crate::events::ScheduleUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: schedule.owner,
+        schedule: schedule.key(),
+        schedule_id,
+        days_of_week,
+        wake_minute,
+        utc_offset_minutes,
+    });
+
+    msg!(
+        "Schedule {} created: days={:#09b}, wake_minute={}, utc_offset={}",
+        schedule_id,
+        days_of_week,
+        wake_minute,
+        utc_offset_minutes
+    );
+    Ok(())
+}
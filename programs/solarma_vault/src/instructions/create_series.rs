@@ -0,0 +1,149 @@
+//! Create series instruction - fund a recurring daily/weekly wake schedule
+//!
+//! The whole deposit (`deposit_per_occurrence * occurrences`) is escrowed on
+//! the series PDA once. Each night `spawn_next_occurrence` moves one share
+//! into a fresh Alarm + Vault pair, so no further deposits are needed.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{AlarmSeries, Charity, GlobalConfig, PenaltyRoute};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+#[instruction(series_id: u64)]
+pub struct CreateSeries<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = AlarmSeries::SIZE,
+        seeds = [b"series", owner.key().as_ref(), &series_id.to_le_bytes()],
+        bump
+    )]
+    pub series: Account<'info, AlarmSeries>,
+
+    /// Global config - new deposits are rejected while paused
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ SolarmaError::ProtocolPaused
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Registry entry for a Donate destination (needed when verified charities are required)
+    pub charity: Option<Account<'info, Charity>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process_create_series(
+    ctx: Context<CreateSeries>,
+    series_id: u64,
+    first_alarm_time: i64,
+    window_seconds: i64,
+    interval_seconds: i64,
+    occurrences: u16,
+    deposit_per_occurrence: u64,
+    penalty_route: u8,
+    penalty_destination: Option<Pubkey>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let route =
+        PenaltyRoute::try_from(penalty_route).map_err(|_| SolarmaError::InvalidPenaltyRoute)?;
+    helpers::validate_series_params(
+        first_alarm_time,
+        window_seconds,
+        interval_seconds,
+        occurrences,
+        deposit_per_occurrence,
+        clock.unix_timestamp,
+    )
+    .map_err(|e| match e {
+        "alarm_time_in_past" => SolarmaError::AlarmTimeInPast,
+        "invalid_deadline" => SolarmaError::InvalidDeadline,
+        "deposit_too_small" => SolarmaError::DepositTooSmall,
+        _ => SolarmaError::InvalidSeriesConfig,
+    })?;
+    // Every occurrence gets the same window, so bound it like create_alarm
+    helpers::validate_wake_window(0, window_seconds, ctx.accounts.config.wake_window_bounds())
+        .map_err(|_| SolarmaError::WakeWindowOutOfBounds)?;
+    // Split legs and savings vaults can only be configured through create_alarm
+    require!(
+        !matches!(route, PenaltyRoute::Split | PenaltyRoute::Savings),
+        SolarmaError::InvalidPenaltyRoute
+    );
+    // Donate destinations must be vetted when the registry is enforced
+    let registered = ctx.accounts.charity.as_ref().map(|c| c.address.to_bytes());
+    require!(
+        helpers::is_charity_verified(
+            ctx.accounts.config.require_verified_charity,
+            penalty_route,
+            penalty_destination.map(|d| d.to_bytes()).as_ref(),
+            registered.as_ref(),
+        ),
+        SolarmaError::CharityNotVerified
+    );
+    if deposit_per_occurrence > 0 && route != PenaltyRoute::Burn {
+        require!(
+            penalty_destination.is_some(),
+            SolarmaError::PenaltyDestinationRequired
+        );
+    }
+
+    let total = helpers::series_total_deposit(deposit_per_occurrence, occurrences)
+        .ok_or(SolarmaError::Overflow)?;
+    if total > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.series.to_account_info(),
+                },
+            ),
+            total,
+        )?;
+    }
+
+    let series = &mut ctx.accounts.series;
+    series.owner = ctx.accounts.owner.key();
+    series.series_id = series_id;
+    series.next_alarm_time = first_alarm_time;
+    series.interval_seconds = interval_seconds;
+    series.window_seconds = window_seconds;
+    series.deposit_per_occurrence = deposit_per_occurrence;
+    series.escrowed_amount = total;
+    series.remaining_occurrences = occurrences;
+    series.penalty_route = penalty_route;
+    series.penalty_destination = penalty_destination;
+    series.bump = ctx.bumps.series;
+    series.skipped_slots = 0;
+    series.skip_period = 0;
+    series.skips_in_period = 0;
+
+    emit!(crate::events::SeriesCreated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: series.owner,
+        series: series.key(),
+        series_id,
+        first_alarm_time,
+        interval_seconds,
+        occurrences,
+        deposit_per_occurrence,
+    });
+
+    msg!(
+        "Series {} created: {} occurrences every {}s, escrow={}",
+        series_id,
+        occurrences,
+        interval_seconds,
+        total
+    );
+    Ok(())
+}
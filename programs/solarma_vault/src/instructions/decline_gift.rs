@@ -0,0 +1,65 @@
+//! Decline gift instruction - recipient refuses a pending gift alarm
+//!
+//! Both the alarm and vault accounts are closed to the gifter, returning
+//! the deposit plus all rent they paid.
+
+use crate::error::SolarmaError;
+use crate::state::{Alarm, AlarmStatus, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct DeclineGift<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::PendingGift @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.gifter == Some(gifter.key()) @ SolarmaError::InvalidGiftRecipient,
+        close = gifter
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA holding the gifted deposit - closed to gifter
+    #[account(
+        mut,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump = alarm.vault_bump,
+        close = gifter
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Original funder of the gift
+    /// CHECK: Key is verified against `alarm.gifter`
+    #[account(mut)]
+    pub gifter: UncheckedAccount<'info>,
+
+    /// Gift recipient (recorded as alarm owner at creation)
+    pub owner: Signer<'info>,
+}
+
+pub fn process_decline_gift(ctx: Context<DeclineGift>) -> Result<()> {
+    let returned = ctx
+        .accounts
+        .vault
+        .to_account_info()
+        .lamports()
+        .checked_add(ctx.accounts.alarm.to_account_info().lamports())
+        .ok_or(SolarmaError::Overflow)?;
+
+    emit!(crate::events::GiftReturned {
+        version: crate::events::EVENT_VERSION,
+        timestamp: crate::clock::get(ctx.remaining_accounts)?.unix_timestamp,
+        gifter: ctx.accounts.gifter.key(),
+        recipient: ctx.accounts.owner.key(),
+        alarm: ctx.accounts.alarm.key(),
+        alarm_id: ctx.accounts.alarm.alarm_id,
+        returned_amount: returned,
+        expired: false,
+    });
+
+    msg!(
+        "Gift alarm {} declined: returned {} lamports to gifter",
+        ctx.accounts.alarm.alarm_id,
+        returned
+    );
+    Ok(())
+}
@@ -1,9 +1,13 @@
 //! Emergency refund instruction - owner can cancel alarm and get deposit back
+//!
+//! A deposit funded by a sponsor or gifter (`Alarm::deposit_funder`) goes
+//! back to that funder instead; the owner only gets the vault rent.
 
-use crate::constants::BURN_SINK;
 use crate::error::SolarmaError;
 use crate::helpers;
-use crate::state::{Alarm, AlarmStatus, Vault};
+use crate::instructions::claim::return_deposit_to_funder;
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::state::{Alarm, AlarmIndex, AlarmStatus, GlobalConfig, Vault};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -11,7 +15,9 @@ pub struct EmergencyRefund<'info> {
     #[account(
         mut,
         has_one = owner,
-        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState
+        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState,
+        // Both parties are committed once a bet is matched
+        constraint = alarm.challenger_stake == 0 @ SolarmaError::BetLocked
     )]
     pub alarm: Account<'info, Alarm>,
 
@@ -24,25 +30,43 @@ pub struct EmergencyRefund<'info> {
     )]
     pub vault: Account<'info, Vault>,
 
+    /// Global config naming the deployment's burn sink (not checked for pause)
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
     /// Sink account receives emergency refund penalty
-    /// CHECK: Validated against BURN_SINK constant
+    /// CHECK: Validated against the config's burn sink
     #[account(
         mut,
-        constraint = sink.key() == BURN_SINK @ SolarmaError::InvalidSinkAddress
+        constraint = sink.key() == config.effective_burn_sink() @ SolarmaError::InvalidSinkAddress
     )]
     pub sink: UncheckedAccount<'info>,
 
+    /// Owner's alarm index; the refunded alarm is removed from it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", owner.key().as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+
     #[account(mut)]
     pub owner: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Sponsor or gifter that funded the deposit; required when
+    /// `Alarm::deposit_funder` is set, and receives the refund
+    /// CHECK: Validated against alarm.payer in `return_deposit_to_funder`
+    #[account(mut)]
+    pub funder: Option<UncheckedAccount<'info>>,
 }
 
 pub fn process_emergency_refund(ctx: Context<EmergencyRefund>) -> Result<()> {
     let alarm_key = ctx.accounts.alarm.key();
     let owner_key = ctx.accounts.owner.key();
     let alarm = &mut ctx.accounts.alarm;
-    let clock = Clock::get()?;
+    let clock = crate::clock::get(ctx.remaining_accounts)?;
 
     // CRITICAL: Can only refund BEFORE alarm time
     // This is the escape hatch if something goes wrong
@@ -85,8 +109,15 @@ pub fn process_emergency_refund(ctx: Context<EmergencyRefund>) -> Result<()> {
     // For accurate event emission, calculate what the user actually receives:
     // vault lamports after penalty deduction (includes rent-exempt balance).
     let actual_returned = ctx.accounts.vault.to_account_info().lamports();
+    return_deposit_to_funder(
+        alarm.deposit_funder(),
+        &ctx.accounts.vault.to_account_info(),
+        ctx.accounts.funder.as_ref().map(|f| f.as_ref()),
+    )?;
 
     emit!(crate::events::EmergencyRefundExecuted {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
         owner: owner_key,
         alarm: alarm_key,
         alarm_id: alarm.alarm_id,
@@ -97,6 +128,7 @@ pub fn process_emergency_refund(ctx: Context<EmergencyRefund>) -> Result<()> {
     // Mark as claimed (terminal state)
     alarm.status = AlarmStatus::Claimed;
     alarm.remaining_amount = 0;
+    remove_from_alarm_index(ctx.accounts.alarm_index.as_deref_mut(), alarm.alarm_id);
 
     msg!("Alarm cancelled by owner {}", owner_key);
     Ok(())
@@ -0,0 +1,72 @@
+//! Expire gift instruction - permissionless return of an unaccepted gift
+//!
+//! Once the alarm time passes without acceptance, anyone may close the
+//! alarm and vault back to the gifter.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Alarm, AlarmStatus, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExpireGift<'info> {
+    #[account(
+        mut,
+        constraint = alarm.status == AlarmStatus::PendingGift @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.gifter == Some(gifter.key()) @ SolarmaError::InvalidGiftRecipient,
+        close = gifter
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA holding the gifted deposit - closed to gifter
+    #[account(
+        mut,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump = alarm.vault_bump,
+        close = gifter
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Original funder of the gift
+    /// CHECK: Key is verified against `alarm.gifter`
+    #[account(mut)]
+    pub gifter: UncheckedAccount<'info>,
+
+    /// Anyone can expire a gift after alarm time
+    pub caller: Signer<'info>,
+}
+
+pub fn process_expire_gift(ctx: Context<ExpireGift>) -> Result<()> {
+    let clock = crate::clock::get(ctx.remaining_accounts)?;
+    require!(
+        helpers::is_gift_expired(ctx.accounts.alarm.alarm_time, clock.unix_timestamp),
+        SolarmaError::GiftNotExpired
+    );
+
+    let returned = ctx
+        .accounts
+        .vault
+        .to_account_info()
+        .lamports()
+        .checked_add(ctx.accounts.alarm.to_account_info().lamports())
+        .ok_or(SolarmaError::Overflow)?;
+
+    emit!(crate::events::GiftReturned {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        gifter: ctx.accounts.gifter.key(),
+        recipient: ctx.accounts.alarm.owner,
+        alarm: ctx.accounts.alarm.key(),
+        alarm_id: ctx.accounts.alarm.alarm_id,
+        returned_amount: returned,
+        expired: true,
+    });
+
+    msg!(
+        "Gift alarm {} expired by {}: returned {} lamports to gifter",
+        ctx.accounts.alarm.alarm_id,
+        ctx.accounts.caller.key(),
+        returned
+    );
+    Ok(())
+}
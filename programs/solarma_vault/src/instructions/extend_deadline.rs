@@ -0,0 +1,108 @@
+//! Extend deadline instruction - buy a longer wake window before the alarm
+//! fires, for a burned fee proportional to the extension
+//!
+//! Unlike `reschedule_alarm`, `alarm_time` stays put and only the deadline
+//! moves, so the owner pays `DEADLINE_EXTENSION_FEE_BPS_PER_HOUR` of the
+//! remaining deposit per hour added. The new window must still fit the
+//! config's wake window bounds.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Alarm, AlarmStatus, GlobalConfig, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExtendDeadline<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState,
+        // An offered or matched bet was agreed on the original schedule.
+        constraint = alarm.challenger.is_none() @ SolarmaError::BetLocked
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA the fee is taken from
+    #[account(
+        mut,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump = alarm.vault_bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Global config naming the burn sink and wake window bounds
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Sink account receives the extension fee
+    /// CHECK: Validated against the config's burn sink
+    #[account(
+        mut,
+        constraint = sink.key() == config.effective_burn_sink() @ SolarmaError::InvalidSinkAddress
+    )]
+    pub sink: UncheckedAccount<'info>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_extend_deadline(ctx: Context<ExtendDeadline>, extra_seconds: i64) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let clock = crate::clock::get(ctx.remaining_accounts)?;
+    let alarm = &mut ctx.accounts.alarm;
+
+    // Only while the alarm has not fired yet
+    require!(
+        clock.unix_timestamp < alarm.alarm_time,
+        SolarmaError::TooLateForRefund
+    );
+    require!(extra_seconds > 0, SolarmaError::InvalidDeadline);
+
+    let old_deadline = alarm.deadline;
+    let new_deadline = old_deadline
+        .checked_add(extra_seconds)
+        .ok_or(SolarmaError::Overflow)?;
+    helpers::validate_wake_window(
+        alarm.alarm_time,
+        new_deadline,
+        ctx.accounts.config.wake_window_bounds(),
+    )
+    .map_err(|_| SolarmaError::WakeWindowOutOfBounds)?;
+
+    let fee = helpers::deadline_extension_fee(alarm.remaining_amount, extra_seconds)
+        .ok_or(SolarmaError::Overflow)?;
+
+    // C1: Rent-exempt guard — never drain the vault below its rent minimum
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let min_balance = Rent::get()?.minimum_balance(vault_info.data_len());
+    let final_fee = helpers::cap_at_rent_exempt(fee, vault_info.lamports(), min_balance);
+    if final_fee > 0 {
+        **vault_info.try_borrow_mut_lamports()? -= final_fee;
+        **ctx.accounts.sink.try_borrow_mut_lamports()? += final_fee;
+    }
+
+    alarm.remaining_amount = alarm
+        .remaining_amount
+        .checked_sub(final_fee)
+        .ok_or(SolarmaError::Overflow)?;
+    alarm.deadline = new_deadline;
+
+    emit!(crate::events::DeadlineExtended {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: alarm.owner,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        old_deadline,
+        new_deadline,
+        fee: final_fee,
+        remaining: alarm.remaining_amount,
+    });
+
+    msg!(
+        "Alarm {} deadline extended to {} for {} lamports",
+        alarm.alarm_id,
+        new_deadline,
+        final_fee
+    );
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! Extend lookup table instruction - admin appends the program's hot
+//! accounts to the config's lookup table
+//!
+//! The addresses are computed here, not passed in: the burn sink, config,
+//! treasury and attestation config. Ones already in the table are skipped,
+//! so after a `burn_sink` change a rerun only appends the new sink.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::pda;
+use crate::state::GlobalConfig;
+use anchor_lang::prelude::*;
+use solana_address_lookup_table_interface::instruction::extend_lookup_table;
+use solana_address_lookup_table_interface::program::ID as LOOKUP_TABLE_PROGRAM_ID;
+use solana_address_lookup_table_interface::state::AddressLookupTable;
+use solana_program::program::invoke_signed;
+
+#[derive(Accounts)]
+pub struct ExtendLookupTable<'info> {
+    #[account(
+        has_one = admin,
+        has_one = lookup_table @ SolarmaError::InvalidLookupTable,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// CHECK: Matches `config.lookup_table`; owner and authority checked in the handler
+    #[account(mut, owner = LOOKUP_TABLE_PROGRAM_ID @ SolarmaError::InvalidLookupTable)]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    /// Config admin; pays the rent for the appended addresses
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Address constrained to the lookup table program
+    #[account(address = LOOKUP_TABLE_PROGRAM_ID)]
+    pub lookup_table_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts most instructions touch, in table order.
+pub fn hot_accounts(config: &GlobalConfig) -> [Pubkey; 4] {
+    [
+        config.effective_burn_sink(),
+        pda::config_address().0,
+        pda::treasury_address().0,
+        pda::attestation_config_address().0,
+    ]
+}
+
+pub fn process_extend_lookup_table(ctx: Context<ExtendLookupTable>) -> Result<()> {
+    let config_key = ctx.accounts.config.key();
+    let table_key = ctx.accounts.lookup_table.key();
+
+    let existing: Vec<[u8; 32]> = {
+        let data = ctx.accounts.lookup_table.try_borrow_data()?;
+        let table =
+            AddressLookupTable::deserialize(&data).map_err(|_| SolarmaError::InvalidLookupTable)?;
+        require!(
+            table.meta.authority == Some(config_key),
+            SolarmaError::InvalidLookupTable
+        );
+        table.addresses.iter().map(|a| a.to_bytes()).collect()
+    };
+    let wanted = hot_accounts(&ctx.accounts.config).map(|a| a.to_bytes());
+    let missing = helpers::missing_lookup_addresses(&existing, &wanted);
+    require!(!missing.is_empty(), SolarmaError::LookupTableUpToDate);
+    let added = missing.len() as u8;
+
+    let ix = extend_lookup_table(
+        table_key,
+        config_key,
+        Some(ctx.accounts.admin.key()),
+        missing.into_iter().map(Pubkey::new_from_array).collect(),
+    );
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.lookup_table.to_account_info(),
+            ctx.accounts.config.to_account_info(),
+            ctx.accounts.admin.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[&[b"config", &[ctx.accounts.config.bump]]],
+    )?;
+
+    emit!(crate::events::LookupTableUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        lookup_table: table_key,
+        added,
+    });
+
+    msg!("Lookup table {} extended by {}", table_key, added);
+    Ok(())
+}
@@ -0,0 +1,20 @@
+//! Finalize claim instruction - second phase of a two-phase claim
+//!
+//! Takes exactly the `claim` accounts and pays out the same way, once the
+//! `request_claim` delay has passed without a `flag_claim` veto. The claim
+//! window is judged at the request time, so a request made just before
+//! the grace cutoff can still be finalized after it.
+
+// Glob: the nested `Claim` needs its Anchor-generated modules in scope
+use crate::instructions::claim::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct FinalizeClaim<'info> {
+    /// The `claim` accounts, unchanged
+    pub claim: Claim<'info>,
+}
+
+pub fn process_finalize_claim(ctx: Context<FinalizeClaim>) -> Result<()> {
+    settle_claim(&mut ctx.accounts.claim, ctx.remaining_accounts, true)
+}
@@ -0,0 +1,49 @@
+//! Finalize epoch instruction - permissionless; freezes a leaderboard
+//! epoch once it has ended and publishes its totals
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::LeaderboardEpoch;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct FinalizeEpoch<'info> {
+    #[account(
+        mut,
+        seeds = [b"epoch".as_ref(), &leaderboard_epoch.epoch.to_le_bytes()],
+        bump = leaderboard_epoch.bump
+    )]
+    pub leaderboard_epoch: Account<'info, LeaderboardEpoch>,
+
+    /// Anyone can finalize an ended epoch
+    pub caller: Signer<'info>,
+}
+
+pub fn process_finalize_epoch(ctx: Context<FinalizeEpoch>) -> Result<()> {
+    let clock = Clock::get()?;
+    let leaderboard_epoch = &mut ctx.accounts.leaderboard_epoch;
+    require!(!leaderboard_epoch.finalized, SolarmaError::EpochClosed);
+    require!(
+        helpers::can_finalize_epoch(
+            leaderboard_epoch.epoch,
+            leaderboard_epoch.finalized,
+            clock.unix_timestamp
+        ),
+        SolarmaError::EpochNotEnded
+    );
+
+    leaderboard_epoch.finalized = true;
+
+    emit!(crate::events::EpochFinalized {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        epoch: leaderboard_epoch.epoch,
+        participants: leaderboard_epoch.participants,
+        total_claims: leaderboard_epoch.total_claims,
+        total_slashes: leaderboard_epoch.total_slashes,
+        finalized_by: ctx.accounts.caller.key(),
+    });
+
+    msg!("Leaderboard epoch {} finalized", leaderboard_epoch.epoch);
+    Ok(())
+}
@@ -0,0 +1,75 @@
+//! Flag claim instruction - veto a pending two-phase claim
+//!
+//! During the `request_claim` delay, the alarm's buddy (Buddy route) or a
+//! valid attestation key can flag the request. A flagged alarm can no
+//! longer be finalized or re-requested; its deposit returns to the owner
+//! through `sweep_acknowledged` after the grace window, or earlier through
+//! guardian recovery. Flagging never moves funds.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Alarm, AlarmStatus, AttestationConfig, PenaltyRoute};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct FlagClaim<'info> {
+    #[account(
+        mut,
+        constraint = alarm.status == AlarmStatus::Acknowledged @ SolarmaError::InvalidAlarmState
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Registered attestation keys; needed when the server flags
+    #[account(seeds = [b"attestation"], bump = attestation_config.bump)]
+    pub attestation_config: Option<Account<'info, AttestationConfig>>,
+
+    /// The alarm's buddy or an attestation key
+    pub flagger: Signer<'info>,
+}
+
+pub fn process_flag_claim(ctx: Context<FlagClaim>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let flagger_key = ctx.accounts.flagger.key();
+    let alarm = &mut ctx.accounts.alarm;
+    let clock = Clock::get()?;
+
+    let is_buddy = alarm.penalty_route == PenaltyRoute::Buddy as u8
+        && alarm.penalty_destination == Some(flagger_key);
+    let is_attestor = ctx.accounts.attestation_config.as_ref().is_some_and(|c| {
+        let attestors = c.attestors.map(|k| k.to_bytes());
+        helpers::count_attestations(
+            &[flagger_key.to_bytes()],
+            &c.current_key.to_bytes(),
+            &c.previous_key.to_bytes(),
+            c.previous_valid_until,
+            &attestors,
+            clock.unix_timestamp,
+        ) > 0
+    });
+    require!(is_buddy || is_attestor, SolarmaError::UnauthorizedSigner);
+    require!(
+        helpers::is_claim_veto_open(
+            alarm.claim_requested_at,
+            alarm.claim_flagged,
+            clock.unix_timestamp
+        ),
+        SolarmaError::InvalidAlarmState
+    );
+
+    alarm.claim_flagged = true;
+
+    emit!(crate::events::ClaimFlagged {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        flagger: flagger_key,
+    });
+
+    msg!(
+        "Claim on alarm {} flagged by {}",
+        alarm.alarm_id,
+        flagger_key
+    );
+    Ok(())
+}
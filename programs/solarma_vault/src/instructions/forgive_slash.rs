@@ -0,0 +1,97 @@
+//! Forgive slash instruction - spend a forgiveness token to settle a missed
+//! alarm as a penalty-free late claim
+//!
+//! Open to the owner for `FORGIVENESS_WINDOW_SECONDS` after the deadline,
+//! while the alarm is still unresolved. The whole vault goes back to the
+//! owner and one token is burned. Slashing opens at the same deadline, so
+//! a slash that lands first settles the alarm and the token is kept.
+//! Matched bets and sponsored alarms are excluded: the challenger's stake
+//! and the sponsor's deposit are not the owner's to forgive.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::state::{Alarm, AlarmIndex, AlarmStatus, ForgivenessToken, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ForgiveSlash<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA holding the deposit - closed and everything returned to owner
+    #[account(
+        mut,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump = alarm.vault_bump,
+        close = owner
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Owner's forgiveness tokens; one is burned
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"forgiveness", owner.key().as_ref()],
+        bump = forgiveness_token.bump
+    )]
+    pub forgiveness_token: Account<'info, ForgivenessToken>,
+
+    /// Owner's alarm index; the settled alarm is removed from it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", owner.key().as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn process_forgive_slash(ctx: Context<ForgiveSlash>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let clock = Clock::get()?;
+    let alarm = &mut ctx.accounts.alarm;
+
+    require!(
+        helpers::is_forgiveness_window(alarm.deadline, clock.unix_timestamp),
+        SolarmaError::ForgivenessWindowClosed
+    );
+    require!(alarm.challenger_stake == 0, SolarmaError::BetLocked);
+    require!(alarm.sponsor().is_none(), SolarmaError::InvalidFunder);
+
+    let token = &mut ctx.accounts.forgiveness_token;
+    require!(token.balance > 0, SolarmaError::NoForgivenessToken);
+    token.balance -= 1;
+    token.redeemed = token.redeemed.saturating_add(1);
+
+    // `close = owner` returns every lamport (deposit + rent)
+    let vault_lamports = ctx.accounts.vault.to_account_info().lamports();
+
+    alarm.status = AlarmStatus::Claimed;
+    alarm.remaining_amount = 0;
+    remove_from_alarm_index(ctx.accounts.alarm_index.as_deref_mut(), alarm.alarm_id);
+
+    emit!(crate::events::SlashForgiven {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: alarm.owner,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        returned_amount: vault_lamports,
+        balance: token.balance,
+    });
+
+    msg!(
+        "Alarm {} forgiven: {} lamports back to owner, {} tokens left",
+        alarm.alarm_id,
+        vault_lamports,
+        token.balance
+    );
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//! Fund campaign instruction - add matching funds to a donation campaign
+//!
+//! Anyone may top up a campaign; the funds can only leave as matches paid
+//! to the campaign's charity.
+
+use crate::error::SolarmaError;
+use crate::state::{Campaign, GlobalConfig};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+pub struct FundCampaign<'info> {
+    #[account(
+        mut,
+        seeds = [b"campaign", campaign.sponsor.as_ref(), &campaign.campaign_id.to_le_bytes()],
+        bump = campaign.bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    /// Global config - new deposits are rejected while paused
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ SolarmaError::ProtocolPaused
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_fund_campaign(ctx: Context<FundCampaign>, amount: u64) -> Result<()> {
+    require!(amount > 0, SolarmaError::DepositTooSmall);
+    let clock = Clock::get()?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.campaign.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.total_funded = campaign
+        .total_funded
+        .checked_add(amount)
+        .ok_or(SolarmaError::Overflow)?;
+
+    emit!(crate::events::CampaignFunded {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        campaign: campaign.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+        total_funded: campaign.total_funded,
+    });
+
+    msg!(
+        "Campaign {} funded with {} lamports",
+        campaign.campaign_id,
+        amount
+    );
+    Ok(())
+}
@@ -0,0 +1,54 @@
+//! Init alarm index instruction - create the per-owner PDA listing live
+//! alarm ids
+//!
+//! Once it exists, pass it to `create_alarm` and to claim, slash,
+//! emergency_refund and close_alarm to keep it current.
+
+use crate::constants::MAX_INDEXED_ALARMS;
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::AlarmIndex;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitAlarmIndex<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = AlarmIndex::SIZE,
+        seeds = [b"alarm-index", owner.key().as_ref()],
+        bump
+    )]
+    pub alarm_index: Account<'info, AlarmIndex>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// List a newly created alarm on the owner's index, if one was passed.
+pub(crate) fn add_to_alarm_index(index: Option<&mut AlarmIndex>, alarm_id: u64) -> Result<()> {
+    if let Some(index) = index {
+        helpers::index_alarm(&mut index.alarm_ids, alarm_id, MAX_INDEXED_ALARMS)
+            .map_err(|_| SolarmaError::AlarmIndexFull)?;
+    }
+    Ok(())
+}
+
+/// Drop a resolved alarm from the owner's index, if one was passed.
+pub(crate) fn remove_from_alarm_index(index: Option<&mut AlarmIndex>, alarm_id: u64) {
+    if let Some(index) = index {
+        helpers::unindex_alarm(&mut index.alarm_ids, alarm_id);
+    }
+}
+
+pub fn process_init_alarm_index(ctx: Context<InitAlarmIndex>) -> Result<()> {
+    let alarm_index = &mut ctx.accounts.alarm_index;
+    alarm_index.owner = ctx.accounts.owner.key();
+    alarm_index.alarm_ids = Vec::new();
+    alarm_index.bump = ctx.bumps.alarm_index;
+
+    msg!("Alarm index created for {}", alarm_index.owner);
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! Init attestation config instruction - admin creates the attestation
+//! signer PDA with the first server key
+
+use crate::error::SolarmaError;
+use crate::state::{AttestationConfig, GlobalConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitAttestationConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = AttestationConfig::SIZE,
+        seeds = [b"attestation"],
+        bump
+    )]
+    pub attestation_config: Account<'info, AttestationConfig>,
+
+    #[account(has_one = admin, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_init_attestation_config(
+    ctx: Context<InitAttestationConfig>,
+    key: Pubkey,
+) -> Result<()> {
+    require!(key != Pubkey::default(), SolarmaError::InvalidConfig);
+
+    let attestation = &mut ctx.accounts.attestation_config;
+    attestation.current_key = key;
+    attestation.previous_key = Pubkey::default();
+    attestation.previous_valid_until = 0;
+    attestation.threshold = 1;
+    attestation.bump = ctx.bumps.attestation_config;
+
+    emit!(crate::events::AttestationKeyUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        admin: ctx.accounts.admin.key(),
+        previous_key: Pubkey::default(),
+        current_key: key,
+        previous_valid_until: 0,
+    });
+
+    msg!("Attestation key set to {}", key);
+    Ok(())
+}
@@ -0,0 +1,105 @@
+//! Init config instruction - create the global protocol config PDA
+//!
+//! Only the program upgrade authority may initialize the config, which
+//! prevents a front-runner from claiming the admin role after deploy.
+//! The `ProtocolParams` PDA is published alongside the config.
+
+use crate::constants::{
+    DEFAULT_SLASHER_BOUNTY_BPS, DEFAULT_SNOOZE_EXTENSION_SECONDS, DEFAULT_SNOOZE_PERCENT,
+    MAX_SNOOZE_COUNT,
+};
+use crate::error::SolarmaError;
+use crate::program::SolarmaVault;
+use crate::state::{GlobalConfig, ProtocolParams, Treasury};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = GlobalConfig::SIZE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Treasury PDA receiving protocol fees
+    #[account(
+        init,
+        payer = authority,
+        space = Treasury::SIZE,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Published copy of the protocol constants
+    #[account(
+        init,
+        payer = authority,
+        space = ProtocolParams::SIZE,
+        seeds = [b"protocol-params"],
+        bump
+    )]
+    pub protocol_params: Account<'info, ProtocolParams>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ SolarmaError::NotUpgradeAuthority
+    )]
+    pub program: Program<'info, SolarmaVault>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ SolarmaError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Program upgrade authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_init_config(ctx: Context<InitConfig>, admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = admin;
+    config.protocol_fee_bps = 0;
+    config.slasher_bounty_bps = DEFAULT_SLASHER_BOUNTY_BPS;
+    config.default_snooze_percent = DEFAULT_SNOOZE_PERCENT as u8;
+    config.default_snooze_extension_seconds = DEFAULT_SNOOZE_EXTENSION_SECONDS;
+    config.default_max_snoozes = MAX_SNOOZE_COUNT;
+    config.paused = false;
+    config.require_verified_charity = false;
+    config.max_deposit_lamports = 0;
+    config.daily_deposit_cap_lamports = 0;
+    config.max_open_alarms = 0;
+    config.referral_fee_bps = 0;
+    config.min_wake_window_seconds = 0;
+    config.max_wake_window_seconds = 0;
+    config.treasury_signers = Default::default();
+    config.burn_sink = Pubkey::default();
+    config.two_phase_claim_threshold = 0;
+    config.bump = ctx.bumps.config;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_collected = 0;
+    treasury.total_withdrawn = 0;
+    treasury.bump = ctx.bumps.treasury;
+
+    ctx.accounts
+        .protocol_params
+        .set_inner(ProtocolParams::current(ctx.bumps.protocol_params));
+
+    emit!(crate::events::ConfigInitialized {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        admin,
+        config: ctx.accounts.config.key(),
+    });
+
+    msg!("Global config initialized, admin={}", admin);
+    Ok(())
+}
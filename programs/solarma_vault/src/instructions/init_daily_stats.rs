@@ -0,0 +1,82 @@
+//! Init daily stats instruction - permissionless creation of the per-day
+//! `DailyStats` PDA that `create_alarm`, `claim` and `slash` update
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::DailyStats;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(day: u64)]
+pub struct InitDailyStats<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = DailyStats::SIZE,
+        seeds = [b"daily-stats".as_ref(), &day.to_le_bytes()],
+        bump
+    )]
+    pub daily_stats: Account<'info, DailyStats>,
+
+    /// Anyone can open today's or a future day's stats
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// What happened, for [`record_daily_stat`].
+pub(crate) enum DailyStat {
+    AlarmCreated,
+    Claimed,
+    /// Lamports forfeited
+    Slashed(u64),
+}
+
+/// Count `stat` on `stats` if it is today's PDA. Counters saturate and a
+/// stale day is ignored, so stats can never make the user's instruction fail.
+pub(crate) fn record_daily_stat(
+    stats: Option<&mut Account<DailyStats>>,
+    stat: DailyStat,
+    current_time: i64,
+) {
+    let Some(stats) = stats else {
+        return;
+    };
+    if helpers::stats_day_of(current_time) != Some(stats.day) {
+        return;
+    }
+    match stat {
+        DailyStat::AlarmCreated => stats.alarms_created = stats.alarms_created.saturating_add(1),
+        DailyStat::Claimed => stats.claims = stats.claims.saturating_add(1),
+        DailyStat::Slashed(amount) => {
+            stats.slashes = stats.slashes.saturating_add(1);
+            stats.lamports_slashed = stats.lamports_slashed.saturating_add(amount);
+        }
+    }
+}
+
+pub fn process_init_daily_stats(ctx: Context<InitDailyStats>, day: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        helpers::is_stats_day_open(day, clock.unix_timestamp),
+        SolarmaError::StatsDayClosed
+    );
+
+    let daily_stats = &mut ctx.accounts.daily_stats;
+    daily_stats.day = day;
+    daily_stats.alarms_created = 0;
+    daily_stats.claims = 0;
+    daily_stats.slashes = 0;
+    daily_stats.lamports_slashed = 0;
+    daily_stats.bump = ctx.bumps.daily_stats;
+
+    emit!(crate::events::DailyStatsOpened {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        day,
+    });
+
+    msg!("Daily stats opened for day {}", day);
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! Init forgiveness token instruction - open the owner's `ForgivenessToken`
+//! PDA so claims can credit the tokens their wake streak earns
+//!
+//! The account starts empty; `claim` credits it when passed alongside the
+//! owner's profile.
+
+use crate::constants::MAX_FORGIVENESS_TOKENS;
+use crate::helpers;
+use crate::state::ForgivenessToken;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitForgivenessToken<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = ForgivenessToken::SIZE,
+        seeds = [b"forgiveness", owner.key().as_ref()],
+        bump
+    )]
+    pub forgiveness_token: Account<'info, ForgivenessToken>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_init_forgiveness_token(ctx: Context<InitForgivenessToken>) -> Result<()> {
+    let token = &mut ctx.accounts.forgiveness_token;
+    token.owner = ctx.accounts.owner.key();
+    token.balance = 0;
+    token.redeemed = 0;
+    token.bump = ctx.bumps.forgiveness_token;
+
+    msg!("Forgiveness tokens opened for {}", token.owner);
+    Ok(())
+}
+
+/// Credit one forgiveness token when a claim moved the streak onto a
+/// multiple of `FORGIVENESS_STREAK_DAYS`, up to `MAX_FORGIVENESS_TOKENS`.
+/// A missing account earns nothing.
+pub(crate) fn credit_forgiveness(
+    token: Option<&mut Account<ForgivenessToken>>,
+    old_streak: u16,
+    new_streak: u16,
+    current_time: i64,
+) {
+    let Some(token) = token else {
+        return;
+    };
+    if !helpers::forgiveness_earned(old_streak, new_streak)
+        || token.balance >= MAX_FORGIVENESS_TOKENS
+    {
+        return;
+    }
+    token.balance += 1;
+
+    emit!(crate::events::ForgivenessEarned {
+        version: crate::events::EVENT_VERSION,
+        timestamp: current_time,
+        owner: token.owner,
+        streak: new_streak,
+        balance: token.balance,
+    });
+}
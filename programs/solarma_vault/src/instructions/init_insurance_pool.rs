@@ -0,0 +1,59 @@
+//! Init insurance pool instruction - admin creates the PDA that receives
+//! `insurance_bps` of every slash
+//!
+//! `claim_insurance` pays owners back out of it when the attestation server
+//! confirms their slash was caused by an infrastructure failure.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{GlobalConfig, InsurancePool};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitInsurancePool<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = InsurancePool::SIZE,
+        seeds = [b"insurance"],
+        bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    #[account(has_one = admin, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Move `insurance_bps` of `slashed` from `vault` into the pool before the
+/// vault is closed. Returns the amount moved; the pool is required whenever
+/// the share is non-zero.
+pub(crate) fn collect_insurance_share(
+    vault: &AccountInfo,
+    pool: Option<&mut Account<InsurancePool>>,
+    slashed: u64,
+    insurance_bps: u16,
+) -> Result<u64> {
+    let share = helpers::bps_of(slashed, insurance_bps).ok_or(SolarmaError::Overflow)?;
+    if share == 0 {
+        return Ok(0);
+    }
+    let pool = pool.ok_or(SolarmaError::InsurancePoolRequired)?;
+    **vault.try_borrow_mut_lamports()? -= share;
+    **pool.to_account_info().try_borrow_mut_lamports()? += share;
+    pool.total_collected = pool
+        .total_collected
+        .checked_add(share)
+        .ok_or(SolarmaError::Overflow)?;
+    Ok(share)
+}
+
+pub fn process_init_insurance_pool(ctx: Context<InitInsurancePool>) -> Result<()> {
+    ctx.accounts.insurance_pool.bump = ctx.bumps.insurance_pool;
+    msg!("Insurance pool initialized");
+    Ok(())
+}
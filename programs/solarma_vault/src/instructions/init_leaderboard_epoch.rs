@@ -0,0 +1,48 @@
+//! Init leaderboard epoch instruction - permissionless creation of the
+//! weekly epoch PDA that aggregates leaderboard totals
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::LeaderboardEpoch;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct InitLeaderboardEpoch<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = LeaderboardEpoch::SIZE,
+        seeds = [b"epoch".as_ref(), &epoch.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard_epoch: Account<'info, LeaderboardEpoch>,
+
+    /// Anyone can open the current or a future epoch
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_init_leaderboard_epoch(
+    ctx: Context<InitLeaderboardEpoch>,
+    epoch: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        helpers::is_epoch_open(epoch, false, clock.unix_timestamp),
+        SolarmaError::EpochClosed
+    );
+
+    let leaderboard_epoch = &mut ctx.accounts.leaderboard_epoch;
+    leaderboard_epoch.epoch = epoch;
+    leaderboard_epoch.participants = 0;
+    leaderboard_epoch.total_claims = 0;
+    leaderboard_epoch.total_slashes = 0;
+    leaderboard_epoch.finalized = false;
+    leaderboard_epoch.bump = ctx.bumps.leaderboard_epoch;
+
+    msg!("Leaderboard epoch {} opened", epoch);
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! Init savings vault instruction - create the per-user PDA that receives
+//! `PenaltyRoute::Savings` penalties
+//!
+//! Every penalty restarts the lock (`lock_seconds` from that moment);
+//! `withdraw_savings` pays out once it expires.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::SavingsVault;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitSavingsVault<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = SavingsVault::SIZE,
+        seeds = [b"savings", owner.key().as_ref()],
+        bump
+    )]
+    pub savings_vault: Account<'info, SavingsVault>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Record a penalty already moved into `savings` and extend its lock.
+///
+/// `savings` must be the owner's savings vault (the alarm's validated
+/// `penalty_destination`).
+pub(crate) fn credit_savings(
+    savings: &AccountInfo,
+    owner: &Pubkey,
+    amount: u64,
+    current_time: i64,
+) -> Result<()> {
+    require!(
+        savings.owner == &crate::ID,
+        SolarmaError::InvalidPenaltyRecipient
+    );
+    let mut vault = SavingsVault::try_deserialize(&mut &savings.try_borrow_data()?[..])?;
+    require!(vault.owner == *owner, SolarmaError::InvalidPenaltyRecipient);
+
+    vault.unlock_at = helpers::savings_unlock_at(vault.unlock_at, vault.lock_seconds, current_time)
+        .ok_or(SolarmaError::Overflow)?;
+    vault.total_deposited = vault
+        .total_deposited
+        .checked_add(amount)
+        .ok_or(SolarmaError::Overflow)?;
+    vault.try_serialize(&mut &mut savings.try_borrow_mut_data()?[..])?;
+
+    emit!(crate::events::SavingsCredited {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: *owner,
+        savings_vault: savings.key(),
+        amount,
+        unlock_at: vault.unlock_at,
+    });
+    Ok(())
+}
+
+pub fn process_init_savings_vault(ctx: Context<InitSavingsVault>, lock_seconds: i64) -> Result<()> {
+    require!(
+        helpers::validate_savings_lock(lock_seconds),
+        SolarmaError::InvalidSavingsLock
+    );
+
+    let savings_vault = &mut ctx.accounts.savings_vault;
+    savings_vault.owner = ctx.accounts.owner.key();
+    savings_vault.lock_seconds = lock_seconds;
+    savings_vault.unlock_at = 0;
+    savings_vault.total_deposited = 0;
+    savings_vault.bump = ctx.bumps.savings_vault;
+
+    msg!(
+        "Savings vault created for {} (lock {}s)",
+        savings_vault.owner,
+        lock_seconds
+    );
+    Ok(())
+}
@@ -1,5 +1,13 @@
 //! Initialize user profile
+//!
+//! `initialize_with_referrer` also records who referred the owner. The
+//! referrer then earns `config.referral_fee_bps` of every protocol fee the
+//! owner's alarms generate, whenever the keeper passes the owner's profile
+//! and the referrer account (see `pay_referral_reward`).
 
+use crate::constants::PROFILE_LAYOUT_VERSION;
+use crate::error::SolarmaError;
+use crate::helpers;
 use crate::state::UserProfile;
 use anchor_lang::prelude::*;
 
@@ -7,29 +15,100 @@ use anchor_lang::prelude::*;
 pub struct Initialize<'info> {
     #[account(
         init,
-        payer = owner,
+        payer = payer,
         space = UserProfile::SIZE,
         seeds = [b"user-profile", owner.key().as_ref()],
         bump
     )]
     pub user_profile: Account<'info, UserProfile>,
 
-    #[account(mut)]
     pub owner: Signer<'info>,
 
+    /// Pays the profile rent and fees; the owner itself, or a sponsor
+    /// onboarding a new user
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
+/// Move the referrer's share of `fee` out of `from` (the account the fee
+/// is taken from) before the rest reaches the treasury. Returns the amount
+/// paid; 0 when no profile is passed, it has no referrer, or the share
+/// rounds to zero.
+pub(crate) fn pay_referral_reward(
+    from: &AccountInfo,
+    profile: Option<&UserProfile>,
+    referrer: Option<&AccountInfo>,
+    fee: u64,
+    referral_fee_bps: u16,
+    alarm: Pubkey,
+) -> Result<u64> {
+    let Some(profile) = profile else {
+        return Ok(0);
+    };
+    if profile.referrer == Pubkey::default() {
+        return Ok(0);
+    }
+    let reward = helpers::bps_of(fee, referral_fee_bps).ok_or(SolarmaError::Overflow)?;
+    if reward == 0 {
+        return Ok(0);
+    }
+
+    let referrer = referrer.ok_or(SolarmaError::InvalidReferrer)?;
+    require!(
+        referrer.key() == profile.referrer && referrer.is_writable,
+        SolarmaError::InvalidReferrer
+    );
+    **from.try_borrow_mut_lamports()? -= reward;
+    **referrer.try_borrow_mut_lamports()? += reward;
+
+    emit!(crate::events::ReferralRewardPaid {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        referrer: profile.referrer,
+        referee: profile.owner,
+        alarm,
+        amount: reward,
+    });
+    Ok(reward)
+}
+
 pub fn process_initialize(ctx: Context<Initialize>) -> Result<()> {
     let user_profile = &mut ctx.accounts.user_profile;
     user_profile.owner = ctx.accounts.owner.key();
     user_profile.tag_hash = None;
     user_profile.bump = ctx.bumps.user_profile;
+    user_profile.layout_version = PROFILE_LAYOUT_VERSION;
 
     emit!(crate::events::ProfileInitialized {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
         owner: ctx.accounts.owner.key(),
     });
 
     msg!("User profile initialized for {}", ctx.accounts.owner.key());
     Ok(())
 }
+
+/// Same as `initialize`, recording `referrer` on the new profile.
+pub fn process_initialize_with_referrer(ctx: Context<Initialize>, referrer: Pubkey) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    require!(
+        helpers::validate_referrer(&owner.to_bytes(), &referrer.to_bytes()),
+        SolarmaError::InvalidReferrer
+    );
+
+    ctx.accounts.user_profile.referrer = referrer;
+    process_initialize(ctx)?;
+
+    emit!(crate::events::ReferralRegistered {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner,
+        referrer,
+    });
+
+    msg!("Referrer {} registered for {}", referrer, owner);
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! Join bet instruction - designated challenger matches the owner's deposit
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Alarm, AlarmStatus, GlobalConfig, Vault};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+pub struct JoinBet<'info> {
+    #[account(
+        mut,
+        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.challenger == Some(challenger.key()) @ SolarmaError::BetUnavailable,
+        constraint = alarm.challenger_stake == 0 @ SolarmaError::BetUnavailable
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA holding the pot
+    #[account(
+        mut,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump = alarm.vault_bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Global config - new deposits are rejected while paused
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ SolarmaError::ProtocolPaused
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_join_bet(ctx: Context<JoinBet>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let challenger_key = ctx.accounts.challenger.key();
+    let clock = crate::clock::get(ctx.remaining_accounts)?;
+
+    let (alarm_time, snooze_count, stake) = {
+        let alarm = &ctx.accounts.alarm;
+        (alarm.alarm_time, alarm.snooze_count, alarm.remaining_amount)
+    };
+    require!(
+        helpers::can_join_bet(alarm_time, snooze_count, clock.unix_timestamp),
+        SolarmaError::BetUnavailable
+    );
+    require!(stake > 0, SolarmaError::InsufficientDeposit);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.challenger.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        stake,
+    )?;
+
+    let alarm = &mut ctx.accounts.alarm;
+    alarm.challenger_stake = stake;
+    let pot = helpers::bet_pot(alarm.remaining_amount, stake).ok_or(SolarmaError::Overflow)?;
+
+    emit!(crate::events::BetMatched {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        challenger: challenger_key,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        stake,
+        pot,
+    });
+
+    msg!(
+        "Bet on alarm {} matched by {}: pot={}",
+        alarm.alarm_id,
+        challenger_key,
+        pot
+    );
+    Ok(())
+}
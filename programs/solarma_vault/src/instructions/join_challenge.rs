@@ -0,0 +1,100 @@
+//! Join challenge instruction - stake into a group wake challenge
+
+use crate::constants::MAX_CHALLENGE_PARTICIPANTS;
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Challenge, ChallengeParticipant, GlobalConfig};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+pub struct JoinChallenge<'info> {
+    #[account(
+        mut,
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.challenge_id.to_le_bytes()],
+        bump = challenge.bump
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        init,
+        payer = user,
+        space = ChallengeParticipant::SIZE,
+        seeds = [b"participant", challenge.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub participant: Account<'info, ChallengeParticipant>,
+
+    /// Global config - new deposits are rejected while paused
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ SolarmaError::ProtocolPaused
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_join_challenge(ctx: Context<JoinChallenge>) -> Result<()> {
+    let clock = Clock::get()?;
+    let challenge = &ctx.accounts.challenge;
+
+    require!(
+        helpers::can_join_challenge(
+            challenge.wake_time,
+            challenge.participant_count,
+            MAX_CHALLENGE_PARTICIPANTS,
+            clock.unix_timestamp,
+        ),
+        SolarmaError::ChallengeClosed
+    );
+
+    let stake = challenge.deposit_amount;
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.challenge.to_account_info(),
+            },
+        ),
+        stake,
+    )?;
+
+    let participant = &mut ctx.accounts.participant;
+    participant.challenge = ctx.accounts.challenge.key();
+    participant.user = ctx.accounts.user.key();
+    participant.acked = false;
+    participant.bump = ctx.bumps.participant;
+
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.participant_count = challenge
+        .participant_count
+        .checked_add(1)
+        .ok_or(SolarmaError::Overflow)?;
+    challenge.total_pot = challenge
+        .total_pot
+        .checked_add(stake)
+        .ok_or(SolarmaError::Overflow)?;
+
+    emit!(crate::events::ChallengeJoined {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        user: ctx.accounts.user.key(),
+        challenge: challenge.key(),
+        participant_count: challenge.participant_count,
+        total_pot: challenge.total_pot,
+    });
+
+    msg!(
+        "User {} joined challenge {} ({} participants)",
+        ctx.accounts.user.key(),
+        challenge.challenge_id,
+        challenge.participant_count
+    );
+    Ok(())
+}
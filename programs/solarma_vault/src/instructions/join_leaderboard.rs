@@ -0,0 +1,115 @@
+//! Join leaderboard instruction - the user opens their entry for an epoch.
+//!
+//! `claim` and `slash` update the entry when it is passed together with
+//! the epoch PDA; see `record_leaderboard_result`.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{LeaderboardEntry, LeaderboardEpoch};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct JoinLeaderboard<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = LeaderboardEntry::SIZE,
+        seeds = [
+            b"leaderboard".as_ref(),
+            &leaderboard_epoch.epoch.to_le_bytes(),
+            owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub leaderboard_entry: Account<'info, LeaderboardEntry>,
+
+    #[account(
+        mut,
+        seeds = [b"epoch".as_ref(), &leaderboard_epoch.epoch.to_le_bytes()],
+        bump = leaderboard_epoch.bump
+    )]
+    pub leaderboard_epoch: Account<'info, LeaderboardEpoch>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Record a claim (`success`) or slash on the owner's entry for the
+/// current epoch. Both accounts must be passed, or neither.
+pub(crate) fn record_leaderboard_result(
+    entry: Option<&mut Account<LeaderboardEntry>>,
+    epoch: Option<&mut Account<LeaderboardEpoch>>,
+    success: bool,
+    amount: u64,
+    current_time: i64,
+) -> Result<()> {
+    let (entry, epoch) = match (entry, epoch) {
+        (Some(entry), Some(epoch)) => (entry, epoch),
+        (None, None) => return Ok(()),
+        _ => return err!(SolarmaError::EpochClosed),
+    };
+    require!(
+        entry.epoch == epoch.epoch
+            && !epoch.finalized
+            && helpers::epoch_of(current_time) == Some(epoch.epoch),
+        SolarmaError::EpochClosed
+    );
+
+    if success {
+        entry.claims = entry.claims.checked_add(1).ok_or(SolarmaError::Overflow)?;
+        entry.claimed_lamports = entry
+            .claimed_lamports
+            .checked_add(amount)
+            .ok_or(SolarmaError::Overflow)?;
+        epoch.total_claims = epoch
+            .total_claims
+            .checked_add(1)
+            .ok_or(SolarmaError::Overflow)?;
+    } else {
+        entry.slashes = entry.slashes.checked_add(1).ok_or(SolarmaError::Overflow)?;
+        entry.slashed_lamports = entry
+            .slashed_lamports
+            .checked_add(amount)
+            .ok_or(SolarmaError::Overflow)?;
+        epoch.total_slashes = epoch
+            .total_slashes
+            .checked_add(1)
+            .ok_or(SolarmaError::Overflow)?;
+    }
+    Ok(())
+}
+
+pub fn process_join_leaderboard(ctx: Context<JoinLeaderboard>) -> Result<()> {
+    let clock = Clock::get()?;
+    let leaderboard_epoch = &mut ctx.accounts.leaderboard_epoch;
+    require!(
+        helpers::is_epoch_open(
+            leaderboard_epoch.epoch,
+            leaderboard_epoch.finalized,
+            clock.unix_timestamp
+        ),
+        SolarmaError::EpochClosed
+    );
+    leaderboard_epoch.participants = leaderboard_epoch
+        .participants
+        .checked_add(1)
+        .ok_or(SolarmaError::Overflow)?;
+
+    let entry = &mut ctx.accounts.leaderboard_entry;
+    entry.owner = ctx.accounts.owner.key();
+    entry.epoch = leaderboard_epoch.epoch;
+    entry.claims = 0;
+    entry.slashes = 0;
+    entry.claimed_lamports = 0;
+    entry.slashed_lamports = 0;
+    entry.bump = ctx.bumps.leaderboard_entry;
+
+    msg!(
+        "{} joined leaderboard epoch {}",
+        entry.owner,
+        leaderboard_epoch.epoch
+    );
+    Ok(())
+}
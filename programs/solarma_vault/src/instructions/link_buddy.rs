@@ -0,0 +1,56 @@
+//! Link buddy instruction - owner asks another user to be their buddy
+//!
+//! The `BuddyLink` starts inactive; the buddy turns it on with
+//! `accept_buddy_link`. Either side can end it with `unlink_buddy`.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::BuddyLink;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(buddy: Pubkey)]
+pub struct LinkBuddy<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = BuddyLink::SIZE,
+        seeds = [b"buddy-link", owner.key().as_ref(), buddy.as_ref()],
+        bump
+    )]
+    pub buddy_link: Account<'info, BuddyLink>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_link_buddy(ctx: Context<LinkBuddy>, buddy: Pubkey) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    require!(
+        helpers::validate_buddy_link(&owner.to_bytes(), &buddy.to_bytes()),
+        SolarmaError::InvalidBuddyLink
+    );
+    let clock = Clock::get()?;
+
+    let link = &mut ctx.accounts.buddy_link;
+    link.owner = owner;
+    link.buddy = buddy;
+    link.active = false;
+    link.created_at = clock.unix_timestamp;
+    link.bump = ctx.bumps.buddy_link;
+
+    emit!(crate::events::BuddyLinkUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner,
+        buddy,
+        link: link.key(),
+        active: false,
+        removed: false,
+    });
+
+    msg!("Buddy link requested: {} -> {}", owner, buddy);
+    Ok(())
+}
@@ -0,0 +1,163 @@
+//! Migrate alarm / profile instructions - grow an `Alarm` or `UserProfile`
+//! created by an older release to the current layout and stamp its
+//! `layout_version`
+//!
+//! New fields are appended (or carved out of the alarm's zeroed padding),
+//! so zero-filling the new bytes gives every new field its "unset" value,
+//! as in `migrate_config`. The exceptions are the alarm fields that later
+//! releases set at creation; `backfill_legacy_alarm` fills those on
+//! version-0 alarms. An account that is too short fails Anchor
+//! deserialization in every handler until it is migrated, which is the
+//! compatibility check. Both instructions are permissionless: the caller
+//! pays the extra rent, and a keeper can migrate an old alarm it needs to
+//! settle. Re-running on a current account is a no-op.
+
+use crate::constants::{ALARM_LAYOUT_VERSION, CLAIM_GRACE_SECONDS, PROFILE_LAYOUT_VERSION};
+use crate::helpers::{self, SnoozeParams};
+use crate::instructions::migrate_config::grow_account;
+use crate::state::{Alarm, UserProfile};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateAlarm<'info> {
+    /// CHECK: Read as raw bytes because an older layout cannot deserialize;
+    /// owner and discriminator are checked in `grow_account`
+    #[account(mut)]
+    pub alarm: UncheckedAccount<'info>,
+
+    /// Pays the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateProfile<'info> {
+    /// CHECK: As for `MigrateAlarm::alarm`; the PDA is checked by its seeds
+    /// once the owner is read from the raw bytes
+    #[account(mut)]
+    pub user_profile: UncheckedAccount<'info>,
+
+    /// Pays the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow `account` to `size`, then rewrite it with `set_version` applied
+/// when it was short or its stored layout version is behind. Returns `(size, version)`.
+fn migrate<'info, T>(
+    account: &AccountInfo<'info>,
+    size: usize,
+    current_version: u8,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    version_of: fn(&T) -> u8,
+    set_version: fn(&mut T, u8),
+) -> Result<(usize, u8)>
+where
+    T: AccountSerialize + AccountDeserialize + Discriminator,
+{
+    let old_len = account.data_len();
+    let new_len = grow_account(account, T::DISCRIMINATOR, size, payer, system_program)?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    let mut state = T::try_deserialize(&mut &data[..])?;
+    let version = version_of(&state);
+    if !helpers::needs_migration(old_len, size, version, current_version) {
+        return Ok((new_len, version));
+    }
+    set_version(&mut state, current_version);
+    state.try_serialize(&mut &mut data[..])?;
+    Ok((new_len, current_version))
+}
+
+/// Give a version-0 alarm the values `create_alarm` now sets up front.
+///
+/// A baseline alarm reads zero for its snooze limits (so it could never
+/// snooze), its claim grace and its payer. Each field is only filled while
+/// still unset, so a pre-versioning alarm that already carries its own
+/// snooze economics or payer keeps them.
+pub(crate) fn backfill_legacy_alarm(alarm: &mut Alarm) {
+    if alarm.max_snoozes == 0 && alarm.snooze_extension_seconds == 0 {
+        alarm.set_snooze_params(&SnoozeParams::default());
+    }
+    if alarm.claim_grace_seconds == 0 {
+        alarm.claim_grace_seconds = CLAIM_GRACE_SECONDS;
+    }
+    if alarm.payer == Pubkey::default() {
+        alarm.payer = alarm.owner;
+    }
+}
+
+pub fn process_migrate_alarm(ctx: Context<MigrateAlarm>) -> Result<()> {
+    let alarm = ctx.accounts.alarm.to_account_info();
+    let (size, layout_version) = migrate::<Alarm>(
+        &alarm,
+        Alarm::SIZE,
+        ALARM_LAYOUT_VERSION,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |a| a.layout_version,
+        |a, v| {
+            if a.layout_version == 0 {
+                backfill_legacy_alarm(a);
+            }
+            a.layout_version = v;
+        },
+    )?;
+
+    emit!(crate::events::AccountMigrated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        account: alarm.key(),
+        size: size as u32,
+        layout_version,
+    });
+
+    msg!("Alarm migrated: {} bytes, layout v{}", size, layout_version);
+    Ok(())
+}
+
+pub fn process_migrate_profile(ctx: Context<MigrateProfile>) -> Result<()> {
+    let profile = ctx.accounts.user_profile.to_account_info();
+
+    // `owner` is the first field after the discriminator in every layout
+    let owner = profile
+        .try_borrow_data()?
+        .get(8..40)
+        .and_then(|bytes| Pubkey::try_from(bytes).ok())
+        .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+    require_keys_eq!(
+        profile.key(),
+        UserProfile::find_address(&owner).0,
+        ErrorCode::ConstraintSeeds
+    );
+
+    let (size, layout_version) = migrate::<UserProfile>(
+        &profile,
+        UserProfile::SIZE,
+        PROFILE_LAYOUT_VERSION,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |p| p.layout_version,
+        |p, v| p.layout_version = v,
+    )?;
+
+    emit!(crate::events::AccountMigrated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        account: profile.key(),
+        size: size as u32,
+        layout_version,
+    });
+
+    msg!(
+        "Profile migrated: {} bytes, layout v{}",
+        size,
+        layout_version
+    );
+    Ok(())
+}
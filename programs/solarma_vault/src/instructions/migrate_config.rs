@@ -0,0 +1,113 @@
+//! Migrate config instruction - grow the config and treasury PDAs to the
+//! current layout after a program upgrade
+//!
+//! New fields are appended to `GlobalConfig` and `Treasury`, so accounts
+//! created by an older release are too short to deserialize. The admin
+//! (read from the raw config bytes) pays the extra rent; the appended bytes
+//! are zeroed, which is every new field's "unset" value. Accounts already at
+//! the current size are left alone, so re-running is harmless.
+
+use crate::helpers;
+use crate::state::{GlobalConfig, Treasury};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: Read as raw bytes because an older layout cannot deserialize;
+    /// owner and discriminator are checked in `grow_account`
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
+
+    /// CHECK: As for `config`
+    #[account(mut, seeds = [b"treasury"], bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Config admin; pays the extra rent
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Check `account` is a program account with `discriminator`, then grow it
+/// to `size`, topping its rent up from `payer`. Returns the new length.
+pub(crate) fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    discriminator: &[u8],
+    size: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<usize> {
+    require_keys_eq!(
+        *account.owner,
+        crate::ID,
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+    require!(
+        account.try_borrow_data()?.starts_with(discriminator),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    if account.data_len() >= size {
+        return Ok(account.data_len());
+    }
+
+    let min_balance = Rent::get()?.minimum_balance(size);
+    let topup = helpers::rent_topup(account.lamports(), min_balance);
+    if topup > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            topup,
+        )?;
+    }
+    account.resize(size)?;
+    Ok(size)
+}
+
+pub fn process_migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config = ctx.accounts.config.to_account_info();
+    let admin = ctx.accounts.admin.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    // `admin` is the first field after the discriminator in every layout
+    let stored_admin = config
+        .try_borrow_data()?
+        .get(8..40)
+        .map(|bytes| bytes == admin.key.as_ref());
+    require!(stored_admin == Some(true), ErrorCode::ConstraintHasOne);
+
+    let config_size = grow_account(
+        &config,
+        GlobalConfig::DISCRIMINATOR,
+        GlobalConfig::SIZE,
+        &admin,
+        &system_program,
+    )?;
+    let treasury_size = grow_account(
+        &ctx.accounts.treasury.to_account_info(),
+        Treasury::DISCRIMINATOR,
+        Treasury::SIZE,
+        &admin,
+        &system_program,
+    )?;
+
+    emit!(crate::events::ConfigMigrated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        config_size: config_size as u32,
+        treasury_size: treasury_size as u32,
+    });
+
+    msg!(
+        "Config migrated: config {} bytes, treasury {} bytes",
+        config_size,
+        treasury_size
+    );
+    Ok(())
+}
@@ -1,21 +1,225 @@
 //! Instruction handlers
 
+pub mod accept_buddy_link;
+pub mod accept_duel;
+pub mod accept_gift;
 pub mod ack_awake;
+pub mod ack_awake_attested;
+pub mod ack_awake_partial;
+pub mod ack_awake_relayed;
+pub mod ack_challenge;
+pub mod ack_duel;
+pub mod add_charity;
+pub mod anchor_proof;
+pub mod approve_recovery;
+pub mod authorize_auto_claim;
+pub mod auto_claim;
+pub mod block_destination;
+pub mod buddy_accept;
+pub mod buddy_stake;
+pub mod cancel_buddy_request;
+pub mod cancel_recovery;
+pub mod cancel_series;
 pub mod claim;
+pub mod claim_achievement;
+pub mod claim_and_roll;
+pub mod claim_attested;
+pub mod claim_insurance;
+pub mod claim_late;
+pub mod claim_many;
+pub mod claim_relayed;
+pub mod close_alarm;
+pub mod close_permit_nonce;
+pub mod close_schedule;
+pub mod close_settlement_schedule;
+pub mod crank_settle;
 pub mod create_alarm;
+pub mod create_alarm_from_template;
+pub mod create_campaign;
+pub mod create_challenge;
+pub mod create_duel;
+pub mod create_gift_alarm;
+pub mod create_lookup_table;
+pub mod create_schedule;
+pub mod create_series;
+pub mod decline_gift;
 pub mod emergency_refund;
+pub mod expire_gift;
+pub mod extend_deadline;
+pub mod extend_lookup_table;
+pub mod finalize_claim;
+pub mod finalize_epoch;
+pub mod flag_claim;
+pub mod forgive_slash;
+pub mod fund_campaign;
+pub mod init_alarm_index;
+pub mod init_attestation_config;
+pub mod init_config;
+pub mod init_daily_stats;
+pub mod init_forgiveness_token;
+pub mod init_insurance_pool;
+pub mod init_leaderboard_epoch;
+pub mod init_savings_vault;
 pub mod initialize;
+pub mod join_bet;
+pub mod join_challenge;
+pub mod join_leaderboard;
+pub mod link_buddy;
+pub mod materialize_next_alarm;
+pub mod migrate_account;
+pub mod migrate_config;
+pub mod offer_bet;
+pub mod raise_dispute;
+pub mod recover_alarm;
+pub mod register_tag;
+pub mod release_buddy_stake;
+pub mod remove_charity;
+pub mod request_claim;
+pub mod reschedule_alarm;
+pub mod resolve_dispute;
+pub mod rotate_tag;
+pub mod save_template;
+pub mod schedule_settlement;
+pub mod set_alarm_memo;
+pub mod set_attestation_key;
+pub mod set_attestors;
+pub mod set_claim_destination;
+pub mod set_co_owners;
+pub mod set_delegate;
+pub mod set_guardians;
+#[cfg(feature = "test-clock")]
+pub mod set_mock_time;
+pub mod set_treasury_signers;
+pub mod settle_challenge;
+pub mod settle_duel;
+pub mod settle_scheduled;
+pub mod skip_occurrence;
 pub mod slash;
+pub mod slash_partial;
 pub mod snooze;
+pub mod spawn_next_occurrence;
 pub mod sweep_acknowledged;
+pub mod sweep_expired;
+pub mod sync_protocol_params;
+pub mod unblock_destination;
+pub mod unlink_buddy;
+pub mod update_config;
+pub mod update_penalty_destination;
+pub mod update_schedule;
+pub mod validate_create_alarm_params;
+pub mod view_alarm;
+pub mod withdraw_savings;
+pub mod withdraw_treasury;
 
 // Re-export Accounts structs and Anchor-generated types for the #[program] macro.
 // Handler functions have unique names (process_*) so no glob collision occurs.
+pub use accept_buddy_link::*;
+pub use accept_duel::*;
+pub use accept_gift::*;
 pub use ack_awake::*;
+pub use ack_awake_attested::*;
+pub use ack_awake_partial::*;
+pub use ack_awake_relayed::*;
+pub use ack_challenge::*;
+pub use ack_duel::*;
+pub use add_charity::*;
+pub use anchor_proof::*;
+pub use approve_recovery::*;
+pub use authorize_auto_claim::*;
+pub use auto_claim::*;
+pub use block_destination::*;
+pub use buddy_accept::*;
+pub use buddy_stake::*;
+pub use cancel_buddy_request::*;
+pub use cancel_recovery::*;
+pub use cancel_series::*;
 pub use claim::*;
+pub use claim_achievement::*;
+pub use claim_and_roll::*;
+pub use claim_attested::*;
+pub use claim_insurance::*;
+pub use claim_late::*;
+pub use claim_many::*;
+pub use claim_relayed::*;
+pub use close_alarm::*;
+pub use close_permit_nonce::*;
+pub use close_schedule::*;
+pub use close_settlement_schedule::*;
+pub use crank_settle::*;
 pub use create_alarm::*;
+pub use create_alarm_from_template::*;
+pub use create_campaign::*;
+pub use create_challenge::*;
+pub use create_duel::*;
+pub use create_gift_alarm::*;
+pub use create_lookup_table::*;
+pub use create_schedule::*;
+pub use create_series::*;
+pub use decline_gift::*;
 pub use emergency_refund::*;
+pub use expire_gift::*;
+pub use extend_deadline::*;
+pub use extend_lookup_table::*;
+pub use finalize_claim::*;
+pub use finalize_epoch::*;
+pub use flag_claim::*;
+pub use forgive_slash::*;
+pub use fund_campaign::*;
+pub use init_alarm_index::*;
+pub use init_attestation_config::*;
+pub use init_config::*;
+pub use init_daily_stats::*;
+pub use init_forgiveness_token::*;
+pub use init_insurance_pool::*;
+pub use init_leaderboard_epoch::*;
+pub use init_savings_vault::*;
 pub use initialize::*;
+pub use join_bet::*;
+pub use join_challenge::*;
+pub use join_leaderboard::*;
+pub use link_buddy::*;
+pub use materialize_next_alarm::*;
+pub use migrate_account::*;
+pub use migrate_config::*;
+pub use offer_bet::*;
+pub use raise_dispute::*;
+pub use recover_alarm::*;
+pub use register_tag::*;
+pub use release_buddy_stake::*;
+pub use remove_charity::*;
+pub use request_claim::*;
+pub use reschedule_alarm::*;
+pub use resolve_dispute::*;
+pub use rotate_tag::*;
+pub use save_template::*;
+pub use schedule_settlement::*;
+pub use set_alarm_memo::*;
+pub use set_attestation_key::*;
+pub use set_attestors::*;
+pub use set_claim_destination::*;
+pub use set_co_owners::*;
+pub use set_delegate::*;
+pub use set_guardians::*;
+#[cfg(feature = "test-clock")]
+pub use set_mock_time::*;
+pub use set_treasury_signers::*;
+pub use settle_challenge::*;
+pub use settle_duel::*;
+pub use settle_scheduled::*;
+pub use skip_occurrence::*;
 pub use slash::*;
+pub use slash_partial::*;
 pub use snooze::*;
+pub use spawn_next_occurrence::*;
 pub use sweep_acknowledged::*;
+pub use sweep_expired::*;
+pub use sync_protocol_params::*;
+pub use unblock_destination::*;
+pub use unlink_buddy::*;
+pub use update_config::*;
+pub use update_penalty_destination::*;
+pub use update_schedule::*;
+pub use validate_create_alarm_params::*;
+pub use view_alarm::*;
+pub use withdraw_savings::*;
+pub use withdraw_treasury::*;
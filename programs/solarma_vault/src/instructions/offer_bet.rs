@@ -0,0 +1,60 @@
+//! Offer bet instruction - owner invites a challenger to match the deposit
+//!
+//! Once matched, the whole pot goes to the owner on claim, or to the
+//! challenger on slash (overriding the penalty route).
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Alarm, AlarmStatus};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct OfferBet<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.challenger_stake == 0 @ SolarmaError::BetUnavailable,
+        // A matched pot is all-or-nothing; progressive alarms cannot be bet on.
+        constraint = !alarm.is_progressive() @ SolarmaError::BetUnavailable,
+        // A staked buddy was promised the deposit on failure
+        constraint = alarm.buddy_stake == 0 @ SolarmaError::BetUnavailable
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_offer_bet(ctx: Context<OfferBet>, challenger: Pubkey) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let owner_key = ctx.accounts.owner.key();
+    let alarm = &mut ctx.accounts.alarm;
+    let clock = crate::clock::get(ctx.remaining_accounts)?;
+
+    require!(
+        alarm.remaining_amount > 0,
+        SolarmaError::InsufficientDeposit
+    );
+    require!(
+        challenger != owner_key && challenger != Pubkey::default(),
+        SolarmaError::BetUnavailable
+    );
+    require!(
+        helpers::can_join_bet(alarm.alarm_time, alarm.snooze_count, clock.unix_timestamp),
+        SolarmaError::BetUnavailable
+    );
+
+    alarm.challenger = Some(challenger);
+
+    emit!(crate::events::BetOffered {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: owner_key,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        challenger,
+    });
+
+    msg!("Alarm {} offered as bet to {}", alarm.alarm_id, challenger);
+    Ok(())
+}
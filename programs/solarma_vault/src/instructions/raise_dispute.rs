@@ -0,0 +1,57 @@
+//! Raise dispute instruction - the owner of a Buddy alarm with an arbiter
+//! freezes slashing while the arbiter decides
+//!
+//! Meant for failures outside the owner's control (dead phone, crashed
+//! app). The freeze lasts `DISPUTE_WINDOW_SECONDS`; if the arbiter does not
+//! rule in time, slashing resumes as if no dispute had been raised.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::Alarm;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RaiseDispute<'info> {
+    #[account(mut, has_one = owner)]
+    pub alarm: Account<'info, Alarm>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_raise_dispute(ctx: Context<RaiseDispute>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let alarm = &mut ctx.accounts.alarm;
+    let clock = Clock::get()?;
+
+    let dispute_until = helpers::dispute_window_end(
+        alarm.status,
+        alarm.arbiter.is_some(),
+        alarm.dispute_until,
+        alarm.alarm_time,
+        clock.unix_timestamp,
+    )
+    .map_err(|e| match e {
+        "invalid_state" => SolarmaError::InvalidAlarmState,
+        "overflow" => SolarmaError::Overflow,
+        _ => SolarmaError::DisputeUnavailable,
+    })?;
+    let arbiter = alarm.arbiter.ok_or(SolarmaError::DisputeUnavailable)?;
+    alarm.dispute_until = dispute_until;
+
+    emit!(crate::events::DisputeRaised {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: alarm.owner,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        arbiter,
+        dispute_until,
+    });
+
+    msg!(
+        "Dispute raised on alarm {} until {}",
+        alarm.alarm_id,
+        dispute_until
+    );
+    Ok(())
+}
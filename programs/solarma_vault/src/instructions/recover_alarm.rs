@@ -0,0 +1,98 @@
+//! Recover alarm instruction - after enough guardian approvals and the
+//! timelock, a guardian pays an acknowledged alarm out to the owner's
+//! recovery address
+//!
+//! The alarm ends Claimed, as if the owner had claimed it to a new wallet.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::state::{Alarm, AlarmIndex, AlarmStatus, UserProfile, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RecoverAlarm<'info> {
+    #[account(
+        mut,
+        constraint = alarm.status == AlarmStatus::Acknowledged @ SolarmaError::InvalidAlarmState
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA holding the deposit - closed to the recovery address
+    #[account(
+        mut,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump = alarm.vault_bump,
+        close = recovery_address
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Owner's profile holding the guardian list and recovery address
+    #[account(
+        seeds = [b"user-profile", alarm.owner.as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    /// Address the owner designated in `set_guardians`
+    /// CHECK: Validated against user_profile.recovery_address
+    #[account(
+        mut,
+        constraint = recovery_address.key() == user_profile.recovery_address
+            @ SolarmaError::InvalidGuardians
+    )]
+    pub recovery_address: UncheckedAccount<'info>,
+
+    /// Owner's alarm index; the recovered alarm is removed from it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", alarm.owner.as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+
+    pub guardian: Signer<'info>,
+}
+
+pub fn process_recover_alarm(ctx: Context<RecoverAlarm>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let recovery_address = ctx.accounts.recovery_address.key();
+    let clock = Clock::get()?;
+
+    require!(
+        ctx.accounts
+            .user_profile
+            .guardian_slot(&ctx.accounts.guardian.key())
+            .is_some(),
+        SolarmaError::NotGuardian
+    );
+    // An empty guardian list leaves recovery_address unset
+    require!(
+        recovery_address != Pubkey::default(),
+        SolarmaError::InvalidGuardians
+    );
+
+    let alarm = &mut ctx.accounts.alarm;
+    require!(
+        helpers::is_recovery_unlocked(alarm.recovery_unlocks_at, clock.unix_timestamp),
+        SolarmaError::RecoveryNotReady
+    );
+
+    emit!(crate::events::AlarmRecovered {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: alarm.owner,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        recovery_address,
+        amount: alarm.remaining_amount,
+    });
+
+    // The `close = recovery_address` constraint moves the deposit and rent
+    alarm.status = AlarmStatus::Claimed;
+    alarm.remaining_amount = 0;
+    remove_from_alarm_index(ctx.accounts.alarm_index.as_deref_mut(), alarm.alarm_id);
+
+    msg!("Alarm {} recovered to {}", alarm.alarm_id, recovery_address);
+    Ok(())
+}
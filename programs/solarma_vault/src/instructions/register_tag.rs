@@ -0,0 +1,37 @@
+//! Register tag instruction - bind an NFC/QR tag hash to the user profile
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::UserProfile;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RegisterTag<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump,
+        constraint = user_profile.tag_hash.is_none() @ SolarmaError::TagAlreadyRegistered
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_register_tag(ctx: Context<RegisterTag>, tag_hash: [u8; 32]) -> Result<()> {
+    helpers::validate_tag_hash(&tag_hash).map_err(|_| SolarmaError::InvalidTagHash)?;
+
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.tag_hash = Some(tag_hash);
+
+    emit!(crate::events::TagRegistered {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: user_profile.owner,
+        tag_hash,
+    });
+
+    msg!("Tag registered for {}", user_profile.owner);
+    Ok(())
+}
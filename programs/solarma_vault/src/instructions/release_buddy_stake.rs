@@ -0,0 +1,56 @@
+//! Release buddy stake instruction - return a buddy's stake once the alarm
+//! it backed is resolved
+//!
+//! The alarm must be Claimed or Slashed, or already closed (refunded,
+//! cancelled or cleaned up with `close_alarm`). The stake PDA closes to the
+//! buddy with its rent.
+
+use crate::error::SolarmaError;
+use crate::state::{Alarm, AlarmStatus, BuddyStake};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ReleaseBuddyStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"buddy-stake", alarm.key().as_ref()],
+        bump = buddy_stake.bump,
+        has_one = alarm,
+        has_one = buddy,
+        close = buddy
+    )]
+    pub buddy_stake: Account<'info, BuddyStake>,
+
+    /// CHECK: Key is verified by `buddy_stake.has_one = alarm`; may already be closed
+    pub alarm: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub buddy: Signer<'info>,
+}
+
+pub fn process_release_buddy_stake(ctx: Context<ReleaseBuddyStake>) -> Result<()> {
+    let alarm_info = ctx.accounts.alarm.to_account_info();
+    // A closed alarm no longer belongs to the program
+    let status = if alarm_info.owner == &crate::ID && !alarm_info.data_is_empty() {
+        let data = alarm_info.try_borrow_data()?;
+        Some(Alarm::try_deserialize(&mut &data[..])?.status)
+    } else {
+        None
+    };
+    if let Some(status) = status {
+        require!(status.is_terminal(), SolarmaError::BuddyStakeLocked);
+    }
+
+    let stake = &ctx.accounts.buddy_stake;
+    emit!(crate::events::BuddyStakeReleased {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        alarm: stake.alarm,
+        buddy: stake.buddy,
+        amount: stake.amount,
+        owner_slashed: status == Some(AlarmStatus::Slashed),
+    });
+
+    msg!("Buddy stake of {} released", stake.amount);
+    Ok(())
+}
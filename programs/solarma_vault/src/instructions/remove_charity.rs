@@ -0,0 +1,38 @@
+//! Remove charity instruction - admin delists a donation target
+//!
+//! Existing alarms keep their destination; only new Donate-route alarms
+//! are affected while `require_verified_charity` is set.
+
+use crate::state::{Charity, GlobalConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveCharity<'info> {
+    #[account(
+        mut,
+        seeds = [b"charity", charity.address.as_ref()],
+        bump = charity.bump,
+        close = admin
+    )]
+    pub charity: Account<'info, Charity>,
+
+    #[account(has_one = admin, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn process_remove_charity(ctx: Context<RemoveCharity>) -> Result<()> {
+    let charity = &ctx.accounts.charity;
+
+    emit!(crate::events::CharityRemoved {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        charity: charity.key(),
+        address: charity.address,
+    });
+
+    msg!("Charity {} removed from registry", charity.address);
+    Ok(())
+}
@@ -0,0 +1,90 @@
+//! Request claim instruction - first phase of a two-phase claim
+//!
+//! Alarms whose deposit reached `config.two_phase_claim_threshold` cannot
+//! `claim` directly. The owner, co-owner or profile delegate requests the
+//! claim inside the normal claim window; after `CLAIM_FINALIZE_DELAY_SECONDS`
+//! `finalize_claim` pays out, unless the buddy or the attestation server
+//! vetoed it with `flag_claim` in between. This bounds what a stolen session
+//! key can do on high-value commitments.
+
+use crate::constants::CLAIM_FINALIZE_DELAY_SECONDS;
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Alarm, AlarmStatus, UserProfile};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RequestClaim<'info> {
+    #[account(
+        mut,
+        constraint = alarm.status == AlarmStatus::Acknowledged @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.two_phase_claim @ SolarmaError::InvalidAlarmState,
+        constraint = !alarm.require_attestation @ SolarmaError::AttestationRequired
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Owner's profile; when present, its delegate may sign
+    #[account(
+        seeds = [b"user-profile", alarm.owner.as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Owner, co-owner or profile delegate requesting the claim
+    pub authority: Signer<'info>,
+}
+
+pub fn process_request_claim(ctx: Context<RequestClaim>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let authority_key = ctx.accounts.authority.key();
+    let user_profile = ctx.accounts.user_profile.as_deref();
+    let alarm = &mut ctx.accounts.alarm;
+    let clock = Clock::get()?;
+
+    require!(
+        alarm.is_authorized(&authority_key)
+            || user_profile
+                .is_some_and(|p| p.is_active_delegate(&authority_key, clock.unix_timestamp)),
+        SolarmaError::UnauthorizedSigner
+    );
+    // One request per alarm; a vetoed alarm falls back to sweep or recovery
+    require!(
+        alarm.claim_requested_at == 0 && !alarm.claim_flagged,
+        SolarmaError::InvalidAlarmState
+    );
+    helpers::check_claim_window(
+        alarm.status,
+        helpers::wake_window_start(alarm.alarm_time, alarm.early_ack_seconds),
+        alarm.deadline,
+        alarm.claim_grace_seconds,
+        clock.unix_timestamp,
+    )
+    .map_err(|e| match e {
+        "too_early" => SolarmaError::TooEarly,
+        "deadline_passed" => SolarmaError::DeadlinePassed,
+        "overflow" => SolarmaError::Overflow,
+        _ => SolarmaError::InvalidAlarmState,
+    })?;
+
+    alarm.claim_requested_at = clock.unix_timestamp;
+    let finalize_after = clock
+        .unix_timestamp
+        .checked_add(CLAIM_FINALIZE_DELAY_SECONDS)
+        .ok_or(SolarmaError::Overflow)?;
+
+    emit!(crate::events::ClaimRequested {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        authority: authority_key,
+        finalize_after,
+    });
+
+    msg!(
+        "Claim requested for alarm {}, finalizable at {}",
+        alarm.alarm_id,
+        finalize_after
+    );
+    Ok(())
+}
@@ -0,0 +1,108 @@
+//! Reschedule alarm instruction - move alarm_time and deadline before the
+//! alarm fires, without the emergency refund penalty
+//!
+//! The new window must respect the configured wake-window bounds, as at
+//! creation. A soft deadline keeps the same lead on the new deadline and
+//! must still fall after the new alarm time.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Alarm, AlarmStatus, GlobalConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RescheduleAlarm<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState,
+        // An offered or matched bet was agreed on the original schedule.
+        constraint = alarm.challenger.is_none() @ SolarmaError::BetLocked
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Global config bounding the new wake window
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_reschedule_alarm(
+    ctx: Context<RescheduleAlarm>,
+    new_alarm_time: i64,
+    new_deadline: i64,
+) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let alarm = &mut ctx.accounts.alarm;
+    let clock = crate::clock::get(ctx.remaining_accounts)?;
+
+    // Only while the current alarm has not fired yet
+    require!(
+        clock.unix_timestamp < alarm.alarm_time,
+        SolarmaError::TooLateForRefund
+    );
+
+    helpers::validate_alarm_params(
+        new_alarm_time,
+        new_deadline,
+        clock.unix_timestamp,
+        alarm.remaining_amount,
+        alarm.penalty_route,
+        alarm.penalty_destination.is_some(),
+        &alarm.snooze_params(),
+    )
+    .map_err(|e| match e {
+        "alarm_time_in_past" => SolarmaError::AlarmTimeInPast,
+        "invalid_deadline" => SolarmaError::InvalidDeadline,
+        "deposit_too_small" => SolarmaError::DepositTooSmall,
+        "invalid_penalty_route" => SolarmaError::InvalidPenaltyRoute,
+        "penalty_destination_required" => SolarmaError::PenaltyDestinationRequired,
+        _ => SolarmaError::InvalidSnoozeConfig,
+    })?;
+    helpers::validate_wake_window(
+        new_alarm_time,
+        new_deadline,
+        ctx.accounts.config.wake_window_bounds(),
+    )
+    .map_err(|_| SolarmaError::WakeWindowOutOfBounds)?;
+
+    // A soft deadline keeps its lead on the deadline
+    let new_soft_deadline = if alarm.soft_deadline == 0 {
+        0
+    } else {
+        new_deadline
+            .checked_sub(alarm.deadline - alarm.soft_deadline)
+            .ok_or(SolarmaError::Overflow)?
+    };
+    require!(
+        helpers::validate_soft_deadline(new_alarm_time, new_soft_deadline, new_deadline),
+        SolarmaError::InvalidSoftDeadline
+    );
+
+    let old_alarm_time = alarm.alarm_time;
+    let old_deadline = alarm.deadline;
+    alarm.alarm_time = new_alarm_time;
+    alarm.deadline = new_deadline;
+    alarm.soft_deadline = new_soft_deadline;
+
+    emit!(crate::events::AlarmRescheduled {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: alarm.owner,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        old_alarm_time,
+        old_deadline,
+        new_alarm_time,
+        new_deadline,
+    });
+
+    msg!(
+        "Alarm {} rescheduled: time={}, deadline={}",
+        alarm.alarm_id,
+        new_alarm_time,
+        new_deadline
+    );
+    Ok(())
+}
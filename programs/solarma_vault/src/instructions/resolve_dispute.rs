@@ -0,0 +1,90 @@
+//! Resolve dispute instruction - the arbiter rules on an open dispute
+//!
+//! `refund_owner = true` closes the vault to the owner with no penalty and
+//! marks the alarm Claimed. `false` lifts the freeze immediately, so the
+//! alarm is slashed through its penalty route as usual.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::state::{Alarm, AlarmIndex, AlarmStatus, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.arbiter == Some(arbiter.key()) @ SolarmaError::UnauthorizedSigner
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA holding the deposit - closed to the owner on a refund
+    #[account(
+        mut,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump = alarm.vault_bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Alarm owner, refunded when the arbiter sides with them
+    /// CHECK: Validated by has_one on alarm
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// Owner's alarm index; a refunded alarm is removed from it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", owner.key().as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+
+    /// Arbiter named at creation
+    pub arbiter: Signer<'info>,
+}
+
+pub fn process_resolve_dispute(ctx: Context<ResolveDispute>, refund_owner: bool) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let arbiter_key = ctx.accounts.arbiter.key();
+    let clock = Clock::get()?;
+
+    require!(
+        helpers::is_dispute_active(ctx.accounts.alarm.dispute_until, clock.unix_timestamp),
+        SolarmaError::NoActiveDispute
+    );
+
+    let alarm = &mut ctx.accounts.alarm;
+    let refunded_amount = if refund_owner {
+        let amount = alarm.remaining_amount;
+        alarm.status = AlarmStatus::Claimed;
+        alarm.remaining_amount = 0;
+        remove_from_alarm_index(ctx.accounts.alarm_index.as_deref_mut(), alarm.alarm_id);
+        ctx.accounts
+            .vault
+            .close(ctx.accounts.owner.to_account_info())?;
+        amount
+    } else {
+        0
+    };
+    // Either way the dispute is over and cannot be raised again
+    alarm.dispute_until = clock.unix_timestamp;
+
+    emit!(crate::events::DisputeResolved {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        arbiter: arbiter_key,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        refunded_owner: refund_owner,
+        refunded_amount,
+    });
+
+    msg!(
+        "Dispute on alarm {} resolved: refund_owner={}",
+        alarm.alarm_id,
+        refund_owner
+    );
+    Ok(())
+}
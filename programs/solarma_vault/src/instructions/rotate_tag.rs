@@ -0,0 +1,47 @@
+//! Rotate tag instruction - replace a lost or compromised NFC/QR tag
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::UserProfile;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RotateTag<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_rotate_tag(
+    ctx: Context<RotateTag>,
+    old_hash: [u8; 32],
+    new_hash: [u8; 32],
+) -> Result<()> {
+    let user_profile = &mut ctx.accounts.user_profile;
+
+    helpers::validate_tag_rotation(user_profile.tag_hash, &old_hash, &new_hash).map_err(
+        |e| match e {
+            "tag_mismatch" => SolarmaError::TagMismatch,
+            _ => SolarmaError::InvalidTagHash,
+        },
+    )?;
+
+    user_profile.tag_hash = Some(new_hash);
+
+    emit!(crate::events::TagRotated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: user_profile.owner,
+        old_hash,
+        new_hash,
+    });
+
+    msg!("Tag rotated for {}", user_profile.owner);
+    Ok(())
+}
@@ -0,0 +1,110 @@
+//! Save template instruction - store an alarm's settings for one-step reuse
+//!
+//! The template keeps the alarm time, wake window, deposit, penalty route
+//! and destination, plus the claim grace and snooze options, so the app can
+//! repeat an alarm ("same as yesterday") with `create_alarm_from_template`.
+//! No deposit is taken here, and the full `create_alarm` checks run when
+//! each alarm is created.
+
+use crate::constants::MIN_DEPOSIT_LAMPORTS;
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{AlarmOptions, AlarmTemplate, GlobalConfig, PenaltyRoute};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(template_id: u64)]
+pub struct SaveTemplate<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = AlarmTemplate::SIZE,
+        seeds = [b"alarm-template", owner.key().as_ref(), &template_id.to_le_bytes()],
+        bump
+    )]
+    pub template: Account<'info, AlarmTemplate>,
+
+    /// Global config bounding the wake window
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process_save_template(
+    ctx: Context<SaveTemplate>,
+    template_id: u64,
+    alarm_time: i64,
+    deadline: i64,
+    deposit_amount: u64,
+    penalty_route: u8,
+    penalty_destination: Option<Pubkey>,
+    options: Option<AlarmOptions>,
+) -> Result<()> {
+    let options = options.unwrap_or_default();
+    require!(
+        AlarmTemplate::is_templatable(&options),
+        SolarmaError::InvalidTemplate
+    );
+    require!(deadline > alarm_time, SolarmaError::InvalidDeadline);
+    helpers::validate_wake_window(
+        alarm_time,
+        deadline,
+        ctx.accounts.config.wake_window_bounds(),
+    )
+    .map_err(|_| SolarmaError::WakeWindowOutOfBounds)?;
+
+    // Split legs can only be configured through create_alarm options
+    let route =
+        PenaltyRoute::try_from(penalty_route).map_err(|_| SolarmaError::InvalidPenaltyRoute)?;
+    require!(route != PenaltyRoute::Split, SolarmaError::InvalidTemplate);
+    if deposit_amount > 0 {
+        require!(
+            deposit_amount >= MIN_DEPOSIT_LAMPORTS,
+            SolarmaError::DepositTooSmall
+        );
+        require!(
+            route == PenaltyRoute::Burn || penalty_destination.is_some(),
+            SolarmaError::PenaltyDestinationRequired
+        );
+    }
+
+    let template = &mut ctx.accounts.template;
+    template.owner = ctx.accounts.owner.key();
+    template.template_id = template_id;
+    template.alarm_time = alarm_time;
+    template.window_seconds = deadline - alarm_time;
+    template.deposit_amount = deposit_amount;
+    template.penalty_route = penalty_route;
+    template.penalty_destination = penalty_destination;
+    template.claim_grace_seconds = options.claim_grace_seconds;
+    template.snooze_percent = options.snooze_percent;
+    template.snooze_extension_seconds = options.snooze_extension_seconds;
+    template.max_snoozes = options.max_snoozes;
+    template.free_snoozes = options.free_snoozes;
+    template.snooze_model = options.snooze_model;
+    template.bump = ctx.bumps.template;
+
+    emit!(crate::events::TemplateSaved {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: template.owner,
+        template: template.key(),
+        template_id,
+        deposit_amount,
+        penalty_route,
+    });
+
+    msg!(
+        "Template {} saved: alarm_time={}, window={}s, deposit={}",
+        template_id,
+        alarm_time,
+        template.window_seconds,
+        deposit_amount
+    );
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! Schedule settlement instruction - anyone pins the accounts needed to
+//! settle an alarm, for automation networks (permissionless)
+//!
+//! `slash` takes a route-dependent recipient and `sweep_acknowledged` takes
+//! the owner, so a thread registered before the deadline cannot know which
+//! account metas to use. The `SettlementSchedule` PDA resolves both up
+//! front; a thread then registers `settle_scheduled` once, with fixed
+//! metas, to fire at `trigger_at`.
+//!
+//! Only alarms `crank_settle` could settle qualify: Split-route (without a
+//! matched bet) and progressive alarms are refused. A bet joined after
+//! scheduling changes the slash recipient; close and re-create the schedule.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Alarm, AlarmStatus, GlobalConfig, PenaltyRoute, SettlementSchedule};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ScheduleSettlement<'info> {
+    #[account(
+        constraint = matches!(alarm.status, AlarmStatus::Created | AlarmStatus::Acknowledged)
+            @ SolarmaError::ScheduleUnavailable
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = SettlementSchedule::SIZE,
+        seeds = [b"schedule", alarm.key().as_ref()],
+        bump
+    )]
+    pub schedule: Account<'info, SettlementSchedule>,
+
+    /// Global config naming the burn sink a Burn-route slash pays
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Pays the schedule's rent (usually the owner or the automation operator)
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_schedule_settlement(ctx: Context<ScheduleSettlement>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let schedule_key = ctx.accounts.schedule.key();
+    let alarm = &ctx.accounts.alarm;
+    let clock = Clock::get()?;
+
+    let route = PenaltyRoute::try_from(alarm.penalty_route)
+        .map_err(|_| SolarmaError::InvalidPenaltyRoute)?;
+    let is_bet = alarm.challenger_stake > 0;
+    require!(
+        !alarm.is_progressive() && (is_bet || route != PenaltyRoute::Split),
+        SolarmaError::ScheduleUnavailable
+    );
+    let penalty_recipient = if is_bet {
+        alarm.challenger
+    } else if route == PenaltyRoute::Burn {
+        Some(ctx.accounts.config.effective_burn_sink())
+    } else {
+        alarm.penalty_destination
+    }
+    .ok_or(SolarmaError::PenaltyDestinationNotSet)?;
+    let trigger_at = helpers::settlement_trigger_time(
+        alarm.deadline,
+        alarm.claim_grace_seconds,
+        route == PenaltyRoute::Buddy && !is_bet,
+    )
+    .ok_or(SolarmaError::Overflow)?;
+
+    let schedule = &mut ctx.accounts.schedule;
+    schedule.alarm = alarm_key;
+    schedule.owner = alarm.owner;
+    schedule.penalty_recipient = penalty_recipient;
+    schedule.creator = ctx.accounts.creator.key();
+    schedule.trigger_at = trigger_at;
+    schedule.bump = ctx.bumps.schedule;
+
+    emit!(crate::events::SettlementScheduled {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        alarm: alarm_key,
+        schedule: schedule_key,
+        penalty_recipient,
+        trigger_at,
+    });
+
+    msg!(
+        "Settlement of alarm {} scheduled at {}",
+        alarm.alarm_id,
+        trigger_at
+    );
+    Ok(())
+}
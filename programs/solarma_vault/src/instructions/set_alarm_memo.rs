@@ -0,0 +1,38 @@
+//! Set alarm memo instruction - owner or co-owner tags an alarm
+//!
+//! The memo lets any device render the alarm's name ("gym", "flight")
+//! from on-chain data alone. It has no effect on funds or state transitions.
+
+use crate::error::SolarmaError;
+use crate::state::Alarm;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetAlarmMemo<'info> {
+    #[account(
+        mut,
+        constraint = !alarm.status.is_terminal() @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.is_authorized(&authority.key()) @ SolarmaError::UnauthorizedSigner
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Owner or co-owner
+    pub authority: Signer<'info>,
+}
+
+pub fn process_set_alarm_memo(ctx: Context<SetAlarmMemo>, memo: [u8; 32]) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let alarm = &mut ctx.accounts.alarm;
+    alarm.memo = memo;
+
+    emit!(crate::events::AlarmMemoUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        memo,
+    });
+
+    msg!("Alarm {} memo updated", alarm.alarm_id);
+    Ok(())
+}
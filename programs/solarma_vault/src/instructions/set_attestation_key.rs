@@ -0,0 +1,64 @@
+//! Set attestation key instruction - admin rotates the server signer
+//!
+//! The outgoing key keeps validating for `transition_seconds` so permits
+//! already issued (and servers not yet switched over) keep working.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{AttestationConfig, GlobalConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetAttestationKey<'info> {
+    #[account(mut, seeds = [b"attestation"], bump = attestation_config.bump)]
+    pub attestation_config: Account<'info, AttestationConfig>,
+
+    #[account(has_one = admin, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn process_set_attestation_key(
+    ctx: Context<SetAttestationKey>,
+    new_key: Pubkey,
+    transition_seconds: i64,
+) -> Result<()> {
+    let attestation = &mut ctx.accounts.attestation_config;
+    let clock = Clock::get()?;
+
+    helpers::validate_attestation_rotation(
+        &attestation.current_key.to_bytes(),
+        &new_key.to_bytes(),
+        transition_seconds,
+    )
+    .map_err(|_| SolarmaError::InvalidConfig)?;
+    // A registered attestor cannot also take the primary slot (double counting)
+    require!(
+        !attestation.attestors.contains(&new_key),
+        SolarmaError::InvalidConfig
+    );
+
+    attestation.previous_key = attestation.current_key;
+    attestation.previous_valid_until = clock
+        .unix_timestamp
+        .checked_add(transition_seconds)
+        .ok_or(SolarmaError::Overflow)?;
+    attestation.current_key = new_key;
+
+    emit!(crate::events::AttestationKeyUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        admin: ctx.accounts.admin.key(),
+        previous_key: attestation.previous_key,
+        current_key: new_key,
+        previous_valid_until: attestation.previous_valid_until,
+    });
+
+    msg!(
+        "Attestation key rotated to {}; previous key valid until {}",
+        new_key,
+        attestation.previous_valid_until
+    );
+    Ok(())
+}
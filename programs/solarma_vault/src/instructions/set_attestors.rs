@@ -0,0 +1,47 @@
+//! Set attestors instruction - admin registers redundant attestation
+//! servers and the number of signatures each permit needs (M-of-N)
+
+use crate::constants::MAX_ATTESTORS;
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{AttestationConfig, GlobalConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetAttestors<'info> {
+    #[account(mut, seeds = [b"attestation"], bump = attestation_config.bump)]
+    pub attestation_config: Account<'info, AttestationConfig>,
+
+    #[account(has_one = admin, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn process_set_attestors(
+    ctx: Context<SetAttestors>,
+    attestors: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let attestation = &mut ctx.accounts.attestation_config;
+
+    let keys: Vec<[u8; 32]> = attestors.iter().map(|k| k.to_bytes()).collect();
+    helpers::validate_attestor_set(&attestation.current_key.to_bytes(), &keys, threshold)
+        .map_err(|_| SolarmaError::InvalidConfig)?;
+
+    let mut slots = [Pubkey::default(); MAX_ATTESTORS];
+    slots[..attestors.len()].copy_from_slice(&attestors);
+    attestation.attestors = slots;
+    attestation.threshold = threshold;
+
+    emit!(crate::events::AttestorsUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        admin: ctx.accounts.admin.key(),
+        attestors,
+        threshold,
+    });
+
+    msg!("Attestation threshold set to {}", threshold);
+    Ok(())
+}
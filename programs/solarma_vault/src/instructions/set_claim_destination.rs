@@ -0,0 +1,55 @@
+//! Set claim destination instruction - owner picks the wallet a claim pays
+//! the deposit to (e.g. a cold wallet instead of the hot signing key)
+//!
+//! Only the owner may change it, and only before the alarm fires, so a key
+//! used at wake time cannot redirect the deposit. `None` restores the owner.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Alarm, AlarmStatus};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetClaimDestination<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_set_claim_destination(
+    ctx: Context<SetClaimDestination>,
+    claim_to: Option<Pubkey>,
+) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let alarm = &mut ctx.accounts.alarm;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp < alarm.alarm_time,
+        SolarmaError::TooLateForRefund
+    );
+    if let Some(claim_to) = claim_to {
+        require!(
+            helpers::validate_claim_to(&alarm.owner.to_bytes(), &claim_to.to_bytes()),
+            SolarmaError::InvalidClaimDestination
+        );
+    }
+    alarm.claim_to = claim_to;
+
+    emit!(crate::events::ClaimDestinationUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: alarm.owner,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        claim_to,
+    });
+
+    msg!("Alarm {} claim destination: {:?}", alarm.alarm_id, claim_to);
+    Ok(())
+}
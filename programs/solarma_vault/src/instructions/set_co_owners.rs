@@ -0,0 +1,57 @@
+//! Set co-owners instruction - owner registers extra keys that may act on an alarm
+//!
+//! Co-owners (e.g. a phone hot key, a hardware wallet, a partner) can
+//! ack, snooze and claim. Funds always return to the primary owner, and
+//! only the primary owner may refund or change this list.
+
+use crate::constants::MAX_CO_OWNERS;
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Alarm, AlarmStatus};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetCoOwners<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = matches!(alarm.status, AlarmStatus::Created | AlarmStatus::Acknowledged)
+            @ SolarmaError::InvalidAlarmState
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_set_co_owners(ctx: Context<SetCoOwners>, co_owners: Vec<Pubkey>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let owner_key = ctx.accounts.owner.key();
+    let alarm = &mut ctx.accounts.alarm;
+
+    let keys: Vec<[u8; 32]> = co_owners.iter().map(|k| k.to_bytes()).collect();
+    helpers::validate_co_owners(&owner_key.to_bytes(), &keys).map_err(|e| match e {
+        "too_many_co_owners" => SolarmaError::TooManyCoOwners,
+        _ => SolarmaError::InvalidCoOwner,
+    })?;
+
+    let count = co_owners.len();
+    let mut slots = [Pubkey::default(); MAX_CO_OWNERS];
+    slots[..count].copy_from_slice(&co_owners);
+    alarm.co_owners = slots;
+
+    emit!(crate::events::CoOwnersUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: owner_key,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        co_owners,
+    });
+
+    msg!(
+        "Alarm {} co-owners updated ({} keys)",
+        alarm.alarm_id,
+        count
+    );
+    Ok(())
+}
@@ -0,0 +1,64 @@
+//! Set delegate instruction - owner registers a session key on their profile
+//!
+//! The delegate (e.g. a hot key stored in the app) may sign `ack_awake`
+//! and `claim` for every alarm of the owner until it expires, so the
+//! morning flow needs no wallet round-trip. Funds always go to the owner.
+//! Passing `Pubkey::default()` revokes the delegate.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::UserProfile;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetDelegate<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_set_delegate(
+    ctx: Context<SetDelegate>,
+    delegate: Pubkey,
+    expires_at: i64,
+) -> Result<()> {
+    let owner_key = ctx.accounts.owner.key();
+    let clock = Clock::get()?;
+
+    helpers::validate_delegate(
+        &owner_key.to_bytes(),
+        &delegate.to_bytes(),
+        expires_at,
+        clock.unix_timestamp,
+    )
+    .map_err(|e| match e {
+        "overflow" => SolarmaError::Overflow,
+        _ => SolarmaError::InvalidDelegate,
+    })?;
+
+    let expires_at = if delegate == Pubkey::default() {
+        0
+    } else {
+        expires_at
+    };
+    let profile = &mut ctx.accounts.user_profile;
+    profile.delegate = delegate;
+    profile.delegate_expires_at = expires_at;
+
+    emit!(crate::events::DelegateUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: owner_key,
+        delegate,
+        expires_at,
+    });
+
+    msg!("Delegate for {} set to {}", owner_key, delegate);
+    Ok(())
+}
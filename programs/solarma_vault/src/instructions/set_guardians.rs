@@ -0,0 +1,61 @@
+//! Set guardians instruction - owner registers recovery guardians on their profile
+//!
+//! If the owner key is lost after an alarm is acknowledged, any 2 of the
+//! guardians can redirect its deposit to `recovery_address` (see
+//! `approve_recovery` / `recover_alarm`). The address is chosen here, by the
+//! owner, so guardians can never pay themselves. An empty list disables
+//! recovery.
+
+use crate::constants::MAX_GUARDIANS;
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::UserProfile;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetGuardians<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_set_guardians(
+    ctx: Context<SetGuardians>,
+    guardians: Vec<Pubkey>,
+    recovery_address: Pubkey,
+) -> Result<()> {
+    let owner_key = ctx.accounts.owner.key();
+
+    let keys: Vec<[u8; 32]> = guardians.iter().map(|k| k.to_bytes()).collect();
+    helpers::validate_guardians(&owner_key.to_bytes(), &keys, &recovery_address.to_bytes())
+        .map_err(|_| SolarmaError::InvalidGuardians)?;
+
+    let recovery_address = if guardians.is_empty() {
+        Pubkey::default()
+    } else {
+        recovery_address
+    };
+    let count = guardians.len();
+    let mut slots = [Pubkey::default(); MAX_GUARDIANS];
+    slots[..count].copy_from_slice(&guardians);
+    let profile = &mut ctx.accounts.user_profile;
+    profile.guardians = slots;
+    profile.recovery_address = recovery_address;
+
+    emit!(crate::events::GuardiansUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: owner_key,
+        guardians,
+        recovery_address,
+    });
+
+    msg!("Guardians for {} updated ({} keys)", owner_key, count);
+    Ok(())
+}
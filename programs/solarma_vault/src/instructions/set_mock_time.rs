@@ -0,0 +1,42 @@
+//! Set mock time instruction - admin moves the localnet test clock
+//!
+//! Only compiled with the `test-clock` feature. Writes the `MockClock` PDA
+//! that [`crate::clock::get`] reads when it is passed as the last remaining
+//! account; 0 falls back to the `Clock` sysvar. Never deploy a build with
+//! this feature: the admin could move time for every alarm.
+
+use crate::state::{GlobalConfig, MockClock};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetMockTime<'info> {
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = MockClock::SIZE,
+        seeds = [b"mock-clock"],
+        bump
+    )]
+    pub mock_clock: Account<'info, MockClock>,
+
+    #[account(
+        has_one = admin,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_set_mock_time(ctx: Context<SetMockTime>, unix_timestamp: i64) -> Result<()> {
+    let mock_clock = &mut ctx.accounts.mock_clock;
+    mock_clock.unix_timestamp = unix_timestamp;
+    mock_clock.bump = ctx.bumps.mock_clock;
+
+    msg!("Mock time set to {}", unix_timestamp);
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! Set treasury signers instruction - register or replace the treasury multisig
+//!
+//! Needs the same authority as `withdraw_treasury`: the admin while no
+//! signers are set, then 2 of the current 3. All-default keys return the
+//! treasury to admin control.
+
+use crate::constants::TREASURY_SIGNER_COUNT;
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::withdraw_treasury::require_treasury_authority;
+use crate::state::GlobalConfig;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetTreasurySigners<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Config admin, or a current treasury signer once the multisig is set
+    pub authority: Signer<'info>,
+}
+
+pub fn process_set_treasury_signers(
+    ctx: Context<SetTreasurySigners>,
+    signers: [Pubkey; TREASURY_SIGNER_COUNT],
+) -> Result<()> {
+    require_treasury_authority(
+        &ctx.accounts.config,
+        &ctx.accounts.authority,
+        ctx.remaining_accounts,
+    )?;
+    let keys: Vec<[u8; 32]> = signers.iter().map(|k| k.to_bytes()).collect();
+    require!(
+        helpers::validate_treasury_signers(&keys),
+        SolarmaError::InvalidTreasurySigners
+    );
+
+    ctx.accounts.config.treasury_signers = signers;
+
+    emit!(crate::events::TreasurySignersUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        signers,
+    });
+
+    msg!(
+        "Treasury signers updated by {}",
+        ctx.accounts.authority.key()
+    );
+    Ok(())
+}
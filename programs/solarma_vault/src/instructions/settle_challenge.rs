@@ -0,0 +1,127 @@
+//! Settle challenge instruction - pay out one participant after the deadline
+//!
+//! Permissionless so keepers can drain a challenge. Winners split the
+//! whole pot equally (failed stakes are redistributed pro-rata). If nobody
+//! acknowledged, each failed stake is burned instead. The participant
+//! account is closed to its user in every case.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Challenge, ChallengeParticipant, GlobalConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SettleChallenge<'info> {
+    #[account(
+        mut,
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.challenge_id.to_le_bytes()],
+        bump = challenge.bump
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        mut,
+        has_one = challenge,
+        has_one = user,
+        seeds = [b"participant", challenge.key().as_ref(), user.key().as_ref()],
+        bump = participant.bump,
+        close = user
+    )]
+    pub participant: Account<'info, ChallengeParticipant>,
+
+    /// Participant being settled - receives payout and participant rent
+    /// CHECK: Verified via `participant.user`
+    #[account(mut)]
+    pub user: UncheckedAccount<'info>,
+
+    /// Global config naming the deployment's burn sink
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Receives failed stakes when there are no winners
+    /// CHECK: Validated against the config's burn sink
+    #[account(
+        mut,
+        constraint = sink.key() == config.effective_burn_sink() @ SolarmaError::InvalidSinkAddress
+    )]
+    pub sink: UncheckedAccount<'info>,
+
+    /// Anyone can settle after the deadline
+    pub caller: Signer<'info>,
+}
+
+pub fn process_settle_challenge(ctx: Context<SettleChallenge>) -> Result<()> {
+    let clock = Clock::get()?;
+    let acked = ctx.accounts.participant.acked;
+    let challenge = &ctx.accounts.challenge;
+
+    require!(
+        clock.unix_timestamp >= challenge.deadline,
+        SolarmaError::DeadlineNotPassed
+    );
+
+    let mut payout = 0;
+    let mut burned = 0;
+    if acked {
+        payout = helpers::challenge_winner_share(
+            challenge.total_pot,
+            challenge.paid_out,
+            challenge.acked_count,
+            challenge.winners_paid,
+        )
+        .ok_or(SolarmaError::Overflow)?;
+    } else if challenge.acked_count == 0 {
+        burned = challenge.deposit_amount;
+    }
+    // Otherwise: failed stake stays in the pot for the winners
+
+    let moved = payout.checked_add(burned).ok_or(SolarmaError::Overflow)?;
+    if moved > 0 {
+        **ctx
+            .accounts
+            .challenge
+            .to_account_info()
+            .try_borrow_mut_lamports()? -= moved;
+    }
+    if payout > 0 {
+        **ctx.accounts.user.try_borrow_mut_lamports()? += payout;
+    }
+    if burned > 0 {
+        **ctx.accounts.sink.try_borrow_mut_lamports()? += burned;
+    }
+
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.paid_out = challenge
+        .paid_out
+        .checked_add(moved)
+        .ok_or(SolarmaError::Overflow)?;
+    challenge.settled_count = challenge
+        .settled_count
+        .checked_add(1)
+        .ok_or(SolarmaError::Overflow)?;
+    if acked {
+        challenge.winners_paid = challenge
+            .winners_paid
+            .checked_add(1)
+            .ok_or(SolarmaError::Overflow)?;
+    }
+
+    emit!(crate::events::ChallengeSettled {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        user: ctx.accounts.user.key(),
+        challenge: challenge.key(),
+        acked,
+        payout,
+        burned,
+    });
+
+    msg!(
+        "Challenge {} settled for {}: payout={}, burned={}",
+        challenge.challenge_id,
+        ctx.accounts.user.key(),
+        payout,
+        burned
+    );
+    Ok(())
+}
@@ -0,0 +1,87 @@
+//! Settle duel instruction - pay out both sides and close the duel
+//!
+//! Permissionless. Runs once both sides acked, after both deadlines, or
+//! (for an unaccepted duel) once the accept window closed. See
+//! `helpers::duel_payouts`; the duel rent returns to the creator.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::Duel;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SettleDuel<'info> {
+    #[account(
+        mut,
+        has_one = creator,
+        has_one = opponent,
+        seeds = [b"duel", duel.creator.as_ref(), &duel.duel_id.to_le_bytes()],
+        bump = duel.bump,
+        close = creator
+    )]
+    pub duel: Account<'info, Duel>,
+
+    /// Duel creator - receives their payout and the duel rent
+    /// CHECK: Verified via `duel.has_one = creator`
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// Duel opponent - receives their payout
+    /// CHECK: Verified via `duel.has_one = opponent`
+    #[account(mut)]
+    pub opponent: UncheckedAccount<'info>,
+
+    /// Anyone can settle
+    pub caller: Signer<'info>,
+}
+
+pub fn process_settle_duel(ctx: Context<SettleDuel>) -> Result<()> {
+    let clock = Clock::get()?;
+    let duel = &ctx.accounts.duel;
+
+    require!(
+        helpers::can_settle_duel(
+            duel.accepted,
+            duel.creator_acked,
+            duel.opponent_acked,
+            duel.creator_wake_time.min(duel.opponent_wake_time),
+            duel.creator_deadline.max(duel.opponent_deadline),
+            clock.unix_timestamp,
+        ),
+        SolarmaError::DuelNotSettleable
+    );
+
+    let (creator_payout, opponent_payout) = helpers::duel_payouts(
+        duel.stake,
+        duel.accepted,
+        duel.creator_acked,
+        duel.opponent_acked,
+    )
+    .ok_or(SolarmaError::Overflow)?;
+
+    // The creator's share stays in the account and leaves with `close = creator`
+    if opponent_payout > 0 {
+        **ctx
+            .accounts
+            .duel
+            .to_account_info()
+            .try_borrow_mut_lamports()? -= opponent_payout;
+        **ctx.accounts.opponent.try_borrow_mut_lamports()? += opponent_payout;
+    }
+
+    emit!(crate::events::DuelSettled {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        duel: ctx.accounts.duel.key(),
+        creator_payout,
+        opponent_payout,
+    });
+
+    msg!(
+        "Duel {} settled: creator={}, opponent={}",
+        ctx.accounts.duel.duel_id,
+        creator_payout,
+        opponent_payout
+    );
+    Ok(())
+}
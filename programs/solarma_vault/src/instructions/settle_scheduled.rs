@@ -0,0 +1,134 @@
+//! Settle scheduled instruction - an automation thread slashes or sweeps an
+//! alarm through its `SettlementSchedule` (permissionless)
+//!
+//! Every account is fixed when the schedule is created, and the only
+//! signer is the fee payer, so the instruction can be registered once and
+//! replayed by any scheduler. The alarm is settled exactly as
+//! `crank_settle` would settle it: a slash pays the fee payer the slasher
+//! bounty, a sweep returns the vault to the owner. The schedule closes to
+//! its creator.
+//!
+//! Fails with NothingToSettle when neither settlement is open yet (a snooze
+//! or reschedule moved the deadline, a dispute or recovery is pending, the
+//! protocol is paused, or an insurance share is owed and the pool is not
+//! passed) so the thread can retry later. Alarms with an unclaimed penalty
+//! are never swept here; `sweep_acknowledged` settles them. If the alarm was
+//! settled some other way, `close_settlement_schedule` reclaims the rent.
+
+use crate::error::SolarmaError;
+use crate::instructions::crank_settle::{slash_expired, sweep_expired, Settlement};
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::state::{
+    Alarm, AlarmIndex, AlarmStatus, GlobalConfig, InsurancePool, SettlementSchedule, Treasury,
+    Vault,
+};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SettleScheduled<'info> {
+    #[account(
+        mut,
+        seeds = [b"schedule", alarm.key().as_ref()],
+        bump = schedule.bump,
+        has_one = alarm,
+        has_one = owner,
+        has_one = penalty_recipient,
+        has_one = creator,
+        close = creator
+    )]
+    pub schedule: Account<'info, SettlementSchedule>,
+
+    #[account(mut)]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA holding the deposit - closed to whichever side settles
+    #[account(
+        mut,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump = alarm.vault_bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Key is verified by `schedule.has_one = owner`; receives a sweep
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: Key is verified by `schedule.has_one = penalty_recipient`;
+    /// receives a slash
+    #[account(mut)]
+    pub penalty_recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Key is verified by `schedule.has_one = creator`; receives the
+    /// schedule's rent
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Treasury PDA receiving the protocol fee of a slash
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Thread fee payer; receives the slasher bounty
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Insurance pool; required for a slash while `config.insurance_bps` is set
+    #[account(mut, seeds = [b"insurance"], bump = insurance_pool.bump)]
+    pub insurance_pool: Option<Account<'info, InsurancePool>>,
+
+    /// Owner's alarm index; the settled alarm is removed from it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", alarm.owner.as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+}
+
+pub fn process_settle_scheduled(ctx: Context<SettleScheduled>) -> Result<()> {
+    let accounts = ctx.accounts;
+    let clock = Clock::get()?;
+
+    let (settlement, recipient) = match accounts.alarm.status {
+        AlarmStatus::Created => (
+            slash_expired(
+                &accounts.config,
+                &mut accounts.treasury,
+                accounts.insurance_pool.as_mut(),
+                &accounts.payer,
+                &mut accounts.alarm,
+                &accounts.vault,
+                &accounts.penalty_recipient,
+                crate::events::event_authority!(accounts),
+                clock.unix_timestamp,
+            )?,
+            accounts.penalty_recipient.to_account_info(),
+        ),
+        AlarmStatus::Acknowledged => (
+            sweep_expired(
+                &mut accounts.alarm,
+                &accounts.vault,
+                &accounts.owner,
+                crate::events::event_authority!(accounts),
+                clock.unix_timestamp,
+            )?,
+            accounts.owner.to_account_info(),
+        ),
+        _ => return err!(SolarmaError::InvalidAlarmState),
+    };
+    require!(
+        !matches!(settlement, Settlement::Skipped),
+        SolarmaError::NothingToSettle
+    );
+    accounts.vault.close(recipient)?;
+    remove_from_alarm_index(accounts.alarm_index.as_deref_mut(), accounts.alarm.alarm_id);
+
+    msg!(
+        "Scheduled settlement of alarm {} executed",
+        accounts.alarm.alarm_id
+    );
+    Ok(())
+}
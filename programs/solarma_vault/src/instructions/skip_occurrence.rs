@@ -0,0 +1,76 @@
+//! Skip occurrence instruction - owner marks an upcoming series occurrence
+//! as skipped (vacation mode)
+//!
+//! `spawn_next_occurrence` passes over the marked slot, so no alarm exists
+//! for it and nothing can be slashed. The skipped slot does not use up an
+//! occurrence: the escrow stays on the series and the series simply ends
+//! one interval later. Each series allows `MAX_SERIES_SKIPS_PER_PERIOD`
+//! skips per 30 days.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::AlarmSeries;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SkipOccurrence<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"series", owner.key().as_ref(), &series.series_id.to_le_bytes()],
+        bump = series.bump
+    )]
+    pub series: Account<'info, AlarmSeries>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_skip_occurrence(ctx: Context<SkipOccurrence>, occurrence_time: i64) -> Result<()> {
+    let series_key = ctx.accounts.series.key();
+    let series = &mut ctx.accounts.series;
+    let clock = Clock::get()?;
+
+    require!(
+        series.remaining_occurrences > 0,
+        SolarmaError::SeriesExhausted
+    );
+    // Only a future slot, before its alarm time, that is not skipped yet
+    let index = helpers::series_slot_index(
+        series.next_alarm_time,
+        series.interval_seconds,
+        occurrence_time,
+        clock.unix_timestamp,
+    )
+    .ok_or(SolarmaError::SkipUnavailable)?;
+    require!(
+        series.skipped_slots & (1 << index) == 0,
+        SolarmaError::SkipUnavailable
+    );
+
+    let (skip_period, skips_in_period) = helpers::apply_skip_quota(
+        series.skip_period,
+        series.skips_in_period,
+        clock.unix_timestamp,
+    )
+    .map_err(|_| SolarmaError::SkipLimitReached)?;
+    series.skipped_slots |= 1 << index;
+    series.skip_period = skip_period;
+    series.skips_in_period = skips_in_period;
+
+    emit!(crate::events::OccurrenceSkipped {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: series.owner,
+        series: series_key,
+        occurrence_time,
+        skips_in_period,
+    });
+
+    msg!(
+        "Series {} skips occurrence at {} ({} this period)",
+        series.series_id,
+        occurrence_time,
+        skips_in_period
+    );
+    Ok(())
+}
@@ -1,16 +1,32 @@
 //! Slash instruction - transfer deposit after deadline (permissionless)
 
-use crate::constants::{BUDDY_ONLY_SECONDS, BURN_SINK};
 use crate::error::SolarmaError;
-use crate::state::{Alarm, AlarmStatus, PenaltyRoute, Vault};
+use crate::helpers;
+use crate::instructions::create_campaign::match_donation;
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::instructions::init_daily_stats::{record_daily_stat, DailyStat};
+use crate::instructions::init_insurance_pool::collect_insurance_share;
+use crate::instructions::init_savings_vault::credit_savings;
+use crate::instructions::initialize::pay_referral_reward;
+use crate::instructions::join_leaderboard::record_leaderboard_result;
+use crate::state::{
+    Alarm, AlarmIndex, AlarmStatus, Campaign, DailyStats, GlobalConfig, InsurancePool,
+    LeaderboardEntry, LeaderboardEpoch, PenaltyRoute, Treasury, UserProfile, Vault,
+};
 use anchor_lang::prelude::*;
+use anchor_spl::memo::{self, BuildMemo, Memo};
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct Slash<'info> {
     #[account(
         mut,
-        // Slash is only possible while alarm is still unresolved (Created).
-        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState
+        // Created alarms are slashable; Acknowledged ones get a dedicated
+        // error from `post_deadline_resolution` below.
+        constraint = matches!(alarm.status, AlarmStatus::Created | AlarmStatus::Acknowledged)
+            @ SolarmaError::InvalidAlarmState,
+        // Progressive alarms are slashed in tranches via slash_partial.
+        constraint = !alarm.is_progressive() @ SolarmaError::InvalidAlarmState
     )]
     pub alarm: Account<'info, Alarm>,
 
@@ -24,87 +40,340 @@ pub struct Slash<'info> {
     pub vault: Account<'info, Vault>,
 
     /// Penalty destination - varies based on route
-    /// CHECK: Validated against alarm.penalty_destination or BURN_SINK
+    /// CHECK: Validated against alarm.penalty_destination or the config's burn sink
     #[account(mut)]
     pub penalty_recipient: UncheckedAccount<'info>,
 
-    /// Anyone can trigger slash after deadline
+    /// Global config - slashing is halted while the protocol is paused
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ SolarmaError::ProtocolPaused
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Treasury PDA receiving the protocol fee
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Owner's leaderboard entry for the current epoch; pass with `leaderboard_epoch`
+    #[account(
+        mut,
+        constraint = leaderboard_entry.owner == alarm.owner @ SolarmaError::InvalidLeaderboardEntry,
+        seeds = [b"leaderboard".as_ref(), &leaderboard_entry.epoch.to_le_bytes(), alarm.owner.as_ref()],
+        bump = leaderboard_entry.bump
+    )]
+    pub leaderboard_entry: Option<Account<'info, LeaderboardEntry>>,
+
+    /// Current leaderboard epoch; pass with `leaderboard_entry`
+    #[account(
+        mut,
+        seeds = [b"epoch".as_ref(), &leaderboard_epoch.epoch.to_le_bytes()],
+        bump = leaderboard_epoch.bump
+    )]
+    pub leaderboard_epoch: Option<Account<'info, LeaderboardEpoch>>,
+
+    /// Owner's alarm index; the slashed alarm is removed from it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", alarm.owner.as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+
+    /// Owner's profile; when it names a referrer, `referral_fee_bps` of the
+    /// protocol fee goes to `referrer`
+    #[account(
+        seeds = [b"user-profile", alarm.owner.as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Referrer named by `user_profile`; required when it earns a share
+    /// CHECK: Validated against user_profile.referrer in `pay_referral_reward`
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Anyone can trigger slash after deadline; receives the slasher bounty
+    #[account(mut)]
     pub caller: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// SPL Memo program; when passed, a settlement memo is recorded
+    pub memo_program: Option<Program<'info, Memo>>,
+
+    /// Today's protocol counters; the slash is counted on it
+    #[account(mut, seeds = [b"daily-stats".as_ref(), &daily_stats.day.to_le_bytes()], bump = daily_stats.bump)]
+    pub daily_stats: Option<Account<'info, DailyStats>>,
+
+    /// Insurance pool; required while `config.insurance_bps` is set
+    #[account(mut, seeds = [b"insurance"], bump = insurance_pool.bump)]
+    pub insurance_pool: Option<Account<'info, InsurancePool>>,
+
+    /// Donation matching campaign for the alarm's charity; Donate route only
+    #[account(
+        mut,
+        seeds = [b"campaign", campaign.sponsor.as_ref(), &campaign.campaign_id.to_le_bytes()],
+        bump = campaign.bump
+    )]
+    pub campaign: Option<Account<'info, Campaign>>,
+}
+
+/// Record `payload` through the SPL Memo program, if one was passed, so
+/// wallets that display memos show what happened to the deposit.
+pub(crate) fn record_settlement_memo(
+    memo_program: Option<&Program<'_, Memo>>,
+    payload: &str,
+) -> Result<()> {
+    if let Some(memo_program) = memo_program {
+        memo::build_memo(
+            CpiContext::new(memo_program.to_account_info(), BuildMemo {}),
+            payload.as_bytes(),
+        )?;
+    }
+    Ok(())
 }
 
 pub fn process_slash(ctx: Context<Slash>) -> Result<()> {
-    let alarm_key = ctx.accounts.alarm.key();
-    let caller_key = ctx.accounts.caller.key();
-    let recipient_key = ctx.accounts.penalty_recipient.key();
-    let alarm = &mut ctx.accounts.alarm;
-    let clock = Clock::get()?;
+    let bounty_bps = ctx.accounts.config.slasher_bounty_bps;
+    settle_slash(ctx.accounts, ctx.remaining_accounts, bounty_bps, "slash")
+}
 
-    // CRITICAL: Can only slash AFTER deadline
-    require!(
-        clock.unix_timestamp >= alarm.deadline,
-        SolarmaError::DeadlineNotPassed
-    );
+/// Slash body shared by `slash` and `sweep_expired`, which nests these
+/// accounts and pays a reduced `bounty_bps`. `action` names the settlement
+/// in the memo.
+pub(crate) fn settle_slash(
+    accounts: &mut Slash,
+    remaining_accounts: &[AccountInfo],
+    bounty_bps: u16,
+    action: &str,
+) -> Result<()> {
+    let alarm_key = accounts.alarm.key();
+    let caller_key = accounts.caller.key();
+    let recipient_key = accounts.penalty_recipient.key();
+    let alarm = &mut accounts.alarm;
+    let clock = crate::clock::get(remaining_accounts)?;
+    let remaining_accounts = crate::clock::without_mock_clock(remaining_accounts);
 
-    // Validate penalty recipient based on route
     let route = PenaltyRoute::try_from(alarm.penalty_route)
         .map_err(|_| SolarmaError::InvalidPenaltyRoute)?;
 
-    match route {
-        PenaltyRoute::Burn => {
-            require!(
-                ctx.accounts.penalty_recipient.key() == BURN_SINK,
-                SolarmaError::InvalidPenaltyRecipient
-            );
+    // An open dispute freezes slashing until the arbiter rules or it lapses
+    require!(
+        !helpers::is_dispute_active(alarm.dispute_until, clock.unix_timestamp),
+        SolarmaError::DisputeActive
+    );
+
+    // CRITICAL: Only unacknowledged alarms past their deadline are slashable;
+    // on the Buddy route the buddy gets an exclusive first window.
+    let resolution = helpers::post_deadline_resolution(
+        alarm.status,
+        route == PenaltyRoute::Buddy && alarm.challenger_stake == 0,
+        Some(caller_key) == alarm.penalty_destination,
+        alarm.deadline,
+        alarm.claim_grace_seconds,
+        clock.unix_timestamp,
+    )
+    .map_err(|e| match e {
+        "deadline_not_passed" => SolarmaError::DeadlineNotPassed,
+        "buddy_only_window" => SolarmaError::BuddyOnlyWindow,
+        _ => SolarmaError::InvalidAlarmState,
+    })?;
+    require!(
+        resolution == helpers::Resolution::Slash,
+        SolarmaError::AlarmAcknowledged
+    );
+
+    // Validate penalty recipient based on route
+    if alarm.challenger_stake > 0 {
+        // Matched bet: the challenger wins the whole pot regardless of route.
+        let challenger = alarm.challenger.ok_or(SolarmaError::BetUnavailable)?;
+        require!(
+            recipient_key == challenger,
+            SolarmaError::InvalidPenaltyRecipient
+        );
+    } else {
+        match route {
+            PenaltyRoute::Burn => {
+                require!(
+                    accounts.penalty_recipient.key() == accounts.config.effective_burn_sink(),
+                    SolarmaError::InvalidPenaltyRecipient
+                );
+            }
+            PenaltyRoute::Donate
+            | PenaltyRoute::Buddy
+            | PenaltyRoute::Split
+            | PenaltyRoute::Savings => {
+                let expected = alarm
+                    .penalty_destination
+                    .ok_or(SolarmaError::PenaltyDestinationNotSet)?;
+                require!(
+                    accounts.penalty_recipient.key() == expected,
+                    SolarmaError::InvalidPenaltyRecipient
+                );
+            }
         }
-        PenaltyRoute::Donate => {
-            let expected = alarm
-                .penalty_destination
-                .ok_or(SolarmaError::PenaltyDestinationNotSet)?;
+    }
+
+    let slashed = helpers::bet_pot(alarm.remaining_amount, alarm.challenger_stake)
+        .ok_or(SolarmaError::Overflow)?;
+
+    // Take the protocol fee and keeper bounty out of the vault before it is closed
+    let fee =
+        helpers::bps_of(slashed, accounts.config.protocol_fee_bps).ok_or(SolarmaError::Overflow)?;
+    let bounty = helpers::bps_of(slashed, bounty_bps).ok_or(SolarmaError::Overflow)?;
+    let referral = pay_referral_reward(
+        &accounts.vault.to_account_info(),
+        accounts.user_profile.as_deref(),
+        accounts.referrer.as_deref(),
+        fee,
+        accounts.config.referral_fee_bps,
+        alarm_key,
+    )?;
+    let treasury_fee = fee.checked_sub(referral).ok_or(SolarmaError::Overflow)?;
+    if treasury_fee > 0 {
+        **accounts.vault.to_account_info().try_borrow_mut_lamports()? -= treasury_fee;
+        **accounts
+            .treasury
+            .to_account_info()
+            .try_borrow_mut_lamports()? += treasury_fee;
+        let treasury = &mut accounts.treasury;
+        treasury.total_collected = treasury
+            .total_collected
+            .checked_add(treasury_fee)
+            .ok_or(SolarmaError::Overflow)?;
+    }
+    if bounty > 0 {
+        **accounts.vault.to_account_info().try_borrow_mut_lamports()? -= bounty;
+        **accounts
+            .caller
+            .to_account_info()
+            .try_borrow_mut_lamports()? += bounty;
+    }
+    let insurance = collect_insurance_share(
+        &accounts.vault.to_account_info(),
+        accounts.insurance_pool.as_mut(),
+        slashed,
+        accounts.config.insurance_bps,
+    )?;
+
+    // Split route: pay every leg after the first from remaining_accounts
+    // (in order). The first leg is penalty_recipient and receives the rest.
+    if route == PenaltyRoute::Split && alarm.challenger_stake == 0 {
+        let splits = alarm.active_splits();
+        let distributable = slashed
+            .checked_sub(fee)
+            .and_then(|v| v.checked_sub(bounty))
+            .and_then(|v| v.checked_sub(insurance))
+            .ok_or(SolarmaError::Overflow)?;
+        let bps: Vec<u16> = splits.iter().map(|s| s.bps).collect();
+        let shares =
+            helpers::split_shares(distributable, &bps).ok_or(SolarmaError::InvalidPenaltySplit)?;
+        require!(
+            remaining_accounts.len() == splits.len() - 1,
+            SolarmaError::InvalidPenaltyRecipient
+        );
+        for ((leg, share), account) in splits
+            .iter()
+            .zip(shares.iter())
+            .skip(1)
+            .zip(remaining_accounts.iter())
+        {
             require!(
-                ctx.accounts.penalty_recipient.key() == expected,
+                account.key() == leg.destination && account.is_writable,
                 SolarmaError::InvalidPenaltyRecipient
             );
+            **accounts.vault.to_account_info().try_borrow_mut_lamports()? -= *share;
+            **account.try_borrow_mut_lamports()? += *share;
         }
-        PenaltyRoute::Buddy => {
-            let expected = alarm
-                .penalty_destination
-                .ok_or(SolarmaError::PenaltyDestinationNotSet)?;
-            require!(
-                ctx.accounts.penalty_recipient.key() == expected,
-                SolarmaError::InvalidPenaltyRecipient
-            );
+    }
 
-            // During the first buddy-only window, only buddy can slash.
-            let buddy_only_end = alarm
-                .deadline
-                .checked_add(BUDDY_ONLY_SECONDS)
-                .ok_or(SolarmaError::Overflow)?;
-            if clock.unix_timestamp < buddy_only_end {
-                require!(caller_key == expected, SolarmaError::BuddyOnlyWindow);
-            }
-        }
+    // Savings route: extend the owner's lock for the incoming penalty
+    if route == PenaltyRoute::Savings && alarm.challenger_stake == 0 {
+        let credited = slashed
+            .checked_sub(fee)
+            .and_then(|v| v.checked_sub(bounty))
+            .and_then(|v| v.checked_sub(insurance))
+            .ok_or(SolarmaError::Overflow)?;
+        credit_savings(
+            &accounts.penalty_recipient.to_account_info(),
+            &alarm.owner,
+            credited,
+            clock.unix_timestamp,
+        )?;
     }
 
-    // The `close = penalty_recipient` constraint automatically transfers
-    // all lamports (rent + remaining deposit) to penalty_recipient
+    // Donate route: a campaign for this charity matches the donation 1:1
+    if let Some(campaign) = accounts.campaign.as_mut() {
+        require!(
+            route == PenaltyRoute::Donate && alarm.challenger_stake == 0,
+            SolarmaError::CampaignMismatch
+        );
+        let donated = slashed
+            .checked_sub(fee)
+            .and_then(|v| v.checked_sub(bounty))
+            .and_then(|v| v.checked_sub(insurance))
+            .ok_or(SolarmaError::Overflow)?;
+        match_donation(
+            campaign,
+            &accounts.penalty_recipient.to_account_info(),
+            alarm_key,
+            donated,
+        )?;
+    }
 
-    let slashed = alarm.remaining_amount;
+    // The `close = penalty_recipient` constraint automatically transfers
+    // all remaining lamports (rent + deposit minus fee, bounty, insurance
+    // share and other split legs) to penalty_recipient
 
-    emit!(crate::events::AlarmSlashed {
-        alarm: alarm_key,
-        alarm_id: alarm.alarm_id,
-        penalty_recipient: recipient_key,
-        slashed_amount: slashed,
-        caller: caller_key,
-    });
+    crate::events::emit_settlement(
+        crate::events::event_authority!(accounts),
+        crate::events::AlarmSlashed {
+            version: crate::events::EVENT_VERSION,
+            timestamp: clock.unix_timestamp,
+            alarm: alarm_key,
+            alarm_id: alarm.alarm_id,
+            penalty_recipient: recipient_key,
+            slashed_amount: slashed,
+            caller: caller_key,
+            caller_bounty: bounty,
+            protocol_fee: fee,
+            penalty_route: alarm.penalty_route,
+        },
+    )?;
 
     msg!("Slashed {} lamports to {:?}", slashed, route);
 
-    // Mark as slashed (terminal state)
+    let memo_route = if alarm.challenger_stake > 0 {
+        "challenger"
+    } else {
+        helpers::penalty_route_name(alarm.penalty_route)
+    };
+    record_settlement_memo(
+        accounts.memo_program.as_ref(),
+        &helpers::settlement_memo(action, alarm.alarm_id, slashed, memo_route),
+    )?;
+
+    record_leaderboard_result(
+        accounts.leaderboard_entry.as_mut(),
+        accounts.leaderboard_epoch.as_mut(),
+        false,
+        alarm.remaining_amount,
+        clock.unix_timestamp,
+    )?;
+
+    // Mark as slashed (terminal state); the owner's own deposit stays
+    // claimable from the insurance pool on an attested outage
     alarm.status = AlarmStatus::Slashed;
+    alarm.insured_loss = alarm.remaining_amount;
     alarm.remaining_amount = 0;
+    remove_from_alarm_index(accounts.alarm_index.as_deref_mut(), alarm.alarm_id);
+    record_daily_stat(
+        accounts.daily_stats.as_mut(),
+        DailyStat::Slashed(slashed),
+        clock.unix_timestamp,
+    );
 
     msg!("Alarm slashed by {}", caller_key);
     Ok(())
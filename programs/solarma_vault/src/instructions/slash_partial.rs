@@ -0,0 +1,218 @@
+//! Slash partial instruction - forfeit the tranches of a progressive alarm
+//! that have come due since the deadline (permissionless, repeatable)
+//!
+//! Once every tranche has been slashed the alarm becomes Slashed and the
+//! vault is closed to the penalty recipient. Until then the owner can
+//! recover the rest with `claim_late`.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::instructions::init_savings_vault::credit_savings;
+use crate::instructions::initialize::pay_referral_reward;
+use crate::state::{
+    Alarm, AlarmIndex, AlarmStatus, GlobalConfig, PenaltyRoute, Treasury, UserProfile, Vault,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SlashPartial<'info> {
+    #[account(
+        mut,
+        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.is_progressive() @ SolarmaError::InvalidAlarmState
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA holding the deposit - closed once fully slashed
+    #[account(
+        mut,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump = alarm.vault_bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Penalty destination - varies based on route
+    /// CHECK: Validated against alarm.penalty_destination or the config's burn sink
+    #[account(mut)]
+    pub penalty_recipient: UncheckedAccount<'info>,
+
+    /// Global config - slashing is halted while the protocol is paused
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ SolarmaError::ProtocolPaused
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Treasury PDA receiving the protocol fee
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Owner's profile; when it names a referrer, `referral_fee_bps` of the
+    /// protocol fee goes to `referrer`
+    #[account(
+        seeds = [b"user-profile", alarm.owner.as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Referrer named by `user_profile`; required when it earns a share
+    /// CHECK: Validated against user_profile.referrer in `pay_referral_reward`
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Anyone can trigger a tranche; receives the slasher bounty
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Owner's alarm index; the fully slashed alarm is removed from it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", alarm.owner.as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+}
+
+pub fn process_slash_partial(ctx: Context<SlashPartial>) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let caller_key = ctx.accounts.caller.key();
+    let recipient_key = ctx.accounts.penalty_recipient.key();
+    let clock = Clock::get()?;
+
+    require!(
+        ctx.accounts.alarm.challenger_stake == 0,
+        SolarmaError::BetLocked
+    );
+    require!(
+        clock.unix_timestamp >= ctx.accounts.alarm.deadline,
+        SolarmaError::DeadlineNotPassed
+    );
+    require!(
+        !helpers::is_dispute_active(ctx.accounts.alarm.dispute_until, clock.unix_timestamp),
+        SolarmaError::DisputeActive
+    );
+
+    let alarm = &ctx.accounts.alarm;
+    helpers::validate_penalty_recipient(
+        alarm.penalty_route,
+        &recipient_key.to_bytes(),
+        &ctx.accounts.config.effective_burn_sink().to_bytes(),
+        alarm.penalty_destination.map(|d| d.to_bytes()).as_ref(),
+    )
+    .map_err(|e| match e {
+        "invalid_penalty_route" => SolarmaError::InvalidPenaltyRoute,
+        "penalty_destination_not_set" => SolarmaError::PenaltyDestinationNotSet,
+        _ => SolarmaError::InvalidPenaltyRecipient,
+    })?;
+
+    let due = helpers::partial_slash_due(
+        alarm.remaining_amount,
+        alarm.partial_slashed,
+        alarm.partial_slash_bps,
+        alarm.partial_slash_interval_seconds,
+        alarm.deadline,
+        clock.unix_timestamp,
+    )
+    .ok_or(SolarmaError::Overflow)?;
+    require!(due > 0, SolarmaError::NothingToSlash);
+
+    let fee =
+        helpers::bps_of(due, ctx.accounts.config.protocol_fee_bps).ok_or(SolarmaError::Overflow)?;
+    let bounty = helpers::bps_of(due, ctx.accounts.config.slasher_bounty_bps)
+        .ok_or(SolarmaError::Overflow)?;
+    let to_recipient = due
+        .checked_sub(fee)
+        .and_then(|v| v.checked_sub(bounty))
+        .ok_or(SolarmaError::Overflow)?;
+
+    let referral = pay_referral_reward(
+        &ctx.accounts.vault.to_account_info(),
+        ctx.accounts.user_profile.as_deref(),
+        ctx.accounts.referrer.as_deref(),
+        fee,
+        ctx.accounts.config.referral_fee_bps,
+        alarm_key,
+    )?;
+    let treasury_fee = fee.checked_sub(referral).ok_or(SolarmaError::Overflow)?;
+
+    **ctx
+        .accounts
+        .vault
+        .to_account_info()
+        .try_borrow_mut_lamports()? -= due - referral;
+    **ctx
+        .accounts
+        .treasury
+        .to_account_info()
+        .try_borrow_mut_lamports()? += treasury_fee;
+    **ctx
+        .accounts
+        .caller
+        .to_account_info()
+        .try_borrow_mut_lamports()? += bounty;
+    **ctx
+        .accounts
+        .penalty_recipient
+        .to_account_info()
+        .try_borrow_mut_lamports()? += to_recipient;
+
+    if ctx.accounts.alarm.penalty_route == PenaltyRoute::Savings as u8 {
+        credit_savings(
+            &ctx.accounts.penalty_recipient.to_account_info(),
+            &ctx.accounts.alarm.owner,
+            to_recipient,
+            clock.unix_timestamp,
+        )?;
+    }
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_collected = treasury
+        .total_collected
+        .checked_add(treasury_fee)
+        .ok_or(SolarmaError::Overflow)?;
+
+    let alarm = &mut ctx.accounts.alarm;
+    alarm.remaining_amount = alarm
+        .remaining_amount
+        .checked_sub(due)
+        .ok_or(SolarmaError::Overflow)?;
+    alarm.partial_slashed = alarm
+        .partial_slashed
+        .checked_add(due)
+        .ok_or(SolarmaError::Overflow)?;
+
+    emit!(crate::events::PartialSlashed {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        penalty_recipient: recipient_key,
+        slashed_amount: due,
+        total_slashed: alarm.partial_slashed,
+        remaining_amount: alarm.remaining_amount,
+        caller: caller_key,
+        caller_bounty: bounty,
+        protocol_fee: fee,
+    });
+
+    msg!(
+        "Partially slashed {} lamports ({} remaining)",
+        due,
+        alarm.remaining_amount
+    );
+
+    // Last tranche: the alarm is fully slashed, vault rent goes to the recipient
+    if alarm.remaining_amount == 0 {
+        alarm.status = AlarmStatus::Slashed;
+        remove_from_alarm_index(ctx.accounts.alarm_index.as_deref_mut(), alarm.alarm_id);
+        ctx.accounts
+            .vault
+            .close(ctx.accounts.penalty_recipient.to_account_info())?;
+        msg!("Alarm fully slashed by {}", caller_key);
+    }
+    Ok(())
+}
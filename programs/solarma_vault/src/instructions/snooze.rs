@@ -1,17 +1,39 @@
 //! Snooze instruction - reduce deposit for extra time
+//!
+//! By default a snooze adds the alarm's `snooze_extension_seconds` at the
+//! full cost. With `extension_minutes` (1–15) the owner picks the length
+//! instead and pays pro rata (`helpers::snooze_cost_scaled_for`).
+//!
+//! The first `free_snoozes` snoozes move the alarm without touching the
+//! deposit; paid snoozes then start the cost curve from its first step.
+//! The curve is the alarm's `snooze_model`: exponential (default), linear
+//! or flat.
+//!
+//! Costs follow the alarm's penalty route like a slash would: Burn goes to
+//! the config's burn sink, every other route to `penalty_destination` (the
+//! first leg for Split; Savings also restarts the savings lock). A missing
+//! destination falls back to the burn sink.
+//!
+//! A soft deadline shifts with the alarm.
+//!
+//! Passing the owner's profile adds the snooze to its lifetime totals.
 
-use crate::constants::{BURN_SINK, DEFAULT_SNOOZE_EXTENSION_SECONDS, MAX_SNOOZE_COUNT};
 use crate::error::SolarmaError;
 use crate::helpers;
-use crate::state::{Alarm, AlarmStatus, Vault};
+use crate::instructions::init_savings_vault::credit_savings;
+use crate::state::{
+    Alarm, AlarmStatus, GlobalConfig, PenaltyRoute, SnoozeModel, UserProfile, Vault,
+};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct Snooze<'info> {
     #[account(
         mut,
-        has_one = owner,
-        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState
+        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState,
+        constraint = alarm.is_authorized(&authority.key()) @ SolarmaError::UnauthorizedSigner,
+        // Snoozing would burn the challenger's stake
+        constraint = alarm.challenger_stake == 0 @ SolarmaError::BetLocked
     )]
     pub alarm: Account<'info, Alarm>,
 
@@ -23,25 +45,75 @@ pub struct Snooze<'info> {
     )]
     pub vault: Account<'info, Vault>,
 
-    /// Sink account receives snooze penalties
-    /// CHECK: This is validated against the BURN_SINK constant
+    /// Receives snooze penalties: the alarm's penalty destination, or
+    /// the config's burn sink for the Burn route
+    /// CHECK: Validated against alarm.penalty_route in the handler
+    #[account(mut)]
+    pub sink: UncheckedAccount<'info>,
+
+    /// Global config - snoozing is halted while the protocol is paused
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ SolarmaError::ProtocolPaused
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Owner's profile; the snooze is added to its lifetime totals
     #[account(
         mut,
-        constraint = sink.key() == BURN_SINK @ SolarmaError::InvalidSinkAddress
+        seeds = [b"user-profile", alarm.owner.as_ref()],
+        bump = user_profile.bump
     )]
-    pub sink: UncheckedAccount<'info>,
+    pub user_profile: Option<Account<'info, UserProfile>>,
 
+    /// Owner or co-owner requesting the snooze
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
-pub fn process_snooze(ctx: Context<Snooze>, expected_snooze_count: u8) -> Result<()> {
+/// Where snooze costs and soft-deadline forfeits go: the alarm's penalty
+/// destination on routes that pay it, otherwise `burn_sink`.
+pub(crate) fn penalty_sink(alarm: &Alarm, burn_sink: Pubkey) -> Result<Pubkey> {
+    let route = PenaltyRoute::try_from(alarm.penalty_route)
+        .map_err(|_| SolarmaError::InvalidPenaltyRoute)?;
+    Ok(match alarm.penalty_destination {
+        Some(destination) if helpers::routes_snooze_to_destination(route) => destination,
+        _ => burn_sink,
+    })
+}
+
+/// Move `amount` from `vault` to `sink`, which must be the alarm's
+/// `penalty_sink`. On the Savings route the owner's lock restarts.
+pub(crate) fn pay_penalty_sink(
+    alarm: &Alarm,
+    vault: &AccountInfo,
+    sink: &AccountInfo,
+    burn_sink: Pubkey,
+    amount: u64,
+    current_time: i64,
+) -> Result<()> {
+    let sink_key = penalty_sink(alarm, burn_sink)?;
+    require!(sink.key() == sink_key, SolarmaError::InvalidSinkAddress);
+    **vault.try_borrow_mut_lamports()? -= amount;
+    **sink.try_borrow_mut_lamports()? += amount;
+
+    if alarm.penalty_route == PenaltyRoute::Savings as u8 && sink_key != burn_sink {
+        credit_savings(sink, &alarm.owner, amount, current_time)?;
+    }
+    Ok(())
+}
+
+pub fn process_snooze(
+    ctx: Context<Snooze>,
+    expected_snooze_count: u8,
+    extension_minutes: Option<u8>,
+) -> Result<()> {
     let alarm_key = ctx.accounts.alarm.key();
-    let owner_key = ctx.accounts.owner.key();
     let alarm = &mut ctx.accounts.alarm;
-    let clock = Clock::get()?;
+    let clock = crate::clock::get(ctx.remaining_accounts)?;
 
     // CRITICAL: Cannot snooze BEFORE alarm time
     require!(
@@ -55,9 +127,10 @@ pub fn process_snooze(ctx: Context<Snooze>, expected_snooze_count: u8) -> Result
         SolarmaError::DeadlinePassed
     );
 
-    // Check snooze limit
+    // Check per-alarm snooze limit
+    let params = alarm.snooze_params();
     require!(
-        alarm.snooze_count < MAX_SNOOZE_COUNT,
+        !helpers::is_max_snooze_for(alarm.snooze_count, params.max_snoozes),
         SolarmaError::MaxSnoozesReached
     );
 
@@ -69,27 +142,75 @@ pub fn process_snooze(ctx: Context<Snooze>, expected_snooze_count: u8) -> Result
         SolarmaError::InvalidAlarmState
     );
 
-    // Calculate snooze cost (exponential: 10% * 2^snooze_count, capped at remaining)
-    let cost = helpers::snooze_cost(alarm.remaining_amount, alarm.snooze_count)
-        .ok_or(SolarmaError::Overflow)?;
-    require!(cost > 0, SolarmaError::InsufficientDeposit);
-
-    // C1: Rent-exempt guard — never drain vault below rent-exempt minimum.
-    // If we did, the Solana runtime would garbage-collect the account,
-    // making both claim and slash impossible (irrecoverable fund loss).
-    let rent = Rent::get()?;
-    let vault_info = ctx.accounts.vault.to_account_info();
-    let min_balance = rent.minimum_balance(vault_info.data_len());
-    let final_cost = helpers::cap_at_rent_exempt(cost, vault_info.lamports(), min_balance);
-    require!(final_cost > 0, SolarmaError::InsufficientDeposit);
-
-    // Transfer penalty from vault to sink
-    **ctx
-        .accounts
-        .vault
-        .to_account_info()
-        .try_borrow_mut_lamports()? -= final_cost;
-    **ctx.accounts.sink.try_borrow_mut_lamports()? += final_cost;
+    let extension_seconds = match extension_minutes {
+        Some(minutes) => {
+            require!(
+                helpers::validate_snooze_extension_minutes(minutes),
+                SolarmaError::InvalidSnoozeExtension
+            );
+            i64::from(minutes) * 60
+        }
+        None => params.extension_seconds,
+    };
+
+    // Penalties follow the alarm's route, with Burn as the fallback
+    let burn_sink = ctx.accounts.config.effective_burn_sink();
+    let sink_key = penalty_sink(alarm, burn_sink)?;
+    require!(
+        ctx.accounts.sink.key() == sink_key,
+        SolarmaError::InvalidSinkAddress
+    );
+
+    let model =
+        SnoozeModel::try_from(alarm.snooze_model).map_err(|_| SolarmaError::InvalidSnoozeConfig)?;
+
+    // Free snoozes skip the cost entirely. Yields the quoted cost and the
+    // cost actually charged after the rent-exempt guard
+    let paid_index = helpers::paid_snooze_index(alarm.snooze_count, alarm.free_snoozes);
+    let (quoted_cost, final_cost) = match paid_index {
+        None => (0, 0),
+        Some(index) => {
+            // Calculate snooze cost under the alarm's model (capped at
+            // remaining), scaled to the owner-selected length if one was given
+            let cost = match extension_minutes {
+                Some(minutes) => helpers::snooze_cost_scaled_for(
+                    alarm.remaining_amount,
+                    index,
+                    &params,
+                    model,
+                    minutes,
+                ),
+                None => helpers::snooze_cost_for_model(
+                    model,
+                    alarm.remaining_amount,
+                    index,
+                    params.percent,
+                ),
+            }
+            .ok_or(SolarmaError::Overflow)?;
+            require!(cost > 0, SolarmaError::InsufficientDeposit);
+
+            // C1: Rent-exempt guard — never drain vault below rent-exempt minimum.
+            // If we did, the Solana runtime would garbage-collect the account,
+            // making both claim and slash impossible (irrecoverable fund loss).
+            let rent = Rent::get()?;
+            let vault_info = ctx.accounts.vault.to_account_info();
+            let min_balance = rent.minimum_balance(vault_info.data_len());
+            let final_cost = helpers::cap_at_rent_exempt(cost, vault_info.lamports(), min_balance);
+            require!(final_cost > 0, SolarmaError::InsufficientDeposit);
+
+            // Transfer penalty from vault to sink (Savings restarts the lock)
+            pay_penalty_sink(
+                alarm,
+                &vault_info,
+                &ctx.accounts.sink.to_account_info(),
+                burn_sink,
+                final_cost,
+                clock.unix_timestamp,
+            )?;
+            (cost, final_cost)
+        }
+    };
 
     // Update alarm state
     alarm.remaining_amount = alarm
@@ -102,17 +223,30 @@ pub fn process_snooze(ctx: Context<Snooze>, expected_snooze_count: u8) -> Result
         .checked_add(1)
         .ok_or(SolarmaError::Overflow)?;
 
-    let (new_alarm_time, new_deadline) = helpers::snooze_time_extension(
-        alarm.alarm_time,
-        alarm.deadline,
-        DEFAULT_SNOOZE_EXTENSION_SECONDS,
-    )
-    .ok_or(SolarmaError::Overflow)?;
+    let (new_alarm_time, new_deadline) =
+        helpers::snooze_time_extension(alarm.alarm_time, alarm.deadline, extension_seconds)
+            .ok_or(SolarmaError::Overflow)?;
     alarm.alarm_time = new_alarm_time;
     alarm.deadline = new_deadline;
+    // A soft deadline moves with the window it sits in
+    if alarm.soft_deadline != 0 {
+        alarm.soft_deadline = alarm
+            .soft_deadline
+            .checked_add(extension_seconds)
+            .ok_or(SolarmaError::Overflow)?;
+    }
+
+    // Counters are statistics only, so they saturate instead of failing
+    if let Some(profile) = ctx.accounts.user_profile.as_mut() {
+        profile.lifetime_snooze_lamports =
+            profile.lifetime_snooze_lamports.saturating_add(final_cost);
+        profile.lifetime_snoozes = profile.lifetime_snoozes.saturating_add(1);
+    }
 
     emit!(crate::events::AlarmSnoozed {
-        owner: owner_key,
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: alarm.owner,
         alarm: alarm_key,
         alarm_id: alarm.alarm_id,
         snooze_count: alarm.snooze_count,
@@ -120,7 +254,20 @@ pub fn process_snooze(ctx: Context<Snooze>, expected_snooze_count: u8) -> Result
         remaining: alarm.remaining_amount,
         new_alarm_time: alarm.alarm_time,
         new_deadline: alarm.deadline,
+        penalty_recipient: sink_key,
     });
+    if final_cost < quoted_cost {
+        emit!(crate::events::SnoozeCostCapped {
+            version: crate::events::EVENT_VERSION,
+            timestamp: clock.unix_timestamp,
+            owner: alarm.owner,
+            alarm: alarm_key,
+            alarm_id: alarm.alarm_id,
+            snooze_count: alarm.snooze_count,
+            requested: quoted_cost,
+            charged: final_cost,
+        });
+    }
 
     msg!(
         "Snooze #{}: cost={}, remaining={}",
@@ -0,0 +1,153 @@
+//! Spawn next occurrence instruction - materialize the next series alarm
+//!
+//! Creates a regular Alarm + Vault pair funded from the series escrow.
+//! If the owner spawns late, missed slots are rolled forward to the first
+//! future slot without penalty. Slots marked by `skip_occurrence` are passed
+//! over the same way; neither uses up an occurrence. The series destination
+//! is checked against the owner's current blocklist at every spawn.
+
+use crate::constants::{ALARM_LAYOUT_VERSION, CLAIM_GRACE_SECONDS};
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::create_alarm::check_destinations_not_blocked;
+use crate::state::{Alarm, AlarmSeries, AlarmStatus, UserProfile, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(alarm_id: u64)]
+pub struct SpawnNextOccurrence<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"series", owner.key().as_ref(), &series.series_id.to_le_bytes()],
+        bump = series.bump
+    )]
+    pub series: Account<'info, AlarmSeries>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = Alarm::SIZE,
+        seeds = [b"alarm", owner.key().as_ref(), &alarm_id.to_le_bytes()],
+        bump
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA for the occurrence - INITIALIZED here
+    #[account(
+        init,
+        payer = owner,
+        space = Vault::SIZE,
+        seeds = [b"vault", alarm.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Owner's profile; the series destination must not be on its
+    /// blocklist (required for Buddy series)
+    #[account(
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+}
+
+pub fn process_spawn_next_occurrence(
+    ctx: Context<SpawnNextOccurrence>,
+    alarm_id: u64,
+) -> Result<()> {
+    let series_key = ctx.accounts.series.key();
+    let clock = Clock::get()?;
+
+    let series = &mut ctx.accounts.series;
+    require!(
+        series.remaining_occurrences > 0,
+        SolarmaError::SeriesExhausted
+    );
+    check_destinations_not_blocked(
+        ctx.accounts.user_profile.as_deref(),
+        series.penalty_route,
+        series.penalty_destination.as_slice(),
+    )?;
+
+    let (alarm_time, skipped_slots) = helpers::next_unskipped_occurrence(
+        series.next_alarm_time,
+        series.interval_seconds,
+        series.skipped_slots,
+        clock.unix_timestamp,
+    )
+    .ok_or(SolarmaError::Overflow)?;
+    let deadline = alarm_time
+        .checked_add(series.window_seconds)
+        .ok_or(SolarmaError::Overflow)?;
+    let deposit = series.deposit_per_occurrence;
+
+    series.next_alarm_time = alarm_time
+        .checked_add(series.interval_seconds)
+        .ok_or(SolarmaError::Overflow)?;
+    series.skipped_slots = skipped_slots >> 1;
+    series.remaining_occurrences -= 1;
+    series.escrowed_amount = series
+        .escrowed_amount
+        .checked_sub(deposit)
+        .ok_or(SolarmaError::InsufficientDeposit)?;
+
+    if deposit > 0 {
+        **series.to_account_info().try_borrow_mut_lamports()? -= deposit;
+        **ctx
+            .accounts
+            .vault
+            .to_account_info()
+            .try_borrow_mut_lamports()? += deposit;
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.alarm = ctx.accounts.alarm.key();
+    vault.bump = ctx.bumps.vault;
+
+    let alarm = &mut ctx.accounts.alarm;
+    alarm.owner = ctx.accounts.owner.key();
+    alarm.alarm_id = alarm_id;
+    alarm.alarm_time = alarm_time;
+    alarm.deadline = deadline;
+    alarm.initial_amount = deposit;
+    alarm.remaining_amount = deposit;
+    alarm.penalty_route = series.penalty_route;
+    alarm.penalty_destination = series.penalty_destination;
+    alarm.snooze_count = 0;
+    alarm.status = AlarmStatus::Created;
+    alarm.bump = ctx.bumps.alarm;
+    alarm.vault_bump = ctx.bumps.vault;
+    alarm.layout_version = ALARM_LAYOUT_VERSION;
+    alarm.set_snooze_params(&helpers::SnoozeParams::default());
+    alarm.claim_grace_seconds = CLAIM_GRACE_SECONDS;
+    alarm.series = Some(series_key);
+    alarm.payer = ctx.accounts.owner.key();
+
+    emit!(crate::events::OccurrenceSpawned {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: alarm.owner,
+        series: series_key,
+        alarm: alarm.key(),
+        alarm_id,
+        alarm_time,
+        deadline,
+        deposit_amount: deposit,
+        remaining_occurrences: series.remaining_occurrences,
+    });
+
+    msg!(
+        "Series {} spawned alarm {} at {} ({} left)",
+        series.series_id,
+        alarm_id,
+        alarm_time,
+        series.remaining_occurrences
+    );
+    Ok(())
+}
@@ -1,10 +1,22 @@
 //! Sweep acknowledged instruction - permissionless owner return after claim grace.
+//!
+//! When the alarm has `unclaimed_penalty_bps` set, that share of the
+//! deposit first goes where a snooze cost would (the penalty destination,
+//! or the burn sink) so an ack that is never claimed is not free. The
+//! alarm still ends `Claimed`: the ack counted, only the deposit is cut.
+//! A sponsored alarm's deposit goes back to the sponsor, as on `claim`.
 
 use crate::error::SolarmaError;
 use crate::helpers;
-use crate::state::{Alarm, AlarmStatus, Vault};
+use crate::instructions::claim::return_deposit_to_funder;
+use crate::instructions::init_alarm_index::remove_from_alarm_index;
+use crate::instructions::slash::record_settlement_memo;
+use crate::instructions::snooze::{pay_penalty_sink, penalty_sink};
+use crate::state::{Alarm, AlarmIndex, AlarmStatus, GlobalConfig, Vault};
 use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct SweepAcknowledged<'info> {
     #[account(
@@ -32,6 +44,32 @@ pub struct SweepAcknowledged<'info> {
     pub caller: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// SPL Memo program; when passed, a settlement memo is recorded
+    pub memo_program: Option<Program<'info, Memo>>,
+
+    /// Receives the unclaimed penalty, as it would a snooze cost
+    /// CHECK: Validated against the alarm's penalty route in `pay_penalty_sink`
+    #[account(mut)]
+    pub penalty_recipient: Option<UncheckedAccount<'info>>,
+
+    /// Global config supplying the burn sink for the unclaimed penalty
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Option<Account<'info, GlobalConfig>>,
+
+    /// Owner's alarm index; the swept alarm is removed from it
+    #[account(
+        mut,
+        seeds = [b"alarm-index", alarm.owner.as_ref()],
+        bump = alarm_index.bump
+    )]
+    pub alarm_index: Option<Account<'info, AlarmIndex>>,
+
+    /// Sponsor that funded the deposit; required for sponsored alarms
+    /// (`Alarm::sponsor`), and receives the deposit instead of the owner
+    /// CHECK: Validated against alarm.payer in `return_deposit_to_funder`
+    #[account(mut)]
+    pub funder: Option<UncheckedAccount<'info>>,
 }
 
 pub fn process_sweep_acknowledged(ctx: Context<SweepAcknowledged>) -> Result<()> {
@@ -39,37 +77,96 @@ pub fn process_sweep_acknowledged(ctx: Context<SweepAcknowledged>) -> Result<()>
     let owner_key = ctx.accounts.owner.key();
     let caller_key = ctx.accounts.caller.key();
     let alarm = &mut ctx.accounts.alarm;
-    let clock = Clock::get()?;
+    let clock = crate::clock::get(ctx.remaining_accounts)?;
 
-    let claim_deadline =
-        helpers::claim_deadline_with_grace(alarm.deadline).ok_or(SolarmaError::Overflow)?;
+    // A lost owner key is why guardians step in: don't sweep to it meanwhile
+    require!(alarm.recovery_approvals == 0, SolarmaError::RecoveryPending);
 
-    // Sweep is only allowed strictly after claim grace has expired.
+    // Sweep is only allowed strictly after claim grace has expired; until
+    // then the alarm belongs to the owner's claim window.
+    let resolution = helpers::post_deadline_resolution(
+        alarm.status,
+        false,
+        false,
+        alarm.deadline,
+        alarm.claim_grace_seconds,
+        clock.unix_timestamp,
+    )
+    .map_err(|_| SolarmaError::InvalidAlarmState)?;
     require!(
-        clock.unix_timestamp > claim_deadline,
+        resolution == helpers::Resolution::Sweep,
         SolarmaError::DeadlineNotPassed
     );
 
+    let penalty = helpers::bps_of(alarm.remaining_amount, alarm.unclaimed_penalty_bps)
+        .ok_or(SolarmaError::Overflow)?;
+    if penalty > 0 {
+        let (Some(recipient), Some(config)) = (
+            ctx.accounts.penalty_recipient.as_ref(),
+            ctx.accounts.config.as_ref(),
+        ) else {
+            return err!(SolarmaError::UnclaimedPenaltyAccountsRequired);
+        };
+        let burn_sink = config.effective_burn_sink();
+        pay_penalty_sink(
+            alarm,
+            &ctx.accounts.vault.to_account_info(),
+            &recipient.to_account_info(),
+            burn_sink,
+            penalty,
+            clock.unix_timestamp,
+        )?;
+        emit!(crate::events::UnclaimedDepositPenalized {
+            version: crate::events::EVENT_VERSION,
+            timestamp: clock.unix_timestamp,
+            owner: owner_key,
+            alarm: alarm_key,
+            alarm_id: alarm.alarm_id,
+            penalty,
+            penalty_recipient: penalty_sink(alarm, burn_sink)?,
+        });
+    }
+
     // The `close = owner` constraint automatically transfers all lamports
-    // (rent + remaining deposit) back to owner when vault account is closed.
+    // (rent + remaining deposit) back to owner when vault account is closed,
+    // after the deposit went to the sponsor if there is one
     let vault_lamports = ctx.accounts.vault.to_account_info().lamports();
+    let to_sponsor = return_deposit_to_funder(
+        alarm.sponsor(),
+        &ctx.accounts.vault.to_account_info(),
+        ctx.accounts.funder.as_ref().map(|f| f.as_ref()),
+    )?;
+    let returned_to = if to_sponsor > 0 { "sponsor" } else { "owner" };
 
-    emit!(crate::events::AlarmClaimed {
-        owner: owner_key,
-        alarm: alarm_key,
-        alarm_id: alarm.alarm_id,
-        returned_amount: vault_lamports,
-    });
+    crate::events::emit_settlement(
+        crate::events::event_authority!(ctx.accounts),
+        crate::events::AlarmClaimed {
+            version: crate::events::EVENT_VERSION,
+            timestamp: clock.unix_timestamp,
+            owner: owner_key,
+            alarm: alarm_key,
+            alarm_id: alarm.alarm_id,
+            returned_amount: vault_lamports,
+            buddy_reward: 0,
+        },
+    )?;
 
     msg!(
-        "Sweep acknowledged by {}: returned {} lamports to owner {}",
+        "Sweep acknowledged by {}: returned {} lamports to {} of owner {}",
         caller_key,
         vault_lamports,
+        returned_to,
         owner_key
     );
 
+    record_settlement_memo(
+        ctx.accounts.memo_program.as_ref(),
+        &helpers::settlement_memo("sweep", alarm.alarm_id, vault_lamports, returned_to),
+    )?;
+
     alarm.status = AlarmStatus::Claimed;
     alarm.remaining_amount = 0;
+    remove_from_alarm_index(ctx.accounts.alarm_index.as_deref_mut(), alarm.alarm_id);
 
     Ok(())
 }
@@ -0,0 +1,46 @@
+//! Sweep expired instruction - late, permissionless settlement of alarms
+//! nobody slashed
+//!
+//! A missed alarm normally ends with `slash`, paid for by the slasher
+//! bounty. When the bounty is too small to attract keepers, the deposit
+//! could sit in the vault forever. From `SWEEP_EXPIRED_DELAY_SECONDS` after
+//! the deadline, anyone may settle it here exactly as `slash` would (same
+//! accounts, route, fee and split legs in `remaining_accounts`), for half
+//! the slasher bounty.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+// Glob: the nested `Slash` needs its Anchor-generated modules in scope
+use crate::instructions::slash::*;
+use crate::state::AlarmStatus;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SweepExpired<'info> {
+    /// The `slash` accounts, unchanged
+    pub slash: Slash<'info>,
+}
+
+pub fn process_sweep_expired(ctx: Context<SweepExpired>) -> Result<()> {
+    let alarm = &ctx.accounts.slash.alarm;
+    require!(
+        alarm.status == AlarmStatus::Created,
+        SolarmaError::InvalidAlarmState
+    );
+    require!(
+        helpers::is_sweep_expired_window(
+            alarm.deadline,
+            crate::clock::get(ctx.remaining_accounts)?.unix_timestamp,
+        ),
+        SolarmaError::DeadlineNotPassed
+    );
+
+    let bounty_bps =
+        helpers::sweep_expired_bounty_bps(ctx.accounts.slash.config.slasher_bounty_bps);
+    settle_slash(
+        &mut ctx.accounts.slash,
+        ctx.remaining_accounts,
+        bounty_bps,
+        "sweep_expired",
+    )
+}
@@ -0,0 +1,50 @@
+//! Protocol params instructions - publish or refresh the `ProtocolParams`
+//! PDA mirroring `constants.rs`
+//!
+//! Both are permissionless: the values come from the program itself, so the
+//! caller only chooses when to pay for the write. `init_protocol_params`
+//! covers deployments configured before the account existed, and
+//! `sync_protocol_params` rewrites it after an upgrade changes a constant.
+
+use crate::state::ProtocolParams;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitProtocolParams<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = ProtocolParams::SIZE,
+        seeds = [b"protocol-params"],
+        bump
+    )]
+    pub protocol_params: Account<'info, ProtocolParams>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SyncProtocolParams<'info> {
+    #[account(mut, seeds = [b"protocol-params"], bump = protocol_params.bump)]
+    pub protocol_params: Account<'info, ProtocolParams>,
+}
+
+pub fn process_init_protocol_params(ctx: Context<InitProtocolParams>) -> Result<()> {
+    ctx.accounts
+        .protocol_params
+        .set_inner(ProtocolParams::current(ctx.bumps.protocol_params));
+    msg!("Protocol params published");
+    Ok(())
+}
+
+pub fn process_sync_protocol_params(ctx: Context<SyncProtocolParams>) -> Result<()> {
+    let bump = ctx.accounts.protocol_params.bump;
+    ctx.accounts
+        .protocol_params
+        .set_inner(ProtocolParams::current(bump));
+    msg!("Protocol params synced");
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! Unblock destination instruction - owner removes a penalty destination
+//! from the profile blocklist, freeing its slot
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::UserProfile;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UnblockDestination<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_unblock_destination(
+    ctx: Context<UnblockDestination>,
+    destination: Pubkey,
+) -> Result<()> {
+    let profile = &mut ctx.accounts.user_profile;
+    let blocked = profile.blocked_destinations.map(|k| k.to_bytes());
+    let slot = helpers::blocklist_slot(&destination.to_bytes(), &blocked)
+        .ok_or(SolarmaError::DestinationNotBlocked)?;
+    profile.blocked_destinations[slot] = Pubkey::default();
+
+    emit!(crate::events::DestinationBlocklistUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: profile.owner,
+        destination,
+        blocked: false,
+    });
+
+    msg!("Destination {} unblocked", destination);
+    Ok(())
+}
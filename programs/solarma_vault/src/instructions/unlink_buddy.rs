@@ -0,0 +1,47 @@
+//! Unlink buddy instruction - either side ends a buddy link
+//!
+//! The link is closed and its rent returned to the owner who paid it.
+//! Alarms already pointing at the buddy are unaffected.
+
+use crate::error::SolarmaError;
+use crate::state::BuddyLink;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UnlinkBuddy<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = authority.key() == buddy_link.owner || authority.key() == buddy_link.buddy
+            @ SolarmaError::UnauthorizedSigner,
+        seeds = [b"buddy-link", owner.key().as_ref(), buddy_link.buddy.as_ref()],
+        bump = buddy_link.bump,
+        close = owner
+    )]
+    pub buddy_link: Account<'info, BuddyLink>,
+
+    /// Owner of the link; receives the rent
+    /// CHECK: Key is verified by `buddy_link.has_one = owner`
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// Owner or buddy ending the link
+    pub authority: Signer<'info>,
+}
+
+pub fn process_unlink_buddy(ctx: Context<UnlinkBuddy>) -> Result<()> {
+    let link = &ctx.accounts.buddy_link;
+
+    emit!(crate::events::BuddyLinkUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: link.owner,
+        buddy: link.buddy,
+        link: link.key(),
+        active: false,
+        removed: true,
+    });
+
+    msg!("Buddy link {} -> {} removed", link.owner, link.buddy);
+    Ok(())
+}
@@ -0,0 +1,127 @@
+//! Update config instruction - admin mutates global protocol parameters
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{ConfigUpdate, GlobalConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn process_update_config(ctx: Context<UpdateConfig>, update: ConfigUpdate) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    if let Some(admin) = update.admin {
+        config.admin = admin;
+    }
+    if let Some(fee) = update.protocol_fee_bps {
+        config.protocol_fee_bps = fee;
+    }
+    if let Some(bounty) = update.slasher_bounty_bps {
+        config.slasher_bounty_bps = bounty;
+    }
+    if let Some(percent) = update.default_snooze_percent {
+        config.default_snooze_percent = percent;
+    }
+    if let Some(extension) = update.default_snooze_extension_seconds {
+        config.default_snooze_extension_seconds = extension;
+    }
+    if let Some(max_snoozes) = update.default_max_snoozes {
+        config.default_max_snoozes = max_snoozes;
+    }
+    if let Some(paused) = update.paused {
+        config.paused = paused;
+    }
+    if let Some(required) = update.require_verified_charity {
+        config.require_verified_charity = required;
+    }
+    if let Some(max_deposit) = update.max_deposit_lamports {
+        config.max_deposit_lamports = max_deposit;
+    }
+    if let Some(daily_cap) = update.daily_deposit_cap_lamports {
+        config.daily_deposit_cap_lamports = daily_cap;
+    }
+    if let Some(max_open) = update.max_open_alarms {
+        config.max_open_alarms = max_open;
+    }
+    if let Some(referral_fee) = update.referral_fee_bps {
+        config.referral_fee_bps = referral_fee;
+    }
+    if let Some(min_window) = update.min_wake_window_seconds {
+        config.min_wake_window_seconds = min_window;
+    }
+    if let Some(max_window) = update.max_wake_window_seconds {
+        config.max_wake_window_seconds = max_window;
+    }
+    if let Some(burn_sink) = update.burn_sink {
+        config.burn_sink = burn_sink;
+    }
+    if let Some(threshold) = update.two_phase_claim_threshold {
+        config.two_phase_claim_threshold = threshold;
+    }
+    if let Some(insurance_bps) = update.insurance_bps {
+        config.insurance_bps = insurance_bps;
+    }
+    if let Some(registry) = update.proof_type_registry {
+        config.proof_type_registry = registry;
+    }
+    if let Some(bps) = update.default_unclaimed_penalty_bps {
+        config.default_unclaimed_penalty_bps = bps;
+    }
+
+    helpers::validate_config_params(
+        config.protocol_fee_bps,
+        config.slasher_bounty_bps,
+        &config.default_snooze_params(),
+    )
+    .map_err(|_| SolarmaError::InvalidConfig)?;
+    require!(
+        helpers::validate_referral_fee(config.referral_fee_bps),
+        SolarmaError::InvalidConfig
+    );
+    require!(
+        helpers::validate_insurance_bps(config.insurance_bps),
+        SolarmaError::InvalidConfig
+    );
+    require!(
+        helpers::validate_proof_type_registry(config.proof_type_registry),
+        SolarmaError::InvalidConfig
+    );
+    require!(
+        helpers::validate_unclaimed_penalty_bps(config.default_unclaimed_penalty_bps),
+        SolarmaError::InvalidConfig
+    );
+    require!(
+        helpers::validate_wake_window_bounds(
+            config.min_wake_window_seconds,
+            config.max_wake_window_seconds
+        ),
+        SolarmaError::InvalidConfig
+    );
+
+    emit!(crate::events::ConfigUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        admin: config.admin,
+        config: config.key(),
+        protocol_fee_bps: config.protocol_fee_bps,
+        paused: config.paused,
+    });
+
+    msg!(
+        "Global config updated: fee_bps={}, paused={}",
+        config.protocol_fee_bps,
+        config.paused
+    );
+    Ok(())
+}
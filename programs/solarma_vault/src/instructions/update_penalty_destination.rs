@@ -0,0 +1,143 @@
+//! Update penalty destination instruction - owner fixes where a missed
+//! alarm's penalty goes (e.g. a mistyped buddy address)
+//!
+//! Only before the alarm fires and while it is `Created`, with the same
+//! checks `create_alarm` applies to a destination: blocked recipients,
+//! verified charities and distinct Split legs (the
+//! first leg moves with it). A Buddy route needs the new
+//! buddy's signature, so a buddy is never named without consent, and a
+//! buddy who already staked cannot be replaced. Burn and Savings alarms
+//! have nothing to change.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::instructions::create_alarm::check_destinations_not_blocked;
+use crate::state::{Alarm, AlarmStatus, Charity, GlobalConfig, PenaltyRoute, UserProfile};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdatePenaltyDestination<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = alarm.status == AlarmStatus::Created @ SolarmaError::InvalidAlarmState
+    )]
+    pub alarm: Account<'info, Alarm>,
+
+    /// Global config - read for the charity registry flag
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Registry entry for a Donate destination (needed when verified charities are required)
+    pub charity: Option<Account<'info, Charity>>,
+
+    /// Owner's profile; its blocked recipients can never be routed to
+    /// (required for Buddy and Split alarms)
+    #[account(
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// New buddy co-signing a Buddy-route change
+    pub new_buddy: Option<Signer<'info>>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_update_penalty_destination(
+    ctx: Context<UpdatePenaltyDestination>,
+    new_destination: Pubkey,
+) -> Result<()> {
+    let alarm_key = ctx.accounts.alarm.key();
+    let alarm = &mut ctx.accounts.alarm;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp < alarm.alarm_time,
+        SolarmaError::TooLateForRefund
+    );
+    helpers::check_penalty_destination_mutable(alarm.penalty_route, alarm.buddy_stake).map_err(
+        |e| match e {
+            "buddy_staked" => SolarmaError::BuddyStakeLocked,
+            _ => SolarmaError::PenaltyDestinationFixed,
+        },
+    )?;
+    let route = PenaltyRoute::try_from(alarm.penalty_route)
+        .map_err(|_| SolarmaError::InvalidPenaltyRoute)?;
+
+    check_destinations_not_blocked(
+        ctx.accounts.user_profile.as_deref(),
+        alarm.penalty_route,
+        &[new_destination],
+    )?;
+
+    match route {
+        PenaltyRoute::Buddy => {
+            require!(
+                ctx.accounts
+                    .new_buddy
+                    .as_ref()
+                    .is_some_and(|b| b.key() == new_destination),
+                SolarmaError::BuddyConsentRequired
+            );
+            if let Some(arbiter) = alarm.arbiter {
+                helpers::validate_arbiter(
+                    &arbiter.to_bytes(),
+                    &alarm.owner.to_bytes(),
+                    Some(&new_destination.to_bytes()),
+                    alarm.penalty_route,
+                )
+                .map_err(|_| SolarmaError::InvalidArbiter)?;
+            }
+        }
+        PenaltyRoute::Donate => {
+            let registered = ctx.accounts.charity.as_ref().map(|c| c.address.to_bytes());
+            require!(
+                helpers::is_charity_verified(
+                    ctx.accounts.config.require_verified_charity,
+                    alarm.penalty_route,
+                    Some(&new_destination.to_bytes()),
+                    registered.as_ref(),
+                ),
+                SolarmaError::CharityNotVerified
+            );
+        }
+        PenaltyRoute::Split => {
+            // The destination is the first leg; it must stay distinct from the others
+            let mut legs: Vec<([u8; 32], u16)> = alarm
+                .penalty_splits
+                .iter()
+                .filter(|s| s.bps > 0)
+                .map(|s| (s.destination.to_bytes(), s.bps))
+                .collect();
+            if let Some(primary) = legs.first_mut() {
+                primary.0 = new_destination.to_bytes();
+            }
+            helpers::validate_penalty_splits(&new_destination.to_bytes(), &legs)
+                .map_err(|_| SolarmaError::InvalidPenaltySplit)?;
+            alarm.penalty_splits[0].destination = new_destination;
+        }
+        _ => return err!(SolarmaError::PenaltyDestinationFixed),
+    }
+
+    let old_destination = alarm.penalty_destination;
+    alarm.penalty_destination = Some(new_destination);
+
+    emit!(crate::events::PenaltyDestinationUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: alarm.owner,
+        alarm: alarm_key,
+        alarm_id: alarm.alarm_id,
+        old_destination,
+        new_destination,
+    });
+
+    msg!(
+        "Alarm {} penalty destination: {}",
+        alarm.alarm_id,
+        new_destination
+    );
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! Update schedule instruction - owner changes the weekdays, local wake time
+//! or UTC offset of a weekly schedule
+//!
+//! Typically sent when daylight saving starts or ends, or the owner travels.
+//! Alarms already materialized keep their times; the next
+//! `materialize_next_alarm` uses the new timing.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::Schedule;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateSchedule<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"wake-schedule", owner.key().as_ref(), &schedule.schedule_id.to_le_bytes()],
+        bump = schedule.bump
+    )]
+    pub schedule: Account<'info, Schedule>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn process_update_schedule(
+    ctx: Context<UpdateSchedule>,
+    days_of_week: u8,
+    wake_minute: u16,
+    utc_offset_minutes: i16,
+) -> Result<()> {
+    helpers::validate_weekly_schedule(days_of_week, wake_minute, utc_offset_minutes)
+        .map_err(|_| SolarmaError::InvalidSchedule)?;
+
+    let schedule_key = ctx.accounts.schedule.key();
+    let schedule = &mut ctx.accounts.schedule;
+    schedule.days_of_week = days_of_week;
+    schedule.wake_minute = wake_minute;
+    schedule.utc_offset_minutes = utc_offset_minutes;
+
+    emit!(crate::events::ScheduleUpdated {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        owner: schedule.owner,
+        schedule: schedule_key,
+        schedule_id: schedule.schedule_id,
+        days_of_week,
+        wake_minute,
+        utc_offset_minutes,
+    });
+
+    msg!(
+        "Schedule {} updated: days={:#09b}, wake_minute={}, utc_offset={}",
+        schedule.schedule_id,
+        days_of_week,
+        wake_minute,
+        utc_offset_minutes
+    );
+    Ok(())
+}
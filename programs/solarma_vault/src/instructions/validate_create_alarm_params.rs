@@ -0,0 +1,87 @@
+//! Preflight for `create_alarm` - runs the full validation path without
+//! creating any account or moving funds.
+//!
+//! Validation failures are returned as data instead of failing the
+//! transaction, so a simulation always yields the exact reason.
+
+use crate::instructions::create_alarm::validate_create_alarm;
+use crate::state::{
+    AlarmOptions, AlarmStatus, Charity, GlobalConfig, PreflightResult, SavingsVault, UserProfile,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ValidateCreateAlarmParams<'info> {
+    /// Global config supplying defaults, limits and the pause flag
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Registry entry for a Donate destination, as passed to `create_alarm`
+    pub charity: Option<Account<'info, Charity>>,
+
+    /// Owner's savings vault, as passed to `create_alarm`
+    #[account(
+        seeds = [b"savings", owner.key().as_ref()],
+        bump = savings_vault.bump
+    )]
+    pub savings_vault: Option<Account<'info, SavingsVault>>,
+
+    /// Owner's profile, as passed to `create_alarm`
+    #[account(
+        seeds = [b"user-profile", owner.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
+
+    /// Future alarm owner; no signature needed since nothing is written
+    /// CHECK: Only used to derive the optional PDAs above
+    pub owner: UncheckedAccount<'info>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process_validate_create_alarm_params(
+    ctx: Context<ValidateCreateAlarmParams>,
+    alarm_time: i64,
+    deadline: i64,
+    deposit_amount: u64,
+    penalty_route: u8,
+    penalty_destination: Option<Pubkey>,
+    options: Option<AlarmOptions>,
+    pending_buddy: bool,
+) -> Result<PreflightResult> {
+    let status = if pending_buddy {
+        AlarmStatus::PendingBuddy
+    } else {
+        AlarmStatus::Created
+    };
+    let validated = validate_create_alarm(
+        &ctx.accounts.config,
+        &ctx.accounts.owner.key(),
+        ctx.accounts.charity.as_deref(),
+        ctx.accounts.savings_vault.as_ref().map(|s| s.key()),
+        ctx.accounts.user_profile.as_deref(),
+        ctx.accounts.user_profile.as_deref(),
+        alarm_time,
+        deadline,
+        deposit_amount,
+        penalty_route,
+        penalty_destination,
+        &options.unwrap_or_default(),
+        status,
+        Clock::get()?.unix_timestamp,
+    );
+
+    match validated {
+        Ok(_) => Ok(PreflightResult {
+            valid: true,
+            error_code: 0,
+            error_name: String::new(),
+        }),
+        Err(Error::AnchorError(e)) => Ok(PreflightResult {
+            valid: false,
+            error_code: e.error_code_number,
+            error_name: e.error_name,
+        }),
+        Err(e) => Err(e),
+    }
+}
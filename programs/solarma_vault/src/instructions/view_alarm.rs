@@ -0,0 +1,153 @@
+//! Read-only alarm views.
+//!
+//! None of these mutate state. Anchor writes each return value to the
+//! transaction return data, so clients read it from `simulateTransaction`
+//! and calling programs read it with `get_return_data` after the CPI.
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{Alarm, AlarmStatus, AlarmStatusView, GlobalConfig, SnoozeModel, SnoozeQuote};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ViewAlarm<'info> {
+    pub alarm: Account<'info, Alarm>,
+
+    /// Vault PDA; only its balance is read (0 once closed)
+    /// CHECK: Address is derived from the alarm
+    #[account(seeds = [b"vault", alarm.key().as_ref()], bump = alarm.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// Global config - the pause flag gates snooze and slash
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn process_get_alarm_status(ctx: Context<ViewAlarm>) -> Result<AlarmStatusView> {
+    let alarm = &ctx.accounts.alarm;
+    let paused = ctx.accounts.config.paused;
+    let now = Clock::get()?.unix_timestamp;
+    let created = alarm.status == AlarmStatus::Created;
+    let acknowledged = alarm.status == AlarmStatus::Acknowledged;
+
+    Ok(AlarmStatusView {
+        status: alarm.status,
+        remaining_amount: alarm.remaining_amount,
+        snooze_count: alarm.snooze_count,
+        alarm_time: alarm.alarm_time,
+        deadline: alarm.deadline,
+        can_snooze: created
+            && !paused
+            && alarm.challenger_stake == 0
+            && helpers::is_snooze_window(alarm.alarm_time, alarm.deadline, now)
+            && !helpers::is_max_snooze_for(alarm.snooze_count, alarm.snooze_params().max_snoozes),
+        can_claim: acknowledged
+            && helpers::is_claim_window_with_grace_for(
+                helpers::wake_window_start(alarm.alarm_time, alarm.early_ack_seconds),
+                alarm.deadline,
+                alarm.claim_grace_seconds,
+                now,
+            ),
+        can_slash: created && !paused && helpers::is_slash_window(alarm.deadline, now),
+        can_sweep: acknowledged
+            && helpers::is_sweep_window_for(alarm.deadline, alarm.claim_grace_seconds, now),
+        current_time: now,
+    })
+}
+
+/// Lamports a claim would return to the owner right now (0 outside the claim window).
+pub fn process_get_claimable_amount(ctx: Context<ViewAlarm>) -> Result<u64> {
+    let alarm = &ctx.accounts.alarm;
+    let now = Clock::get()?.unix_timestamp;
+    if alarm.status != AlarmStatus::Acknowledged
+        || !helpers::is_claim_window_with_grace_for(
+            helpers::wake_window_start(alarm.alarm_time, alarm.early_ack_seconds),
+            alarm.deadline,
+            alarm.claim_grace_seconds,
+            now,
+        )
+    {
+        return Ok(0);
+    }
+
+    let amount = helpers::claimable_amount(
+        ctx.accounts.vault.lamports(),
+        alarm.remaining_amount,
+        alarm.buddy_reward_bps,
+    )
+    .ok_or(SolarmaError::Overflow)?;
+    Ok(amount)
+}
+
+/// Lamports the next snooze would cost (0 when the alarm cannot be snoozed).
+pub fn process_get_next_snooze_cost(ctx: Context<ViewAlarm>) -> Result<u64> {
+    let alarm = &ctx.accounts.alarm;
+    if alarm.status != AlarmStatus::Created || alarm.challenger_stake > 0 {
+        return Ok(0);
+    }
+
+    let vault = ctx.accounts.vault.to_account_info();
+    let min_balance = Rent::get()?.minimum_balance(vault.data_len());
+    let cost = helpers::next_snooze_cost(
+        alarm.remaining_amount,
+        alarm.snooze_count,
+        &alarm.snooze_params(),
+        SnoozeModel::try_from(alarm.snooze_model).map_err(|_| SolarmaError::InvalidSnoozeConfig)?,
+        alarm.free_snoozes,
+        vault.lamports(),
+        min_balance,
+    )
+    .ok_or(SolarmaError::Overflow)?;
+    Ok(cost)
+}
+
+/// Exact quote for a snooze of `extension_minutes` (1–15), or of the
+/// alarm's own length when `None`, against the live vault balance.
+pub fn process_quote_snooze(
+    ctx: Context<ViewAlarm>,
+    extension_minutes: Option<u8>,
+) -> Result<SnoozeQuote> {
+    let alarm = &ctx.accounts.alarm;
+    let now = Clock::get()?.unix_timestamp;
+    let params = alarm.snooze_params();
+    let extension_seconds = match extension_minutes {
+        Some(minutes) => {
+            require!(
+                helpers::validate_snooze_extension_minutes(minutes),
+                SolarmaError::InvalidSnoozeExtension
+            );
+            i64::from(minutes) * 60
+        }
+        None => params.extension_seconds,
+    };
+    let can_snooze = alarm.status == AlarmStatus::Created
+        && !ctx.accounts.config.paused
+        && alarm.challenger_stake == 0
+        && helpers::is_snooze_window(alarm.alarm_time, alarm.deadline, now)
+        && !helpers::is_max_snooze_for(alarm.snooze_count, params.max_snoozes);
+
+    let vault = ctx.accounts.vault.to_account_info();
+    let min_balance = Rent::get()?.minimum_balance(vault.data_len());
+    let cost = helpers::quoted_snooze_cost(
+        alarm.remaining_amount,
+        alarm.snooze_count,
+        &params,
+        SnoozeModel::try_from(alarm.snooze_model).map_err(|_| SolarmaError::InvalidSnoozeConfig)?,
+        alarm.free_snoozes,
+        extension_minutes,
+        vault.lamports(),
+        min_balance,
+    )
+    .ok_or(SolarmaError::Overflow)?;
+    let (new_alarm_time, new_deadline) =
+        helpers::snooze_time_extension(alarm.alarm_time, alarm.deadline, extension_seconds)
+            .ok_or(SolarmaError::Overflow)?;
+
+    Ok(SnoozeQuote {
+        can_snooze,
+        cost: if can_snooze { cost } else { 0 },
+        extension_seconds,
+        new_alarm_time,
+        new_deadline,
+    })
+}
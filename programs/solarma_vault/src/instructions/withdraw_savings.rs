@@ -0,0 +1,52 @@
+//! Withdraw savings instruction - owner takes everything above rent out of
+//! their savings vault once the lock has expired
+
+use crate::error::SolarmaError;
+use crate::state::SavingsVault;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct WithdrawSavings<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"savings", owner.key().as_ref()],
+        bump = savings_vault.bump
+    )]
+    pub savings_vault: Account<'info, SavingsVault>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn process_withdraw_savings(ctx: Context<WithdrawSavings>) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= ctx.accounts.savings_vault.unlock_at,
+        SolarmaError::SavingsLocked
+    );
+
+    // The vault stays open (and rent-exempt) for future penalties
+    let rent = Rent::get()?;
+    let vault_info = ctx.accounts.savings_vault.to_account_info();
+    let min_balance = rent.minimum_balance(vault_info.data_len());
+    let amount = vault_info.lamports().saturating_sub(min_balance);
+    require!(amount > 0, SolarmaError::InsufficientDeposit);
+
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    **ctx
+        .accounts
+        .owner
+        .to_account_info()
+        .try_borrow_mut_lamports()? += amount;
+
+    emit!(crate::events::SavingsWithdrawn {
+        version: crate::events::EVENT_VERSION,
+        timestamp: clock.unix_timestamp,
+        owner: ctx.accounts.owner.key(),
+        amount,
+    });
+
+    msg!("Withdrew {} lamports of savings", amount);
+    Ok(())
+}
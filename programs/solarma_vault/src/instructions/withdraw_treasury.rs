@@ -0,0 +1,95 @@
+//! Withdraw treasury instruction - the treasury authority moves collected
+//! protocol fees out
+//!
+//! The treasury authority is the config admin until `set_treasury_signers`
+//! registers a multisig; from then on 2 of its 3 keys must sign (`authority`
+//! plus any others as signing remaining accounts).
+
+use crate::error::SolarmaError;
+use crate::helpers;
+use crate::state::{GlobalConfig, Treasury};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Fee destination chosen by the treasury authority
+    /// CHECK: Any system account may receive lamports
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// Config admin, or a treasury signer once the multisig is set
+    pub authority: Signer<'info>,
+}
+
+/// Require the admin, or `TREASURY_MULTISIG_THRESHOLD` treasury signers
+/// among `authority` and the signing `remaining_accounts`.
+pub(crate) fn require_treasury_authority(
+    config: &GlobalConfig,
+    authority: &Signer,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    let signed: Vec<[u8; 32]> = std::iter::once(authority.key())
+        .chain(
+            remaining_accounts
+                .iter()
+                .filter(|a| a.is_signer)
+                .map(|a| a.key()),
+        )
+        .map(|k| k.to_bytes())
+        .collect();
+    let treasury_signers: Vec<[u8; 32]> = config
+        .treasury_signers
+        .iter()
+        .map(|k| k.to_bytes())
+        .collect();
+    helpers::check_treasury_authority(&config.admin.to_bytes(), &treasury_signers, &signed)
+        .map_err(|_| SolarmaError::TreasuryApprovalsMissing)?;
+    Ok(())
+}
+
+pub fn process_withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    require_treasury_authority(
+        &ctx.accounts.config,
+        &ctx.accounts.authority,
+        ctx.remaining_accounts,
+    )?;
+    require!(amount > 0, SolarmaError::InsufficientTreasury);
+
+    // Treasury must stay rent-exempt
+    let rent = Rent::get()?;
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    let min_balance = rent.minimum_balance(treasury_info.data_len());
+    let available = helpers::cap_at_rent_exempt(amount, treasury_info.lamports(), min_balance);
+    require!(available == amount, SolarmaError::InsufficientTreasury);
+
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.destination.try_borrow_mut_lamports()? += amount;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_withdrawn = treasury
+        .total_withdrawn
+        .checked_add(amount)
+        .ok_or(SolarmaError::Overflow)?;
+
+    emit!(crate::events::TreasuryWithdrawn {
+        version: crate::events::EVENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+        admin: ctx.accounts.authority.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+    });
+
+    msg!(
+        "Withdrew {} lamports from treasury ({} withdrawn of {} collected)",
+        amount,
+        treasury.total_withdrawn,
+        treasury.total_collected
+    );
+    Ok(())
+}
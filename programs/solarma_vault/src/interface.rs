@@ -0,0 +1,333 @@
+//! Typed CPI entrypoints for programs that create and resolve alarms on
+//! behalf of their users.
+//!
+//! Depend on this crate with `features = ["cpi"]` (which implies
+//! `no-entrypoint`). Each function checks that the supplied accounts are
+//! the PDAs Solarma expects, assembles the Anchor-generated
+//! `cpi::accounts::*` struct in its fixed order and invokes the program.
+//! Optional accounts not listed here (leaderboard, charity registry) are
+//! passed as `None`; use the raw `cpi` module when you need them.
+//!
+//! Signers owned by the calling program sign through `signer_seeds`; pass
+//! `&[]` when every signer already signed the outer transaction.
+
+use crate::state::{
+    Alarm, AlarmIndex, AlarmOptions, GlobalConfig, InsurancePool, Treasury, UserProfile, Vault,
+};
+use anchor_lang::prelude::*;
+
+fn require_pda(info: &AccountInfo, expected: Pubkey) -> Result<()> {
+    require_keys_eq!(info.key(), expected, ErrorCode::ConstraintSeeds);
+    Ok(())
+}
+
+/// Accounts for [`create_alarm`].
+pub struct CreateAlarmAccounts<'info> {
+    /// Alarm owner and rent payer; must be able to fund the deposit
+    pub owner: AccountInfo<'info>,
+    pub alarm: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub config: AccountInfo<'info>,
+    /// Owner's savings vault (Savings route only)
+    pub savings_vault: Option<AccountInfo<'info>>,
+    /// Owner's profile (required while a daily deposit cap is set and for
+    /// Buddy or Split destinations)
+    pub user_profile: Option<AccountInfo<'info>>,
+    /// Owner's alarm index, if they keep one
+    pub alarm_index: Option<AccountInfo<'info>>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Create an alarm for `accounts.owner` via CPI.
+#[allow(clippy::too_many_arguments)]
+pub fn create_alarm<'info>(
+    program: AccountInfo<'info>,
+    accounts: CreateAlarmAccounts<'info>,
+    signer_seeds: &[&[&[u8]]],
+    alarm_id: u64,
+    alarm_time: i64,
+    deadline: i64,
+    deposit_amount: u64,
+    penalty_route: u8,
+    penalty_destination: Option<Pubkey>,
+    options: Option<AlarmOptions>,
+) -> Result<()> {
+    let (alarm, _) = Alarm::find_address(accounts.owner.key, alarm_id);
+    require_pda(&accounts.alarm, alarm)?;
+    require_pda(&accounts.vault, Vault::find_address(&alarm).0)?;
+    require_pda(&accounts.config, GlobalConfig::find_address().0)?;
+
+    let cpi_accounts = crate::cpi::accounts::CreateAlarm {
+        alarm: accounts.alarm,
+        vault: accounts.vault,
+        config: accounts.config,
+        charity: None,
+        savings_vault: accounts.savings_vault,
+        user_profile: accounts.user_profile,
+        alarm_index: accounts.alarm_index,
+        owner: accounts.owner.clone(),
+        payer: accounts.owner,
+        system_program: accounts.system_program,
+        daily_stats: None,
+        payer_profile: None,
+    };
+    crate::cpi::create_alarm(
+        CpiContext::new_with_signer(program, cpi_accounts, signer_seeds),
+        alarm_id,
+        alarm_time,
+        deadline,
+        deposit_amount,
+        penalty_route,
+        penalty_destination,
+        options,
+    )
+}
+
+/// Accounts for [`ack_awake`].
+pub struct AckAwakeAccounts<'info> {
+    pub alarm: AccountInfo<'info>,
+    /// Owner's profile; needed when a tag is registered or a delegate signs
+    pub user_profile: Option<AccountInfo<'info>>,
+    /// Owner, co-owner or profile delegate
+    pub authority: AccountInfo<'info>,
+    /// Vault, penalty recipient and config; required to ack after the
+    /// alarm's soft deadline
+    pub vault: Option<AccountInfo<'info>>,
+    pub penalty_recipient: Option<AccountInfo<'info>>,
+    pub config: Option<AccountInfo<'info>>,
+}
+
+/// Record the wake proof for an alarm via CPI.
+pub fn ack_awake<'info>(
+    program: AccountInfo<'info>,
+    accounts: AckAwakeAccounts<'info>,
+    signer_seeds: &[&[&[u8]]],
+    proof_hash: Option<[u8; 32]>,
+    proof_completed_at: i64,
+    proof_type: u8,
+    proof_preimage: Option<[u8; 32]>,
+) -> Result<()> {
+    if let Some(vault) = accounts.vault.as_ref() {
+        require_pda(vault, Vault::find_address(accounts.alarm.key).0)?;
+    }
+    if let Some(config) = accounts.config.as_ref() {
+        require_pda(config, GlobalConfig::find_address().0)?;
+    }
+
+    let cpi_accounts = crate::cpi::accounts::AckAwake {
+        alarm: accounts.alarm,
+        user_profile: accounts.user_profile,
+        authority: accounts.authority,
+        vault: accounts.vault,
+        penalty_recipient: accounts.penalty_recipient,
+        config: accounts.config,
+    };
+    crate::cpi::ack_awake(
+        CpiContext::new_with_signer(program, cpi_accounts, signer_seeds),
+        proof_hash,
+        proof_completed_at,
+        proof_type,
+        proof_preimage,
+    )
+}
+
+/// Accounts for [`claim`].
+pub struct ClaimAccounts<'info> {
+    pub alarm: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    /// Alarm owner; receives the funds, or only the vault rent when the
+    /// alarm has a claim destination
+    pub owner: AccountInfo<'info>,
+    pub user_profile: Option<AccountInfo<'info>>,
+    /// Buddy receiving the thank-you share (required when `buddy_reward_bps > 0`)
+    pub buddy: Option<AccountInfo<'info>>,
+    /// Owner's alarm index, if they keep one
+    pub alarm_index: Option<AccountInfo<'info>>,
+    /// Owner, co-owner or profile delegate
+    pub authority: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    /// The alarm's `claim_to` wallet (required when set)
+    pub claim_to: Option<AccountInfo<'info>>,
+    /// The alarm's sponsor (required for sponsored alarms)
+    pub funder: Option<AccountInfo<'info>>,
+    /// Self-CPI event authority, see [`crate::pda::event_authority_address`]
+    #[cfg(feature = "event-cpi")]
+    pub event_authority: AccountInfo<'info>,
+}
+
+/// Claim an acknowledged alarm back to its owner via CPI.
+pub fn claim<'info>(
+    program: AccountInfo<'info>,
+    accounts: ClaimAccounts<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    require_pda(&accounts.vault, Vault::find_address(accounts.alarm.key).0)?;
+    #[cfg(feature = "event-cpi")]
+    require_pda(
+        &accounts.event_authority,
+        crate::pda::event_authority_address().0,
+    )?;
+    if let Some(profile) = accounts.user_profile.as_ref() {
+        require_pda(profile, UserProfile::find_address(accounts.owner.key).0)?;
+    }
+    if let Some(index) = accounts.alarm_index.as_ref() {
+        require_pda(index, AlarmIndex::find_address(accounts.owner.key).0)?;
+    }
+
+    let cpi_accounts = crate::cpi::accounts::Claim {
+        alarm: accounts.alarm,
+        vault: accounts.vault,
+        owner: accounts.owner,
+        user_profile: accounts.user_profile,
+        leaderboard_entry: None,
+        leaderboard_epoch: None,
+        buddy: accounts.buddy,
+        alarm_index: accounts.alarm_index,
+        authority: accounts.authority,
+        system_program: accounts.system_program,
+        daily_stats: None,
+        claim_to: accounts.claim_to,
+        forgiveness_token: None,
+        funder: accounts.funder,
+        #[cfg(feature = "event-cpi")]
+        event_authority: accounts.event_authority,
+        #[cfg(feature = "event-cpi")]
+        program: program.clone(),
+    };
+    crate::cpi::claim(CpiContext::new_with_signer(
+        program,
+        cpi_accounts,
+        signer_seeds,
+    ))
+}
+
+/// Accounts for [`slash`].
+pub struct SlashAccounts<'info> {
+    pub alarm: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    /// Burn sink, or the alarm's penalty destination (first leg for Split)
+    pub penalty_recipient: AccountInfo<'info>,
+    pub config: AccountInfo<'info>,
+    pub treasury: AccountInfo<'info>,
+    /// Owner's alarm index, if they keep one
+    pub alarm_index: Option<AccountInfo<'info>>,
+    /// Required once `GlobalConfig.insurance_bps` is set
+    pub insurance_pool: Option<AccountInfo<'info>>,
+    /// Caller receiving the slasher bounty
+    pub caller: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    /// Split route: destinations of legs 2..n, in order
+    pub split_legs: Vec<AccountInfo<'info>>,
+    /// Self-CPI event authority, see [`crate::pda::event_authority_address`]
+    #[cfg(feature = "event-cpi")]
+    pub event_authority: AccountInfo<'info>,
+}
+
+/// Slash an expired, unacknowledged alarm via CPI.
+pub fn slash<'info>(
+    program: AccountInfo<'info>,
+    accounts: SlashAccounts<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    require_pda(&accounts.vault, Vault::find_address(accounts.alarm.key).0)?;
+    #[cfg(feature = "event-cpi")]
+    require_pda(
+        &accounts.event_authority,
+        crate::pda::event_authority_address().0,
+    )?;
+    require_pda(&accounts.config, GlobalConfig::find_address().0)?;
+    require_pda(&accounts.treasury, Treasury::find_address().0)?;
+    if let Some(pool) = accounts.insurance_pool.as_ref() {
+        require_pda(pool, InsurancePool::find_address().0)?;
+    }
+
+    let cpi_accounts = crate::cpi::accounts::Slash {
+        alarm: accounts.alarm,
+        vault: accounts.vault,
+        penalty_recipient: accounts.penalty_recipient,
+        config: accounts.config,
+        treasury: accounts.treasury,
+        leaderboard_entry: None,
+        leaderboard_epoch: None,
+        alarm_index: accounts.alarm_index,
+        user_profile: None,
+        referrer: None,
+        caller: accounts.caller,
+        system_program: accounts.system_program,
+        memo_program: None,
+        daily_stats: None,
+        insurance_pool: accounts.insurance_pool,
+        campaign: None,
+        #[cfg(feature = "event-cpi")]
+        event_authority: accounts.event_authority,
+        #[cfg(feature = "event-cpi")]
+        program: program.clone(),
+    };
+    crate::cpi::slash(
+        CpiContext::new_with_signer(program, cpi_accounts, signer_seeds)
+            .with_remaining_accounts(accounts.split_legs),
+    )
+}
+
+/// Accounts for [`sweep_acknowledged`].
+pub struct SweepAcknowledgedAccounts<'info> {
+    pub alarm: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    /// Alarm owner; receives the deposit
+    pub owner: AccountInfo<'info>,
+    pub caller: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    /// Penalty recipient and config; required when the alarm sets
+    /// `unclaimed_penalty_bps`
+    pub penalty_recipient: Option<AccountInfo<'info>>,
+    pub config: Option<AccountInfo<'info>>,
+    /// Owner's alarm index, if they keep one
+    pub alarm_index: Option<AccountInfo<'info>>,
+    /// The alarm's sponsor (required for sponsored alarms)
+    pub funder: Option<AccountInfo<'info>>,
+    /// Self-CPI event authority, see [`crate::pda::event_authority_address`]
+    #[cfg(feature = "event-cpi")]
+    pub event_authority: AccountInfo<'info>,
+}
+
+/// Return an acknowledged alarm's deposit after the claim grace via CPI.
+pub fn sweep_acknowledged<'info>(
+    program: AccountInfo<'info>,
+    accounts: SweepAcknowledgedAccounts<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    require_pda(&accounts.vault, Vault::find_address(accounts.alarm.key).0)?;
+    if let Some(config) = accounts.config.as_ref() {
+        require_pda(config, GlobalConfig::find_address().0)?;
+    }
+    if let Some(index) = accounts.alarm_index.as_ref() {
+        require_pda(index, AlarmIndex::find_address(accounts.owner.key).0)?;
+    }
+    #[cfg(feature = "event-cpi")]
+    require_pda(
+        &accounts.event_authority,
+        crate::pda::event_authority_address().0,
+    )?;
+
+    let cpi_accounts = crate::cpi::accounts::SweepAcknowledged {
+        alarm: accounts.alarm,
+        vault: accounts.vault,
+        owner: accounts.owner,
+        caller: accounts.caller,
+        system_program: accounts.system_program,
+        memo_program: None,
+        penalty_recipient: accounts.penalty_recipient,
+        config: accounts.config,
+        alarm_index: accounts.alarm_index,
+        funder: accounts.funder,
+        #[cfg(feature = "event-cpi")]
+        event_authority: accounts.event_authority,
+        #[cfg(feature = "event-cpi")]
+        program: program.clone(),
+    };
+    crate::cpi::sweep_acknowledged(CpiContext::new_with_signer(
+        program,
+        cpi_accounts,
+        signer_seeds,
+    ))
+}
@@ -3,16 +3,29 @@
 //! An onchain commitment vault for the Solarma alarm app.
 //! Users deposit SOL when setting alarms. They claim back
 //! after completing wake proof, or the deposit is slashed after deadline.
+//!
+//! Other programs depend on this crate with the `cpi` feature, which
+//! implies `no-entrypoint` so the program entrypoint is not linked into
+//! the caller; see [`interface`] for typed CPI entrypoints.
+
+// The generated `cpi` wrappers mirror each handler's argument list.
+#![cfg_attr(feature = "cpi", allow(clippy::too_many_arguments))]
 
 use anchor_lang::prelude::*;
 
 declare_id!("F54LpWS97bCvkn5PGfUsFi8cU8HyYBZgyozkSkAbAjzP");
 
+#[cfg(feature = "client")]
+pub mod client;
+pub mod clock;
 pub mod constants;
 pub mod error;
 pub mod events;
 pub mod helpers;
 pub mod instructions;
+#[cfg(feature = "cpi")]
+pub mod interface;
+pub mod pda;
 pub mod state;
 
 #[cfg(test)]
@@ -31,7 +44,14 @@ pub mod solarma_vault {
         instructions::initialize::process_initialize(ctx)
     }
 
-    /// Create a new alarm with optional deposit
+    /// Initialize user profile with a referrer who earns a share of the
+    /// protocol fees this user's alarms generate
+    pub fn initialize_with_referrer(ctx: Context<Initialize>, referrer: Pubkey) -> Result<()> {
+        instructions::initialize::process_initialize_with_referrer(ctx, referrer)
+    }
+
+    /// Create a new alarm with optional deposit and per-alarm options
+    #[allow(clippy::too_many_arguments)]
     pub fn create_alarm(
         ctx: Context<CreateAlarm>,
         alarm_id: u64,
@@ -40,6 +60,7 @@ pub mod solarma_vault {
         deposit_amount: u64,
         penalty_route: u8,
         penalty_destination: Option<Pubkey>,
+        options: Option<state::AlarmOptions>,
     ) -> Result<()> {
         instructions::create_alarm::process_create_alarm(
             ctx,
@@ -49,6 +70,7 @@ pub mod solarma_vault {
             deposit_amount,
             penalty_route,
             penalty_destination,
+            options,
         )
     }
 
@@ -59,8 +81,13 @@ pub mod solarma_vault {
 
     /// Snooze the alarm (reduces deposit).
     /// `expected_snooze_count` — current snooze count (idempotency guard).
-    pub fn snooze(ctx: Context<Snooze>, expected_snooze_count: u8) -> Result<()> {
-        instructions::snooze::process_snooze(ctx, expected_snooze_count)
+    /// `extension_minutes` — optional 1–15 minute length, priced pro rata.
+    pub fn snooze(
+        ctx: Context<Snooze>,
+        expected_snooze_count: u8,
+        extension_minutes: Option<u8>,
+    ) -> Result<()> {
+        instructions::snooze::process_snooze(ctx, expected_snooze_count, extension_minutes)
     }
 
     /// Slash the deposit after deadline (Created only; buddy-only subwindow for Buddy route)
@@ -79,7 +106,859 @@ pub mod solarma_vault {
     }
 
     /// H3: Record wake proof completion on-chain
-    pub fn ack_awake(ctx: Context<AckAwake>) -> Result<()> {
-        instructions::ack_awake::process_ack_awake(ctx)
+    pub fn ack_awake(
+        ctx: Context<AckAwake>,
+        proof_hash: Option<[u8; 32]>,
+        proof_completed_at: i64,
+        proof_type: u8,
+        proof_preimage: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::ack_awake::process_ack_awake(
+            ctx,
+            proof_hash,
+            proof_completed_at,
+            proof_type,
+            proof_preimage,
+        )
+    }
+
+    /// Replace the co-owner keys allowed to ack/snooze/claim (owner only)
+    pub fn set_co_owners(ctx: Context<SetCoOwners>, co_owners: Vec<Pubkey>) -> Result<()> {
+        instructions::set_co_owners::process_set_co_owners(ctx, co_owners)
+    }
+
+    /// Create a fully-funded alarm for another user (PendingGift until accepted)
+    pub fn create_gift_alarm(
+        ctx: Context<CreateGiftAlarm>,
+        alarm_id: u64,
+        alarm_time: i64,
+        deadline: i64,
+        deposit_amount: u64,
+        penalty_route: u8,
+        penalty_destination: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_gift_alarm::process_create_gift_alarm(
+            ctx,
+            alarm_id,
+            alarm_time,
+            deadline,
+            deposit_amount,
+            penalty_route,
+            penalty_destination,
+        )
+    }
+
+    /// Recipient accepts a pending gift alarm (PendingGift → Created)
+    pub fn accept_gift(ctx: Context<AcceptGift>) -> Result<()> {
+        instructions::accept_gift::process_accept_gift(ctx)
+    }
+
+    /// Recipient declines a pending gift; funds and rent return to gifter
+    pub fn decline_gift(ctx: Context<DeclineGift>) -> Result<()> {
+        instructions::decline_gift::process_decline_gift(ctx)
+    }
+
+    /// Permissionless return of an unaccepted gift after alarm time
+    pub fn expire_gift(ctx: Context<ExpireGift>) -> Result<()> {
+        instructions::expire_gift::process_expire_gift(ctx)
+    }
+
+    /// Offer a matched-deposit bet on an alarm to a designated challenger
+    pub fn offer_bet(ctx: Context<OfferBet>, challenger: Pubkey) -> Result<()> {
+        instructions::offer_bet::process_offer_bet(ctx, challenger)
+    }
+
+    /// Challenger matches the owner's deposit into the same vault
+    pub fn join_bet(ctx: Context<JoinBet>) -> Result<()> {
+        instructions::join_bet::process_join_bet(ctx)
+    }
+
+    /// Create a recurring alarm series backed by a single escrowed deposit
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_series(
+        ctx: Context<CreateSeries>,
+        series_id: u64,
+        first_alarm_time: i64,
+        window_seconds: i64,
+        interval_seconds: i64,
+        occurrences: u16,
+        deposit_per_occurrence: u64,
+        penalty_route: u8,
+        penalty_destination: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_series::process_create_series(
+            ctx,
+            series_id,
+            first_alarm_time,
+            window_seconds,
+            interval_seconds,
+            occurrences,
+            deposit_per_occurrence,
+            penalty_route,
+            penalty_destination,
+        )
+    }
+
+    /// Spawn the next occurrence of a series as a funded Alarm
+    pub fn spawn_next_occurrence(ctx: Context<SpawnNextOccurrence>, alarm_id: u64) -> Result<()> {
+        instructions::spawn_next_occurrence::process_spawn_next_occurrence(ctx, alarm_id)
+    }
+
+    /// Cancel a series and return the unused escrow to the owner
+    pub fn cancel_series(ctx: Context<CancelSeries>) -> Result<()> {
+        instructions::cancel_series::process_cancel_series(ctx)
+    }
+
+    /// Initialize the global protocol config (program upgrade authority only)
+    pub fn init_config(ctx: Context<InitConfig>, admin: Pubkey) -> Result<()> {
+        instructions::init_config::process_init_config(ctx, admin)
+    }
+
+    /// Update global protocol parameters (config admin only)
+    pub fn update_config(ctx: Context<UpdateConfig>, update: state::ConfigUpdate) -> Result<()> {
+        instructions::update_config::process_update_config(ctx, update)
+    }
+
+    /// Set the localnet test clock (config admin only; `test-clock` builds)
+    #[cfg(feature = "test-clock")]
+    pub fn set_mock_time(ctx: Context<SetMockTime>, unix_timestamp: i64) -> Result<()> {
+        instructions::set_mock_time::process_set_mock_time(ctx, unix_timestamp)
+    }
+
+    /// Register the user's NFC/QR tag hash (first time only)
+    pub fn register_tag(ctx: Context<RegisterTag>, tag_hash: [u8; 32]) -> Result<()> {
+        instructions::register_tag::process_register_tag(ctx, tag_hash)
+    }
+
+    /// Replace the registered tag hash, proving knowledge of the old one
+    pub fn rotate_tag(
+        ctx: Context<RotateTag>,
+        old_hash: [u8; 32],
+        new_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::rotate_tag::process_rotate_tag(ctx, old_hash, new_hash)
+    }
+
+    /// Withdraw accumulated protocol fees from the treasury (config admin, or
+    /// 2 of 3 treasury signers once set)
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instructions::withdraw_treasury::process_withdraw_treasury(ctx, amount)
+    }
+
+    /// Close a claimed or slashed alarm and return its rent to the owner
+    pub fn close_alarm(ctx: Context<CloseAlarm>) -> Result<()> {
+        instructions::close_alarm::process_close_alarm(ctx)
+    }
+
+    /// Register a verified charity for Donate-route alarms (config admin only)
+    pub fn add_charity(
+        ctx: Context<AddCharity>,
+        address: Pubkey,
+        name_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::add_charity::process_add_charity(ctx, address, name_hash)
+    }
+
+    /// Remove a charity from the registry (config admin only)
+    pub fn remove_charity(ctx: Context<RemoveCharity>) -> Result<()> {
+        instructions::remove_charity::process_remove_charity(ctx)
+    }
+
+    /// Create a Buddy-route alarm that activates only once the buddy co-signs
+    pub fn create_alarm_pending_buddy(
+        ctx: Context<CreateAlarm>,
+        alarm_id: u64,
+        alarm_time: i64,
+        deadline: i64,
+        deposit_amount: u64,
+        penalty_destination: Option<Pubkey>,
+        options: Option<state::AlarmOptions>,
+    ) -> Result<()> {
+        instructions::create_alarm::process_create_alarm_pending_buddy(
+            ctx,
+            alarm_id,
+            alarm_time,
+            deadline,
+            deposit_amount,
+            penalty_destination,
+            options,
+        )
+    }
+
+    /// Buddy co-signs a pending Buddy-route alarm (PendingBuddy → Created)
+    pub fn buddy_accept(ctx: Context<BuddyAccept>) -> Result<()> {
+        instructions::buddy_accept::process_buddy_accept(ctx)
+    }
+
+    /// Owner withdraws a Buddy alarm the buddy has not accepted (no penalty)
+    pub fn cancel_buddy_request(ctx: Context<CancelBuddyRequest>) -> Result<()> {
+        instructions::cancel_buddy_request::process_cancel_buddy_request(ctx)
+    }
+
+    /// Open a group wake challenge with a fixed per-participant stake
+    pub fn create_challenge(
+        ctx: Context<CreateChallenge>,
+        challenge_id: u64,
+        wake_time: i64,
+        deadline: i64,
+        deposit_amount: u64,
+    ) -> Result<()> {
+        instructions::create_challenge::process_create_challenge(
+            ctx,
+            challenge_id,
+            wake_time,
+            deadline,
+            deposit_amount,
+        )
+    }
+
+    /// Stake into a challenge before its wake time
+    pub fn join_challenge(ctx: Context<JoinChallenge>) -> Result<()> {
+        instructions::join_challenge::process_join_challenge(ctx)
+    }
+
+    /// Record a participant's wake-up before the shared deadline
+    pub fn ack_challenge(ctx: Context<AckChallenge>) -> Result<()> {
+        instructions::ack_challenge::process_ack_challenge(ctx)
+    }
+
+    /// Settle one participant after the deadline (permissionless)
+    pub fn settle_challenge(ctx: Context<SettleChallenge>) -> Result<()> {
+        instructions::settle_challenge::process_settle_challenge(ctx)
+    }
+
+    /// Slash the tranches of a progressive alarm that are due (permissionless, repeatable)
+    pub fn slash_partial(ctx: Context<SlashPartial>) -> Result<()> {
+        instructions::slash_partial::process_slash_partial(ctx)
+    }
+
+    /// Recover a time-decayed share of an unslashed deposit after the deadline
+    pub fn claim_late(ctx: Context<ClaimLate>) -> Result<()> {
+        instructions::claim_late::process_claim_late(ctx)
+    }
+
+    /// Acknowledge wake-up with a server-signed Ed25519 permit (one-time nonce)
+    pub fn ack_awake_attested(
+        ctx: Context<AckAwakeAttested>,
+        nonce: u64,
+        expires_at: i64,
+        proof_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::ack_awake_attested::process_ack_awake_attested(
+            ctx, nonce, expires_at, proof_hash,
+        )
+    }
+
+    /// Claim an acknowledged alarm with a server-signed Ed25519 permit (one-time nonce)
+    pub fn claim_attested(ctx: Context<ClaimAttested>, nonce: u64, expires_at: i64) -> Result<()> {
+        instructions::claim_attested::process_claim_attested(ctx, nonce, expires_at)
+    }
+
+    /// Create the attestation signer PDA (config admin only)
+    pub fn init_attestation_config(ctx: Context<InitAttestationConfig>, key: Pubkey) -> Result<()> {
+        instructions::init_attestation_config::process_init_attestation_config(ctx, key)
+    }
+
+    /// Rotate the attestation signer; the old key validates for `transition_seconds`
+    pub fn set_attestation_key(
+        ctx: Context<SetAttestationKey>,
+        new_key: Pubkey,
+        transition_seconds: i64,
+    ) -> Result<()> {
+        instructions::set_attestation_key::process_set_attestation_key(
+            ctx,
+            new_key,
+            transition_seconds,
+        )
+    }
+
+    /// Register additional attestor keys and the M-of-N signature threshold
+    pub fn set_attestors(
+        ctx: Context<SetAttestors>,
+        attestors: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_attestors::process_set_attestors(ctx, attestors, threshold)
+    }
+
+    /// Reclaim rent from an expired permit nonce once its alarm is terminal
+    pub fn close_permit_nonce(ctx: Context<ClosePermitNonce>) -> Result<()> {
+        instructions::close_permit_nonce::process_close_permit_nonce(ctx)
+    }
+
+    /// Move alarm_time and deadline before the alarm fires (owner only, no penalty)
+    pub fn reschedule_alarm(
+        ctx: Context<RescheduleAlarm>,
+        new_alarm_time: i64,
+        new_deadline: i64,
+    ) -> Result<()> {
+        instructions::reschedule_alarm::process_reschedule_alarm(ctx, new_alarm_time, new_deadline)
+    }
+
+    /// Mint an achievement badge once profile counters cross its threshold
+    pub fn claim_achievement(ctx: Context<ClaimAchievement>, badge_id: u8) -> Result<()> {
+        instructions::claim_achievement::process_claim_achievement(ctx, badge_id)
+    }
+
+    /// Open the weekly leaderboard epoch PDA (permissionless)
+    pub fn init_leaderboard_epoch(ctx: Context<InitLeaderboardEpoch>, epoch: u64) -> Result<()> {
+        instructions::init_leaderboard_epoch::process_init_leaderboard_epoch(ctx, epoch)
+    }
+
+    /// Open the caller's leaderboard entry for a current or future epoch
+    pub fn join_leaderboard(ctx: Context<JoinLeaderboard>) -> Result<()> {
+        instructions::join_leaderboard::process_join_leaderboard(ctx)
+    }
+
+    /// Freeze an ended leaderboard epoch and publish its totals (permissionless)
+    pub fn finalize_epoch(ctx: Context<FinalizeEpoch>) -> Result<()> {
+        instructions::finalize_epoch::process_finalize_epoch(ctx)
+    }
+
+    /// Register (or clear) a session key allowed to ack and claim for the owner
+    pub fn set_delegate(
+        ctx: Context<SetDelegate>,
+        delegate: Pubkey,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::set_delegate::process_set_delegate(ctx, delegate, expires_at)
+    }
+
+    /// Let anyone push the claim to the owner once the alarm is acknowledged
+    pub fn authorize_auto_claim(ctx: Context<AuthorizeAutoClaim>) -> Result<()> {
+        instructions::authorize_auto_claim::process_authorize_auto_claim(ctx)
+    }
+
+    /// Claim a pre-authorized acknowledged alarm to its owner (permissionless)
+    pub fn auto_claim(ctx: Context<AutoClaim>) -> Result<()> {
+        instructions::auto_claim::process_auto_claim(ctx)
+    }
+
+    /// Open a head-to-head duel against `opponent`, staking `stake`
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_duel(
+        ctx: Context<CreateDuel>,
+        duel_id: u64,
+        opponent: Pubkey,
+        stake: u64,
+        creator_wake_time: i64,
+        creator_deadline: i64,
+        opponent_wake_time: i64,
+        opponent_deadline: i64,
+    ) -> Result<()> {
+        instructions::create_duel::process_create_duel(
+            ctx,
+            duel_id,
+            opponent,
+            stake,
+            creator_wake_time,
+            creator_deadline,
+            opponent_wake_time,
+            opponent_deadline,
+        )
+    }
+
+    /// Match the stake of a duel you were invited to
+    pub fn accept_duel(ctx: Context<AcceptDuel>) -> Result<()> {
+        instructions::accept_duel::process_accept_duel(ctx)
+    }
+
+    /// Acknowledge your wake-up in a duel, within your own window
+    pub fn ack_duel(ctx: Context<AckDuel>) -> Result<()> {
+        instructions::ack_duel::process_ack_duel(ctx)
+    }
+
+    /// Pay out both sides of a duel and close it (permissionless)
+    pub fn settle_duel(ctx: Context<SettleDuel>) -> Result<()> {
+        instructions::settle_duel::process_settle_duel(ctx)
+    }
+
+    /// Create the caller's savings vault for `PenaltyRoute::Savings`
+    pub fn init_savings_vault(ctx: Context<InitSavingsVault>, lock_seconds: i64) -> Result<()> {
+        instructions::init_savings_vault::process_init_savings_vault(ctx, lock_seconds)
+    }
+
+    /// Withdraw unlocked savings to the owner
+    pub fn withdraw_savings(ctx: Context<WithdrawSavings>) -> Result<()> {
+        instructions::withdraw_savings::process_withdraw_savings(ctx)
+    }
+
+    /// Read-only: alarm status and which actions are currently open
+    pub fn get_alarm_status(ctx: Context<ViewAlarm>) -> Result<state::AlarmStatusView> {
+        instructions::view_alarm::process_get_alarm_status(ctx)
+    }
+
+    /// Read-only: lamports a claim would return to the owner right now
+    pub fn get_claimable_amount(ctx: Context<ViewAlarm>) -> Result<u64> {
+        instructions::view_alarm::process_get_claimable_amount(ctx)
+    }
+
+    /// Read-only: lamports the next snooze would cost
+    pub fn get_next_snooze_cost(ctx: Context<ViewAlarm>) -> Result<u64> {
+        instructions::view_alarm::process_get_next_snooze_cost(ctx)
+    }
+
+    /// Read-only preflight: run `create_alarm` validation and return the
+    /// first failure (if any) as data. Set `pending_buddy` to check
+    /// `create_alarm_pending_buddy` instead
+    #[allow(clippy::too_many_arguments)]
+    pub fn validate_create_alarm_params(
+        ctx: Context<ValidateCreateAlarmParams>,
+        alarm_time: i64,
+        deadline: i64,
+        deposit_amount: u64,
+        penalty_route: u8,
+        penalty_destination: Option<Pubkey>,
+        options: Option<state::AlarmOptions>,
+        pending_buddy: bool,
+    ) -> Result<state::PreflightResult> {
+        instructions::validate_create_alarm_params::process_validate_create_alarm_params(
+            ctx,
+            alarm_time,
+            deadline,
+            deposit_amount,
+            penalty_route,
+            penalty_destination,
+            options,
+            pending_buddy,
+        )
+    }
+
+    /// Tag an alarm with a 32-byte label, short URI or hash (owner or co-owner)
+    pub fn set_alarm_memo(ctx: Context<SetAlarmMemo>, memo: [u8; 32]) -> Result<()> {
+        instructions::set_alarm_memo::process_set_alarm_memo(ctx, memo)
+    }
+
+    /// Create the caller's alarm index for listing live alarms
+    pub fn init_alarm_index(ctx: Context<InitAlarmIndex>) -> Result<()> {
+        instructions::init_alarm_index::process_init_alarm_index(ctx)
+    }
+
+    /// Owner of a Buddy alarm with an arbiter freezes slashing for the
+    /// dispute window (once per alarm, from alarm time on)
+    pub fn raise_dispute(ctx: Context<RaiseDispute>) -> Result<()> {
+        instructions::raise_dispute::process_raise_dispute(ctx)
+    }
+
+    /// Arbiter rules on an open dispute: refund the owner in full, or let
+    /// the penalty route proceed
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, refund_owner: bool) -> Result<()> {
+        instructions::resolve_dispute::process_resolve_dispute(ctx, refund_owner)
+    }
+
+    /// Owner registers 2-3 recovery guardians and the address they may
+    /// redirect acknowledged alarms to (empty list disables recovery)
+    pub fn set_guardians(
+        ctx: Context<SetGuardians>,
+        guardians: Vec<Pubkey>,
+        recovery_address: Pubkey,
+    ) -> Result<()> {
+        instructions::set_guardians::process_set_guardians(ctx, guardians, recovery_address)
+    }
+
+    /// Guardian approves redirecting an acknowledged alarm of an owner who
+    /// lost their key (blocks sweep; 2 approvals start the timelock)
+    pub fn approve_recovery(ctx: Context<ApproveRecovery>) -> Result<()> {
+        instructions::approve_recovery::process_approve_recovery(ctx)
+    }
+
+    /// Keeper slashes or sweeps up to 10 expired alarms passed as
+    /// `[alarm, vault, recipient, alarm_index]` groups in remaining_accounts
+    pub fn crank_settle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CrankSettle<'info>>,
+    ) -> Result<()> {
+        instructions::crank_settle::process_crank_settle(ctx)
+    }
+
+    /// Owner discards guardian approvals on an alarm
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        instructions::cancel_recovery::process_cancel_recovery(ctx)
+    }
+
+    /// Guardian pays an acknowledged alarm to the owner's recovery address
+    /// after 2 approvals and the timelock
+    pub fn recover_alarm(ctx: Context<RecoverAlarm>) -> Result<()> {
+        instructions::recover_alarm::process_recover_alarm(ctx)
+    }
+
+    /// Anyone pins the accounts needed to settle an alarm, so automation
+    /// threads can register `settle_scheduled` once at `trigger_at`
+    pub fn schedule_settlement(ctx: Context<ScheduleSettlement>) -> Result<()> {
+        instructions::schedule_settlement::process_schedule_settlement(ctx)
+    }
+
+    /// Fee payer slashes or sweeps a scheduled alarm with fixed account
+    /// metas (thread-friendly); closes the schedule to its creator
+    pub fn settle_scheduled(ctx: Context<SettleScheduled>) -> Result<()> {
+        instructions::settle_scheduled::process_settle_scheduled(ctx)
+    }
+
+    /// Schedule creator reclaims the rent of an unused settlement schedule
+    pub fn close_settlement_schedule(ctx: Context<CloseSettlementSchedule>) -> Result<()> {
+        instructions::close_settlement_schedule::process_close_settlement_schedule(ctx)
+    }
+
+    /// Owner stores a timezone-aware weekly schedule (local weekdays, wake
+    /// time and UTC offset) as the source of truth for recurring alarms
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_schedule(
+        ctx: Context<CreateSchedule>,
+        schedule_id: u64,
+        days_of_week: u8,
+        wake_minute: u16,
+        utc_offset_minutes: i16,
+        window_seconds: i64,
+        deposit_amount: u64,
+        penalty_route: u8,
+        penalty_destination: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_schedule::process_create_schedule(
+            ctx,
+            schedule_id,
+            days_of_week,
+            wake_minute,
+            utc_offset_minutes,
+            window_seconds,
+            deposit_amount,
+            penalty_route,
+            penalty_destination,
+        )
+    }
+
+    /// Owner changes a schedule's weekdays, wake time or UTC offset (e.g. for
+    /// daylight saving)
+    pub fn update_schedule(
+        ctx: Context<UpdateSchedule>,
+        days_of_week: u8,
+        wake_minute: u16,
+        utc_offset_minutes: i16,
+    ) -> Result<()> {
+        instructions::update_schedule::process_update_schedule(
+            ctx,
+            days_of_week,
+            wake_minute,
+            utc_offset_minutes,
+        )
+    }
+
+    /// Owner turns the schedule's next occurrence into a funded Alarm
+    pub fn materialize_next_alarm(ctx: Context<MaterializeNextAlarm>, alarm_id: u64) -> Result<()> {
+        instructions::materialize_next_alarm::process_materialize_next_alarm(ctx, alarm_id)
+    }
+
+    /// Owner deletes a weekly schedule and reclaims its rent
+    pub fn close_schedule(ctx: Context<CloseSchedule>) -> Result<()> {
+        instructions::close_schedule::process_close_schedule(ctx)
+    }
+
+    /// Owner skips an upcoming series occurrence without penalty (at most
+    /// 4 per 30 days); the series ends one interval later instead
+    pub fn skip_occurrence(ctx: Context<SkipOccurrence>, occurrence_time: i64) -> Result<()> {
+        instructions::skip_occurrence::process_skip_occurrence(ctx, occurrence_time)
+    }
+
+    /// Buddy escrows a deposit matching the owner's on a Buddy-route alarm;
+    /// it is returned once the alarm resolves
+    pub fn buddy_stake(ctx: Context<StakeAsBuddy>) -> Result<()> {
+        instructions::buddy_stake::process_buddy_stake(ctx)
+    }
+
+    /// Buddy takes back their stake after the alarm was claimed, slashed or
+    /// closed
+    pub fn release_buddy_stake(ctx: Context<ReleaseBuddyStake>) -> Result<()> {
+        instructions::release_buddy_stake::process_release_buddy_stake(ctx)
+    }
+
+    /// `ack_awake` signed by the owner and submitted by a fee-paying relayer
+    pub fn ack_awake_relayed(
+        ctx: Context<AckAwakeRelayed>,
+        proof_hash: Option<[u8; 32]>,
+        proof_completed_at: i64,
+        proof_type: u8,
+        proof_preimage: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::ack_awake_relayed::process_ack_awake_relayed(
+            ctx,
+            proof_hash,
+            proof_completed_at,
+            proof_type,
+            proof_preimage,
+        )
+    }
+
+    /// `claim` signed by the owner and submitted by a fee-paying relayer
+    pub fn claim_relayed(ctx: Context<ClaimRelayed>) -> Result<()> {
+        instructions::claim_relayed::process_claim_relayed(ctx)
+    }
+
+    /// Register, replace or clear the 2-of-3 treasury multisig (treasury authority)
+    pub fn set_treasury_signers(
+        ctx: Context<SetTreasurySigners>,
+        signers: [Pubkey; 3],
+    ) -> Result<()> {
+        instructions::set_treasury_signers::process_set_treasury_signers(ctx, signers)
+    }
+
+    /// Grow the config and treasury PDAs to the current layout (config admin)
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate_config::process_migrate_config(ctx)
+    }
+
+    /// Open the per-day protocol stats PDA (permissionless)
+    pub fn init_daily_stats(ctx: Context<InitDailyStats>, day: u64) -> Result<()> {
+        instructions::init_daily_stats::process_init_daily_stats(ctx, day)
+    }
+
+    /// Pin a content hash of off-chain wake evidence to an acknowledged alarm (write-once)
+    pub fn anchor_proof(
+        ctx: Context<AnchorProof>,
+        proof_hash: [u8; 32],
+        proof_type: u8,
+    ) -> Result<()> {
+        instructions::anchor_proof::process_anchor_proof(ctx, proof_hash, proof_type)
+    }
+
+    /// Start a two-phase claim on an alarm above the config threshold
+    pub fn request_claim(ctx: Context<RequestClaim>) -> Result<()> {
+        instructions::request_claim::process_request_claim(ctx)
+    }
+
+    /// Pay out a two-phase claim once its veto delay has passed
+    pub fn finalize_claim(ctx: Context<FinalizeClaim>) -> Result<()> {
+        instructions::finalize_claim::process_finalize_claim(ctx)
+    }
+
+    /// Buddy or attestation server vetoes a pending two-phase claim
+    pub fn flag_claim(ctx: Context<FlagClaim>) -> Result<()> {
+        instructions::flag_claim::process_flag_claim(ctx)
+    }
+
+    /// Add a penalty destination to the owner's profile blocklist
+    pub fn block_destination(ctx: Context<BlockDestination>, destination: Pubkey) -> Result<()> {
+        instructions::block_destination::process_block_destination(ctx, destination)
+    }
+
+    /// Remove a penalty destination from the owner's profile blocklist
+    pub fn unblock_destination(
+        ctx: Context<UnblockDestination>,
+        destination: Pubkey,
+    ) -> Result<()> {
+        instructions::unblock_destination::process_unblock_destination(ctx, destination)
+    }
+
+    /// Settle an alarm nobody slashed, a week after its deadline, for half the bounty
+    pub fn sweep_expired(ctx: Context<SweepExpired>) -> Result<()> {
+        instructions::sweep_expired::process_sweep_expired(ctx)
+    }
+
+    /// Read-only: exact cost and new times of a snooze (optionally of
+    /// `extension_minutes`), returned as transaction return data
+    pub fn quote_snooze(
+        ctx: Context<ViewAlarm>,
+        extension_minutes: Option<u8>,
+    ) -> Result<state::SnoozeQuote> {
+        instructions::view_alarm::process_quote_snooze(ctx, extension_minutes)
+    }
+
+    /// Owner pushes the deadline back before the alarm fires, for a burned
+    /// fee proportional to `extra_seconds`
+    pub fn extend_deadline(ctx: Context<ExtendDeadline>, extra_seconds: i64) -> Result<()> {
+        instructions::extend_deadline::process_extend_deadline(ctx, extra_seconds)
+    }
+
+    /// Owner claims up to 8 acknowledged alarms passed as `[alarm, vault]`
+    /// pairs in remaining_accounts
+    pub fn claim_many<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimMany<'info>>) -> Result<()> {
+        instructions::claim_many::process_claim_many(ctx)
+    }
+
+    /// Admin creates the program's Address Lookup Table (authority: the
+    /// config PDA) and records it in the config
+    pub fn create_lookup_table(ctx: Context<CreateLookupTable>, recent_slot: u64) -> Result<()> {
+        instructions::create_lookup_table::process_create_lookup_table(ctx, recent_slot)
+    }
+
+    /// Admin appends the hot accounts (burn sink, config, treasury,
+    /// attestation config) missing from the lookup table
+    pub fn extend_lookup_table(ctx: Context<ExtendLookupTable>) -> Result<()> {
+        instructions::extend_lookup_table::process_extend_lookup_table(ctx)
+    }
+
+    /// Admin creates the insurance pool PDA funded by `insurance_bps` of every slash
+    pub fn init_insurance_pool(ctx: Context<InitInsurancePool>) -> Result<()> {
+        instructions::init_insurance_pool::process_init_insurance_pool(ctx)
+    }
+
+    /// Owner recovers a slashed deposit from the insurance pool with an
+    /// attestation permit for an infrastructure failure (rate-limited)
+    pub fn claim_insurance(
+        ctx: Context<ClaimInsurance>,
+        nonce: u64,
+        expires_at: i64,
+        incident_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::claim_insurance::process_claim_insurance(
+            ctx,
+            nonce,
+            expires_at,
+            incident_hash,
+        )
+    }
+
+    /// Sponsor opens a campaign matching Donate-route slashes to a verified charity
+    pub fn create_campaign(ctx: Context<CreateCampaign>, campaign_id: u64) -> Result<()> {
+        instructions::create_campaign::process_create_campaign(ctx, campaign_id)
+    }
+
+    /// Anyone adds matching funds to a donation campaign
+    pub fn fund_campaign(ctx: Context<FundCampaign>, amount: u64) -> Result<()> {
+        instructions::fund_campaign::process_fund_campaign(ctx, amount)
+    }
+
+    /// Owner asks `buddy` to be their buddy; inactive until accepted
+    pub fn link_buddy(ctx: Context<LinkBuddy>, buddy: Pubkey) -> Result<()> {
+        instructions::link_buddy::process_link_buddy(ctx, buddy)
+    }
+
+    /// Buddy accepts a pending buddy link
+    pub fn accept_buddy_link(ctx: Context<AcceptBuddyLink>) -> Result<()> {
+        instructions::accept_buddy_link::process_accept_buddy_link(ctx)
+    }
+
+    /// Owner or buddy removes a buddy link
+    pub fn unlink_buddy(ctx: Context<UnlinkBuddy>) -> Result<()> {
+        instructions::unlink_buddy::process_unlink_buddy(ctx)
+    }
+
+    /// Anyone publishes the protocol params PDA for a deployment configured
+    /// before it existed
+    pub fn init_protocol_params(ctx: Context<InitProtocolParams>) -> Result<()> {
+        instructions::sync_protocol_params::process_init_protocol_params(ctx)
+    }
+
+    /// Anyone rewrites the protocol params PDA from the current constants
+    pub fn sync_protocol_params(ctx: Context<SyncProtocolParams>) -> Result<()> {
+        instructions::sync_protocol_params::process_sync_protocol_params(ctx)
+    }
+
+    /// Anyone grows an alarm from an older release to the current layout
+    pub fn migrate_alarm(ctx: Context<MigrateAlarm>) -> Result<()> {
+        instructions::migrate_account::process_migrate_alarm(ctx)
+    }
+
+    /// Anyone grows a user profile from an older release to the current layout
+    pub fn migrate_profile(ctx: Context<MigrateProfile>) -> Result<()> {
+        instructions::migrate_account::process_migrate_profile(ctx)
+    }
+
+    /// Owner saves an alarm's time, window, deposit, route and grace/snooze
+    /// options as a reusable template
+    #[allow(clippy::too_many_arguments)]
+    pub fn save_template(
+        ctx: Context<SaveTemplate>,
+        template_id: u64,
+        alarm_time: i64,
+        deadline: i64,
+        deposit_amount: u64,
+        penalty_route: u8,
+        penalty_destination: Option<Pubkey>,
+        options: Option<state::AlarmOptions>,
+    ) -> Result<()> {
+        instructions::save_template::process_save_template(
+            ctx,
+            template_id,
+            alarm_time,
+            deadline,
+            deposit_amount,
+            penalty_route,
+            penalty_destination,
+            options,
+        )
+    }
+
+    /// Owner creates a funded Alarm from a template, `offset_days` after
+    /// its last alarm time
+    pub fn create_alarm_from_template(
+        ctx: Context<CreateAlarmFromTemplate>,
+        alarm_id: u64,
+        offset_days: u16,
+    ) -> Result<()> {
+        instructions::create_alarm_from_template::process_create_alarm_from_template(
+            ctx,
+            alarm_id,
+            offset_days,
+        )
+    }
+
+    /// Owner sets or clears the wallet a claim pays the deposit to, before
+    /// the alarm fires
+    pub fn set_claim_destination(
+        ctx: Context<SetClaimDestination>,
+        claim_to: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_claim_destination::process_set_claim_destination(ctx, claim_to)
+    }
+
+    /// Open the caller's forgiveness token account, credited by wake streaks
+    pub fn init_forgiveness_token(ctx: Context<InitForgivenessToken>) -> Result<()> {
+        instructions::init_forgiveness_token::process_init_forgiveness_token(ctx)
+    }
+
+    /// Owner burns a forgiveness token to settle a missed alarm as a
+    /// penalty-free late claim, shortly after the deadline
+    pub fn forgive_slash(ctx: Context<ForgiveSlash>) -> Result<()> {
+        instructions::forgive_slash::process_forgive_slash(ctx)
+    }
+
+    /// Owner claims an acknowledged alarm and rolls its deposit straight into
+    /// a new alarm, checked like `create_alarm`, in one transaction
+    #[allow(clippy::too_many_arguments)]
+    pub fn claim_and_roll(
+        ctx: Context<ClaimAndRoll>,
+        new_alarm_id: u64,
+        alarm_time: i64,
+        deadline: i64,
+        penalty_route: u8,
+        penalty_destination: Option<Pubkey>,
+        options: Option<state::AlarmOptions>,
+    ) -> Result<()> {
+        instructions::claim_and_roll::process_claim_and_roll(
+            ctx,
+            new_alarm_id,
+            alarm_time,
+            deadline,
+            penalty_route,
+            penalty_destination,
+            options,
+        )
+    }
+
+    /// Owner moves a Created alarm's penalty destination before alarm time;
+    /// a new buddy must co-sign
+    pub fn update_penalty_destination(
+        ctx: Context<UpdatePenaltyDestination>,
+        new_destination: Pubkey,
+    ) -> Result<()> {
+        instructions::update_penalty_destination::process_update_penalty_destination(
+            ctx,
+            new_destination,
+        )
+    }
+
+    /// Record one proof of a multi-proof alarm; the last one acknowledges it
+    pub fn ack_awake_partial(
+        ctx: Context<AckAwake>,
+        proof_hash: Option<[u8; 32]>,
+        proof_completed_at: i64,
+        proof_type: u8,
+        proof_preimage: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::ack_awake_partial::process_ack_awake_partial(
+            ctx,
+            proof_hash,
+            proof_completed_at,
+            proof_type,
+            proof_preimage,
+        )
     }
 }
@@ -0,0 +1,132 @@
+//! PDA derivation for clients, keepers and tests
+//!
+//! One function per seed layout used by the program's `#[account(seeds = …)]`
+//! constraints, so callers never hand-roll seeds. Nothing here is gated by a
+//! feature or target: the same functions run on-chain and off-chain.
+//! Each returns `(address, bump)` like `Pubkey::find_program_address`.
+
+use anchor_lang::prelude::Pubkey;
+
+/// Alarm PDA: `["alarm", owner, alarm_id (LE)]`.
+pub fn alarm_address(owner: &Pubkey, alarm_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"alarm", owner.as_ref(), &alarm_id.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+/// Vault PDA holding an alarm's deposit: `["vault", alarm]`.
+pub fn vault_address(alarm: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vault", alarm.as_ref()], &crate::ID)
+}
+
+/// User profile PDA: `["user-profile", owner]`.
+pub fn profile_address(owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"user-profile", owner.as_ref()], &crate::ID)
+}
+
+/// Attested-permit nonce PDA: `["permit", alarm, nonce (LE)]`.
+pub fn permit_nonce_address(alarm: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"permit", alarm.as_ref(), &nonce.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+/// Global config singleton: `["config"]`.
+pub fn config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], &crate::ID)
+}
+
+/// Treasury singleton: `["treasury"]`.
+pub fn treasury_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"treasury"], &crate::ID)
+}
+
+/// Insurance pool singleton: `["insurance"]`.
+pub fn insurance_pool_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"insurance"], &crate::ID)
+}
+
+/// Localnet test clock singleton (`test-clock` builds): `["mock-clock"]`.
+pub fn mock_clock_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"mock-clock"], &crate::ID)
+}
+
+/// Attestation server config singleton: `["attestation"]`.
+pub fn attestation_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"attestation"], &crate::ID)
+}
+
+/// Weekly wake schedule: `["wake-schedule", owner, schedule_id (LE)]`.
+pub fn schedule_address(owner: &Pubkey, schedule_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"wake-schedule", owner.as_ref(), &schedule_id.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+/// Buddy's matching stake on an alarm: `["buddy-stake", alarm]`.
+pub fn buddy_stake_address(alarm: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"buddy-stake", alarm.as_ref()], &crate::ID)
+}
+
+/// Saved alarm template: `["alarm-template", owner, template_id (LE)]`.
+pub fn alarm_template_address(owner: &Pubkey, template_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"alarm-template",
+            owner.as_ref(),
+            &template_id.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Owner's forgiveness tokens: `["forgiveness", owner]`.
+pub fn forgiveness_token_address(owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"forgiveness", owner.as_ref()], &crate::ID)
+}
+
+/// Opt-in buddy relationship: `["buddy-link", owner, buddy]`.
+pub fn buddy_link_address(owner: &Pubkey, buddy: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"buddy-link", owner.as_ref(), buddy.as_ref()], &crate::ID)
+}
+
+/// Published protocol constants: `["protocol-params"]`.
+pub fn protocol_params_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"protocol-params"], &crate::ID)
+}
+
+/// Owner's savings vault: `["savings", owner]`.
+pub fn savings_vault_address(owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"savings", owner.as_ref()], &crate::ID)
+}
+
+/// Owner's alarm index: `["alarm-index", owner]`.
+pub fn alarm_index_address(owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"alarm-index", owner.as_ref()], &crate::ID)
+}
+
+/// Protocol counters for one UTC day: `["daily-stats", day]`.
+pub fn daily_stats_address(day: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"daily-stats", &day.to_le_bytes()], &crate::ID)
+}
+
+/// Donation matching campaign: `["campaign", sponsor, campaign_id (LE)]`.
+pub fn campaign_address(sponsor: &Pubkey, campaign_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"campaign", sponsor.as_ref(), &campaign_id.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+/// Self-CPI event authority (`event-cpi` builds): `["__event_authority"]`.
+pub fn event_authority_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"__event_authority"], &crate::ID)
+}
+
+/// Settlement schedule for automation threads: `["schedule", alarm]`.
+pub fn settlement_schedule_address(alarm: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"schedule", alarm.as_ref()], &crate::ID)
+}
@@ -7,6 +7,7 @@
 //! PROPTEST_CASES env var).
 
 use crate::helpers::*;
+use crate::state::SnoozeModel;
 use proptest::prelude::*;
 
 // =====================================================================
@@ -52,6 +53,110 @@ proptest! {
     }
 }
 
+proptest! {
+    #[test]
+    fn snooze_cost_every_model_never_exceeds_remaining(
+        remaining in 0u64..=u64::MAX,
+        count in 0u8..=20u8,
+        percent in 1u64..=50u64
+    ) {
+        for model in [SnoozeModel::Exponential, SnoozeModel::Linear, SnoozeModel::Flat] {
+            if let Some(cost) = snooze_cost_for_model(model, remaining, count, percent) {
+                prop_assert!(cost <= remaining, "{:?} cost {} > {}", model, cost, remaining);
+            }
+        }
+    }
+
+    #[test]
+    fn snooze_cost_models_ordered(
+        remaining in 0u64..=u64::MAX / 100,
+        count in 0u8..=20u8,
+        percent in 1u64..=50u64
+    ) {
+        // flat <= linear <= exponential at every step; equal on the first
+        let flat = snooze_cost_flat(remaining, percent).unwrap();
+        let linear = snooze_cost_linear(remaining, count, percent).unwrap();
+        let exponential = snooze_cost_with_percent(remaining, count, percent);
+        prop_assert!(flat <= linear);
+        if let Some(exponential) = exponential {
+            prop_assert!(linear <= exponential);
+        }
+        if count == 0 {
+            prop_assert_eq!(flat, linear);
+            prop_assert_eq!(Some(linear), exponential);
+        }
+    }
+
+    #[test]
+    fn snooze_cost_linear_monotonic_in_count(
+        remaining in 0u64..=u64::MAX / 100,
+        count in 0u8..=19u8,
+        percent in 1u64..=50u64
+    ) {
+        let low = snooze_cost_linear(remaining, count, percent).unwrap();
+        let high = snooze_cost_linear(remaining, count + 1, percent).unwrap();
+        prop_assert!(high >= low);
+    }
+
+    #[test]
+    fn snooze_cost_flat_independent_of_count(
+        remaining in 0u64..=u64::MAX / 100,
+        count in 0u8..=20u8,
+        percent in 1u64..=50u64
+    ) {
+        prop_assert_eq!(
+            snooze_cost_for_model(SnoozeModel::Flat, remaining, count, percent),
+            snooze_cost_for_model(SnoozeModel::Flat, remaining, 0, percent)
+        );
+    }
+
+    #[test]
+    fn snooze_cost_scaled_never_exceeds_remaining(
+        remaining in 0u64..=u64::MAX,
+        count in 0u8..=20u8,
+        minutes in 1u8..=15u8
+    ) {
+        if let Some(cost) = snooze_cost_scaled(remaining, count, minutes) {
+            prop_assert!(cost <= remaining);
+        }
+    }
+
+    #[test]
+    fn snooze_cost_scaled_default_length_matches_flat(
+        remaining in 0u64..=u64::MAX / 100,
+        count in 0u8..=20u8
+    ) {
+        // A 5-minute scaled snooze costs exactly the default snooze
+        prop_assert_eq!(
+            snooze_cost_scaled(remaining, count, 5),
+            snooze_cost(remaining, count)
+        );
+    }
+
+    #[test]
+    fn snooze_cost_scaled_monotonic_in_minutes(
+        remaining in 0u64..=u64::MAX / 100,
+        count in 0u8..=8u8,
+        minutes in 1u8..=14u8
+    ) {
+        let shorter = snooze_cost_scaled(remaining, count, minutes).unwrap();
+        let longer = snooze_cost_scaled(remaining, count, minutes + 1).unwrap();
+        prop_assert!(shorter <= longer);
+    }
+
+    #[test]
+    fn snooze_cost_scaled_proportional_below_cap(
+        remaining in 0u64..=1_000_000_000_000u64,
+        minutes in 1u8..=5u8
+    ) {
+        // First snooze at <= 5 minutes never hits the remaining cap, so the
+        // cost is the flat cost scaled by minutes / 5 (rounded down)
+        let flat = snooze_cost(remaining, 0).unwrap();
+        let scaled = snooze_cost_scaled(remaining, 0, minutes).unwrap();
+        prop_assert_eq!(scaled, flat * u64::from(minutes) / 5);
+    }
+}
+
 // =====================================================================
 // Emergency penalty invariants
 // =====================================================================
@@ -203,7 +308,7 @@ proptest! {
     ) {
         let _ = validate_alarm_params(
             alarm_time, deadline, current_time,
-            deposit, penalty_route, has_dest,
+            deposit, penalty_route, has_dest, &SnoozeParams::default(),
         );
     }
 
@@ -220,7 +325,7 @@ proptest! {
 
         let result = validate_alarm_params(
             alarm_time, deadline, current_time,
-            deposit, route, has_dest,
+            deposit, route, has_dest, &SnoozeParams::default(),
         );
         prop_assert!(result.is_ok(), "valid params rejected: {:?}", result);
     }
@@ -283,3 +388,112 @@ proptest! {
         }
     }
 }
+
+// =====================================================================
+// Per-alarm snooze economics invariants
+// =====================================================================
+
+proptest! {
+    #[test]
+    fn custom_snooze_cost_never_exceeds_remaining(
+        remaining in 0u64..=u64::MAX,
+        count in 0u8..=20u8,
+        percent in 1u64..=50u64
+    ) {
+        if let Some(cost) = snooze_cost_with_percent(remaining, count, percent) {
+            prop_assert!(cost <= remaining);
+        }
+    }
+
+    #[test]
+    fn custom_snooze_cost_monotonic_in_percent(
+        remaining in 1u64..=1_000_000_000_000u64,
+        count in 0u8..=8u8,
+        percent in 1u64..50u64
+    ) {
+        let low = snooze_cost_with_percent(remaining, count, percent);
+        let high = snooze_cost_with_percent(remaining, count, percent + 1);
+        if let (Some(low), Some(high)) = (low, high) {
+            prop_assert!(high >= low);
+        }
+    }
+
+    #[test]
+    fn in_bounds_snooze_params_always_valid(
+        percent in 1u64..=50u64,
+        extension_seconds in 60i64..=1800i64,
+        max_snoozes in 0u8..=20u8
+    ) {
+        let params = SnoozeParams { percent, extension_seconds, max_snoozes };
+        prop_assert_eq!(validate_snooze_params(&params), Ok(()));
+    }
+
+    #[test]
+    fn bps_share_never_exceeds_amount(
+        amount in 0u64..=u64::MAX,
+        bps in 0u16..=10_000u16
+    ) {
+        let share = bps_of(amount, bps).unwrap();
+        prop_assert!(share <= amount);
+    }
+
+    #[test]
+    fn split_shares_sum_to_amount(
+        amount in 0u64..=u64::MAX / 2,
+        first in 1u16..=9_998u16,
+        second in 1u16..=9_998u16
+    ) {
+        prop_assume!(first + second < 10_000);
+        let third = 10_000 - first - second;
+        let shares = split_shares(amount, &[first, second, third]).unwrap();
+        prop_assert_eq!(shares.iter().sum::<u64>(), amount);
+    }
+
+    #[test]
+    fn challenge_winner_shares_drain_pot(
+        stake in 1u64..=10_000_000_000u64,
+        participants in 1u32..=50u32,
+        winners_seed in 1u32..=50u32
+    ) {
+        let winners = 1 + winners_seed % participants;
+        let pot = stake * u64::from(participants);
+        let mut paid = 0u64;
+        for winners_paid in 0..winners {
+            paid += challenge_winner_share(pot, paid, winners, winners_paid).unwrap();
+        }
+        prop_assert_eq!(paid, pot);
+    }
+
+    #[test]
+    fn partial_slash_never_exceeds_deposit(
+        deposit in 0u64..=u64::MAX / 2,
+        bps in 1u16..=10_000u16,
+        interval in 60i64..=86_400i64,
+        steps in proptest::collection::vec(0i64..=200_000i64, 1..10)
+    ) {
+        let deadline = 1_000_000i64;
+        let mut now = deadline;
+        let (mut remaining, mut slashed) = (deposit, 0u64);
+        for step in steps {
+            now += step;
+            let due = partial_slash_due(remaining, slashed, bps, interval, deadline, now).unwrap();
+            prop_assert!(due <= remaining);
+            remaining -= due;
+            slashed += due;
+            prop_assert_eq!(remaining + slashed, deposit);
+        }
+    }
+
+    #[test]
+    fn late_claim_refund_monotonic(
+        remaining in 0u64..=u64::MAX,
+        deadline in -1_000_000i64..=1_000_000i64,
+        earlier in 0i64..=100_000i64,
+        later_delta in 0i64..=100_000i64
+    ) {
+        let early = late_claim_refund(deadline, deadline + earlier, remaining);
+        let late = late_claim_refund(deadline, deadline + earlier + later_delta, remaining);
+        prop_assert!(early <= remaining);
+        prop_assert!(late <= early);
+    }
+}
@@ -1,5 +1,13 @@
 //! Program state definitions
 
+use crate::constants::{
+    BUDDY_ONLY_SECONDS, BURN_SINK, CLAIM_GRACE_SECONDS, DEFAULT_GRACE_PERIOD,
+    DEFAULT_SNOOZE_EXTENSION_SECONDS, DEFAULT_SNOOZE_PERCENT, EMERGENCY_REFUND_PENALTY_PERCENT,
+    MAX_ATTESTORS, MAX_BLOCKED_DESTINATIONS, MAX_CLAIM_GRACE_SECONDS, MAX_CO_OWNERS, MAX_GUARDIANS,
+    MAX_INDEXED_ALARMS, MAX_PENALTY_SPLITS, MAX_SNOOZE_COUNT, MIN_DEPOSIT_LAMPORTS,
+    SWEEP_EXPIRED_DELAY_SECONDS, TREASURY_SIGNER_COUNT,
+};
+use crate::helpers;
 use anchor_lang::prelude::*;
 
 /// Status of an alarm
@@ -11,14 +19,27 @@ pub enum AlarmStatus {
     Acknowledged,
     Claimed,
     Slashed,
+    /// Funded by a gifter, waiting for the recipient to accept or decline
+    PendingGift,
+    /// Buddy-route alarm waiting for the buddy to co-sign
+    PendingBuddy,
+}
+
+impl AlarmStatus {
+    /// Claimed and Slashed are absorbing: the vault is already closed.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, AlarmStatus::Claimed | AlarmStatus::Slashed)
+    }
 }
 
 /// Penalty route for failed alarms
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum PenaltyRoute {
-    Burn,   // Send to sink address
-    Donate, // Send to charity
-    Buddy,  // Send to friend
+    Burn,    // Send to sink address
+    Donate,  // Send to charity
+    Buddy,   // Send to friend
+    Split,   // Divide between several destinations by bps
+    Savings, // Lock in the owner's SavingsVault
 }
 
 impl TryFrom<u8> for PenaltyRoute {
@@ -29,11 +50,326 @@ impl TryFrom<u8> for PenaltyRoute {
             0 => Ok(PenaltyRoute::Burn),
             1 => Ok(PenaltyRoute::Donate),
             2 => Ok(PenaltyRoute::Buddy),
+            3 => Ok(PenaltyRoute::Split),
+            4 => Ok(PenaltyRoute::Savings),
+            _ => Err(()),
+        }
+    }
+}
+
+/// How snooze cost grows with each paid snooze
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SnoozeModel {
+    Exponential, // percent × 2^n
+    Linear,      // percent × (n + 1)
+    Flat,        // percent every time
+}
+
+impl TryFrom<u8> for SnoozeModel {
+    type Error = ();
+
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        match value {
+            0 => Ok(SnoozeModel::Exponential),
+            1 => Ok(SnoozeModel::Linear),
+            2 => Ok(SnoozeModel::Flat),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Wake proof the owner completed before `ack_awake`
+///
+/// Values match the Android client's `WakeProofEngine.TYPE_*` constants.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProofType {
+    None,       // No proof (or not reported, e.g. attested acks)
+    Steps,      // Step counter target
+    Nfc,        // NFC tag scan
+    Qr,         // QR code scan
+    MathPuzzle, // Arithmetic challenge
+    Shake,      // Shake gesture
+    Photo,      // Camera photo (hash anchored via `anchor_proof`)
+    Location,   // Geofence check-in
+}
+
+impl TryFrom<u8> for ProofType {
+    type Error = ();
+
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ProofType::None),
+            1 => Ok(ProofType::Steps),
+            2 => Ok(ProofType::Nfc),
+            3 => Ok(ProofType::Qr),
+            4 => Ok(ProofType::MathPuzzle),
+            5 => Ok(ProofType::Shake),
+            6 => Ok(ProofType::Photo),
+            7 => Ok(ProofType::Location),
             _ => Err(()),
         }
     }
 }
 
+/// One leg of a split penalty (`bps == 0` marks an empty slot)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct PenaltySplit {
+    pub destination: Pubkey,
+    pub bps: u16,
+}
+
+impl PenaltySplit {
+    pub const SIZE: usize = 32 + 2;
+}
+
+/// Snapshot returned by `get_alarm_status`.
+///
+/// The `can_*` flags mirror the time and state guards of the matching
+/// instruction at `current_time`; signer checks are not included.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AlarmStatusView {
+    pub status: AlarmStatus,
+    pub remaining_amount: u64,
+    pub snooze_count: u8,
+    pub alarm_time: i64,
+    pub deadline: i64,
+    pub can_snooze: bool,
+    pub can_claim: bool,
+    pub can_slash: bool,
+    pub can_sweep: bool,
+    pub current_time: i64,
+}
+
+/// Quote returned by `quote_snooze`.
+///
+/// `cost` is what `snooze` would move out of the vault right now, after
+/// the rent-exempt cap; the new times assume the snooze happens.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SnoozeQuote {
+    pub can_snooze: bool,
+    pub cost: u64,
+    pub extension_seconds: i64,
+    pub new_alarm_time: i64,
+    pub new_deadline: i64,
+}
+
+/// Outcome returned by `validate_create_alarm_params`.
+///
+/// `error_code` is the Anchor error number `create_alarm` would fail with
+/// (0 when valid) and `error_name` its variant name, e.g. `"DepositTooSmall"`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PreflightResult {
+    pub valid: bool,
+    pub error_code: u32,
+    pub error_name: String,
+}
+
+/// Optional per-alarm settings supplied to `create_alarm`.
+///
+/// Every field is optional; `None` falls back to the protocol default.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct AlarmOptions {
+    /// Snooze cost percentage of remaining deposit (default 10)
+    pub snooze_percent: Option<u8>,
+    /// Seconds added per snooze (default 300)
+    pub snooze_extension_seconds: Option<i64>,
+    /// Maximum number of snoozes (default 10)
+    pub max_snoozes: Option<u8>,
+    /// Destinations for `PenaltyRoute::Split`; the first must equal `penalty_destination`
+    pub penalty_splits: Option<Vec<PenaltySplit>>,
+    /// Progressive slashing: bps of the deposit forfeited per elapsed interval (unset = all-or-nothing)
+    pub partial_slash_bps: Option<u16>,
+    /// Progressive slashing: seconds per tranche
+    pub partial_slash_interval_seconds: Option<i64>,
+    /// Only accept server-attested ack/claim (`ack_awake_attested`, `claim_attested`)
+    pub require_attestation: Option<bool>,
+    /// Buddy route: bps of the deposit paid to the buddy on a successful claim
+    pub buddy_reward_bps: Option<u16>,
+    /// User tag for the alarm (see `Alarm::memo`)
+    pub memo: Option<[u8; 32]>,
+    /// Seconds after the deadline an acknowledged alarm may still claim (default 120, max 3600)
+    pub claim_grace_seconds: Option<i64>,
+    /// Leading snooze_count values that cost nothing (default 0, at most `max_snoozes`)
+    pub free_snoozes: Option<u8>,
+    /// `SnoozeModel` as u8 (default 0 = Exponential)
+    pub snooze_model: Option<u8>,
+    /// Buddy route: third party who rules on `raise_dispute` (unset = no disputes)
+    pub arbiter: Option<Pubkey>,
+    /// Dedupe guard for retried submits: the owner's `AlarmIndex` (required
+    /// when set) must list exactly this many alarms before the create
+    pub expected_open_alarms: Option<u16>,
+    /// Take the deposit from the rent `payer` instead of the owner (sponsored onboarding)
+    pub deposit_from_payer: Option<bool>,
+    /// SHA-256 of a secret the owner must reveal at `ack_awake` (e.g. a hash of tag UID + salt)
+    pub proof_commitment: Option<[u8; 32]>,
+    /// Seconds before `alarm_time` that ack and claim already open (default 0, max 1800)
+    pub early_ack_seconds: Option<i64>,
+    /// Unix timestamp after which an ack forfeits a growing share of the
+    /// deposit, reaching all of it at `deadline` (unset = no soft deadline)
+    pub soft_deadline: Option<i64>,
+    /// Wallet that receives the deposit on claim instead of the owner (e.g. a cold wallet)
+    pub claim_to: Option<Pubkey>,
+    /// `ProofType` as u8 that every ack must report (unset = any); must be
+    /// enabled in `GlobalConfig.proof_type_registry`
+    pub required_proof_type: Option<u8>,
+    /// Bitmask (`1 << ProofType`) of two or more proofs that must all be
+    /// recorded with `ack_awake_partial` (unset = a single `ack_awake`)
+    pub required_proofs: Option<u16>,
+    /// Share in bps of an acknowledged deposit that `sweep_acknowledged`
+    /// routes like a penalty when the claim grace lapses (default
+    /// `GlobalConfig.default_unclaimed_penalty_bps`, max 5000)
+    pub unclaimed_penalty_bps: Option<u16>,
+}
+
+impl AlarmOptions {
+    /// Resolve snooze economics, applying `defaults` for unset fields.
+    pub fn snooze_params(&self, defaults: &helpers::SnoozeParams) -> helpers::SnoozeParams {
+        helpers::SnoozeParams {
+            percent: self
+                .snooze_percent
+                .map(u64::from)
+                .unwrap_or(defaults.percent),
+            extension_seconds: self
+                .snooze_extension_seconds
+                .unwrap_or(defaults.extension_seconds),
+            max_snoozes: self.max_snoozes.unwrap_or(defaults.max_snoozes),
+        }
+    }
+}
+
+/// Global protocol configuration PDA (singleton)
+#[account]
+pub struct GlobalConfig {
+    /// Authority allowed to update this config
+    pub admin: Pubkey,
+    /// Protocol fee on slashed deposits, in basis points
+    pub protocol_fee_bps: u16,
+    /// Share of each slash paid to the caller, in basis points
+    pub slasher_bounty_bps: u16,
+    /// Default snooze cost percentage for new alarms
+    pub default_snooze_percent: u8,
+    /// Default snooze extension for new alarms (seconds)
+    pub default_snooze_extension_seconds: i64,
+    /// Default snooze limit for new alarms
+    pub default_max_snoozes: u8,
+    /// Circuit breaker flag
+    pub paused: bool,
+    /// Donate-route destinations must be registered charities
+    pub require_verified_charity: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Largest single alarm deposit (0 = unlimited)
+    pub max_deposit_lamports: u64,
+    /// Per-user total deposited per UTC day, tracked on `UserProfile` (0 = unlimited)
+    pub daily_deposit_cap_lamports: u64,
+    /// Per-user limit on unclosed alarms from `create_alarm`, tracked on `UserProfile` (0 = unlimited)
+    pub max_open_alarms: u16,
+    /// Share of each protocol fee paid to the owner's referrer, in basis points of the fee
+    pub referral_fee_bps: u16,
+    /// Shortest allowed `deadline - alarm_time` (0 = `DEFAULT_MIN_WAKE_WINDOW_SECONDS`)
+    pub min_wake_window_seconds: i64,
+    /// Longest allowed `deadline - alarm_time` (0 = `DEFAULT_MAX_WAKE_WINDOW_SECONDS`)
+    pub max_wake_window_seconds: i64,
+    /// Treasury multisig; all unset = the admin alone controls the treasury,
+    /// otherwise `TREASURY_MULTISIG_THRESHOLD` of these keys must sign
+    pub treasury_signers: [Pubkey; TREASURY_SIGNER_COUNT],
+    /// Burn-route and refund-penalty sink for this deployment
+    /// (`Pubkey::default()` = `BURN_SINK`)
+    pub burn_sink: Pubkey,
+    /// Deposits at or above this many lamports claim in two phases (0 = off)
+    pub two_phase_claim_threshold: u64,
+    /// Address Lookup Table of the program's hot accounts, owned by this
+    /// config PDA (`Pubkey::default()` = none yet)
+    pub lookup_table: Pubkey,
+    /// Share of each slash sent to the `InsurancePool`, in basis points (0 = off)
+    pub insurance_bps: u16,
+    /// Bitmask of `ProofType`s alarms may require, bit `1 << type`
+    /// (0 = every known type)
+    pub proof_type_registry: u16,
+    /// Default `Alarm.unclaimed_penalty_bps` for new alarms (0 = sweep returns everything)
+    pub default_unclaimed_penalty_bps: u16,
+}
+
+impl GlobalConfig {
+    pub const SIZE: usize = 8  // discriminator
+        + 32  // admin
+        + 2   // protocol_fee_bps
+        + 2   // slasher_bounty_bps
+        + 1   // default_snooze_percent
+        + 8   // default_snooze_extension_seconds
+        + 1   // default_max_snoozes
+        + 1   // paused
+        + 1   // require_verified_charity
+        + 1   // bump
+        + 8   // max_deposit_lamports
+        + 8   // daily_deposit_cap_lamports
+        + 2   // max_open_alarms
+        + 2   // referral_fee_bps
+        + 8   // min_wake_window_seconds
+        + 8   // max_wake_window_seconds
+        + 32 * TREASURY_SIGNER_COUNT  // treasury_signers
+        + 32  // burn_sink
+        + 8   // two_phase_claim_threshold
+        + 32  // lookup_table
+        + 2   // insurance_bps
+        + 2   // proof_type_registry
+        + 2   // default_unclaimed_penalty_bps
+        + 14; // padding for future fields
+
+    /// Derive the singleton config PDA.
+    pub fn find_address() -> (Pubkey, u8) {
+        crate::pda::config_address()
+    }
+
+    /// Default snooze economics applied to alarms that don't override them.
+    pub fn default_snooze_params(&self) -> helpers::SnoozeParams {
+        helpers::SnoozeParams {
+            percent: u64::from(self.default_snooze_percent),
+            extension_seconds: self.default_snooze_extension_seconds,
+            max_snoozes: self.default_max_snoozes,
+        }
+    }
+
+    /// Effective `(min, max)` bounds for `deadline - alarm_time`.
+    pub fn wake_window_bounds(&self) -> (i64, i64) {
+        helpers::wake_window_bounds(self.min_wake_window_seconds, self.max_wake_window_seconds)
+    }
+
+    /// Sink that burn-route penalties, slashes and refund penalties must go to.
+    pub fn effective_burn_sink(&self) -> Pubkey {
+        if self.burn_sink == Pubkey::default() {
+            BURN_SINK
+        } else {
+            self.burn_sink
+        }
+    }
+}
+
+/// Partial update applied by `update_config`; `None` leaves a field unchanged.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ConfigUpdate {
+    pub admin: Option<Pubkey>,
+    pub protocol_fee_bps: Option<u16>,
+    pub slasher_bounty_bps: Option<u16>,
+    pub default_snooze_percent: Option<u8>,
+    pub default_snooze_extension_seconds: Option<i64>,
+    pub default_max_snoozes: Option<u8>,
+    pub paused: Option<bool>,
+    pub require_verified_charity: Option<bool>,
+    pub max_deposit_lamports: Option<u64>,
+    pub daily_deposit_cap_lamports: Option<u64>,
+    pub max_open_alarms: Option<u16>,
+    pub referral_fee_bps: Option<u16>,
+    pub min_wake_window_seconds: Option<i64>,
+    pub max_wake_window_seconds: Option<i64>,
+    /// `Pubkey::default()` restores `BURN_SINK`
+    pub burn_sink: Option<Pubkey>,
+    pub two_phase_claim_threshold: Option<u64>,
+    pub insurance_bps: Option<u16>,
+    pub proof_type_registry: Option<u16>,
+    pub default_unclaimed_penalty_bps: Option<u16>,
+}
+
 /// User profile PDA
 #[account]
 #[derive(Default)]
@@ -44,13 +380,122 @@ pub struct UserProfile {
     pub tag_hash: Option<[u8; 32]>,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Alarms claimed (profile passed to `claim`)
+    pub total_claims: u32,
+    /// Consecutive UTC days with at least one claim
+    pub current_streak: u16,
+    /// Longest day streak reached
+    pub best_streak: u16,
+    /// Consecutive claim days without any snoozed claim
+    pub clean_streak: u16,
+    /// Longest snooze-free day streak reached
+    pub best_clean_streak: u16,
+    /// UTC day index (`unix_timestamp / 86_400`) of the last claim
+    pub last_claim_day: i64,
+    /// Session key allowed to `ack_awake` and `claim` for the owner
+    /// (`Pubkey::default()` = none)
+    pub delegate: Pubkey,
+    /// Unix timestamp after which the delegate is no longer accepted
+    pub delegate_expires_at: i64,
+    /// UTC day index of `deposited_today`
+    pub deposit_day: i64,
+    /// Lamports deposited through `create_alarm` on `deposit_day`
+    pub deposited_today: u64,
+    /// Alarms opened through `create_alarm` and not yet closed with `close_alarm`
+    pub open_alarm_count: u16,
+    /// Referrer set by `initialize_with_referrer` (`Pubkey::default()` = none)
+    pub referrer: Pubkey,
+    /// Keys that may jointly redirect acknowledged alarms if the owner key
+    /// is lost (`Pubkey::default()` = empty slot)
+    pub guardians: [Pubkey; MAX_GUARDIANS],
+    /// Owner-designated address that `recover_alarm` pays out to
+    pub recovery_address: Pubkey,
+    /// Penalty destinations `create_alarm` refuses for this owner
+    /// (`Pubkey::default()` = empty slot)
+    pub blocked_destinations: [Pubkey; MAX_BLOCKED_DESTINATIONS],
+    /// Unix timestamp of the last `claim_insurance` payout (0 = never)
+    pub last_insurance_claim_at: i64,
+    /// Lamports lost to paid snoozes (profile passed to `snooze`)
+    pub lifetime_snooze_lamports: u64,
+    /// Snoozes taken, free ones included (profile passed to `snooze`)
+    pub lifetime_snoozes: u32,
+    /// `PROFILE_LAYOUT_VERSION` at creation or the last `migrate_profile`
+    pub layout_version: u8,
 }
 
 impl UserProfile {
     pub const SIZE: usize = 8  // discriminator
         + 32  // owner
         + 1 + 32  // Option<[u8; 32]>
-        + 1; // bump
+        + 1   // bump
+        + 4   // total_claims
+        + 2   // current_streak
+        + 2   // best_streak
+        + 2   // clean_streak
+        + 2   // best_clean_streak
+        + 8   // last_claim_day
+        + 32  // delegate
+        + 8   // delegate_expires_at
+        + 8   // deposit_day
+        + 8   // deposited_today
+        + 2   // open_alarm_count
+        + 32  // referrer
+        + 32 * MAX_GUARDIANS // guardians
+        + 32  // recovery_address
+        + 32 * MAX_BLOCKED_DESTINATIONS // blocked_destinations
+        + 8   // last_insurance_claim_at
+        + 8   // lifetime_snooze_lamports
+        + 4   // lifetime_snoozes
+        + 1; // layout_version
+
+    /// Derive the profile PDA for `owner`.
+    pub fn find_address(owner: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::profile_address(owner)
+    }
+
+    /// Claim counters used for achievements.
+    pub fn stats(&self) -> helpers::ProfileStats {
+        helpers::ProfileStats {
+            total_claims: self.total_claims,
+            current_streak: self.current_streak,
+            best_streak: self.best_streak,
+            clean_streak: self.clean_streak,
+            best_clean_streak: self.best_clean_streak,
+            last_claim_day: self.last_claim_day,
+        }
+    }
+
+    /// Guardian slot of `key`, if it is one of this profile's guardians.
+    pub fn guardian_slot(&self, key: &Pubkey) -> Option<usize> {
+        let guardians = self.guardians.map(|k| k.to_bytes());
+        helpers::guardian_slot(&key.to_bytes(), &guardians)
+    }
+
+    /// Returns `true` if the owner blocked `destination` as a penalty recipient.
+    pub fn is_destination_blocked(&self, destination: &Pubkey) -> bool {
+        let blocked = self.blocked_destinations.map(|k| k.to_bytes());
+        helpers::blocklist_slot(&destination.to_bytes(), &blocked).is_some()
+    }
+
+    /// Returns `true` if `key` is this profile's delegate and it has not expired.
+    pub fn is_active_delegate(&self, key: &Pubkey, current_time: i64) -> bool {
+        helpers::is_delegate_active(
+            &key.to_bytes(),
+            &self.delegate.to_bytes(),
+            self.delegate_expires_at,
+            current_time,
+        )
+    }
+
+    /// Store updated claim counters.
+    pub fn set_stats(&mut self, stats: &helpers::ProfileStats) {
+        self.total_claims = stats.total_claims;
+        self.current_streak = stats.current_streak;
+        self.best_streak = stats.best_streak;
+        self.clean_streak = stats.clean_streak;
+        self.best_clean_streak = stats.best_clean_streak;
+        self.last_claim_day = stats.last_claim_day;
+    }
 }
 
 /// Alarm PDA
@@ -81,6 +526,104 @@ pub struct Alarm {
     pub bump: u8,
     /// Bump seed for vault PDA
     pub vault_bump: u8,
+    /// Additional keys allowed to ack/snooze/claim (`Pubkey::default()` = empty slot).
+    /// Refund and configuration changes still require the primary owner.
+    pub co_owners: [Pubkey; MAX_CO_OWNERS],
+    /// Funder of a gift alarm (refunded on decline/expiry)
+    pub gifter: Option<Pubkey>,
+    /// Designated challenger for a matched-deposit bet
+    pub challenger: Option<Pubkey>,
+    /// Lamports matched by the challenger (0 = bet not joined)
+    pub challenger_stake: u64,
+    /// Snooze cost percentage of remaining deposit
+    pub snooze_percent: u8,
+    /// Seconds added to alarm_time and deadline per snooze
+    pub snooze_extension_seconds: i64,
+    /// Maximum number of snoozes allowed
+    pub max_snoozes: u8,
+    /// Recurring series this alarm was spawned from, if any
+    pub series: Option<Pubkey>,
+    /// Split penalty legs (only used with `PenaltyRoute::Split`)
+    pub penalty_splits: [PenaltySplit; MAX_PENALTY_SPLITS],
+    /// Bps of the deposit forfeited per elapsed interval after the deadline (0 = all-or-nothing)
+    pub partial_slash_bps: u16,
+    /// Seconds per progressive slash tranche
+    pub partial_slash_interval_seconds: i64,
+    /// Lamports already forfeited through `slash_partial`
+    pub partial_slashed: u64,
+    /// Ack and claim must go through the attested instructions
+    pub require_attestation: bool,
+    /// Owner pre-authorized `auto_claim`: anyone may push the claim to the owner
+    pub auto_claim_authorized: bool,
+    /// Buddy route: bps of the remaining deposit sent to the buddy on claim
+    pub buddy_reward_bps: u16,
+    /// User tag: a zero-padded UTF-8 label or short URI, or a hash of a longer
+    /// one (all zeros = none). Informational only; never read by the program.
+    pub memo: [u8; 32],
+    /// Seconds after `deadline` an acknowledged alarm may still claim before
+    /// `sweep_acknowledged` opens
+    pub claim_grace_seconds: i64,
+    /// When the owner finished the wake proof, as reported to `ack_awake`
+    /// (0 = not reported)
+    pub proof_completed_at: i64,
+    /// `ProofType` reported to `ack_awake`
+    pub proof_type: u8,
+    /// Snoozes that extend time without touching the deposit; the
+    /// exponential cost starts after them
+    pub free_snoozes: u8,
+    /// `SnoozeModel` pricing paid snoozes
+    pub snooze_model: u8,
+    /// Buddy route: third party who rules on a dispute
+    pub arbiter: Option<Pubkey>,
+    /// Slashing is frozen until this time by `raise_dispute`
+    /// (0 = never disputed; set to the ruling time once resolved)
+    pub dispute_until: i64,
+    /// Bitmask of the owner's guardian slots that approved redirecting this
+    /// alarm; non-zero blocks `sweep_acknowledged`
+    pub recovery_approvals: u8,
+    /// When `recover_alarm` opens (0 = approval threshold not reached)
+    pub recovery_unlocks_at: i64,
+    /// Lamports the buddy escrowed in the `BuddyStake` PDA (0 = none)
+    pub buddy_stake: u64,
+    /// Account that paid the rent at creation (a sponsor, or the owner)
+    pub payer: Pubkey,
+    /// The deposit came from `payer` rather than the owner
+    pub deposit_from_payer: bool,
+    /// SHA-256 commitment `ack_awake` must open with its preimage (zero = none)
+    pub proof_commitment: [u8; 32],
+    /// Write-once content hash of off-chain wake evidence (zero = none)
+    pub anchored_proof_hash: [u8; 32],
+    /// `ProofType` of the anchored evidence
+    pub anchored_proof_type: u8,
+    /// Deposit reached `config.two_phase_claim_threshold` at creation
+    pub two_phase_claim: bool,
+    /// Unix timestamp of the pending `request_claim` (0 = none)
+    pub claim_requested_at: i64,
+    /// The pending request was vetoed by `flag_claim`
+    pub claim_flagged: bool,
+    /// Seconds before `alarm_time` that ack and claim already open
+    pub early_ack_seconds: i64,
+    /// Owner's deposit lost to the slash, until `claim_insurance` pays it (0 = nothing to claim)
+    pub insured_loss: u64,
+    /// Acks after this forfeit a growing share of the deposit (0 = none)
+    pub soft_deadline: i64,
+    /// `ALARM_LAYOUT_VERSION` at creation or the last `migrate_alarm`
+    pub layout_version: u8,
+    /// `claim` sends the deposit here instead of the owner (None = owner)
+    pub claim_to: Option<Pubkey>,
+    /// `ProofType` every ack must report (0 = `None`, any proof)
+    pub required_proof_type: u8,
+    /// Proofs `ack_awake_partial` must record, bit `1 << ProofType` (0 = single ack)
+    pub required_proofs: u16,
+    /// Proofs recorded so far by `ack_awake_partial`
+    pub completed_proofs: u16,
+    /// Share in bps of the deposit `sweep_acknowledged` routes like a penalty (0 = none)
+    pub unclaimed_penalty_bps: u16,
+    /// Counted in the owner's `open_alarm_count`; `close_alarm` then needs the profile
+    pub open_alarm_counted: bool,
+    /// The owner had a registered tag at creation; acks then need the profile
+    /// so the tag check cannot be skipped
+    pub tag_required: bool,
 }
 
 impl Alarm {
@@ -97,7 +640,416 @@ impl Alarm {
         + 1   // status
         + 1   // bump
         + 1   // vault_bump
-        + 64; // padding for future fields (e.g. deposit_mint)
+        + 32 * MAX_CO_OWNERS // co_owners
+        + 1 + 32  // Option<Pubkey> gifter
+        + 1 + 32  // Option<Pubkey> challenger
+        + 8   // challenger_stake
+        + 1   // snooze_percent
+        + 8   // snooze_extension_seconds
+        + 1   // max_snoozes
+        + 1 + 32  // Option<Pubkey> series
+        + PenaltySplit::SIZE * MAX_PENALTY_SPLITS // penalty_splits
+        + 2   // partial_slash_bps
+        + 8   // partial_slash_interval_seconds
+        + 8   // partial_slashed
+        + 1   // require_attestation
+        + 1   // auto_claim_authorized
+        + 2   // buddy_reward_bps
+        + 32  // memo
+        + 8   // claim_grace_seconds
+        + 8   // proof_completed_at
+        + 1   // proof_type
+        + 1   // free_snoozes
+        + 1   // snooze_model
+        + 1 + 32  // Option<Pubkey> arbiter
+        + 8   // dispute_until
+        + 1   // recovery_approvals
+        + 8   // recovery_unlocks_at
+        + 8   // buddy_stake
+        + 32  // payer
+        + 1   // deposit_from_payer
+        + 32  // proof_commitment
+        + 32  // anchored_proof_hash
+        + 1   // anchored_proof_type
+        + 1   // two_phase_claim
+        + 8   // claim_requested_at
+        + 1   // claim_flagged
+        + 8   // early_ack_seconds
+        + 8   // insured_loss
+        + 8   // soft_deadline
+        + 1   // layout_version
+        + 1 + 32  // Option<Pubkey> claim_to
+        + 1   // required_proof_type
+        + 2   // required_proofs
+        + 2   // completed_proofs
+        + 2   // unclaimed_penalty_bps
+        + 1   // open_alarm_counted
+        + 1   // tag_required
+        + 21; // padding for future fields (e.g. deposit_mint)
+
+    /// Snooze economics stored on this alarm.
+    pub fn snooze_params(&self) -> helpers::SnoozeParams {
+        helpers::SnoozeParams {
+            percent: u64::from(self.snooze_percent),
+            extension_seconds: self.snooze_extension_seconds,
+            max_snoozes: self.max_snoozes,
+        }
+    }
+
+    /// Store resolved snooze economics on this alarm.
+    pub fn set_snooze_params(&mut self, params: &helpers::SnoozeParams) {
+        // Bounded by MAX_SNOOZE_PERCENT during validation
+        self.snooze_percent = params.percent as u8;
+        self.snooze_extension_seconds = params.extension_seconds;
+        self.max_snoozes = params.max_snoozes;
+    }
+
+    /// Returns `true` if `key` is the owner or one of the registered co-owners.
+    pub fn is_authorized(&self, key: &Pubkey) -> bool {
+        let co_owners = self.co_owners.map(|k| k.to_bytes());
+        helpers::is_authorized_signer(&key.to_bytes(), &self.owner.to_bytes(), &co_owners)
+    }
+
+    /// Populated split legs, in order (empty slots have `bps == 0`).
+    pub fn active_splits(&self) -> Vec<PenaltySplit> {
+        self.penalty_splits
+            .iter()
+            .filter(|s| s.bps > 0)
+            .copied()
+            .collect()
+    }
+
+    /// Derive the alarm PDA for `(owner, alarm_id)`, as used by `create_alarm`.
+    pub fn find_address(owner: &Pubkey, alarm_id: u64) -> (Pubkey, u8) {
+        crate::pda::alarm_address(owner, alarm_id)
+    }
+
+    /// Returns `true` if this alarm is slashed in tranches via `slash_partial`.
+    pub fn is_progressive(&self) -> bool {
+        self.partial_slash_bps > 0
+    }
+
+    /// Payer other than the owner that funded the deposit (a sponsor or
+    /// gifter); an emergency refund goes back to it.
+    pub fn deposit_funder(&self) -> Option<Pubkey> {
+        (self.deposit_from_payer && self.payer != self.owner).then_some(self.payer)
+    }
+
+    /// Funder the deposit goes back to on claim or sweep. Gifts are not
+    /// included: the recipient wins a gift by waking.
+    pub fn sponsor(&self) -> Option<Pubkey> {
+        self.deposit_funder().filter(|_| self.gifter.is_none())
+    }
+}
+
+/// Recurring alarm series PDA - escrows one deposit for many occurrences
+#[account]
+pub struct AlarmSeries {
+    /// Owner of this series
+    pub owner: Pubkey,
+    /// Client-assigned series identifier (used in PDA seeds)
+    pub series_id: u64,
+    /// Alarm time of the next occurrence to spawn (Unix timestamp)
+    pub next_alarm_time: i64,
+    /// Seconds between occurrences
+    pub interval_seconds: i64,
+    /// Seconds from each occurrence's alarm_time to its deadline
+    pub window_seconds: i64,
+    /// Deposit moved into each spawned occurrence's vault
+    pub deposit_per_occurrence: u64,
+    /// Lamports still escrowed for future occurrences
+    pub escrowed_amount: u64,
+    /// Occurrences left to spawn
+    pub remaining_occurrences: u16,
+    /// Penalty route applied to each occurrence
+    pub penalty_route: u8,
+    /// Penalty destination applied to each occurrence
+    pub penalty_destination: Option<Pubkey>,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Slots marked by `skip_occurrence`: bit k = `next_alarm_time + k * interval_seconds`
+    pub skipped_slots: u32,
+    /// Quota period of the last skip (`now / SERIES_SKIP_PERIOD_SECONDS`)
+    pub skip_period: i64,
+    /// Skips requested in `skip_period`
+    pub skips_in_period: u8,
+}
+
+impl AlarmSeries {
+    pub const SIZE: usize = 8  // discriminator
+        + 32  // owner
+        + 8   // series_id
+        + 8   // next_alarm_time
+        + 8   // interval_seconds
+        + 8   // window_seconds
+        + 8   // deposit_per_occurrence
+        + 8   // escrowed_amount
+        + 2   // remaining_occurrences
+        + 1   // penalty_route
+        + 1 + 32  // Option<Pubkey> penalty_destination
+        + 1   // bump
+        + 4   // skipped_slots
+        + 8   // skip_period
+        + 1; // skips_in_period
+}
+
+/// Buddy's matching stake on a Buddy-route alarm, escrowed apart from the
+/// vault and released to the buddy once the alarm is resolved
+#[account]
+pub struct BuddyStake {
+    /// Alarm the stake backs
+    pub alarm: Pubkey,
+    /// Buddy who staked (the alarm's penalty destination)
+    pub buddy: Pubkey,
+    /// Lamports staked (the alarm's remaining deposit at stake time)
+    pub amount: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl BuddyStake {
+    pub const SIZE: usize = 8  // discriminator
+        + 32  // alarm
+        + 32  // buddy
+        + 8   // amount
+        + 1; // bump
+
+    /// Buddy stake PDA for `alarm`.
+    pub fn find_address(alarm: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::buddy_stake_address(alarm)
+    }
+}
+
+/// Opt-in buddy relationship PDA. `owner` and `buddy` sit at fixed offsets
+/// (8 and 40), so a buddy can list everyone relying on them with a memcmp
+/// filter on offset 40
+#[account]
+pub struct BuddyLink {
+    /// User who asked for the buddy
+    pub owner: Pubkey,
+    /// Buddy who keeps the owner accountable
+    pub buddy: Pubkey,
+    /// Set once the buddy accepts with `accept_buddy_link`
+    pub active: bool,
+    /// Unix timestamp of `link_buddy`
+    pub created_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl BuddyLink {
+    pub const SIZE: usize = 8  // discriminator
+        + 32  // owner
+        + 32  // buddy
+        + 1   // active
+        + 8   // created_at
+        + 1; // bump
+
+    /// Buddy link PDA for `owner` and `buddy`.
+    pub fn find_address(owner: &Pubkey, buddy: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::buddy_link_address(owner, buddy)
+    }
+}
+
+/// Timezone-aware weekly wake schedule PDA - the cross-device source of
+/// truth for recurring alarms. Nothing is escrowed: `materialize_next_alarm`
+/// turns the next occurrence into a regular Alarm funded by the owner.
+#[account]
+pub struct Schedule {
+    /// Owner of this schedule
+    pub owner: Pubkey,
+    /// Client-assigned schedule identifier (used in PDA seeds)
+    pub schedule_id: u64,
+    /// Local weekdays to ring on: bit 0 = Monday … bit 6 = Sunday
+    pub days_of_week: u8,
+    /// Local wake time, in minutes after local midnight
+    pub wake_minute: u16,
+    /// Local time minus UTC, in minutes (update it when daylight saving changes)
+    pub utc_offset_minutes: i16,
+    /// Seconds from each occurrence's alarm_time to its deadline
+    pub window_seconds: i64,
+    /// Deposit the owner pays into each materialized alarm
+    pub deposit_amount: u64,
+    /// Penalty route applied to each occurrence
+    pub penalty_route: u8,
+    /// Penalty destination applied to each occurrence
+    pub penalty_destination: Option<Pubkey>,
+    /// Alarm time of the last materialized occurrence (0 = none yet)
+    pub last_alarm_time: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Schedule {
+    pub const SIZE: usize = 8  // discriminator
+        + 32  // owner
+        + 8   // schedule_id
+        + 1   // days_of_week
+        + 2   // wake_minute
+        + 2   // utc_offset_minutes
+        + 8   // window_seconds
+        + 8   // deposit_amount
+        + 1   // penalty_route
+        + 1 + 32  // Option<Pubkey> penalty_destination
+        + 8   // last_alarm_time
+        + 1; // bump
+
+    /// Weekly schedule PDA for `owner` and `schedule_id`.
+    pub fn find_address(owner: &Pubkey, schedule_id: u64) -> (Pubkey, u8) {
+        crate::pda::schedule_address(owner, schedule_id)
+    }
+}
+
+/// Saved alarm settings PDA - `create_alarm_from_template` clones them into
+/// a new Alarm shifted by whole days ("same as yesterday"). Nothing is
+/// escrowed; each alarm is funded by the owner when it is created.
+#[account]
+pub struct AlarmTemplate {
+    /// Owner of this template
+    pub owner: Pubkey,
+    /// Client-assigned template identifier (used in PDA seeds)
+    pub template_id: u64,
+    /// Alarm time that `offset_days` counts from; moves to each created alarm's time
+    pub alarm_time: i64,
+    /// Seconds from alarm_time to deadline
+    pub window_seconds: i64,
+    /// Deposit the owner pays into each created alarm
+    pub deposit_amount: u64,
+    /// Penalty route applied to each created alarm
+    pub penalty_route: u8,
+    /// Penalty destination applied to each created alarm
+    pub penalty_destination: Option<Pubkey>,
+    /// `AlarmOptions.claim_grace_seconds`
+    pub claim_grace_seconds: Option<i64>,
+    /// `AlarmOptions.snooze_percent`
+    pub snooze_percent: Option<u8>,
+    /// `AlarmOptions.snooze_extension_seconds`
+    pub snooze_extension_seconds: Option<i64>,
+    /// `AlarmOptions.max_snoozes`
+    pub max_snoozes: Option<u8>,
+    /// `AlarmOptions.free_snoozes`
+    pub free_snoozes: Option<u8>,
+    /// `AlarmOptions.snooze_model`
+    pub snooze_model: Option<u8>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AlarmTemplate {
+    pub const SIZE: usize = 8  // discriminator
+        + 32  // owner
+        + 8   // template_id
+        + 8   // alarm_time
+        + 8   // window_seconds
+        + 8   // deposit_amount
+        + 1   // penalty_route
+        + 1 + 32  // Option<Pubkey> penalty_destination
+        + 1 + 8   // Option<i64> claim_grace_seconds
+        + 1 + 1   // Option<u8> snooze_percent
+        + 1 + 8   // Option<i64> snooze_extension_seconds
+        + 1 + 1   // Option<u8> max_snoozes
+        + 1 + 1   // Option<u8> free_snoozes
+        + 1 + 1   // Option<u8> snooze_model
+        + 1; // bump
+
+    /// Alarm template PDA for `owner` and `template_id`.
+    pub fn find_address(owner: &Pubkey, template_id: u64) -> (Pubkey, u8) {
+        crate::pda::alarm_template_address(owner, template_id)
+    }
+
+    /// The grace and snooze settings a template keeps; every other
+    /// `AlarmOptions` field must be unset.
+    pub fn is_templatable(options: &AlarmOptions) -> bool {
+        *options
+            == AlarmOptions {
+                claim_grace_seconds: options.claim_grace_seconds,
+                snooze_percent: options.snooze_percent,
+                snooze_extension_seconds: options.snooze_extension_seconds,
+                max_snoozes: options.max_snoozes,
+                free_snoozes: options.free_snoozes,
+                snooze_model: options.snooze_model,
+                ..AlarmOptions::default()
+            }
+    }
+
+    /// Options for alarms created from this template.
+    pub fn options(&self) -> AlarmOptions {
+        AlarmOptions {
+            claim_grace_seconds: self.claim_grace_seconds,
+            snooze_percent: self.snooze_percent,
+            snooze_extension_seconds: self.snooze_extension_seconds,
+            max_snoozes: self.max_snoozes,
+            free_snoozes: self.free_snoozes,
+            snooze_model: self.snooze_model,
+            ..AlarmOptions::default()
+        }
+    }
+}
+
+/// Group wake challenge PDA - holds every participant's stake
+#[account]
+pub struct Challenge {
+    /// Creator of the challenge
+    pub creator: Pubkey,
+    /// Client-assigned challenge identifier (used in PDA seeds)
+    pub challenge_id: u64,
+    /// Shared wake time (Unix timestamp)
+    pub wake_time: i64,
+    /// Shared ACK deadline (Unix timestamp)
+    pub deadline: i64,
+    /// Stake every participant deposits on join
+    pub deposit_amount: u64,
+    /// Sum of all stakes
+    pub total_pot: u64,
+    /// Lamports already paid to winners or forfeited
+    pub paid_out: u64,
+    /// Participants who joined
+    pub participant_count: u32,
+    /// Participants who acknowledged before the deadline
+    pub acked_count: u32,
+    /// Participants settled so far
+    pub settled_count: u32,
+    /// Winners paid so far (the last one receives rounding dust)
+    pub winners_paid: u32,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Challenge {
+    pub const SIZE: usize = 8  // discriminator
+        + 32  // creator
+        + 8   // challenge_id
+        + 8   // wake_time
+        + 8   // deadline
+        + 8   // deposit_amount
+        + 8   // total_pot
+        + 8   // paid_out
+        + 4   // participant_count
+        + 4   // acked_count
+        + 4   // settled_count
+        + 4   // winners_paid
+        + 1; // bump
+}
+
+/// Per-user membership in a challenge
+#[account]
+pub struct ChallengeParticipant {
+    /// Challenge this membership belongs to
+    pub challenge: Pubkey,
+    /// Participating user
+    pub user: Pubkey,
+    /// Whether the user acknowledged before the deadline
+    pub acked: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ChallengeParticipant {
+    pub const SIZE: usize = 8  // discriminator
+        + 32  // challenge
+        + 32  // user
+        + 1   // acked
+        + 1; // bump
 }
 
 /// Vault PDA - holds deposited SOL for an alarm
@@ -113,4 +1065,524 @@ impl Vault {
     pub const SIZE: usize = 8   // discriminator
         + 32  // alarm
         + 1; // bump
+
+    /// Derive the vault PDA holding `alarm`'s deposit.
+    pub fn find_address(alarm: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::vault_address(alarm)
+    }
+}
+
+/// Treasury PDA - accumulates protocol fees taken from slashed deposits
+#[account]
+pub struct Treasury {
+    /// Lifetime protocol fees collected (lamports)
+    pub total_collected: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    /// Lifetime withdrawals (lamports); the balance above rent should equal
+    /// `total_collected - total_withdrawn`
+    pub total_withdrawn: u64,
+}
+
+impl Treasury {
+    pub const SIZE: usize = 8   // discriminator
+        + 8   // total_collected
+        + 1   // bump
+        + 8; // total_withdrawn
+
+    /// Derive the singleton treasury PDA.
+    pub fn find_address() -> (Pubkey, u8) {
+        crate::pda::treasury_address()
+    }
+}
+
+/// Read-only mirror of the protocol constants in `constants.rs`, so wallets
+/// and third-party clients read live values instead of hard-coding them.
+/// Written by `init_config`; `sync_protocol_params` refreshes it after an
+/// upgrade changes a constant
+#[account]
+pub struct ProtocolParams {
+    /// Smallest accepted deposit (lamports)
+    pub min_deposit_lamports: u64,
+    /// Default snooze cost as a percent of the remaining deposit
+    pub default_snooze_percent: u8,
+    /// Default snooze limit per alarm
+    pub max_snooze_count: u8,
+    /// Default time a snooze adds (seconds)
+    pub default_snooze_extension_seconds: i64,
+    /// Default gap between `alarm_time` and `deadline` (seconds)
+    pub grace_period_seconds: i64,
+    /// Default window after `deadline` in which acknowledged alarms claim
+    pub claim_grace_seconds: i64,
+    /// Upper bound for a per-alarm claim grace
+    pub max_claim_grace_seconds: i64,
+    /// Buddy's exclusive slash window after `deadline` (seconds)
+    pub buddy_only_seconds: i64,
+    /// Delay after `deadline` before `sweep_expired` opens (seconds)
+    pub sweep_expired_delay_seconds: i64,
+    /// Share of the deposit `emergency_refund` keeps (percent)
+    pub emergency_refund_penalty_percent: u8,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ProtocolParams {
+    pub const SIZE: usize = 8   // discriminator
+        + 8   // min_deposit_lamports
+        + 1   // default_snooze_percent
+        + 1   // max_snooze_count
+        + 8   // default_snooze_extension_seconds
+        + 8   // grace_period_seconds
+        + 8   // claim_grace_seconds
+        + 8   // max_claim_grace_seconds
+        + 8   // buddy_only_seconds
+        + 8   // sweep_expired_delay_seconds
+        + 1   // emergency_refund_penalty_percent
+        + 1   // bump
+        + 64; // padding for future parameters
+
+    /// The values this build of the program enforces.
+    pub fn current(bump: u8) -> Self {
+        Self {
+            min_deposit_lamports: MIN_DEPOSIT_LAMPORTS,
+            default_snooze_percent: DEFAULT_SNOOZE_PERCENT as u8,
+            max_snooze_count: MAX_SNOOZE_COUNT,
+            default_snooze_extension_seconds: DEFAULT_SNOOZE_EXTENSION_SECONDS,
+            grace_period_seconds: DEFAULT_GRACE_PERIOD,
+            claim_grace_seconds: CLAIM_GRACE_SECONDS,
+            max_claim_grace_seconds: MAX_CLAIM_GRACE_SECONDS,
+            buddy_only_seconds: BUDDY_ONLY_SECONDS,
+            sweep_expired_delay_seconds: SWEEP_EXPIRED_DELAY_SECONDS,
+            emergency_refund_penalty_percent: EMERGENCY_REFUND_PENALTY_PERCENT as u8,
+            bump,
+        }
+    }
+
+    /// Derive the singleton protocol params PDA.
+    pub fn find_address() -> (Pubkey, u8) {
+        crate::pda::protocol_params_address()
+    }
+}
+
+/// Insurance pool PDA - funded by `insurance_bps` of every slash, paid out
+/// by `claim_insurance` to owners slashed by an attested infrastructure failure
+#[account]
+pub struct InsurancePool {
+    /// Lifetime slash shares received (lamports)
+    pub total_collected: u64,
+    /// Lifetime `claim_insurance` payouts (lamports)
+    pub total_paid: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl InsurancePool {
+    pub const SIZE: usize = 8   // discriminator
+        + 8   // total_collected
+        + 8   // total_paid
+        + 1; // bump
+
+    /// Derive the singleton insurance pool PDA.
+    pub fn find_address() -> (Pubkey, u8) {
+        crate::pda::insurance_pool_address()
+    }
+}
+
+/// Donation matching campaign PDA - holds a sponsor's matching funds for
+/// one charity; `slash` matches Donate-route penalties to it 1:1 until the
+/// balance runs out
+#[account]
+pub struct Campaign {
+    /// Sponsor who created the campaign
+    pub sponsor: Pubkey,
+    /// Client-assigned campaign identifier (used in PDA seeds)
+    pub campaign_id: u64,
+    /// Verified charity address whose donations are matched
+    pub charity: Pubkey,
+    /// Lifetime lamports funded into the campaign
+    pub total_funded: u64,
+    /// Lifetime lamports paid out as matches
+    pub total_matched: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Campaign {
+    pub const SIZE: usize = 8   // discriminator
+        + 32  // sponsor
+        + 8   // campaign_id
+        + 32  // charity
+        + 8   // total_funded
+        + 8   // total_matched
+        + 1; // bump
+
+    /// Derive a sponsor's campaign PDA.
+    pub fn find_address(sponsor: &Pubkey, campaign_id: u64) -> (Pubkey, u8) {
+        crate::pda::campaign_address(sponsor, campaign_id)
+    }
+}
+
+/// Verified charity PDA - one per vetted Donate-route destination
+#[account]
+pub struct Charity {
+    /// Donation address
+    pub address: Pubkey,
+    /// Hash of the charity's display name (full name lives off-chain)
+    pub name_hash: [u8; 32],
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Charity {
+    pub const SIZE: usize = 8   // discriminator
+        + 32  // address
+        + 32  // name_hash
+        + 1; // bump
+}
+
+/// Anti-replay marker for a consumed attestation permit
+#[account]
+pub struct PermitNonce {
+    /// Alarm the permit was issued for
+    pub alarm: Pubkey,
+    /// Server-assigned permit nonce (used in PDA seeds)
+    pub nonce: u64,
+    /// Unix timestamp after which the permit is no longer valid
+    pub expires_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl PermitNonce {
+    pub const SIZE: usize = 8   // discriminator
+        + 32  // alarm
+        + 8   // nonce
+        + 8   // expires_at
+        + 1; // bump
+}
+
+/// Attestation signer configuration PDA (singleton, admin-managed)
+#[account]
+pub struct AttestationConfig {
+    /// Key that signs new permits
+    pub current_key: Pubkey,
+    /// Rotated-out key, still accepted until `previous_valid_until`
+    pub previous_key: Pubkey,
+    /// Unix timestamp after which `previous_key` is rejected
+    pub previous_valid_until: i64,
+    /// Additional attestor keys (`Pubkey::default()` = empty slot)
+    pub attestors: [Pubkey; MAX_ATTESTORS],
+    /// Distinct valid signatures required per permit (current/previous key count once)
+    pub threshold: u8,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl AttestationConfig {
+    pub const SIZE: usize = 8   // discriminator
+        + 32  // current_key
+        + 32  // previous_key
+        + 8   // previous_valid_until
+        + 32 * MAX_ATTESTORS // attestors
+        + 1   // threshold
+        + 1; // bump
+}
+
+/// Achievement badge PDA - one per (owner, badge_id), proof of a milestone
+#[account]
+pub struct Achievement {
+    /// Profile owner who unlocked the badge
+    pub owner: Pubkey,
+    /// Badge identifier (see `BADGE_*` constants)
+    pub badge_id: u8,
+    /// Unix timestamp of the unlock
+    pub unlocked_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Achievement {
+    pub const SIZE: usize = 8   // discriminator
+        + 32  // owner
+        + 1   // badge_id
+        + 8   // unlocked_at
+        + 1; // bump
+}
+
+/// Forgiveness token PDA - one per owner, holding the tokens earned by wake
+/// streaks. Not transferable: only the owner's `forgive_slash` spends them.
+#[account]
+pub struct ForgivenessToken {
+    /// Owner who earned the tokens
+    pub owner: Pubkey,
+    /// Unspent tokens (at most `MAX_FORGIVENESS_TOKENS`)
+    pub balance: u8,
+    /// Tokens spent on `forgive_slash` so far
+    pub redeemed: u32,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ForgivenessToken {
+    pub const SIZE: usize = 8   // discriminator
+        + 32  // owner
+        + 1   // balance
+        + 4   // redeemed
+        + 1; // bump
+}
+
+/// Weekly leaderboard epoch PDA - aggregate totals and finalization flag
+#[account]
+pub struct LeaderboardEpoch {
+    /// Epoch index (`unix_timestamp / LEADERBOARD_EPOCH_SECONDS`)
+    pub epoch: u64,
+    /// Users with an entry in this epoch
+    pub participants: u32,
+    /// Claims recorded in this epoch
+    pub total_claims: u32,
+    /// Slashes recorded in this epoch
+    pub total_slashes: u32,
+    /// Set by `finalize_epoch` after the epoch ends; entries are frozen
+    pub finalized: bool,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl LeaderboardEpoch {
+    pub const SIZE: usize = 8   // discriminator
+        + 8   // epoch
+        + 4   // participants
+        + 4   // total_claims
+        + 4   // total_slashes
+        + 1   // finalized
+        + 1; // bump
+}
+
+/// Per-user leaderboard entry for one epoch
+#[account]
+pub struct LeaderboardEntry {
+    /// User this entry ranks
+    pub owner: Pubkey,
+    /// Epoch index
+    pub epoch: u64,
+    /// Alarms claimed in this epoch
+    pub claims: u32,
+    /// Alarms slashed in this epoch
+    pub slashes: u32,
+    /// Lamports returned to the user by claims
+    pub claimed_lamports: u64,
+    /// Lamports lost to slashes
+    pub slashed_lamports: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl LeaderboardEntry {
+    pub const SIZE: usize = 8   // discriminator
+        + 32  // owner
+        + 8   // epoch
+        + 4   // claims
+        + 4   // slashes
+        + 8   // claimed_lamports
+        + 8   // slashed_lamports
+        + 1; // bump
+}
+
+/// Protocol-wide counters for one UTC day (`unix_timestamp / 86_400`), so
+/// dashboards can read aggregates instead of replaying events
+///
+/// Updated best-effort by `create_alarm`, `claim` and `slash` when the
+/// current day's PDA is passed; other days' PDAs are left untouched.
+#[account]
+pub struct DailyStats {
+    /// UTC day index
+    pub day: u64,
+    /// Alarms created
+    pub alarms_created: u32,
+    /// Alarms claimed
+    pub claims: u32,
+    /// Alarms slashed
+    pub slashes: u32,
+    /// Lamports forfeited by those slashes (before fee and bounty)
+    pub lamports_slashed: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl DailyStats {
+    pub const SIZE: usize = 8   // discriminator
+        + 8   // day
+        + 4   // alarms_created
+        + 4   // claims
+        + 4   // slashes
+        + 8   // lamports_slashed
+        + 1; // bump
+
+    /// Daily stats PDA for `day`.
+    pub fn find_address(day: u64) -> (Pubkey, u8) {
+        crate::pda::daily_stats_address(day)
+    }
+}
+
+/// Head-to-head wake duel PDA - holds both stakes
+#[account]
+pub struct Duel {
+    /// User who opened the duel (pays rent, receives it back on settle)
+    pub creator: Pubkey,
+    /// Invited opponent
+    pub opponent: Pubkey,
+    /// Client-assigned duel identifier (used in PDA seeds)
+    pub duel_id: u64,
+    /// Stake each side deposits
+    pub stake: u64,
+    /// Creator's wake time (Unix timestamp)
+    pub creator_wake_time: i64,
+    /// Creator's ACK deadline (Unix timestamp)
+    pub creator_deadline: i64,
+    /// Opponent's wake time (Unix timestamp)
+    pub opponent_wake_time: i64,
+    /// Opponent's ACK deadline (Unix timestamp)
+    pub opponent_deadline: i64,
+    /// Opponent deposited their stake
+    pub accepted: bool,
+    /// Creator acknowledged within their window
+    pub creator_acked: bool,
+    /// Opponent acknowledged within their window
+    pub opponent_acked: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Duel {
+    pub const SIZE: usize = 8  // discriminator
+        + 32  // creator
+        + 32  // opponent
+        + 8   // duel_id
+        + 8   // stake
+        + 8   // creator_wake_time
+        + 8   // creator_deadline
+        + 8   // opponent_wake_time
+        + 8   // opponent_deadline
+        + 1   // accepted
+        + 1   // creator_acked
+        + 1   // opponent_acked
+        + 1; // bump
+}
+
+/// Per-user time-locked savings PDA - receives `PenaltyRoute::Savings` penalties
+#[account]
+pub struct SavingsVault {
+    /// User who can withdraw once unlocked
+    pub owner: Pubkey,
+    /// Lock applied from each incoming penalty
+    pub lock_seconds: i64,
+    /// Earliest withdrawal time (Unix timestamp; 0 = nothing locked yet)
+    pub unlock_at: i64,
+    /// Lifetime penalties received (informational)
+    pub total_deposited: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SavingsVault {
+    pub const SIZE: usize = 8  // discriminator
+        + 32  // owner
+        + 8   // lock_seconds
+        + 8   // unlock_at
+        + 8   // total_deposited
+        + 1; // bump
+
+    /// Savings vault PDA for `owner`.
+    pub fn find_address(owner: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::savings_vault_address(owner)
+    }
+}
+
+/// Per-owner list of live alarm ids, so clients can enumerate a user's
+/// alarms with one index fetch plus one `getMultipleAccounts`
+///
+/// Maintained by `create_alarm` (add) and every settlement path plus
+/// `close_alarm` (remove) whenever the index is passed: claim, slash and
+/// refund, `sweep_acknowledged`, `auto_claim`, `claim_late`,
+/// `claim_attested`, the last `slash_partial` tranche, `crank_settle` and
+/// `settle_scheduled`. A path run without the index leaves the id in place
+/// until `close_alarm`, so clients should still skip terminal alarms.
+#[account]
+pub struct AlarmIndex {
+    /// Owner whose alarms are listed
+    pub owner: Pubkey,
+    /// Active alarm ids, in creation order (at most `MAX_INDEXED_ALARMS`)
+    pub alarm_ids: Vec<u64>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AlarmIndex {
+    pub const SIZE: usize = 8  // discriminator
+        + 32  // owner
+        + 4 + 8 * MAX_INDEXED_ALARMS  // alarm_ids
+        + 1; // bump
+
+    /// Alarm index PDA for `owner`.
+    pub fn find_address(owner: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::alarm_index_address(owner)
+    }
+}
+
+/// Automation hook for one alarm: pins the accounts `settle_scheduled`
+/// takes, so a thread (Clockwork-style scheduler) can register one fixed
+/// instruction at `trigger_at`
+#[account]
+pub struct SettlementSchedule {
+    /// Alarm to settle
+    pub alarm: Pubkey,
+    /// Alarm owner - receives a sweep
+    pub owner: Pubkey,
+    /// Receives a slash (route destination, burn sink or matched challenger)
+    pub penalty_recipient: Pubkey,
+    /// Paid the schedule's rent; refunded when it closes
+    pub creator: Pubkey,
+    /// Earliest time either settlement opens (see `settlement_trigger_time`)
+    pub trigger_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SettlementSchedule {
+    pub const SIZE: usize = 8  // discriminator
+        + 32  // alarm
+        + 32  // owner
+        + 32  // penalty_recipient
+        + 32  // creator
+        + 8   // trigger_at
+        + 1; // bump
+
+    /// Settlement schedule PDA for `alarm`.
+    pub fn find_address(alarm: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::settlement_schedule_address(alarm)
+    }
+}
+
+/// Localnet test clock - a time that overrides `Clock::unix_timestamp` in the
+/// lifecycle handlers when passed as their last remaining account
+/// (see `crate::clock`). Only exists with the `test-clock` feature
+#[cfg(feature = "test-clock")]
+#[account]
+pub struct MockClock {
+    /// Unix time handlers read instead of the sysvar (0 = use the sysvar)
+    pub unix_timestamp: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+#[cfg(feature = "test-clock")]
+impl MockClock {
+    pub const SIZE: usize = 8  // discriminator
+        + 8   // unix_timestamp
+        + 1; // bump
+
+    /// Derive the singleton mock clock PDA.
+    pub fn find_address() -> (Pubkey, u8) {
+        crate::pda::mock_clock_address()
+    }
 }
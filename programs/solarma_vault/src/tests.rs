@@ -5,11 +5,19 @@
 
 use crate::constants::{
     BUDDY_ONLY_SECONDS, CLAIM_GRACE_SECONDS, DEFAULT_GRACE_PERIOD,
-    DEFAULT_SNOOZE_EXTENSION_SECONDS, DEFAULT_SNOOZE_PERCENT, EMERGENCY_REFUND_PENALTY_PERCENT,
-    MAX_SNOOZE_COUNT, MIN_DEPOSIT_LAMPORTS,
+    DEFAULT_SNOOZE_EXTENSION_SECONDS, DEFAULT_SNOOZE_PERCENT, DISPUTE_WINDOW_SECONDS,
+    EMERGENCY_REFUND_PENALTY_PERCENT, FORGIVENESS_WINDOW_SECONDS, LATE_CLAIM_EARLY_SECONDS,
+    LATE_CLAIM_MAX_SECONDS, MAX_CO_OWNERS, MAX_PENALTY_SPLITS, MAX_SNOOZE_COUNT,
+    MIN_DEPOSIT_LAMPORTS, RECOVERY_TIMELOCK_SECONDS,
 };
 use crate::helpers;
-use crate::state::{Alarm, AlarmStatus, PenaltyRoute, UserProfile, Vault};
+use crate::state::{
+    Achievement, Alarm, AlarmIndex, AlarmOptions, AlarmSeries, AlarmStatus, AlarmTemplate,
+    AttestationConfig, BuddyLink, BuddyStake, Campaign, Challenge, ChallengeParticipant, Charity,
+    DailyStats, Duel, ForgivenessToken, GlobalConfig, InsurancePool, LeaderboardEntry,
+    LeaderboardEpoch, PenaltyRoute, PermitNonce, ProofType, SavingsVault, Schedule,
+    SettlementSchedule, SnoozeModel, Treasury, UserProfile, Vault,
+};
 
 #[cfg(test)]
 mod unit_tests {
@@ -20,15 +28,192 @@ mod unit_tests {
     // Account SIZE verification (compile-time)
     // =========================================================================
 
-    const ALARM_MIN_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 32 + 1 + 1 + 1 + 1 + 64;
+    const ALARM_MIN_SIZE: usize = 8 // discriminator
+        + 32 // owner
+        + 8 // alarm_id
+        + 8 // alarm_time
+        + 8 // deadline
+        + 8 // initial_amount
+        + 8 // remaining_amount
+        + 1 // penalty_route
+        + 1
+        + 32 // penalty_destination
+        + 1 // snooze_count
+        + 1 // status
+        + 1 // bump
+        + 1 // vault_bump
+        + 32 * MAX_CO_OWNERS // co_owners
+        + 1
+        + 32 // gifter
+        + 1
+        + 32 // challenger
+        + 8 // challenger_stake
+        + 1 // snooze_percent
+        + 8 // snooze_extension_seconds
+        + 1 // max_snoozes
+        + 1
+        + 32 // series
+        + (32 + 2) * MAX_PENALTY_SPLITS // penalty_splits
+        + 2 // partial_slash_bps
+        + 8 // partial_slash_interval_seconds
+        + 8 // partial_slashed
+        + 1 // require_attestation
+        + 1 // auto_claim_authorized
+        + 2 // buddy_reward_bps
+        + 32 // memo
+        + 8 // claim_grace_seconds
+        + 8 // proof_completed_at
+        + 1 // proof_type
+        + 1 // free_snoozes
+        + 1 // snooze_model
+        + 1
+        + 32 // arbiter
+        + 8 // dispute_until
+        + 1 // recovery_approvals
+        + 8 // recovery_unlocks_at
+        + 8 // buddy_stake
+        + 32 // payer
+        + 1 // deposit_from_payer
+        + 32 // proof_commitment
+        + 32 // anchored_proof_hash
+        + 1 // anchored_proof_type
+        + 1 // two_phase_claim
+        + 8 // claim_requested_at
+        + 1 // claim_flagged
+        + 8 // early_ack_seconds
+        + 8 // insured_loss
+        + 8 // soft_deadline
+        + 1 // layout_version
+        + 33 // claim_to
+        + 1 // required_proof_type
+        + 2 // required_proofs
+        + 2 // completed_proofs
+        + 2 // unclaimed_penalty_bps
+        + 1 // open_alarm_counted
+        + 1 // tag_required
+        + 21; // padding
     const _: () = assert!(Alarm::SIZE == ALARM_MIN_SIZE);
 
-    const PROFILE_MIN_SIZE: usize = 8 + 32 + 1 + 32 + 1;
+    const PROFILE_MIN_SIZE: usize = 8
+        + 32
+        + 1
+        + 32
+        + 1
+        + 4
+        + 2 * 4
+        + 8
+        + 32
+        + 8
+        + 8
+        + 8
+        + 2
+        + 32
+        + 32 * 3
+        + 32
+        + 32 * 8
+        + 8
+        + 8
+        + 4
+        + 1;
     const _: () = assert!(UserProfile::SIZE == PROFILE_MIN_SIZE);
 
     const VAULT_MIN_SIZE: usize = 8 + 32 + 1;
     const _: () = assert!(Vault::SIZE == VAULT_MIN_SIZE);
 
+    const SERIES_MIN_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 2 + 1 + 1 + 32 + 1 + 4 + 8 + 1;
+    const _: () = assert!(AlarmSeries::SIZE == SERIES_MIN_SIZE);
+
+    const CONFIG_MIN_SIZE: usize = 8
+        + 32
+        + 2
+        + 2
+        + 1
+        + 8
+        + 1
+        + 1
+        + 1
+        + 1
+        + 8
+        + 8
+        + 2
+        + 2
+        + 16
+        + 32 * 3
+        + 32
+        + 8
+        + 32
+        + 2
+        + 2
+        + 2
+        + 14;
+    const _: () = assert!(GlobalConfig::SIZE == CONFIG_MIN_SIZE);
+
+    const TREASURY_MIN_SIZE: usize = 8 + 8 + 1 + 8;
+    const _: () = assert!(Treasury::SIZE == TREASURY_MIN_SIZE);
+
+    const PROTOCOL_PARAMS_MIN_SIZE: usize = 8 + 8 + 1 + 1 + 8 * 6 + 1 + 1 + 64;
+    const _: () = assert!(crate::state::ProtocolParams::SIZE == PROTOCOL_PARAMS_MIN_SIZE);
+
+    const INSURANCE_POOL_MIN_SIZE: usize = 8 + 8 + 8 + 1;
+    const _: () = assert!(InsurancePool::SIZE == INSURANCE_POOL_MIN_SIZE);
+
+    const CHARITY_MIN_SIZE: usize = 8 + 32 + 32 + 1;
+    const _: () = assert!(Charity::SIZE == CHARITY_MIN_SIZE);
+
+    const BUDDY_LINK_MIN_SIZE: usize = 8 + 32 + 32 + 1 + 8 + 1;
+    const _: () = assert!(BuddyLink::SIZE == BUDDY_LINK_MIN_SIZE);
+
+    const CAMPAIGN_MIN_SIZE: usize = 8 + 32 + 8 + 32 + 8 + 8 + 1;
+    const _: () = assert!(Campaign::SIZE == CAMPAIGN_MIN_SIZE);
+
+    const CHALLENGE_MIN_SIZE: usize = 8 + 32 + 8 * 6 + 4 * 4 + 1;
+    const _: () = assert!(Challenge::SIZE == CHALLENGE_MIN_SIZE);
+
+    const PERMIT_NONCE_MIN_SIZE: usize = 8 + 32 + 8 + 8 + 1;
+    const _: () = assert!(PermitNonce::SIZE == PERMIT_NONCE_MIN_SIZE);
+
+    const ACHIEVEMENT_MIN_SIZE: usize = 8 + 32 + 1 + 8 + 1;
+    const _: () = assert!(Achievement::SIZE == ACHIEVEMENT_MIN_SIZE);
+
+    const ATTESTATION_CONFIG_MIN_SIZE: usize = 8 + 32 + 32 + 8 + 32 * 4 + 1 + 1;
+    const _: () = assert!(AttestationConfig::SIZE == ATTESTATION_CONFIG_MIN_SIZE);
+
+    const PARTICIPANT_MIN_SIZE: usize = 8 + 32 + 32 + 1 + 1;
+    const _: () = assert!(ChallengeParticipant::SIZE == PARTICIPANT_MIN_SIZE);
+
+    const DUEL_MIN_SIZE: usize = 8 + 32 * 2 + 8 * 6 + 1 + 1 + 1 + 1;
+    const _: () = assert!(Duel::SIZE == DUEL_MIN_SIZE);
+
+    const SAVINGS_VAULT_MIN_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 1;
+    const _: () = assert!(SavingsVault::SIZE == SAVINGS_VAULT_MIN_SIZE);
+
+    const LEADERBOARD_EPOCH_MIN_SIZE: usize = 8 + 8 + 4 * 3 + 1 + 1;
+    const _: () = assert!(LeaderboardEpoch::SIZE == LEADERBOARD_EPOCH_MIN_SIZE);
+
+    const LEADERBOARD_ENTRY_MIN_SIZE: usize = 8 + 32 + 8 + 4 * 2 + 8 * 2 + 1;
+    const _: () = assert!(LeaderboardEntry::SIZE == LEADERBOARD_ENTRY_MIN_SIZE);
+
+    const DAILY_STATS_MIN_SIZE: usize = 8 + 8 + 4 * 3 + 8 + 1;
+    const _: () = assert!(DailyStats::SIZE == DAILY_STATS_MIN_SIZE);
+
+    const ALARM_INDEX_MIN_SIZE: usize = 8 + 32 + 4 + 8 * 32 + 1;
+    const _: () = assert!(AlarmIndex::SIZE == ALARM_INDEX_MIN_SIZE);
+
+    const SETTLEMENT_SCHEDULE_MIN_SIZE: usize = 8 + 32 * 4 + 8 + 1;
+    const _: () = assert!(SettlementSchedule::SIZE == SETTLEMENT_SCHEDULE_MIN_SIZE);
+
+    const BUDDY_STAKE_MIN_SIZE: usize = 8 + 32 + 32 + 8 + 1;
+    const _: () = assert!(BuddyStake::SIZE == BUDDY_STAKE_MIN_SIZE);
+
+    const SCHEDULE_MIN_SIZE: usize = 8 + 32 + 8 + 1 + 2 + 2 + 8 + 8 + 1 + 33 + 8 + 1;
+    const _: () = assert!(Schedule::SIZE == SCHEDULE_MIN_SIZE);
+
+    const ALARM_TEMPLATE_MIN_SIZE: usize = 8 + 32 + 8 * 4 + 1 + 33 + 9 + 2 + 9 + 2 + 2 + 2 + 1;
+    const _: () = assert!(AlarmTemplate::SIZE == ALARM_TEMPLATE_MIN_SIZE);
+
+    const FORGIVENESS_TOKEN_MIN_SIZE: usize = 8 + 32 + 1 + 4 + 1;
+    const _: () = assert!(ForgivenessToken::SIZE == FORGIVENESS_TOKEN_MIN_SIZE);
+
     // =========================================================================
     // Alarm status transitions
     // =========================================================================
@@ -67,13 +252,15 @@ mod unit_tests {
         assert_eq!(PenaltyRoute::try_from(0), Ok(PenaltyRoute::Burn));
         assert_eq!(PenaltyRoute::try_from(1), Ok(PenaltyRoute::Donate));
         assert_eq!(PenaltyRoute::try_from(2), Ok(PenaltyRoute::Buddy));
-        assert!(PenaltyRoute::try_from(3).is_err());
+        assert_eq!(PenaltyRoute::try_from(3), Ok(PenaltyRoute::Split));
+        assert_eq!(PenaltyRoute::try_from(4), Ok(PenaltyRoute::Savings));
+        assert!(PenaltyRoute::try_from(5).is_err());
     }
 
     #[test]
     fn test_penalty_route_exhaustive() {
-        // All values 3..=255 must be invalid
-        for v in 3u8..=255 {
+        // All values 5..=255 must be invalid
+        for v in 5u8..=255 {
             assert!(
                 PenaltyRoute::try_from(v).is_err(),
                 "Expected error for value {}",
@@ -209,6 +396,28 @@ mod unit_tests {
         assert!(result.is_some());
     }
 
+    #[test]
+    fn test_deadline_extension_fee() {
+        // 1 SOL, one hour at 0.5%/h = 5_000_000
+        assert_eq!(
+            helpers::deadline_extension_fee(1_000_000_000, 3_600),
+            Some(5_000_000)
+        );
+        // Pro rata to the second
+        assert_eq!(
+            helpers::deadline_extension_fee(1_000_000_000, 60),
+            Some(83_333)
+        );
+        assert_eq!(helpers::deadline_extension_fee(0, 3_600), Some(0));
+        assert_eq!(helpers::deadline_extension_fee(1_000_000_000, -1), None);
+        // Never more than the remaining deposit (400 hours = 200%)
+        assert_eq!(
+            helpers::deadline_extension_fee(u64::MAX, 2 * 720_000),
+            Some(u64::MAX)
+        );
+        assert_eq!(helpers::deadline_extension_fee(u64::MAX, i64::MAX), None);
+    }
+
     // =========================================================================
     // helpers::validate_alarm_params
     // =========================================================================
@@ -223,6 +432,7 @@ mod unit_tests {
             1_000_000_000, // 1 SOL
             0,             // Burn
             false,
+            &helpers::SnoozeParams::default(),
         )
         .is_ok());
     }
@@ -230,15 +440,30 @@ mod unit_tests {
     #[test]
     fn test_validate_alarm_time_in_past() {
         let now = 1_000_000;
-        let result =
-            helpers::validate_alarm_params(now - 1, now + 7200, now, 1_000_000_000, 0, false);
+        let result = helpers::validate_alarm_params(
+            now - 1,
+            now + 7200,
+            now,
+            1_000_000_000,
+            0,
+            false,
+            &helpers::SnoozeParams::default(),
+        );
         assert_eq!(result, Err("alarm_time_in_past"));
     }
 
     #[test]
     fn test_validate_alarm_time_equal_to_now() {
         let now = 1_000_000;
-        let result = helpers::validate_alarm_params(now, now + 7200, now, 1_000_000_000, 0, false);
+        let result = helpers::validate_alarm_params(
+            now,
+            now + 7200,
+            now,
+            1_000_000_000,
+            0,
+            false,
+            &helpers::SnoozeParams::default(),
+        );
         assert_eq!(result, Err("alarm_time_in_past"));
     }
 
@@ -246,12 +471,26 @@ mod unit_tests {
     fn test_validate_invalid_deadline() {
         let now = 1_000_000;
         // deadline == alarm_time
-        let result =
-            helpers::validate_alarm_params(now + 3600, now + 3600, now, 1_000_000_000, 0, false);
+        let result = helpers::validate_alarm_params(
+            now + 3600,
+            now + 3600,
+            now,
+            1_000_000_000,
+            0,
+            false,
+            &helpers::SnoozeParams::default(),
+        );
         assert_eq!(result, Err("invalid_deadline"));
         // deadline < alarm_time
-        let result =
-            helpers::validate_alarm_params(now + 3600, now + 1800, now, 1_000_000_000, 0, false);
+        let result = helpers::validate_alarm_params(
+            now + 3600,
+            now + 1800,
+            now,
+            1_000_000_000,
+            0,
+            false,
+            &helpers::SnoozeParams::default(),
+        );
         assert_eq!(result, Err("invalid_deadline"));
     }
 
@@ -265,6 +504,7 @@ mod unit_tests {
             MIN_DEPOSIT_LAMPORTS - 1,
             0,
             false,
+            &helpers::SnoozeParams::default(),
         );
         assert_eq!(result, Err("deposit_too_small"));
     }
@@ -273,14 +513,30 @@ mod unit_tests {
     fn test_validate_zero_deposit_ok() {
         let now = 1_000_000;
         // Zero deposit should be fine (commitment alarm without deposit)
-        assert!(helpers::validate_alarm_params(now + 3600, now + 7200, now, 0, 0, false).is_ok());
+        assert!(helpers::validate_alarm_params(
+            now + 3600,
+            now + 7200,
+            now,
+            0,
+            0,
+            false,
+            &helpers::SnoozeParams::default()
+        )
+        .is_ok());
     }
 
     #[test]
     fn test_validate_invalid_penalty_route() {
         let now = 1_000_000;
-        let result =
-            helpers::validate_alarm_params(now + 3600, now + 7200, now, 1_000_000_000, 5, false);
+        let result = helpers::validate_alarm_params(
+            now + 3600,
+            now + 7200,
+            now,
+            1_000_000_000,
+            5,
+            false,
+            &helpers::SnoozeParams::default(),
+        );
         assert_eq!(result, Err("invalid_penalty_route"));
     }
 
@@ -288,8 +544,15 @@ mod unit_tests {
     fn test_validate_buddy_route_needs_destination() {
         let now = 1_000_000;
         // Buddy route (2) without destination
-        let result =
-            helpers::validate_alarm_params(now + 3600, now + 7200, now, 1_000_000_000, 2, false);
+        let result = helpers::validate_alarm_params(
+            now + 3600,
+            now + 7200,
+            now,
+            1_000_000_000,
+            2,
+            false,
+            &helpers::SnoozeParams::default(),
+        );
         assert_eq!(result, Err("penalty_destination_required"));
         // With destination
         assert!(helpers::validate_alarm_params(
@@ -298,7 +561,8 @@ mod unit_tests {
             now,
             1_000_000_000,
             2,
-            true
+            true,
+            &helpers::SnoozeParams::default()
         )
         .is_ok());
     }
@@ -306,8 +570,15 @@ mod unit_tests {
     #[test]
     fn test_validate_donate_route_needs_destination() {
         let now = 1_000_000;
-        let result =
-            helpers::validate_alarm_params(now + 3600, now + 7200, now, 1_000_000_000, 1, false);
+        let result = helpers::validate_alarm_params(
+            now + 3600,
+            now + 7200,
+            now,
+            1_000_000_000,
+            1,
+            false,
+            &helpers::SnoozeParams::default(),
+        );
         assert_eq!(result, Err("penalty_destination_required"));
         assert!(helpers::validate_alarm_params(
             now + 3600,
@@ -315,7 +586,8 @@ mod unit_tests {
             now,
             1_000_000_000,
             1,
-            true
+            true,
+            &helpers::SnoozeParams::default()
         )
         .is_ok());
     }
@@ -330,7 +602,8 @@ mod unit_tests {
             now,
             1_000_000_000,
             0,
-            false
+            false,
+            &helpers::SnoozeParams::default()
         )
         .is_ok());
     }
@@ -403,6 +676,24 @@ mod unit_tests {
         assert!(helpers::is_sweep_window(deadline, after_grace));
     }
 
+    #[test]
+    fn test_sweep_expired_window_and_bounty() {
+        use crate::constants::{DEFAULT_SLASHER_BOUNTY_BPS, SWEEP_EXPIRED_DELAY_SECONDS};
+        let deadline = 1_000i64;
+        let opens_at = deadline + SWEEP_EXPIRED_DELAY_SECONDS;
+        assert!(!helpers::is_sweep_expired_window(deadline, deadline + 1));
+        assert!(!helpers::is_sweep_expired_window(deadline, opens_at));
+        assert!(helpers::is_sweep_expired_window(deadline, opens_at + 1));
+        assert!(!helpers::is_sweep_expired_window(i64::MAX, i64::MAX));
+
+        assert_eq!(
+            helpers::sweep_expired_bounty_bps(DEFAULT_SLASHER_BOUNTY_BPS),
+            DEFAULT_SLASHER_BOUNTY_BPS / 2
+        );
+        assert_eq!(helpers::sweep_expired_bounty_bps(0), 0);
+        assert_eq!(helpers::sweep_expired_bounty_bps(1), 0);
+    }
+
     #[test]
     fn test_buddy_only_window_boundaries() {
         let deadline = 1_000i64;
@@ -685,9 +976,16 @@ mod unit_tests {
         let deposit = 1_000_000_000u64;
 
         // 1. Create alarm
-        assert!(
-            helpers::validate_alarm_params(alarm_time, deadline, now, deposit, 0, false).is_ok()
-        );
+        assert!(helpers::validate_alarm_params(
+            alarm_time,
+            deadline,
+            now,
+            deposit,
+            0,
+            false,
+            &helpers::SnoozeParams::default()
+        )
+        .is_ok());
 
         // 2. Before alarm: refund window is open, claim/snooze/slash closed
         assert!(helpers::is_refund_window(alarm_time, now));
@@ -1069,8 +1367,15 @@ mod unit_tests {
         // Zero-deposit alarms should be valid regardless of penalty route
         let now = 1_000_000i64;
         for route in 0..=2u8 {
-            let result =
-                helpers::validate_alarm_params(now + 3600, now + 7200, now, 0, route, false);
+            let result = helpers::validate_alarm_params(
+                now + 3600,
+                now + 7200,
+                now,
+                0,
+                route,
+                false,
+                &helpers::SnoozeParams::default(),
+            );
             assert!(result.is_ok(), "Zero-deposit should accept route={}", route);
         }
     }
@@ -1158,9 +1463,16 @@ mod unit_tests {
         let deposit = 1_000_000_000u64; // 1 SOL
 
         // Validate creation
-        assert!(
-            helpers::validate_alarm_params(alarm_time, deadline, now, deposit, 0, false).is_ok()
-        );
+        assert!(helpers::validate_alarm_params(
+            alarm_time,
+            deadline,
+            now,
+            deposit,
+            0,
+            false,
+            &helpers::SnoozeParams::default()
+        )
+        .is_ok());
 
         // Before alarm fires: only refund valid
         assert!(helpers::is_refund_window(alarm_time, now));
@@ -1214,9 +1526,16 @@ mod unit_tests {
         let deposit = 500_000_000u64;
 
         // Create valid alarm
-        assert!(
-            helpers::validate_alarm_params(alarm_time, deadline, now, deposit, 0, false).is_ok()
-        );
+        assert!(helpers::validate_alarm_params(
+            alarm_time,
+            deadline,
+            now,
+            deposit,
+            0,
+            false,
+            &helpers::SnoozeParams::default()
+        )
+        .is_ok());
 
         // After deadline: only slash valid
         let after_deadline = deadline + 1;
@@ -1249,7 +1568,13 @@ mod unit_tests {
             for route in route_cases {
                 for has_dest in dest_cases {
                     let result = helpers::validate_alarm_params(
-                        alarm_time, deadline, now, deposit, route, has_dest,
+                        alarm_time,
+                        deadline,
+                        now,
+                        deposit,
+                        route,
+                        has_dest,
+                        &helpers::SnoozeParams::default(),
                     );
 
                     if deposit == 0 {
@@ -1287,9 +1612,16 @@ mod unit_tests {
 
         // 1 lamport below minimum: should fail
         let too_small = MIN_DEPOSIT_LAMPORTS - 1;
-        assert!(
-            helpers::validate_alarm_params(alarm_time, deadline, now, too_small, 0, false).is_err()
-        );
+        assert!(helpers::validate_alarm_params(
+            alarm_time,
+            deadline,
+            now,
+            too_small,
+            0,
+            false,
+            &helpers::SnoozeParams::default()
+        )
+        .is_err());
 
         // Exactly minimum: should pass
         assert!(helpers::validate_alarm_params(
@@ -1298,14 +1630,22 @@ mod unit_tests {
             now,
             MIN_DEPOSIT_LAMPORTS,
             0,
-            false
+            false,
+            &helpers::SnoozeParams::default()
         )
         .is_ok());
 
         // u64::MAX deposit: should pass (amount validation only checks minimum)
-        assert!(
-            helpers::validate_alarm_params(alarm_time, deadline, now, u64::MAX, 0, false).is_ok()
-        );
+        assert!(helpers::validate_alarm_params(
+            alarm_time,
+            deadline,
+            now,
+            u64::MAX,
+            0,
+            false,
+            &helpers::SnoozeParams::default()
+        )
+        .is_ok());
     }
 
     #[test]
@@ -1430,716 +1770,4980 @@ mod unit_tests {
             );
         }
     }
-}
 
-#[cfg(test)]
-mod fuzz_tests {
-    use super::*;
+    // =========================================================================
+    // helpers::is_authorized_signer / validate_co_owners
+    // =========================================================================
 
-    #[derive(Clone, Copy, Debug)]
-    enum TimeKind {
-        BeforeAlarm,
-        AtAlarm,
-        Between,
-        AtDeadline,
-        AfterDeadline,
+    #[test]
+    fn test_owner_is_always_authorized() {
+        let owner = [1u8; 32];
+        assert!(helpers::is_authorized_signer(&owner, &owner, &[]));
+        assert!(helpers::is_authorized_signer(
+            &owner,
+            &owner,
+            &[[0u8; 32]; MAX_CO_OWNERS]
+        ));
     }
 
-    #[derive(Clone, Debug)]
-    enum Op {
-        Ack,
-        Snooze { expected_snooze_count: u8 },
-        Claim,
-        Slash,
-        Sweep,
-        Refund,
+    #[test]
+    fn test_co_owner_is_authorized() {
+        let owner = [1u8; 32];
+        let hot_key = [2u8; 32];
+        let co_owners = [hot_key, [0u8; 32], [0u8; 32]];
+        assert!(helpers::is_authorized_signer(&hot_key, &owner, &co_owners));
+        assert!(!helpers::is_authorized_signer(
+            &[3u8; 32], &owner, &co_owners
+        ));
     }
 
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    struct ModelAlarm {
-        status: AlarmStatus,
-        alarm_time: i64,
-        deadline: i64,
-        initial_amount: u64,
-        remaining_amount: u64,
-        snooze_count: u8,
-        rent_minimum: u64,
-        vault_lamports: u64,
-        vault_closed: bool,
+    #[test]
+    fn test_empty_slot_never_authorizes_default_key() {
+        let owner = [1u8; 32];
+        let co_owners = [[0u8; 32]; MAX_CO_OWNERS];
+        assert!(!helpers::is_authorized_signer(
+            &[0u8; 32], &owner, &co_owners
+        ));
     }
 
-    impl ModelAlarm {
-        fn new(alarm_time: i64, deadline: i64, deposit: u64, rent_minimum: u64) -> Self {
-            Self {
-                status: AlarmStatus::Created,
-                alarm_time,
-                deadline,
-                initial_amount: deposit,
-                remaining_amount: deposit,
-                snooze_count: 0,
-                rent_minimum,
-                vault_lamports: rent_minimum.saturating_add(deposit),
-                vault_closed: false,
-            }
-        }
+    #[test]
+    fn test_alarm_is_authorized_uses_co_owner_slots() {
+        let mut alarm = Alarm {
+            owner: Pubkey::new_unique(),
+            ..Alarm::default()
+        };
+        let partner = Pubkey::new_unique();
+        assert!(alarm.is_authorized(&alarm.owner.clone()));
+        assert!(!alarm.is_authorized(&partner));
+        alarm.co_owners[2] = partner;
+        assert!(alarm.is_authorized(&partner));
+        assert!(!alarm.is_authorized(&Pubkey::default()));
+    }
 
-        fn is_terminal(&self) -> bool {
-            matches!(self.status, AlarmStatus::Claimed | AlarmStatus::Slashed)
-        }
+    #[test]
+    fn test_validate_co_owners() {
+        let owner = [1u8; 32];
+        assert_eq!(helpers::validate_co_owners(&owner, &[]), Ok(()));
+        assert_eq!(
+            helpers::validate_co_owners(&owner, &[[2u8; 32], [3u8; 32], [4u8; 32]]),
+            Ok(())
+        );
+        assert_eq!(
+            helpers::validate_co_owners(&owner, &[[2u8; 32], [3u8; 32], [4u8; 32], [5u8; 32]]),
+            Err("too_many_co_owners")
+        );
+        assert_eq!(
+            helpers::validate_co_owners(&owner, &[owner]),
+            Err("invalid_co_owner")
+        );
+        assert_eq!(
+            helpers::validate_co_owners(&owner, &[[0u8; 32]]),
+            Err("invalid_co_owner")
+        );
+        assert_eq!(
+            helpers::validate_co_owners(&owner, &[[2u8; 32], [2u8; 32]]),
+            Err("invalid_co_owner")
+        );
+    }
 
-        fn assert_invariants(&self) {
-            assert!(
-                self.deadline > self.alarm_time,
-                "deadline must be > alarm_time"
-            );
+    // =========================================================================
+    // Gift alarms
+    // =========================================================================
 
-            assert!(
-                self.remaining_amount <= self.initial_amount,
-                "remaining cannot exceed initial"
-            );
+    #[test]
+    fn test_pending_gift_is_distinct_non_default_status() {
+        assert_ne!(AlarmStatus::PendingGift, AlarmStatus::default());
+        assert_ne!(AlarmStatus::PendingGift, AlarmStatus::Created);
+        assert_ne!(AlarmStatus::PendingGift, AlarmStatus::Claimed);
+    }
 
-            assert!(
-                self.snooze_count <= MAX_SNOOZE_COUNT,
-                "snooze_count must be <= MAX_SNOOZE_COUNT"
+    #[test]
+    fn test_gift_expiry_boundary() {
+        // Acceptable strictly before alarm_time, expired at and after it
+        assert!(!helpers::is_gift_expired(1000, 999));
+        assert!(helpers::is_gift_expired(1000, 1000));
+        assert!(helpers::is_gift_expired(1000, 1001));
+    }
+
+    #[test]
+    fn test_gift_expiry_matches_refund_window() {
+        // A gift can be accepted exactly while an emergency refund would be allowed
+        for now in [0i64, 999, 1000, 1001, 5000] {
+            assert_eq!(
+                !helpers::is_gift_expired(1000, now),
+                helpers::is_refund_window(1000, now),
+                "Mismatch at now={}",
+                now
             );
+        }
+    }
 
-            if !self.vault_closed {
-                assert!(
-                    self.vault_lamports >= self.rent_minimum,
-                    "vault must stay rent-exempt while open"
-                );
-                let available = self.vault_lamports.saturating_sub(self.rent_minimum);
-                assert!(
-                    available >= self.remaining_amount,
-                    "vault available must cover remaining"
-                );
-            }
-
-            if self.is_terminal() {
-                assert!(self.vault_closed, "terminal implies vault closed");
-                assert_eq!(self.remaining_amount, 0, "terminal implies remaining == 0");
-                assert_eq!(
-                    self.vault_lamports, 0,
-                    "closed vault implies 0 lamports in model"
-                );
-            }
+    #[test]
+    fn test_sponsored_deposit_returns_to_payer() {
+        let owner = Pubkey::new_unique();
+        let sponsor = Pubkey::new_unique();
+        let mut alarm = Alarm {
+            owner,
+            payer: sponsor,
+            ..Alarm::default()
+        };
+        // The sponsor only paid the rent: the deposit is the owner's
+        assert_eq!(alarm.deposit_funder(), None);
+        assert_eq!(alarm.sponsor(), None);
 
-            if self.vault_closed {
-                assert!(self.is_terminal(), "vault_closed implies terminal status");
-            }
-        }
+        alarm.deposit_from_payer = true;
+        assert_eq!(alarm.deposit_funder(), Some(sponsor));
+        assert_eq!(alarm.sponsor(), Some(sponsor));
 
-        fn apply(&mut self, op: Op, now: i64) -> Result<(), ()> {
-            if self.is_terminal() || self.vault_closed {
-                return Err(());
-            }
+        // A gift is refunded to the gifter but claimed by the recipient
+        alarm.gifter = Some(sponsor);
+        assert_eq!(alarm.deposit_funder(), Some(sponsor));
+        assert_eq!(alarm.sponsor(), None);
 
-            match op {
-                Op::Ack => {
-                    if self.status != AlarmStatus::Created {
-                        return Err(());
-                    }
-                    if !(now >= self.alarm_time && now < self.deadline) {
-                        return Err(());
-                    }
-                    self.status = AlarmStatus::Acknowledged;
-                    Ok(())
-                }
-                Op::Snooze {
-                    expected_snooze_count,
-                } => {
-                    if self.status != AlarmStatus::Created {
-                        return Err(());
-                    }
-                    if !(now >= self.alarm_time && now < self.deadline) {
-                        return Err(());
-                    }
-                    if self.snooze_count >= MAX_SNOOZE_COUNT {
-                        return Err(());
-                    }
-                    if expected_snooze_count != self.snooze_count {
-                        return Err(());
-                    }
+        // The owner funding their own alarm through the payer slot
+        let own = Alarm {
+            owner,
+            payer: owner,
+            deposit_from_payer: true,
+            ..Alarm::default()
+        };
+        assert_eq!(own.deposit_funder(), None);
+    }
 
-                    let cost =
-                        helpers::snooze_cost(self.remaining_amount, self.snooze_count).ok_or(())?;
-                    if cost == 0 {
-                        return Err(());
-                    }
+    // =========================================================================
+    // Matched-deposit bets
+    // =========================================================================
 
-                    let available = self.vault_lamports.saturating_sub(self.rent_minimum);
-                    let final_cost = cost.min(available);
-                    if final_cost == 0 {
-                        return Err(());
-                    }
+    #[test]
+    fn test_bet_pot_is_sum_of_stakes() {
+        assert_eq!(
+            helpers::bet_pot(1_000_000_000, 1_000_000_000),
+            Some(2_000_000_000)
+        );
+        assert_eq!(
+            helpers::bet_pot(MIN_DEPOSIT_LAMPORTS, 0),
+            Some(MIN_DEPOSIT_LAMPORTS)
+        );
+        assert_eq!(helpers::bet_pot(u64::MAX, 1), None);
+    }
 
-                    self.vault_lamports = self.vault_lamports.checked_sub(final_cost).ok_or(())?;
-                    self.remaining_amount =
-                        self.remaining_amount.checked_sub(final_cost).ok_or(())?;
+    #[test]
+    fn test_can_join_bet_only_before_alarm_without_snoozes() {
+        assert!(helpers::can_join_bet(1000, 0, 999));
+        assert!(!helpers::can_join_bet(1000, 0, 1000));
+        assert!(!helpers::can_join_bet(1000, 0, 1001));
+        assert!(!helpers::can_join_bet(1000, 1, 999));
+    }
 
-                    self.snooze_count = self.snooze_count.checked_add(1).ok_or(())?;
-                    self.alarm_time = self
-                        .alarm_time
-                        .checked_add(DEFAULT_SNOOZE_EXTENSION_SECONDS)
-                        .ok_or(())?;
-                    self.deadline = self
-                        .deadline
-                        .checked_add(DEFAULT_SNOOZE_EXTENSION_SECONDS)
-                        .ok_or(())?;
+    #[test]
+    fn test_unmatched_alarm_has_no_challenger_stake() {
+        let alarm = Alarm::default();
+        assert_eq!(alarm.challenger, None);
+        assert_eq!(alarm.challenger_stake, 0);
+    }
 
-                    Ok(())
-                }
-                Op::Claim => {
-                    if self.status != AlarmStatus::Acknowledged {
-                        return Err(());
-                    }
-                    if !helpers::is_claim_window_with_grace(self.alarm_time, self.deadline, now) {
-                        return Err(());
-                    }
-                    self.status = AlarmStatus::Claimed;
-                    self.remaining_amount = 0;
-                    self.vault_closed = true;
-                    self.vault_lamports = 0;
-                    Ok(())
-                }
-                Op::Slash => {
-                    if self.status != AlarmStatus::Created {
-                        return Err(());
-                    }
-                    if now < self.deadline {
-                        return Err(());
-                    }
-                    self.status = AlarmStatus::Slashed;
-                    self.remaining_amount = 0;
-                    self.vault_closed = true;
-                    self.vault_lamports = 0;
-                    Ok(())
-                }
-                Op::Sweep => {
-                    if self.status != AlarmStatus::Acknowledged {
-                        return Err(());
-                    }
-                    if !helpers::is_sweep_window(self.deadline, now) {
-                        return Err(());
-                    }
-                    self.status = AlarmStatus::Claimed;
-                    self.remaining_amount = 0;
-                    self.vault_closed = true;
-                    self.vault_lamports = 0;
-                    Ok(())
-                }
-                Op::Refund => {
-                    if self.status != AlarmStatus::Created {
-                        return Err(());
-                    }
-                    if now >= self.alarm_time {
-                        return Err(());
-                    }
+    // =========================================================================
+    // Per-alarm snooze economics
+    // =========================================================================
 
-                    let penalty = helpers::emergency_penalty(self.remaining_amount).ok_or(())?;
-                    let final_penalty = helpers::cap_at_rent_exempt(
-                        penalty,
-                        self.vault_lamports,
-                        self.rent_minimum,
-                    );
-                    self.vault_lamports =
-                        self.vault_lamports.checked_sub(final_penalty).ok_or(())?;
+    #[test]
+    fn test_default_snooze_params_match_constants() {
+        let params = helpers::SnoozeParams::default();
+        assert_eq!(params.percent, DEFAULT_SNOOZE_PERCENT);
+        assert_eq!(params.extension_seconds, DEFAULT_SNOOZE_EXTENSION_SECONDS);
+        assert_eq!(params.max_snoozes, MAX_SNOOZE_COUNT);
+        assert_eq!(helpers::validate_snooze_params(&params), Ok(()));
+    }
 
-                    self.status = AlarmStatus::Claimed;
-                    self.remaining_amount = 0;
-                    self.vault_closed = true;
-                    self.vault_lamports = 0;
-                    Ok(())
-                }
-            }
+    #[test]
+    fn test_snooze_cost_with_default_percent_matches_legacy() {
+        for count in 0..MAX_SNOOZE_COUNT {
+            assert_eq!(
+                helpers::snooze_cost_with_percent(1_000_000_000, count, DEFAULT_SNOOZE_PERCENT),
+                helpers::snooze_cost(1_000_000_000, count)
+            );
         }
     }
 
-    #[derive(Clone, Copy)]
-    struct XorShift64 {
-        state: u64,
+    #[test]
+    fn test_snooze_cost_with_custom_percent() {
+        // 1 SOL at 25% → 0.25 SOL, doubled on second snooze
+        assert_eq!(
+            helpers::snooze_cost_with_percent(1_000_000_000, 0, 25),
+            Some(250_000_000)
+        );
+        assert_eq!(
+            helpers::snooze_cost_with_percent(1_000_000_000, 1, 25),
+            Some(500_000_000)
+        );
+        // Capped at remaining
+        assert_eq!(
+            helpers::snooze_cost_with_percent(1_000_000_000, 3, 50),
+            Some(1_000_000_000)
+        );
     }
 
-    impl XorShift64 {
-        fn new(seed: u64) -> Self {
-            // Avoid the all-zero state.
-            Self {
-                state: if seed == 0 {
-                    0xdead_beef_cafe_f00d
-                } else {
-                    seed
-                },
-            }
-        }
+    #[test]
+    fn test_validate_snooze_params_bounds() {
+        let ok = helpers::SnoozeParams::default();
+        let check = |p: helpers::SnoozeParams| helpers::validate_snooze_params(&p);
 
-        fn next_u64(&mut self) -> u64 {
-            let mut x = self.state;
-            x ^= x << 13;
-            x ^= x >> 7;
-            x ^= x << 17;
-            self.state = x;
-            x
-        }
+        assert_eq!(
+            check(helpers::SnoozeParams { percent: 0, ..ok }),
+            Err("invalid_snooze_percent")
+        );
+        assert_eq!(
+            check(helpers::SnoozeParams { percent: 51, ..ok }),
+            Err("invalid_snooze_percent")
+        );
+        assert_eq!(check(helpers::SnoozeParams { percent: 50, ..ok }), Ok(()));
+        assert_eq!(
+            check(helpers::SnoozeParams {
+                extension_seconds: 59,
+                ..ok
+            }),
+            Err("invalid_snooze_extension")
+        );
+        assert_eq!(
+            check(helpers::SnoozeParams {
+                extension_seconds: 1801,
+                ..ok
+            }),
+            Err("invalid_snooze_extension")
+        );
+        assert_eq!(
+            check(helpers::SnoozeParams {
+                max_snoozes: 0,
+                ..ok
+            }),
+            Ok(())
+        );
+        assert_eq!(
+            check(helpers::SnoozeParams {
+                max_snoozes: 21,
+                ..ok
+            }),
+            Err("invalid_max_snoozes")
+        );
+    }
 
-        fn next_u8(&mut self) -> u8 {
-            (self.next_u64() & 0xff) as u8
-        }
+    #[test]
+    fn test_validate_alarm_params_rejects_bad_snooze_config() {
+        let now = 1_000_000;
+        let bad = helpers::SnoozeParams {
+            percent: 99,
+            ..helpers::SnoozeParams::default()
+        };
+        assert_eq!(
+            helpers::validate_alarm_params(now + 100, now + 200, now, 0, 0, false, &bad),
+            Err("invalid_snooze_percent")
+        );
+    }
 
-        fn gen_range_u64(&mut self, start: u64, end_inclusive: u64) -> u64 {
-            if start >= end_inclusive {
-                return start;
-            }
-            let span = end_inclusive - start + 1;
-            start + (self.next_u64() % span)
-        }
+    #[test]
+    fn test_alarm_options_resolve_defaults() {
+        use crate::state::AlarmOptions;
+        let defaults = helpers::SnoozeParams::default();
+        assert_eq!(AlarmOptions::default().snooze_params(&defaults), defaults);
+        let custom = AlarmOptions {
+            snooze_percent: Some(5),
+            snooze_extension_seconds: None,
+            max_snoozes: Some(3),
+            ..AlarmOptions::default()
+        };
+        let params = custom.snooze_params(&defaults);
+        assert_eq!(params.percent, 5);
+        assert_eq!(params.extension_seconds, DEFAULT_SNOOZE_EXTENSION_SECONDS);
+        assert_eq!(params.max_snoozes, 3);
+    }
 
-        fn gen_range_i64(&mut self, start: i64, end_inclusive: i64) -> i64 {
-            if start >= end_inclusive {
-                return start;
-            }
-            let span = (end_inclusive - start + 1) as u64;
-            start + (self.next_u64() % span) as i64
-        }
+    #[test]
+    fn test_alarm_snooze_params_round_trip() {
+        let params = helpers::SnoozeParams {
+            percent: 20,
+            extension_seconds: 120,
+            max_snoozes: 4,
+        };
+        let mut alarm = Alarm::default();
+        alarm.set_snooze_params(&params);
+        assert_eq!(alarm.snooze_params(), params);
+    }
 
-        fn pick_time_kind(&mut self) -> TimeKind {
-            match self.next_u64() % 5 {
-                0 => TimeKind::BeforeAlarm,
-                1 => TimeKind::AtAlarm,
-                2 => TimeKind::Between,
-                3 => TimeKind::AtDeadline,
-                _ => TimeKind::AfterDeadline,
-            }
-        }
+    #[test]
+    fn test_is_max_snooze_for_custom_limit() {
+        assert!(helpers::is_max_snooze_for(0, 0));
+        assert!(!helpers::is_max_snooze_for(2, 3));
+        assert!(helpers::is_max_snooze_for(3, 3));
+    }
 
-        fn pick_op(&mut self) -> Op {
-            match self.next_u64() % 6 {
-                0 => Op::Ack,
-                1 => Op::Snooze {
-                    expected_snooze_count: self.next_u8(),
-                },
-                2 => Op::Claim,
-                3 => Op::Slash,
-                4 => Op::Sweep,
-                _ => Op::Refund,
-            }
-        }
+    // =========================================================================
+    // Recurring series
+    // =========================================================================
+
+    #[test]
+    fn test_validate_series_params_daily_ok() {
+        let now = 1_000_000;
+        assert_eq!(
+            helpers::validate_series_params(now + 3600, 1800, 86_400, 30, 10_000_000, now),
+            Ok(())
+        );
     }
 
-    fn pick_now(kind: TimeKind, alarm_time: i64, deadline: i64) -> i64 {
-        match kind {
-            TimeKind::BeforeAlarm => alarm_time.saturating_sub(1),
-            TimeKind::AtAlarm => alarm_time,
-            TimeKind::Between => {
-                let candidate = alarm_time.saturating_add(1);
-                if candidate < deadline {
-                    candidate
-                } else {
-                    alarm_time
-                }
-            }
-            TimeKind::AtDeadline => deadline,
-            TimeKind::AfterDeadline => deadline.saturating_add(1),
-        }
+    #[test]
+    fn test_validate_series_params_rejections() {
+        let now = 1_000_000;
+        assert_eq!(
+            helpers::validate_series_params(now, 1800, 86_400, 30, 0, now),
+            Err("alarm_time_in_past")
+        );
+        assert_eq!(
+            helpers::validate_series_params(now + 1, 1800, 3600, 30, 0, now),
+            Err("invalid_series_interval")
+        );
+        assert_eq!(
+            helpers::validate_series_params(now + 1, 1800, 604_801, 30, 0, now),
+            Err("invalid_series_interval")
+        );
+        // Window must fit inside the interval
+        assert_eq!(
+            helpers::validate_series_params(now + 1, 86_400, 86_400, 30, 0, now),
+            Err("invalid_deadline")
+        );
+        assert_eq!(
+            helpers::validate_series_params(now + 1, 0, 86_400, 30, 0, now),
+            Err("invalid_deadline")
+        );
+        assert_eq!(
+            helpers::validate_series_params(now + 1, 1800, 86_400, 0, 0, now),
+            Err("invalid_series_occurrences")
+        );
+        assert_eq!(
+            helpers::validate_series_params(now + 1, 1800, 86_400, 367, 0, now),
+            Err("invalid_series_occurrences")
+        );
+        assert_eq!(
+            helpers::validate_series_params(now + 1, 1800, 86_400, 7, 1, now),
+            Err("deposit_too_small")
+        );
     }
 
     #[test]
-    fn fuzz_windows_partition_time_axis() {
-        let mut rng = XorShift64::new(1);
-        for _ in 0..25_000 {
-            let alarm_time = rng.gen_range_i64(10_000, 1_000_000_000);
-            let gap = rng.gen_range_i64(1, 100_000);
-            let deadline = alarm_time.saturating_add(gap);
-            if deadline <= alarm_time {
-                continue;
-            }
+    fn test_series_total_deposit() {
+        assert_eq!(
+            helpers::series_total_deposit(10_000_000, 30),
+            Some(300_000_000)
+        );
+        assert_eq!(helpers::series_total_deposit(0, 30), Some(0));
+        assert_eq!(helpers::series_total_deposit(u64::MAX, 2), None);
+    }
 
-            let now = rng.gen_range_i64(0, 1_100_000_000);
+    #[test]
+    fn test_next_occurrence_time_future_slot_unchanged() {
+        assert_eq!(
+            helpers::next_occurrence_time(2_000, 86_400, 1_000),
+            Some(2_000)
+        );
+    }
 
-            let refund = helpers::is_refund_window(alarm_time, now);
-            let claim = helpers::is_claim_window(alarm_time, deadline, now);
-            let slash = helpers::is_slash_window(deadline, now);
+    #[test]
+    fn test_next_occurrence_time_rolls_past_slots_forward() {
+        let day = 86_400;
+        // Exactly at the slot → next slot
+        assert_eq!(helpers::next_occurrence_time(0, day, 0), Some(day));
+        // Three and a half days late → fourth slot
+        assert_eq!(
+            helpers::next_occurrence_time(0, day, 3 * day + day / 2),
+            Some(4 * day)
+        );
+        assert_eq!(helpers::next_occurrence_time(0, 0, 10), None);
+    }
 
-            let sum = (refund as u8) + (claim as u8) + (slash as u8);
-            assert_eq!(sum, 1, "windows must partition time axis");
-            assert_eq!(helpers::is_snooze_window(alarm_time, deadline, now), claim);
-        }
+    #[test]
+    fn test_series_slot_index() {
+        let day = 86_400;
+        let next = 10 * day;
+        assert_eq!(helpers::series_slot_index(next, day, next, 0), Some(0));
+        assert_eq!(
+            helpers::series_slot_index(next, day, next + 3 * day, 0),
+            Some(3)
+        );
+        assert_eq!(
+            helpers::series_slot_index(next, day, next + 31 * day, 0),
+            Some(31)
+        );
+        // Beyond the lookahead, off-grid, before the next slot, or already rung
+        assert_eq!(
+            helpers::series_slot_index(next, day, next + 32 * day, 0),
+            None
+        );
+        assert_eq!(
+            helpers::series_slot_index(next, day, next + day / 2, 0),
+            None
+        );
+        assert_eq!(helpers::series_slot_index(next, day, next - day, 0), None);
+        assert_eq!(helpers::series_slot_index(next, day, next, next), None);
+        assert_eq!(helpers::series_slot_index(next, 0, next, 0), None);
     }
 
     #[test]
-    fn fuzz_cap_at_rent_exempt_is_safe() {
-        let mut rng = XorShift64::new(2);
-        for _ in 0..100_000 {
-            let desired = rng.next_u64();
-            let current = rng.next_u64();
-            let min_balance = rng.next_u64();
+    fn test_apply_skip_quota() {
+        use crate::constants::{MAX_SERIES_SKIPS_PER_PERIOD, SERIES_SKIP_PERIOD_SECONDS};
+        let now = 5 * SERIES_SKIP_PERIOD_SECONDS + 100;
+        assert_eq!(helpers::apply_skip_quota(0, 0, now), Ok((5, 1)));
+        assert_eq!(helpers::apply_skip_quota(5, 2, now), Ok((5, 3)));
+        assert_eq!(
+            helpers::apply_skip_quota(5, MAX_SERIES_SKIPS_PER_PERIOD, now),
+            Err("skip_limit_reached")
+        );
+        // A new period resets the count
+        assert_eq!(
+            helpers::apply_skip_quota(4, MAX_SERIES_SKIPS_PER_PERIOD, now),
+            Ok((5, 1))
+        );
+    }
 
-            let capped = helpers::cap_at_rent_exempt(desired, current, min_balance);
-            assert!(capped <= desired);
+    #[test]
+    fn test_next_unskipped_occurrence() {
+        let day = 86_400;
+        // No skips: same as next_occurrence_time
+        assert_eq!(
+            helpers::next_unskipped_occurrence(2 * day, day, 0, 0),
+            Some((2 * day, 0))
+        );
+        // Slots 0 and 1 skipped: slot 2 rings, bit 0 re-based onto it
+        assert_eq!(
+            helpers::next_unskipped_occurrence(2 * day, day, 0b1011, 0),
+            Some((4 * day, 0b10))
+        );
+        // One missed slot rolls forward first, consuming its bit
+        assert_eq!(
+            helpers::next_unskipped_occurrence(0, day, 0b110, day / 2),
+            Some((3 * day, 0))
+        );
+        // Rolling past the whole mask clears it
+        assert_eq!(
+            helpers::next_unskipped_occurrence(0, day, u32::MAX, 40 * day),
+            Some((41 * day, 0))
+        );
+        assert_eq!(helpers::next_unskipped_occurrence(0, 0, 0, 0), None);
+    }
 
-            let available = current.saturating_sub(min_balance);
-            assert!(capped <= available);
+    // =========================================================================
+    // Global config
+    // =========================================================================
 
-            if current >= min_balance {
-                assert!(current - capped >= min_balance);
-            }
+    #[test]
+    fn test_validate_config_params_defaults_ok() {
+        assert_eq!(
+            helpers::validate_config_params(0, 0, &helpers::SnoozeParams::default()),
+            Ok(())
+        );
+        assert_eq!(
+            helpers::validate_config_params(
+                crate::constants::MAX_PROTOCOL_FEE_BPS,
+                crate::constants::MAX_SLASHER_BOUNTY_BPS,
+                &helpers::SnoozeParams::default()
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_validate_config_params_rejections() {
+        assert_eq!(
+            helpers::validate_config_params(
+                crate::constants::MAX_PROTOCOL_FEE_BPS + 1,
+                0,
+                &helpers::SnoozeParams::default()
+            ),
+            Err("invalid_protocol_fee")
+        );
+        assert_eq!(
+            helpers::validate_config_params(
+                0,
+                crate::constants::MAX_SLASHER_BOUNTY_BPS + 1,
+                &helpers::SnoozeParams::default()
+            ),
+            Err("invalid_slasher_bounty")
+        );
+        let bad_defaults = helpers::SnoozeParams {
+            percent: 0,
+            ..helpers::SnoozeParams::default()
+        };
+        assert_eq!(
+            helpers::validate_config_params(0, 0, &bad_defaults),
+            Err("invalid_snooze_percent")
+        );
+    }
+
+    #[test]
+    fn test_alarm_options_fall_back_to_config_defaults() {
+        use crate::state::AlarmOptions;
+        let config_defaults = helpers::SnoozeParams {
+            percent: 25,
+            extension_seconds: 600,
+            max_snoozes: 5,
+        };
+        let options = AlarmOptions {
+            max_snoozes: Some(2),
+            ..AlarmOptions::default()
+        };
+        let params = options.snooze_params(&config_defaults);
+        assert_eq!(params.percent, 25);
+        assert_eq!(params.extension_seconds, 600);
+        assert_eq!(params.max_snoozes, 2);
+    }
+
+    // =========================================================================
+    // NFC/QR tag registration
+    // =========================================================================
+
+    #[test]
+    fn test_validate_tag_hash_rejects_zero() {
+        assert_eq!(
+            helpers::validate_tag_hash(&[0u8; 32]),
+            Err("invalid_tag_hash")
+        );
+        assert_eq!(helpers::validate_tag_hash(&[7u8; 32]), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_tag_rotation() {
+        let old = [1u8; 32];
+        let new = [2u8; 32];
+        assert_eq!(
+            helpers::validate_tag_rotation(Some(old), &old, &new),
+            Ok(())
+        );
+        // Wrong old hash or no tag registered
+        assert_eq!(
+            helpers::validate_tag_rotation(Some(old), &new, &[3u8; 32]),
+            Err("tag_mismatch")
+        );
+        assert_eq!(
+            helpers::validate_tag_rotation(None, &old, &new),
+            Err("tag_mismatch")
+        );
+        // No-op and zero rotations
+        assert_eq!(
+            helpers::validate_tag_rotation(Some(old), &old, &old),
+            Err("invalid_tag_hash")
+        );
+        assert_eq!(
+            helpers::validate_tag_rotation(Some(old), &old, &[0u8; 32]),
+            Err("invalid_tag_hash")
+        );
+    }
+
+    #[test]
+    fn test_verify_tag_proof() {
+        let tag = [9u8; 32];
+        // No tag registered → any proof accepted
+        assert_eq!(helpers::verify_tag_proof(None, None), Ok(()));
+        assert_eq!(helpers::verify_tag_proof(None, Some([1u8; 32])), Ok(()));
+        assert_eq!(helpers::verify_tag_proof(Some(tag), Some(tag)), Ok(()));
+        assert_eq!(
+            helpers::verify_tag_proof(Some(tag), Some([1u8; 32])),
+            Err("tag_mismatch")
+        );
+        assert_eq!(
+            helpers::verify_tag_proof(Some(tag), None),
+            Err("tag_mismatch")
+        );
+    }
+
+    #[test]
+    fn test_verify_proof_commitment() {
+        use solana_program::hash::hash;
+        let preimage = [7u8; 32];
+        let commitment = hash(&preimage).to_bytes();
+        // Zero commitment → no reveal needed
+        assert_eq!(helpers::verify_proof_commitment(&[0u8; 32], None), Ok(()));
+        assert_eq!(
+            helpers::verify_proof_commitment(&[0u8; 32], Some(preimage)),
+            Ok(())
+        );
+        assert_eq!(
+            helpers::verify_proof_commitment(&commitment, Some(preimage)),
+            Ok(())
+        );
+        // The commitment itself is not a valid reveal
+        assert_eq!(
+            helpers::verify_proof_commitment(&commitment, Some(commitment)),
+            Err("preimage_mismatch")
+        );
+        assert_eq!(
+            helpers::verify_proof_commitment(&commitment, None),
+            Err("preimage_required")
+        );
+    }
+
+    #[test]
+    fn test_validate_proof_anchor() {
+        let hash = [3u8; 32];
+        let none = [0u8; 32];
+        for status in [AlarmStatus::Acknowledged, AlarmStatus::Claimed] {
+            assert_eq!(helpers::validate_proof_anchor(status, &none, &hash), Ok(()));
+        }
+        for status in [AlarmStatus::Created, AlarmStatus::Slashed] {
+            assert_eq!(
+                helpers::validate_proof_anchor(status, &none, &hash),
+                Err("invalid_state")
+            );
         }
+        // Write-once
+        assert_eq!(
+            helpers::validate_proof_anchor(AlarmStatus::Acknowledged, &hash, &[4u8; 32]),
+            Err("already_anchored")
+        );
+        assert_eq!(
+            helpers::validate_proof_anchor(AlarmStatus::Acknowledged, &none, &none),
+            Err("invalid_proof_hash")
+        );
+    }
+
+    #[test]
+    fn test_bps_of() {
+        assert_eq!(helpers::bps_of(1_000_000_000, 100), Some(10_000_000));
+        assert_eq!(helpers::bps_of(1_000_000_000, 200), Some(20_000_000));
+        assert_eq!(helpers::bps_of(1_000_000_000, 0), Some(0));
+        // Rounds down
+        assert_eq!(helpers::bps_of(99, 100), Some(0));
+        // No intermediate overflow
+        assert_eq!(helpers::bps_of(u64::MAX, 10_000), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_fee_and_bounty_never_exceed_slashed_amount() {
+        use crate::constants::{MAX_PROTOCOL_FEE_BPS, MAX_SLASHER_BOUNTY_BPS};
+        let slashed = 1_000_000_000u64;
+        let fee = helpers::bps_of(slashed, MAX_PROTOCOL_FEE_BPS).unwrap();
+        let bounty = helpers::bps_of(slashed, MAX_SLASHER_BOUNTY_BPS).unwrap();
+        assert_eq!(fee, 100_000_000);
+        assert_eq!(bounty, 20_000_000);
+        assert!(fee + bounty < slashed);
+    }
+
+    #[test]
+    fn test_only_claimed_and_slashed_are_terminal() {
+        assert!(AlarmStatus::Claimed.is_terminal());
+        assert!(AlarmStatus::Slashed.is_terminal());
+        assert!(!AlarmStatus::Created.is_terminal());
+        assert!(!AlarmStatus::Acknowledged.is_terminal());
+        assert!(!AlarmStatus::PendingGift.is_terminal());
+        assert!(!AlarmStatus::PendingBuddy.is_terminal());
+    }
+
+    // =========================================================================
+    // Split penalties
+    // =========================================================================
+
+    #[test]
+    fn test_validate_penalty_splits_ok() {
+        let charity = [1u8; 32];
+        let buddy = [2u8; 32];
+        let burn = crate::constants::BURN_SINK.to_bytes();
+        assert_eq!(
+            helpers::validate_penalty_splits(&charity, &[(charity, 5_000), (buddy, 5_000)]),
+            Ok(())
+        );
+        assert_eq!(
+            helpers::validate_penalty_splits(
+                &charity,
+                &[(charity, 5_000), (buddy, 3_000), (burn, 2_000)]
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_validate_penalty_splits_rejections() {
+        let a = [1u8; 32];
+        let b = [2u8; 32];
+        assert_eq!(
+            helpers::validate_penalty_splits(&a, &[(a, 10_000)]),
+            Err("invalid_split_count")
+        );
+        assert_eq!(
+            helpers::validate_penalty_splits(
+                &a,
+                &[
+                    (a, 2_500),
+                    (b, 2_500),
+                    ([3u8; 32], 2_500),
+                    ([4u8; 32], 2_500)
+                ]
+            ),
+            Err("invalid_split_count")
+        );
+        assert_eq!(
+            helpers::validate_penalty_splits(&b, &[(a, 5_000), (b, 5_000)]),
+            Err("invalid_split_primary")
+        );
+        assert_eq!(
+            helpers::validate_penalty_splits(&a, &[(a, 10_000), (b, 0)]),
+            Err("invalid_split_leg")
+        );
+        assert_eq!(
+            helpers::validate_penalty_splits(&a, &[(a, 5_000), (a, 5_000)]),
+            Err("invalid_split_leg")
+        );
+        assert_eq!(
+            helpers::validate_penalty_splits(&a, &[(a, 5_000), ([0u8; 32], 5_000)]),
+            Err("invalid_split_leg")
+        );
+        assert_eq!(
+            helpers::validate_penalty_splits(&a, &[(a, 5_000), (b, 4_999)]),
+            Err("invalid_split_total")
+        );
+    }
+
+    #[test]
+    fn test_split_shares_first_leg_absorbs_dust() {
+        assert_eq!(
+            helpers::split_shares(1_000, &[5_000, 3_000, 2_000]),
+            Some(vec![500, 300, 200])
+        );
+        // 1001 * 33.33% = 333.63 → 333 each for legs 2 and 3
+        assert_eq!(
+            helpers::split_shares(1_001, &[3_334, 3_333, 3_333]),
+            Some(vec![335, 333, 333])
+        );
+        assert_eq!(helpers::split_shares(1_000, &[]), None);
+    }
+
+    #[test]
+    fn test_alarm_active_splits_skips_empty_slots() {
+        use crate::state::PenaltySplit;
+        let mut alarm = Alarm::default();
+        assert!(alarm.active_splits().is_empty());
+        let leg = PenaltySplit {
+            destination: Pubkey::new_unique(),
+            bps: 6_000,
+        };
+        alarm.penalty_splits[0] = leg;
+        alarm.penalty_splits[1] = PenaltySplit {
+            destination: Pubkey::new_unique(),
+            bps: 4_000,
+        };
+        assert_eq!(alarm.active_splits().len(), 2);
+        assert_eq!(alarm.active_splits()[0], leg);
+        assert_eq!(alarm.penalty_splits.len(), MAX_PENALTY_SPLITS);
+    }
+
+    // =========================================================================
+    // Charity registry
+    // =========================================================================
+
+    #[test]
+    fn test_charity_check_only_applies_when_required_for_donate() {
+        let dest = [7u8; 32];
+        // Not required → anything goes
+        assert!(helpers::is_charity_verified(false, 1, Some(&dest), None));
+        // Required, but not a Donate route
+        assert!(helpers::is_charity_verified(true, 0, None, None));
+        assert!(helpers::is_charity_verified(true, 2, Some(&dest), None));
+    }
+
+    #[test]
+    fn test_charity_check_requires_matching_registry_entry() {
+        let dest = [7u8; 32];
+        let other = [8u8; 32];
+        assert!(helpers::is_charity_verified(
+            true,
+            1,
+            Some(&dest),
+            Some(&dest)
+        ));
+        assert!(!helpers::is_charity_verified(true, 1, Some(&dest), None));
+        assert!(!helpers::is_charity_verified(
+            true,
+            1,
+            Some(&dest),
+            Some(&other)
+        ));
+        assert!(!helpers::is_charity_verified(true, 1, None, Some(&dest)));
+    }
+
+    // =========================================================================
+    // Buddy co-signing
+    // =========================================================================
+
+    #[test]
+    fn test_pending_buddy_status_serializes_after_pending_gift() {
+        use anchor_lang::AnchorSerialize;
+        let mut buf = Vec::new();
+        AlarmStatus::PendingBuddy.serialize(&mut buf).unwrap();
+        // Appended variant: existing on-chain discriminants are unchanged
+        assert_eq!(buf, vec![5]);
+        assert_ne!(AlarmStatus::PendingBuddy, AlarmStatus::Created);
+    }
+
+    // =========================================================================
+    // Group wake challenges
+    // =========================================================================
+
+    #[test]
+    fn test_can_join_challenge() {
+        assert!(helpers::can_join_challenge(1_000, 0, 10, 999));
+        // Closed at wake time
+        assert!(!helpers::can_join_challenge(1_000, 0, 10, 1_000));
+        // Full
+        assert!(!helpers::can_join_challenge(1_000, 10, 10, 0));
+    }
+
+    #[test]
+    fn test_challenge_winners_split_pot_exactly() {
+        // 5 participants x 1 SOL, 3 winners
+        let pot = 5_000_000_000u64;
+        let mut paid = 0u64;
+        let mut shares = Vec::new();
+        for winners_paid in 0..3 {
+            let share = helpers::challenge_winner_share(pot, paid, 3, winners_paid).unwrap();
+            paid += share;
+            shares.push(share);
+        }
+        assert_eq!(shares[0], 1_666_666_666);
+        assert_eq!(shares[1], 1_666_666_666);
+        // Last winner takes the rounding dust
+        assert_eq!(shares[2], 1_666_666_668);
+        assert_eq!(paid, pot);
+        assert_eq!(helpers::challenge_winner_share(pot, paid, 3, 3), None);
+    }
+
+    #[test]
+    fn test_challenge_no_winners_has_no_share() {
+        assert_eq!(helpers::challenge_winner_share(1_000, 0, 0, 0), None);
+    }
+
+    #[test]
+    fn test_validate_partial_slash() {
+        // Disabled
+        assert!(helpers::validate_partial_slash(0, 0, 0).is_ok());
+        assert!(helpers::validate_partial_slash(2_500, 900, 0).is_ok());
+        assert!(helpers::validate_partial_slash(10_000, 60, 2).is_ok());
+        assert_eq!(
+            helpers::validate_partial_slash(0, 900, 0),
+            Err("invalid_partial_slash_bps")
+        );
+        assert_eq!(
+            helpers::validate_partial_slash(10_001, 900, 0),
+            Err("invalid_partial_slash_bps")
+        );
+        assert_eq!(
+            helpers::validate_partial_slash(2_500, 59, 0),
+            Err("invalid_partial_slash_interval")
+        );
+        assert_eq!(
+            helpers::validate_partial_slash(2_500, 86_401, 0),
+            Err("invalid_partial_slash_interval")
+        );
+        assert_eq!(
+            helpers::validate_partial_slash(2_500, 900, 3),
+            Err("split_route_unsupported")
+        );
+    }
+
+    #[test]
+    fn test_partial_slash_tranches() {
+        // 25% per 15 minutes on 1 SOL
+        let deposit = 1_000_000_000u64;
+        let due = |remaining, slashed, now| {
+            helpers::partial_slash_due(remaining, slashed, 2_500, 900, 10_000, now).unwrap()
+        };
+        assert_eq!(due(deposit, 0, 9_999), 0);
+        // First tranche is due at the deadline
+        assert_eq!(due(deposit, 0, 10_000), 250_000_000);
+        assert_eq!(due(deposit, 0, 10_899), 250_000_000);
+        // Already slashed tranches are not charged twice
+        assert_eq!(due(750_000_000, 250_000_000, 10_899), 0);
+        assert_eq!(due(750_000_000, 250_000_000, 10_900), 250_000_000);
+        // Capped at the whole deposit
+        assert_eq!(due(deposit, 0, 10_000 + 900 * 3), deposit);
+        assert_eq!(due(deposit, 0, 10_000 + 900 * 100), deposit);
+        assert_eq!(due(0, deposit, 10_000 + 900 * 100), 0);
+    }
+
+    #[test]
+    fn test_partial_slash_disabled_never_due() {
+        assert_eq!(
+            helpers::partial_slash_due(1_000, 0, 0, 0, 10, i64::MAX),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_late_claim_refund_decays() {
+        let deadline = 10_000i64;
+        let remaining = 1_000_000_000u64;
+        // Before the deadline nothing is forfeited
+        assert_eq!(
+            helpers::late_claim_refund(deadline, 9_999, remaining),
+            remaining
+        );
+        // 50% within the first hour
+        assert_eq!(
+            helpers::late_claim_refund(deadline, deadline, remaining),
+            500_000_000
+        );
+        assert_eq!(
+            helpers::late_claim_refund(
+                deadline,
+                deadline + LATE_CLAIM_EARLY_SECONDS - 1,
+                remaining
+            ),
+            500_000_000
+        );
+        // 25% until six hours
+        assert_eq!(
+            helpers::late_claim_refund(deadline, deadline + LATE_CLAIM_EARLY_SECONDS, remaining),
+            250_000_000
+        );
+        assert_eq!(
+            helpers::late_claim_refund(deadline, deadline + LATE_CLAIM_MAX_SECONDS - 1, remaining),
+            250_000_000
+        );
+        // Nothing afterwards
+        assert_eq!(
+            helpers::late_claim_refund(deadline, deadline + LATE_CLAIM_MAX_SECONDS, remaining),
+            0
+        );
+        assert_eq!(helpers::late_claim_refund(deadline, i64::MAX, remaining), 0);
+    }
+
+    #[test]
+    fn test_late_claim_refund_edge_amounts() {
+        assert_eq!(helpers::late_claim_refund(0, 0, 0), 0);
+        assert_eq!(helpers::late_claim_refund(0, 0, 1), 0);
+        assert_eq!(helpers::late_claim_refund(0, 0, u64::MAX), u64::MAX / 2);
+        assert_eq!(helpers::late_claim_refund(i64::MAX, i64::MIN, 7), 7);
+    }
+
+    /// Ed25519 program instruction data for one inline signature.
+    fn ed25519_ix_data(pubkey: &[u8; 32], message: &[u8]) -> Vec<u8> {
+        let pubkey_offset: u16 = 16;
+        let signature_offset: u16 = pubkey_offset + 32;
+        let message_offset: u16 = signature_offset + 64;
+        let mut data = vec![1u8, 0];
+        for v in [
+            signature_offset,
+            u16::MAX,
+            pubkey_offset,
+            u16::MAX,
+            message_offset,
+            message.len() as u16,
+            u16::MAX,
+        ] {
+            data.extend_from_slice(&v.to_le_bytes());
+        }
+        data.extend_from_slice(pubkey);
+        data.extend_from_slice(&[7u8; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction() {
+        let key = [3u8; 32];
+        let message = helpers::permit_message(0, &[9u8; 32], 5, 1_000, &[1u8; 32]);
+        let data = ed25519_ix_data(&key, &message);
+        let (parsed_key, parsed_message) = helpers::parse_ed25519_instruction(&data).unwrap();
+        assert_eq!(parsed_key, key);
+        assert_eq!(parsed_message, message.as_slice());
+    }
+
+    #[test]
+    fn test_parse_ed25519_rejects_foreign_layouts() {
+        let data = ed25519_ix_data(&[3u8; 32], b"permit");
+        assert!(helpers::parse_ed25519_instruction(&[]).is_none());
+        // Two signatures
+        let mut multi = data.clone();
+        multi[0] = 2;
+        assert!(helpers::parse_ed25519_instruction(&multi).is_none());
+        // Public key read from another instruction
+        let mut foreign = data.clone();
+        foreign[8..10].copy_from_slice(&0u16.to_le_bytes());
+        assert!(helpers::parse_ed25519_instruction(&foreign).is_none());
+        // Message out of bounds
+        let mut truncated = data.clone();
+        truncated.truncate(data.len() - 1);
+        assert!(helpers::parse_ed25519_instruction(&truncated).is_none());
+    }
+
+    #[test]
+    fn test_permit_message_binds_every_field() {
+        let base = helpers::permit_message(0, &[1u8; 32], 1, 100, &[2u8; 32]);
+        assert!(base.starts_with(crate::constants::PERMIT_DOMAIN));
+        assert_ne!(
+            base,
+            helpers::permit_message(1, &[1u8; 32], 1, 100, &[2u8; 32])
+        );
+        assert_ne!(
+            base,
+            helpers::permit_message(0, &[5u8; 32], 1, 100, &[2u8; 32])
+        );
+        assert_ne!(
+            base,
+            helpers::permit_message(0, &[1u8; 32], 2, 100, &[2u8; 32])
+        );
+        assert_ne!(
+            base,
+            helpers::permit_message(0, &[1u8; 32], 1, 101, &[2u8; 32])
+        );
+        assert_ne!(
+            base,
+            helpers::permit_message(0, &[1u8; 32], 1, 100, &[0u8; 32])
+        );
+    }
+
+    #[test]
+    fn test_validate_permit_window() {
+        assert!(helpers::validate_permit_window(1_000, 1_000).is_ok());
+        assert!(helpers::validate_permit_window(1_900, 1_000).is_ok());
+        assert_eq!(
+            helpers::validate_permit_window(999, 1_000),
+            Err("permit_expired")
+        );
+        assert_eq!(
+            helpers::validate_permit_window(1_901, 1_000),
+            Err("permit_ttl_too_long")
+        );
+    }
+
+    #[test]
+    fn test_attestation_key_transition_window() {
+        let (old, new, other) = ([1u8; 32], [2u8; 32], [3u8; 32]);
+        // After rotation at t=1_000 with a 3_600s window
+        let valid_until = 4_600;
+        assert!(helpers::is_attestation_key_valid(
+            &new,
+            &new,
+            &old,
+            valid_until,
+            1_000
+        ));
+        assert!(helpers::is_attestation_key_valid(
+            &old,
+            &new,
+            &old,
+            valid_until,
+            4_600
+        ));
+        assert!(!helpers::is_attestation_key_valid(
+            &old,
+            &new,
+            &old,
+            valid_until,
+            4_601
+        ));
+        assert!(helpers::is_attestation_key_valid(
+            &new,
+            &new,
+            &old,
+            valid_until,
+            i64::MAX
+        ));
+        assert!(!helpers::is_attestation_key_valid(
+            &other,
+            &new,
+            &old,
+            valid_until,
+            1_000
+        ));
+        // An unset previous key never validates
+        assert!(!helpers::is_attestation_key_valid(
+            &[0u8; 32],
+            &new,
+            &[0u8; 32],
+            valid_until,
+            1_000
+        ));
+    }
+
+    #[test]
+    fn test_validate_attestation_rotation() {
+        let current = [1u8; 32];
+        assert!(helpers::validate_attestation_rotation(&current, &[2u8; 32], 0).is_ok());
+        assert!(helpers::validate_attestation_rotation(
+            &current,
+            &[2u8; 32],
+            crate::constants::MAX_ATTESTATION_TRANSITION_SECONDS
+        )
+        .is_ok());
+        assert_eq!(
+            helpers::validate_attestation_rotation(&current, &current, 60),
+            Err("invalid_attestation_key")
+        );
+        assert_eq!(
+            helpers::validate_attestation_rotation(&current, &[0u8; 32], 60),
+            Err("invalid_attestation_key")
+        );
+        assert_eq!(
+            helpers::validate_attestation_rotation(&current, &[2u8; 32], -1),
+            Err("invalid_transition_window")
+        );
+        assert_eq!(
+            helpers::validate_attestation_rotation(
+                &current,
+                &[2u8; 32],
+                crate::constants::MAX_ATTESTATION_TRANSITION_SECONDS + 1
+            ),
+            Err("invalid_transition_window")
+        );
+    }
+
+    #[test]
+    fn test_count_attestations_m_of_n() {
+        let (current, previous) = ([1u8; 32], [2u8; 32]);
+        let attestors = [[3u8; 32], [4u8; 32], [0u8; 32], [0u8; 32]];
+        let count = |signers: &[[u8; 32]], now| {
+            helpers::count_attestations(signers, &current, &previous, 100, &attestors, now)
+        };
+        assert_eq!(count(&[], 0), 0);
+        assert_eq!(count(&[current], 0), 1);
+        assert_eq!(count(&[current, [3u8; 32]], 0), 2);
+        assert_eq!(count(&[current, [3u8; 32], [4u8; 32]], 0), 3);
+        // Current and previous key share one slot
+        assert_eq!(count(&[current, previous], 0), 1);
+        assert_eq!(count(&[previous, [4u8; 32]], 100), 2);
+        assert_eq!(count(&[previous, [4u8; 32]], 101), 1);
+        // Duplicate signatures and unknown keys do not count
+        assert_eq!(count(&[[3u8; 32], [3u8; 32], [9u8; 32]], 0), 1);
+        // Empty attestor slots never match a zero signer
+        assert_eq!(count(&[[0u8; 32]], 0), 0);
+    }
+
+    #[test]
+    fn test_validate_attestor_set() {
+        let current = [1u8; 32];
+        assert!(helpers::validate_attestor_set(&current, &[], 1).is_ok());
+        assert!(helpers::validate_attestor_set(&current, &[[2u8; 32], [3u8; 32]], 3).is_ok());
+        assert_eq!(
+            helpers::validate_attestor_set(&current, &[[2u8; 32]], 3),
+            Err("invalid_threshold")
+        );
+        assert_eq!(
+            helpers::validate_attestor_set(&current, &[], 0),
+            Err("invalid_threshold")
+        );
+        assert_eq!(
+            helpers::validate_attestor_set(&current, &[[2u8; 32], [2u8; 32]], 1),
+            Err("invalid_attestor")
+        );
+        assert_eq!(
+            helpers::validate_attestor_set(&current, &[current], 1),
+            Err("invalid_attestor")
+        );
+        assert_eq!(
+            helpers::validate_attestor_set(&current, &[[0u8; 32]], 1),
+            Err("invalid_attestor")
+        );
+        let five: Vec<[u8; 32]> = (2..7u8).map(|b| [b; 32]).collect();
+        assert_eq!(
+            helpers::validate_attestor_set(&current, &five, 1),
+            Err("too_many_attestors")
+        );
+    }
+
+    // =========================================================================
+    // Achievements
+    // =========================================================================
+
+    const DAY: i64 = 86_400;
+
+    #[test]
+    fn test_record_claim_day_streaks() {
+        let mut stats = helpers::ProfileStats::default();
+        stats = helpers::record_claim(&stats, 10 * DAY + 3_600, 0);
+        assert_eq!((stats.total_claims, stats.current_streak), (1, 1));
+        // Same day: only the claim count moves
+        stats = helpers::record_claim(&stats, 10 * DAY + 7_200, 0);
+        assert_eq!((stats.total_claims, stats.current_streak), (2, 1));
+        // Next day extends, a gap restarts
+        stats = helpers::record_claim(&stats, 11 * DAY, 0);
+        assert_eq!(stats.current_streak, 2);
+        stats = helpers::record_claim(&stats, 13 * DAY, 0);
+        assert_eq!(stats.current_streak, 1);
+        assert_eq!(stats.best_streak, 2);
+        assert_eq!(stats.last_claim_day, 13);
+    }
+
+    #[test]
+    fn test_record_claim_snooze_resets_clean_streak() {
+        let mut stats = helpers::ProfileStats::default();
+        for day in 0..5 {
+            stats = helpers::record_claim(&stats, day * DAY, 0);
+        }
+        assert_eq!(stats.clean_streak, 5);
+        stats = helpers::record_claim(&stats, 5 * DAY, 1);
+        assert_eq!(stats.clean_streak, 0);
+        assert_eq!(stats.current_streak, 6);
+        assert_eq!(stats.best_clean_streak, 5);
+        stats = helpers::record_claim(&stats, 6 * DAY, 0);
+        assert_eq!(stats.clean_streak, 1);
+    }
+
+    #[test]
+    fn test_badge_thresholds() {
+        use crate::constants::{BADGE_SNOOZE_FREE_MONTH, BADGE_THIRTY_CLAIMS, BADGE_WEEK_STREAK};
+        let mut stats = helpers::ProfileStats::default();
+        for day in 0..7 {
+            stats = helpers::record_claim(&stats, day * DAY, 0);
+        }
+        assert_eq!(helpers::badge_unlocked(BADGE_WEEK_STREAK, &stats), Ok(true));
+        assert_eq!(
+            helpers::badge_unlocked(BADGE_THIRTY_CLAIMS, &stats),
+            Ok(false)
+        );
+        for day in 7..30 {
+            stats = helpers::record_claim(&stats, day * DAY, 0);
+        }
+        assert_eq!(
+            helpers::badge_unlocked(BADGE_THIRTY_CLAIMS, &stats),
+            Ok(true)
+        );
+        assert_eq!(
+            helpers::badge_unlocked(BADGE_SNOOZE_FREE_MONTH, &stats),
+            Ok(true)
+        );
+        assert_eq!(helpers::badge_unlocked(3, &stats), Err("unknown_badge"));
+    }
+
+    #[test]
+    fn test_savings_lock() {
+        use crate::constants::{MAX_SAVINGS_LOCK_SECONDS, MIN_SAVINGS_LOCK_SECONDS};
+        assert!(helpers::validate_savings_lock(MIN_SAVINGS_LOCK_SECONDS));
+        assert!(helpers::validate_savings_lock(MAX_SAVINGS_LOCK_SECONDS));
+        assert!(!helpers::validate_savings_lock(
+            MIN_SAVINGS_LOCK_SECONDS - 1
+        ));
+        assert!(!helpers::validate_savings_lock(
+            MAX_SAVINGS_LOCK_SECONDS + 1
+        ));
+
+        let lock = MIN_SAVINGS_LOCK_SECONDS;
+        // First penalty starts the lock
+        assert_eq!(
+            helpers::savings_unlock_at(0, lock, 1_000),
+            Some(1_000 + lock)
+        );
+        // A later penalty restarts it from now
+        assert_eq!(
+            helpers::savings_unlock_at(1_000 + lock, lock, 5_000),
+            Some(5_000 + lock)
+        );
+        // Never moves earlier
+        assert_eq!(
+            helpers::savings_unlock_at(i64::MAX - 1, lock, 5_000),
+            Some(i64::MAX - 1)
+        );
+        assert_eq!(helpers::savings_unlock_at(0, lock, i64::MAX), None);
+    }
+
+    fn preflight_config() -> GlobalConfig {
+        GlobalConfig {
+            admin: Pubkey::new_unique(),
+            protocol_fee_bps: 0,
+            slasher_bounty_bps: 0,
+            default_snooze_percent: DEFAULT_SNOOZE_PERCENT as u8,
+            default_snooze_extension_seconds: DEFAULT_SNOOZE_EXTENSION_SECONDS,
+            default_max_snoozes: MAX_SNOOZE_COUNT,
+            paused: false,
+            require_verified_charity: false,
+            bump: 255,
+            max_deposit_lamports: 0,
+            daily_deposit_cap_lamports: 0,
+            max_open_alarms: 0,
+            referral_fee_bps: 0,
+            min_wake_window_seconds: 0,
+            max_wake_window_seconds: 0,
+            treasury_signers: Default::default(),
+            burn_sink: Pubkey::default(),
+            two_phase_claim_threshold: 0,
+            lookup_table: Pubkey::default(),
+            insurance_bps: 0,
+            proof_type_registry: 0,
+            default_unclaimed_penalty_bps: 0,
+        }
+    }
+
+    /// Name of the first error `create_alarm` would hit, as reported by preflight.
+    fn preflight(
+        config: &GlobalConfig,
+        deposit_amount: u64,
+        penalty_route: u8,
+        penalty_destination: Option<Pubkey>,
+    ) -> Option<String> {
+        use crate::instructions::create_alarm::validate_create_alarm;
+        let now = 1_000;
+        match validate_create_alarm(
+            config,
+            &Pubkey::new_unique(),
+            None,
+            None,
+            None,
+            None,
+            now + 60,
+            now + 3_600,
+            deposit_amount,
+            penalty_route,
+            penalty_destination,
+            &crate::state::AlarmOptions::default(),
+            AlarmStatus::Created,
+            now,
+        ) {
+            Ok(_) => None,
+            Err(anchor_lang::error::Error::AnchorError(e)) => Some(e.error_name),
+            Err(e) => panic!("unexpected error {e:?}"),
+        }
+    }
+
+    #[test]
+    fn test_create_alarm_rejects_blocked_destination() {
+        use crate::instructions::create_alarm::validate_create_alarm;
+        let config = preflight_config();
+        let buddy = Pubkey::new_unique();
+        let mut profile = UserProfile::default();
+        let validate = |profile: &UserProfile| {
+            validate_create_alarm(
+                &config,
+                &Pubkey::new_unique(),
+                None,
+                None,
+                Some(profile),
+                Some(profile),
+                1_060,
+                4_600,
+                MIN_DEPOSIT_LAMPORTS,
+                PenaltyRoute::Buddy as u8,
+                Some(buddy),
+                &crate::state::AlarmOptions::default(),
+                AlarmStatus::Created,
+                1_000,
+            )
+        };
+        assert!(validate(&profile).is_ok());
+        profile.blocked_destinations[2] = buddy;
+        assert!(profile.is_destination_blocked(&buddy));
+        match validate(&profile) {
+            Err(anchor_lang::error::Error::AnchorError(e)) => {
+                assert_eq!(e.error_name, "DestinationBlocked")
+            }
+            Err(e) => panic!("unexpected error {e:?}"),
+            Ok(_) => panic!("blocked destination accepted"),
+        }
+    }
+
+    #[test]
+    fn test_gift_deposit_limits_charge_the_gifter() {
+        use crate::instructions::create_alarm::check_deposit_limits;
+        let error_name = |r: anchor_lang::Result<Option<(i64, u64)>>| match r {
+            Err(anchor_lang::error::Error::AnchorError(e)) => e.error_name,
+            other => panic!("expected an error, got {other:?}"),
+        };
+        let mut config = preflight_config();
+        let mut gifter = UserProfile::default();
+        assert_eq!(
+            check_deposit_limits(&config, None, u64::MAX, 0).unwrap(),
+            None
+        );
+
+        config.max_deposit_lamports = MIN_DEPOSIT_LAMPORTS;
+        assert_eq!(
+            error_name(check_deposit_limits(
+                &config,
+                None,
+                MIN_DEPOSIT_LAMPORTS + 1,
+                0
+            )),
+            "DepositLimitExceeded"
+        );
+
+        config.daily_deposit_cap_lamports = MIN_DEPOSIT_LAMPORTS;
+        assert_eq!(
+            error_name(check_deposit_limits(&config, None, MIN_DEPOSIT_LAMPORTS, 0)),
+            "UserProfileRequired"
+        );
+        let usage = check_deposit_limits(&config, Some(&gifter), MIN_DEPOSIT_LAMPORTS, 86_400)
+            .unwrap()
+            .unwrap();
+        assert_eq!(usage, (1, MIN_DEPOSIT_LAMPORTS));
+        (gifter.deposit_day, gifter.deposited_today) = usage;
+        assert_eq!(
+            error_name(check_deposit_limits(&config, Some(&gifter), 1, 86_401)),
+            "DepositLimitExceeded"
+        );
+    }
+
+    #[test]
+    fn test_sponsored_deposit_limits_charge_the_funder() {
+        use crate::instructions::create_alarm::validate_create_alarm;
+        let mut config = preflight_config();
+        config.daily_deposit_cap_lamports = MIN_DEPOSIT_LAMPORTS;
+        let owner_profile = UserProfile::default();
+        let sponsor_profile = UserProfile {
+            deposit_day: 0,
+            deposited_today: MIN_DEPOSIT_LAMPORTS,
+            ..UserProfile::default()
+        };
+        let validate = |funder: Option<&UserProfile>| {
+            validate_create_alarm(
+                &config,
+                &Pubkey::new_unique(),
+                None,
+                None,
+                Some(&owner_profile),
+                funder,
+                1_060,
+                4_600,
+                MIN_DEPOSIT_LAMPORTS,
+                PenaltyRoute::Burn as u8,
+                None,
+                &crate::state::AlarmOptions::default(),
+                AlarmStatus::Created,
+                1_000,
+            )
+        };
+        // The owner's own cap is untouched, but the sponsor already used theirs today
+        assert_eq!(
+            validate(Some(&owner_profile)).unwrap().deposit_usage,
+            Some((0, MIN_DEPOSIT_LAMPORTS))
+        );
+        match validate(Some(&sponsor_profile)) {
+            Err(anchor_lang::error::Error::AnchorError(e)) => {
+                assert_eq!(e.error_name, "DepositLimitExceeded")
+            }
+            Err(e) => panic!("unexpected error {e:?}"),
+            Ok(_) => panic!("sponsor over its cap accepted"),
+        }
+        match validate(None) {
+            Err(anchor_lang::error::Error::AnchorError(e)) => {
+                assert_eq!(e.error_name, "UserProfileRequired")
+            }
+            Err(e) => panic!("unexpected error {e:?}"),
+            Ok(_) => panic!("missing funder profile accepted"),
+        }
+    }
+
+    #[test]
+    fn test_preflight_reports_create_alarm_errors() {
+        let mut config = preflight_config();
+        let buddy = Some(Pubkey::new_unique());
+        assert_eq!(preflight(&config, MIN_DEPOSIT_LAMPORTS, 0, None), None);
+        // A buddy can only be checked against the owner's blocklist
+        assert_eq!(
+            preflight(&config, 0, 2, buddy).as_deref(),
+            Some("UserProfileRequired")
+        );
+        assert_eq!(
+            preflight(&config, MIN_DEPOSIT_LAMPORTS - 1, 0, None).as_deref(),
+            Some("DepositTooSmall")
+        );
+        assert_eq!(
+            preflight(&config, MIN_DEPOSIT_LAMPORTS, 9, None).as_deref(),
+            Some("InvalidPenaltyRoute")
+        );
+        assert_eq!(
+            preflight(&config, MIN_DEPOSIT_LAMPORTS, 2, None).as_deref(),
+            Some("PenaltyDestinationRequired")
+        );
+        assert_eq!(
+            preflight(&config, MIN_DEPOSIT_LAMPORTS, 4, None).as_deref(),
+            Some("PenaltyDestinationRequired")
+        );
+
+        config.daily_deposit_cap_lamports = MIN_DEPOSIT_LAMPORTS;
+        assert_eq!(
+            preflight(&config, MIN_DEPOSIT_LAMPORTS, 0, None).as_deref(),
+            Some("UserProfileRequired")
+        );
+        config.daily_deposit_cap_lamports = 0;
+        config.max_deposit_lamports = MIN_DEPOSIT_LAMPORTS;
+        assert_eq!(
+            preflight(&config, MIN_DEPOSIT_LAMPORTS + 1, 0, None).as_deref(),
+            Some("DepositLimitExceeded")
+        );
+
+        config.max_deposit_lamports = 0;
+        config.max_open_alarms = 5;
+        assert_eq!(
+            preflight(&config, 0, 0, None).as_deref(),
+            Some("UserProfileRequired")
+        );
+
+        // preflight() uses a 3_540 s wake window
+        config.max_open_alarms = 0;
+        config.min_wake_window_seconds = 3_600;
+        assert_eq!(
+            preflight(&config, 0, 0, None).as_deref(),
+            Some("WakeWindowOutOfBounds")
+        );
+        config.min_wake_window_seconds = 0;
+        config.max_wake_window_seconds = 1_800;
+        assert_eq!(
+            preflight(&config, 0, 0, None).as_deref(),
+            Some("WakeWindowOutOfBounds")
+        );
+
+        config.paused = true;
+        assert_eq!(
+            preflight(&config, MIN_DEPOSIT_LAMPORTS, 0, None).as_deref(),
+            Some("ProtocolPaused")
+        );
+    }
+
+    #[test]
+    fn test_view_claimable_and_next_snooze_cost() {
+        // Whole vault balance, less the buddy share of the deposit
+        assert_eq!(
+            helpers::claimable_amount(1_000_000 + 890_880, 1_000_000, 0),
+            Some(1_890_880)
+        );
+        assert_eq!(
+            helpers::claimable_amount(1_000_000 + 890_880, 1_000_000, 1_000),
+            Some(1_790_880)
+        );
+        assert_eq!(helpers::claimable_amount(0, 1_000_000, 1_000), None);
+
+        let params = helpers::SnoozeParams::default();
+        let rent = 890_880;
+        assert_eq!(
+            helpers::next_snooze_cost(
+                1_000_000,
+                0,
+                &params,
+                SnoozeModel::Exponential,
+                0,
+                1_000_000 + rent,
+                rent
+            ),
+            helpers::snooze_cost(1_000_000, 0)
+        );
+        // Capped at what sits above rent
+        assert_eq!(
+            helpers::next_snooze_cost(
+                1_000_000,
+                0,
+                &params,
+                SnoozeModel::Exponential,
+                0,
+                rent + 5,
+                rent
+            ),
+            Some(5)
+        );
+        // Free, then priced from the bottom of the curve
+        assert_eq!(
+            helpers::next_snooze_cost(
+                1_000_000,
+                1,
+                &params,
+                SnoozeModel::Exponential,
+                2,
+                1_000_000 + rent,
+                rent
+            ),
+            Some(0)
+        );
+        assert_eq!(
+            helpers::next_snooze_cost(
+                1_000_000,
+                2,
+                &params,
+                SnoozeModel::Exponential,
+                2,
+                1_000_000 + rent,
+                rent
+            ),
+            helpers::snooze_cost(1_000_000, 0)
+        );
+        assert_eq!(
+            helpers::next_snooze_cost(
+                1_000_000,
+                params.max_snoozes,
+                &params,
+                SnoozeModel::Exponential,
+                0,
+                1_000_000 + rent,
+                rent
+            ),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_quoted_snooze_cost() {
+        let params = helpers::SnoozeParams::default();
+        let rent = 890_880;
+        // No extension: same as the plain next-snooze cost
+        assert_eq!(
+            helpers::quoted_snooze_cost(
+                1_000_000,
+                0,
+                &params,
+                SnoozeModel::Exponential,
+                0,
+                None,
+                1_000_000 + rent,
+                rent
+            ),
+            helpers::next_snooze_cost(
+                1_000_000,
+                0,
+                &params,
+                SnoozeModel::Exponential,
+                0,
+                1_000_000 + rent,
+                rent
+            )
+        );
+        // Owner-selected length: priced as `snooze` prices it
+        assert_eq!(
+            helpers::quoted_snooze_cost(
+                1_000_000,
+                1,
+                &params,
+                SnoozeModel::Exponential,
+                0,
+                Some(10),
+                1_000_000 + rent,
+                rent
+            ),
+            helpers::snooze_cost_scaled_for(1_000_000, 1, &params, SnoozeModel::Exponential, 10)
+        );
+        // Still capped at what sits above rent, and free snoozes stay free
+        assert_eq!(
+            helpers::quoted_snooze_cost(
+                1_000_000,
+                0,
+                &params,
+                SnoozeModel::Exponential,
+                0,
+                Some(15),
+                rent + 5,
+                rent
+            ),
+            Some(5)
+        );
+        assert_eq!(
+            helpers::quoted_snooze_cost(
+                1_000_000,
+                0,
+                &params,
+                SnoozeModel::Exponential,
+                1,
+                Some(15),
+                1_000_000 + rent,
+                rent
+            ),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_soft_deadline_penalty() {
+        let (alarm_time, soft, hard) = (10_000, 11_000, 12_000);
+        assert!(helpers::validate_soft_deadline(alarm_time, 0, hard));
+        assert!(helpers::validate_soft_deadline(alarm_time, soft, hard));
+        assert!(!helpers::validate_soft_deadline(
+            alarm_time, alarm_time, hard
+        ));
+        assert!(!helpers::validate_soft_deadline(alarm_time, hard, hard));
+
+        let remaining = 1_000_000;
+        // Unset or not yet passed: nothing forfeited
+        assert_eq!(helpers::soft_penalty(0, hard, 11_900, remaining), 0);
+        assert_eq!(helpers::soft_penalty(soft, hard, soft, remaining), 0);
+        // Linear ramp between the two deadlines
+        assert_eq!(
+            helpers::soft_penalty(soft, hard, 11_250, remaining),
+            250_000
+        );
+        assert_eq!(
+            helpers::soft_penalty(soft, hard, 11_999, remaining),
+            999_000
+        );
+        // At the hard deadline everything is forfeited
+        assert_eq!(
+            helpers::soft_penalty(soft, hard, hard, remaining),
+            remaining
+        );
+        assert_eq!(
+            helpers::soft_penalty(soft, hard, 11_500, u64::MAX),
+            u64::MAX / 2
+        );
+    }
+
+    #[test]
+    fn test_early_ack_window() {
+        use crate::constants::MAX_EARLY_ACK_SECONDS;
+        assert!(helpers::validate_early_ack(0));
+        assert!(helpers::validate_early_ack(MAX_EARLY_ACK_SECONDS));
+        assert!(!helpers::validate_early_ack(-1));
+        assert!(!helpers::validate_early_ack(MAX_EARLY_ACK_SECONDS + 1));
+
+        let (alarm_time, deadline) = (10_000, 12_000);
+        assert_eq!(helpers::wake_window_start(alarm_time, 0), alarm_time);
+        let start = helpers::wake_window_start(alarm_time, 600);
+        assert_eq!(start, 9_400);
+        assert_eq!(helpers::wake_window_start(i64::MIN, 600), i64::MIN);
+
+        // Claim opens at the early start, not before
+        assert_eq!(
+            helpers::check_claim_window(
+                AlarmStatus::Acknowledged,
+                start,
+                deadline,
+                CLAIM_GRACE_SECONDS,
+                start
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            helpers::check_claim_window(
+                AlarmStatus::Acknowledged,
+                start,
+                deadline,
+                CLAIM_GRACE_SECONDS,
+                start - 1
+            ),
+            Err("too_early")
+        );
+        // An early proof time is accepted from the same start
+        assert!(helpers::validate_proof_completed_at(
+            start, start, alarm_time
+        ));
+        assert!(!helpers::validate_proof_completed_at(
+            start - 1,
+            start,
+            alarm_time
+        ));
+        // Refund and snooze stay anchored to alarm_time
+        assert!(helpers::is_refund_window(alarm_time, start));
+        assert!(!helpers::is_snooze_window(alarm_time, deadline, start));
+    }
+
+    #[test]
+    fn test_per_alarm_claim_grace() {
+        use crate::constants::MAX_CLAIM_GRACE_SECONDS;
+        assert!(helpers::validate_claim_grace(0));
+        assert!(helpers::validate_claim_grace(MAX_CLAIM_GRACE_SECONDS));
+        assert!(!helpers::validate_claim_grace(-1));
+        assert!(!helpers::validate_claim_grace(MAX_CLAIM_GRACE_SECONDS + 1));
+
+        let (alarm_time, deadline) = (1_000, 2_000);
+        // Default grace matches the constant-based helpers
+        assert_eq!(
+            helpers::claim_deadline_with_grace_for(deadline, CLAIM_GRACE_SECONDS),
+            helpers::claim_deadline_with_grace(deadline)
+        );
+        // A longer grace keeps claim open and sweep closed until it ends
+        let grace = 900;
+        assert!(helpers::is_claim_window_with_grace_for(
+            alarm_time,
+            deadline,
+            grace,
+            deadline + grace
+        ));
+        assert!(!helpers::is_sweep_window_for(
+            deadline,
+            grace,
+            deadline + grace
+        ));
+        assert!(!helpers::is_claim_window_with_grace_for(
+            alarm_time,
+            deadline,
+            grace,
+            deadline + grace + 1
+        ));
+        assert!(helpers::is_sweep_window_for(
+            deadline,
+            grace,
+            deadline + grace + 1
+        ));
+        // Zero grace: sweep opens right after the deadline
+        assert!(helpers::is_claim_window_with_grace_for(
+            alarm_time, deadline, 0, deadline
+        ));
+        assert!(helpers::is_sweep_window_for(deadline, 0, deadline + 1));
+        assert!(!helpers::is_sweep_window_for(
+            deadline,
+            i64::MAX,
+            deadline + 1
+        ));
+    }
+
+    #[test]
+    fn test_claim_window_by_status() {
+        let (alarm_time, deadline, grace) = (1_000, 2_000, CLAIM_GRACE_SECONDS);
+        let check =
+            |status, now| helpers::check_claim_window(status, alarm_time, deadline, grace, now);
+
+        // Acknowledged: alarm time through deadline + grace, inclusive
+        assert_eq!(
+            check(AlarmStatus::Acknowledged, alarm_time - 1),
+            Err("too_early")
+        );
+        assert_eq!(check(AlarmStatus::Acknowledged, alarm_time), Ok(()));
+        assert_eq!(check(AlarmStatus::Acknowledged, deadline), Ok(()));
+        assert_eq!(check(AlarmStatus::Acknowledged, deadline + grace), Ok(()));
+        assert_eq!(
+            check(AlarmStatus::Acknowledged, deadline + grace + 1),
+            Err("deadline_passed")
+        );
+
+        // Created: strict deadline, and an ACK is still required
+        assert_eq!(
+            check(AlarmStatus::Created, alarm_time - 1),
+            Err("too_early")
+        );
+        assert_eq!(
+            check(AlarmStatus::Created, deadline - 1),
+            Err("not_acknowledged")
+        );
+        assert_eq!(
+            check(AlarmStatus::Created, deadline),
+            Err("deadline_passed")
+        );
+        assert_eq!(
+            check(AlarmStatus::Created, deadline + grace),
+            Err("deadline_passed")
+        );
+
+        for status in [
+            AlarmStatus::Claimed,
+            AlarmStatus::Slashed,
+            AlarmStatus::PendingGift,
+            AlarmStatus::PendingBuddy,
+        ] {
+            assert_eq!(check(status, alarm_time), Err("invalid_state"));
+        }
+        assert_eq!(
+            helpers::check_claim_window(AlarmStatus::Acknowledged, 0, i64::MAX, 1, 0),
+            Err("overflow")
+        );
+    }
+
+    #[test]
+    fn test_two_phase_claim_gate() {
+        use crate::constants::CLAIM_FINALIZE_DELAY_SECONDS as DELAY;
+        assert!(!helpers::requires_two_phase_claim(u64::MAX, 0));
+        assert!(!helpers::requires_two_phase_claim(999, 1_000));
+        assert!(helpers::requires_two_phase_claim(1_000, 1_000));
+
+        let requested = 5_000;
+        // Plain claims: only single-phase alarms
+        assert_eq!(
+            helpers::check_claim_phase(false, false, 0, false, 0),
+            Ok(())
+        );
+        assert_eq!(
+            helpers::check_claim_phase(true, false, requested, false, requested + DELAY),
+            Err("request_required")
+        );
+        // Finalizing
+        assert_eq!(
+            helpers::check_claim_phase(true, true, 0, false, requested),
+            Err("not_requested")
+        );
+        assert_eq!(
+            helpers::check_claim_phase(true, true, requested, false, requested + DELAY - 1),
+            Err("too_early")
+        );
+        assert_eq!(
+            helpers::check_claim_phase(true, true, requested, false, requested + DELAY),
+            Ok(())
+        );
+        assert_eq!(
+            helpers::check_claim_phase(true, true, requested, true, requested + DELAY),
+            Err("flagged")
+        );
+        assert_eq!(
+            helpers::check_claim_phase(true, true, i64::MAX, false, i64::MAX),
+            Err("overflow")
+        );
+
+        // Veto only while the request is pending
+        assert!(!helpers::is_claim_veto_open(0, false, requested));
+        assert!(helpers::is_claim_veto_open(requested, false, requested));
+        assert!(helpers::is_claim_veto_open(
+            requested,
+            false,
+            requested + DELAY - 1
+        ));
+        assert!(!helpers::is_claim_veto_open(
+            requested,
+            false,
+            requested + DELAY
+        ));
+        assert!(!helpers::is_claim_veto_open(requested, true, requested));
+    }
+
+    #[test]
+    fn test_post_deadline_resolution() {
+        use helpers::Resolution;
+        let (deadline, grace) = (2_000, CLAIM_GRACE_SECONDS);
+        let resolve = |status, buddy_route, caller_is_buddy, now| {
+            helpers::post_deadline_resolution(
+                status,
+                buddy_route,
+                caller_is_buddy,
+                deadline,
+                grace,
+                now,
+            )
+        };
+
+        // Created: slashable from the deadline
+        assert_eq!(
+            resolve(AlarmStatus::Created, false, false, deadline - 1),
+            Err("deadline_not_passed")
+        );
+        assert_eq!(
+            resolve(AlarmStatus::Created, false, false, deadline),
+            Ok(Resolution::Slash)
+        );
+        // Buddy route: buddy only until the window ends
+        assert_eq!(
+            resolve(AlarmStatus::Created, true, false, deadline),
+            Err("buddy_only_window")
+        );
+        assert_eq!(
+            resolve(AlarmStatus::Created, true, true, deadline),
+            Ok(Resolution::Slash)
+        );
+        assert_eq!(
+            resolve(
+                AlarmStatus::Created,
+                true,
+                false,
+                deadline + BUDDY_ONLY_SECONDS
+            ),
+            Ok(Resolution::Slash)
+        );
+
+        // Acknowledged: never slashable, buddy or not
+        for (buddy_route, caller_is_buddy) in [(false, false), (true, false), (true, true)] {
+            assert_eq!(
+                resolve(
+                    AlarmStatus::Acknowledged,
+                    buddy_route,
+                    caller_is_buddy,
+                    deadline
+                ),
+                Ok(Resolution::Claim)
+            );
+            assert_eq!(
+                resolve(
+                    AlarmStatus::Acknowledged,
+                    buddy_route,
+                    caller_is_buddy,
+                    deadline + grace
+                ),
+                Ok(Resolution::Claim)
+            );
+            assert_eq!(
+                resolve(
+                    AlarmStatus::Acknowledged,
+                    buddy_route,
+                    caller_is_buddy,
+                    deadline + grace + 1
+                ),
+                Ok(Resolution::Sweep)
+            );
+        }
+
+        assert_eq!(
+            resolve(AlarmStatus::Claimed, false, false, deadline),
+            Err("invalid_state")
+        );
+    }
+
+    #[test]
+    fn test_open_alarm_limit() {
+        assert_eq!(helpers::next_open_alarm_count(0, 0), Ok(1));
+        assert_eq!(helpers::next_open_alarm_count(1_000, 0), Ok(1_001));
+        assert_eq!(helpers::next_open_alarm_count(4, 5), Ok(5));
+        assert_eq!(
+            helpers::next_open_alarm_count(5, 5),
+            Err("too_many_open_alarms")
+        );
+        assert_eq!(helpers::next_open_alarm_count(u16::MAX, 0), Err("overflow"));
+    }
+
+    #[test]
+    fn test_alarm_index() {
+        let mut ids = Vec::new();
+        assert_eq!(helpers::index_alarm(&mut ids, 7, 2), Ok(()));
+        assert_eq!(helpers::index_alarm(&mut ids, 3, 2), Ok(()));
+        // Re-listing is a no-op, even when full
+        assert_eq!(helpers::index_alarm(&mut ids, 7, 2), Ok(()));
+        assert_eq!(helpers::index_alarm(&mut ids, 9, 2), Err("index_full"));
+        assert_eq!(ids, vec![7, 3]);
+
+        assert!(helpers::unindex_alarm(&mut ids, 7));
+        assert!(!helpers::unindex_alarm(&mut ids, 7));
+        assert_eq!(ids, vec![3]);
+        assert_eq!(helpers::index_alarm(&mut ids, 9, 2), Ok(()));
+        assert_eq!(ids, vec![3, 9]);
+    }
+
+    #[test]
+    fn test_expected_open_alarms() {
+        assert_eq!(helpers::check_expected_open_alarms(None, None), Ok(()));
+        assert_eq!(helpers::check_expected_open_alarms(None, Some(3)), Ok(()));
+        assert_eq!(
+            helpers::check_expected_open_alarms(Some(0), None),
+            Err("index_required")
+        );
+        assert_eq!(
+            helpers::check_expected_open_alarms(Some(2), Some(2)),
+            Ok(())
+        );
+
+        // A retried submit sees the alarm the first one indexed
+        let mut ids = vec![4, 5];
+        assert_eq!(
+            helpers::check_expected_open_alarms(Some(2), Some(ids.len())),
+            Ok(())
+        );
+        helpers::index_alarm(&mut ids, 6, 32).unwrap();
+        assert_eq!(
+            helpers::check_expected_open_alarms(Some(2), Some(ids.len())),
+            Err("open_alarms_mismatch")
+        );
+    }
+
+    #[test]
+    fn test_snooze_routes_to_destination() {
+        assert!(!helpers::routes_snooze_to_destination(PenaltyRoute::Burn));
+        for route in [
+            PenaltyRoute::Donate,
+            PenaltyRoute::Buddy,
+            PenaltyRoute::Split,
+            PenaltyRoute::Savings,
+        ] {
+            assert!(helpers::routes_snooze_to_destination(route));
+        }
+    }
+
+    #[test]
+    fn test_snooze_models() {
+        let one_sol = 1_000_000_000;
+        // Base step is 10% = 0.1 SOL
+        let costs = |model| -> Vec<u64> {
+            (0..5)
+                .map(|n| helpers::snooze_cost_for_model(model, one_sol, n, 10).unwrap())
+                .collect()
+        };
+        assert_eq!(
+            costs(SnoozeModel::Exponential),
+            [
+                100_000_000,
+                200_000_000,
+                400_000_000,
+                800_000_000,
+                1_000_000_000
+            ]
+        );
+        assert_eq!(
+            costs(SnoozeModel::Linear),
+            [
+                100_000_000,
+                200_000_000,
+                300_000_000,
+                400_000_000,
+                500_000_000
+            ]
+        );
+        assert_eq!(costs(SnoozeModel::Flat), [100_000_000; 5]);
+        // Linear still caps at remaining
+        assert_eq!(helpers::snooze_cost_linear(one_sol, 19, 10), Some(one_sol));
+
+        assert_eq!(SnoozeModel::try_from(1), Ok(SnoozeModel::Linear));
+        assert!(SnoozeModel::try_from(3).is_err());
+    }
+
+    #[test]
+    fn test_free_snoozes() {
+        assert_eq!(helpers::paid_snooze_index(0, 0), Some(0));
+        assert_eq!(helpers::paid_snooze_index(0, 2), None);
+        assert_eq!(helpers::paid_snooze_index(1, 2), None);
+        assert_eq!(helpers::paid_snooze_index(2, 2), Some(0));
+        assert_eq!(helpers::paid_snooze_index(5, 2), Some(3));
+
+        assert!(helpers::validate_free_snoozes(0, 0));
+        assert!(helpers::validate_free_snoozes(10, 10));
+        assert!(!helpers::validate_free_snoozes(11, 10));
+    }
+
+    #[test]
+    fn test_snooze_cost_scaled() {
+        // 1 SOL, first snooze: 10% for 5 minutes
+        assert_eq!(
+            helpers::snooze_cost_scaled(1_000_000_000, 0, 5),
+            Some(100_000_000)
+        );
+        assert_eq!(
+            helpers::snooze_cost_scaled(1_000_000_000, 0, 2),
+            Some(40_000_000)
+        );
+        assert_eq!(
+            helpers::snooze_cost_scaled(1_000_000_000, 0, 15),
+            Some(300_000_000)
+        );
+        // Capped at remaining once the exponential curve runs out
+        assert_eq!(
+            helpers::snooze_cost_scaled(1_000_000_000, 3, 15),
+            Some(1_000_000_000)
+        );
+
+        // Per-alarm pricing: 20% per 10-minute snooze
+        let params = helpers::SnoozeParams {
+            percent: 20,
+            extension_seconds: 600,
+            max_snoozes: 5,
+        };
+        assert_eq!(
+            helpers::snooze_cost_scaled_for(1_000_000_000, 0, &params, SnoozeModel::Exponential, 1),
+            Some(20_000_000)
+        );
+        let zero = helpers::SnoozeParams {
+            extension_seconds: 0,
+            ..params
+        };
+        assert_eq!(
+            helpers::snooze_cost_scaled_for(1_000_000_000, 0, &zero, SnoozeModel::Flat, 1),
+            None
+        );
+
+        assert!(!helpers::validate_snooze_extension_minutes(0));
+        assert!(helpers::validate_snooze_extension_minutes(1));
+        assert!(helpers::validate_snooze_extension_minutes(15));
+        assert!(!helpers::validate_snooze_extension_minutes(16));
+    }
+
+    #[test]
+    fn test_proof_completed_at_window() {
+        assert!(helpers::validate_proof_completed_at(1_000, 1_000, 1_200));
+        assert!(helpers::validate_proof_completed_at(1_200, 1_000, 1_200));
+        assert!(!helpers::validate_proof_completed_at(999, 1_000, 1_200));
+        assert!(!helpers::validate_proof_completed_at(1_201, 1_000, 1_200));
+        assert!(!helpers::validate_proof_completed_at(0, 1_000, 1_200));
+    }
+
+    #[test]
+    fn test_proof_type_from_u8() {
+        assert_eq!(ProofType::try_from(0), Ok(ProofType::None));
+        assert_eq!(ProofType::try_from(2), Ok(ProofType::Nfc));
+        assert_eq!(ProofType::try_from(5), Ok(ProofType::Shake));
+        assert_eq!(ProofType::try_from(6), Ok(ProofType::Photo));
+        assert_eq!(ProofType::try_from(7), Ok(ProofType::Location));
+        assert!(ProofType::try_from(8).is_err());
+    }
+
+    #[test]
+    fn test_proof_type_registry() {
+        use crate::constants::KNOWN_PROOF_TYPES_MASK;
+        assert!(helpers::validate_proof_type_registry(0));
+        assert!(helpers::validate_proof_type_registry(
+            KNOWN_PROOF_TYPES_MASK
+        ));
+        // `None` and unknown types cannot be registered
+        assert!(!helpers::validate_proof_type_registry(1));
+        assert!(!helpers::validate_proof_type_registry(1 << 8));
+
+        let nfc = ProofType::Nfc as u8;
+        let steps = ProofType::Steps as u8;
+        let nfc_only = 1u16 << nfc;
+        assert_eq!(helpers::validate_required_proof_type(nfc, 0), Ok(()));
+        assert_eq!(helpers::validate_required_proof_type(nfc, nfc_only), Ok(()));
+        assert_eq!(
+            helpers::validate_required_proof_type(steps, nfc_only),
+            Err("not_registered")
+        );
+        assert_eq!(
+            helpers::validate_required_proof_type(ProofType::None as u8, 0),
+            Err("unknown_proof_type")
+        );
+        assert_eq!(
+            helpers::validate_required_proof_type(8, 0),
+            Err("unknown_proof_type")
+        );
+        assert_eq!(
+            helpers::validate_required_proof_type(200, 0),
+            Err("unknown_proof_type")
+        );
+    }
+
+    #[test]
+    fn test_required_proofs_mask() {
+        let nfc = 1u16 << ProofType::Nfc as u8;
+        let steps = 1u16 << ProofType::Steps as u8;
+        assert_eq!(helpers::validate_required_proofs(nfc | steps, 0), Ok(()));
+        assert_eq!(
+            helpers::validate_required_proofs(nfc | steps, nfc | steps),
+            Ok(())
+        );
+        assert_eq!(
+            helpers::validate_required_proofs(nfc | steps, nfc),
+            Err("not_registered")
+        );
+        // One proof is a plain `required_proof_type`
+        assert_eq!(
+            helpers::validate_required_proofs(nfc, 0),
+            Err("invalid_mask")
+        );
+        // `None` and unknown bits are refused
+        assert_eq!(
+            helpers::validate_required_proofs(nfc | 1, 0),
+            Err("invalid_mask")
+        );
+        assert_eq!(
+            helpers::validate_required_proofs(nfc | 1 << 9, 0),
+            Err("invalid_mask")
+        );
+    }
+
+    #[test]
+    fn test_record_proof_stage() {
+        let nfc = ProofType::Nfc as u8;
+        let steps = ProofType::Steps as u8;
+        let required = 1u16 << nfc | 1u16 << steps;
+
+        let after_nfc = helpers::record_proof_stage(required, 0, nfc).unwrap();
+        assert_ne!(after_nfc, required);
+        // Repeating a stage changes nothing
+        assert_eq!(
+            helpers::record_proof_stage(required, after_nfc, nfc),
+            Ok(after_nfc)
+        );
+        assert_eq!(
+            helpers::record_proof_stage(required, after_nfc, steps),
+            Ok(required)
+        );
+        assert_eq!(
+            helpers::record_proof_stage(required, 0, ProofType::Qr as u8),
+            Err("not_required")
+        );
+        assert_eq!(helpers::record_proof_stage(0, 0, nfc), Err("not_required"));
+        assert_eq!(
+            helpers::record_proof_stage(required, 0, 200),
+            Err("not_required")
+        );
+    }
+
+    #[test]
+    fn test_required_proof_type_checks_ack() {
+        let nfc = ProofType::Nfc as u8;
+        assert!(helpers::proof_type_satisfies(0, ProofType::Qr as u8));
+        assert!(helpers::proof_type_satisfies(nfc, nfc));
+        assert!(!helpers::proof_type_satisfies(nfc, ProofType::Qr as u8));
+        assert!(!helpers::proof_type_satisfies(nfc, ProofType::None as u8));
+
+        // Untyped alarms keep the legacy ack permit
+        use crate::constants::{PERMIT_ACTION_ACK, PERMIT_ACTION_ACK_TYPED};
+        assert_eq!(helpers::ack_permit_action(0), PERMIT_ACTION_ACK);
+        assert_eq!(
+            helpers::ack_permit_action(nfc),
+            PERMIT_ACTION_ACK_TYPED + nfc
+        );
+    }
+
+    #[test]
+    fn test_referral_validation() {
+        let owner = [1u8; 32];
+        assert!(helpers::validate_referrer(&owner, &[2u8; 32]));
+        assert!(!helpers::validate_referrer(&owner, &owner));
+        assert!(!helpers::validate_referrer(&owner, &[0u8; 32]));
+
+        assert!(helpers::validate_referral_fee(0));
+        assert!(helpers::validate_referral_fee(5_000));
+        assert!(!helpers::validate_referral_fee(5_001));
+        // 20% of a 0.01 SOL protocol fee
+        assert_eq!(helpers::bps_of(10_000_000, 2_000), Some(2_000_000));
+    }
+
+    #[test]
+    fn test_arbiter_validation() {
+        let owner = [1u8; 32];
+        let buddy = [2u8; 32];
+        let arbiter = [3u8; 32];
+        let buddy_route = PenaltyRoute::Buddy as u8;
+        assert!(helpers::validate_arbiter(&arbiter, &owner, Some(&buddy), buddy_route).is_ok());
+        assert_eq!(
+            helpers::validate_arbiter(&arbiter, &owner, Some(&buddy), PenaltyRoute::Burn as u8),
+            Err("buddy_route_required")
+        );
+        for bad in [owner, buddy, [0u8; 32]] {
+            assert_eq!(
+                helpers::validate_arbiter(&bad, &owner, Some(&buddy), buddy_route),
+                Err("arbiter_not_third_party")
+            );
+        }
+    }
+
+    #[test]
+    fn test_dispute_window() {
+        let alarm_time = 1_000;
+        let end = helpers::dispute_window_end(AlarmStatus::Created, true, 0, alarm_time, 1_500);
+        assert_eq!(end, Ok(1_500 + DISPUTE_WINDOW_SECONDS));
+        // Before the alarm rang, without an arbiter, or a second time
+        assert_eq!(
+            helpers::dispute_window_end(AlarmStatus::Created, true, 0, alarm_time, 999),
+            Err("dispute_unavailable")
+        );
+        assert_eq!(
+            helpers::dispute_window_end(AlarmStatus::Created, false, 0, alarm_time, 1_500),
+            Err("dispute_unavailable")
+        );
+        assert_eq!(
+            helpers::dispute_window_end(AlarmStatus::Created, true, 1_200, alarm_time, 1_500),
+            Err("dispute_unavailable")
+        );
+        assert_eq!(
+            helpers::dispute_window_end(AlarmStatus::Acknowledged, true, 0, alarm_time, 1_500),
+            Err("invalid_state")
+        );
+
+        let until = end.unwrap();
+        assert!(!helpers::is_dispute_active(0, 1_500));
+        assert!(helpers::is_dispute_active(until, until - 1));
+        assert!(!helpers::is_dispute_active(until, until));
+    }
+
+    #[test]
+    fn test_guardian_validation() {
+        let owner = [1u8; 32];
+        let (a, b, c) = ([2u8; 32], [3u8; 32], [4u8; 32]);
+        let recovery = [9u8; 32];
+        assert!(helpers::validate_guardians(&owner, &[], &[0u8; 32]).is_ok());
+        assert!(helpers::validate_guardians(&owner, &[a, b], &recovery).is_ok());
+        assert!(helpers::validate_guardians(&owner, &[a, b, c], &recovery).is_ok());
+        assert_eq!(
+            helpers::validate_guardians(&owner, &[a], &recovery),
+            Err("invalid_guardian_count")
+        );
+        assert_eq!(
+            helpers::validate_guardians(&owner, &[a, b, c, [5u8; 32]], &recovery),
+            Err("invalid_guardian_count")
+        );
+        for bad in [[a, a], [a, owner], [a, [0u8; 32]]] {
+            assert_eq!(
+                helpers::validate_guardians(&owner, &bad, &recovery),
+                Err("invalid_guardian")
+            );
+        }
+        for bad in [owner, [0u8; 32]] {
+            assert_eq!(
+                helpers::validate_guardians(&owner, &[a, b], &bad),
+                Err("invalid_recovery_address")
+            );
+        }
+
+        let guardians = [a, b, [0u8; 32]];
+        assert_eq!(helpers::guardian_slot(&b, &guardians), Some(1));
+        assert_eq!(helpers::guardian_slot(&c, &guardians), None);
+        assert_eq!(helpers::guardian_slot(&[0u8; 32], &guardians), None);
+    }
+
+    #[test]
+    fn test_destination_blocklist() {
+        let (a, b) = ([1u8; 32], [2u8; 32]);
+        let mut blocked = [[0u8; 32]; crate::constants::MAX_BLOCKED_DESTINATIONS];
+        assert_eq!(helpers::blocklist_insert_slot(&a, &blocked), Ok(0));
+        blocked[0] = a;
+        assert_eq!(helpers::blocklist_slot(&a, &blocked), Some(0));
+        assert_eq!(helpers::blocklist_slot(&b, &blocked), None);
+        // Empty slots never match
+        assert_eq!(helpers::blocklist_slot(&[0u8; 32], &blocked), None);
+        for bad in [a, [0u8; 32]] {
+            assert_eq!(
+                helpers::blocklist_insert_slot(&bad, &blocked),
+                Err("invalid_destination")
+            );
+        }
+        assert_eq!(helpers::blocklist_insert_slot(&b, &blocked), Ok(1));
+
+        // Unblocking frees a slot that is reused first
+        let mut full = [[0u8; 32]; crate::constants::MAX_BLOCKED_DESTINATIONS];
+        for (i, slot) in full.iter_mut().enumerate() {
+            *slot = [i as u8 + 10; 32];
+        }
+        assert_eq!(
+            helpers::blocklist_insert_slot(&b, &full),
+            Err("blocklist_full")
+        );
+        full[3] = [0u8; 32];
+        assert_eq!(helpers::blocklist_insert_slot(&b, &full), Ok(3));
+    }
+
+    #[test]
+    fn test_destination_blocklist_needs_profile_for_buddy_and_split() {
+        let (a, b) = ([1u8; 32], [2u8; 32]);
+        let mut blocked = [[0u8; 32]; crate::constants::MAX_BLOCKED_DESTINATIONS];
+        blocked[0] = b;
+        let check = helpers::check_destination_blocklist;
+        let (buddy, split, donate) = (
+            PenaltyRoute::Buddy as u8,
+            PenaltyRoute::Split as u8,
+            PenaltyRoute::Donate as u8,
+        );
+
+        assert_eq!(check(buddy, &[a], Some(&blocked)), Ok(()));
+        assert_eq!(check(split, &[a, b], Some(&blocked)), Err("blocked"));
+        assert_eq!(check(donate, &[b], Some(&blocked)), Err("blocked"));
+        // Leaving the profile out cannot skip the check
+        assert_eq!(check(buddy, &[a], None), Err("profile_required"));
+        assert_eq!(check(split, &[a], None), Err("profile_required"));
+        // Nothing to check without a Buddy or Split destination
+        assert_eq!(check(buddy, &[], None), Ok(()));
+        assert_eq!(check(donate, &[b], None), Ok(()));
+    }
+
+    #[test]
+    fn test_recovery_approvals() {
+        let now = 1_000;
+        // One approval blocks sweep but does not start the timelock
+        let (approvals, unlocks_at) = helpers::apply_recovery_approval(0, 0, 0, now).unwrap();
+        assert_eq!((approvals, unlocks_at), (0b001, 0));
+        // The same guardian again changes nothing
+        assert_eq!(
+            helpers::apply_recovery_approval(approvals, unlocks_at, 0, now + 5),
+            Some((0b001, 0))
+        );
+        // A second guardian reaches the threshold
+        let (approvals, unlocks_at) =
+            helpers::apply_recovery_approval(approvals, unlocks_at, 2, now + 10).unwrap();
+        assert_eq!(approvals, 0b101);
+        assert_eq!(unlocks_at, now + 10 + RECOVERY_TIMELOCK_SECONDS);
+        // A third approval does not restart the timelock
+        assert_eq!(
+            helpers::apply_recovery_approval(approvals, unlocks_at, 1, now + 20),
+            Some((0b111, unlocks_at))
+        );
+
+        assert!(!helpers::is_recovery_unlocked(0, i64::MAX));
+        assert!(!helpers::is_recovery_unlocked(unlocks_at, unlocks_at - 1));
+        assert!(helpers::is_recovery_unlocked(unlocks_at, unlocks_at));
+    }
+
+    #[test]
+    fn test_crank_batch_size() {
+        assert_eq!(helpers::crank_batch_size(4), Some(1));
+        assert_eq!(helpers::crank_batch_size(40), Some(10));
+        assert_eq!(helpers::crank_batch_size(0), None);
+        assert_eq!(helpers::crank_batch_size(3), None);
+        assert_eq!(helpers::crank_batch_size(30), None);
+        assert_eq!(helpers::crank_batch_size(44), None);
+    }
+
+    #[test]
+    fn test_missing_lookup_addresses() {
+        let (a, b, c) = ([1u8; 32], [2u8; 32], [3u8; 32]);
+        assert_eq!(helpers::missing_lookup_addresses(&[], &[a, b]), vec![a, b]);
+        assert_eq!(helpers::missing_lookup_addresses(&[a, b], &[b, c]), vec![c]);
+        assert!(helpers::missing_lookup_addresses(&[a, b, c], &[c, a]).is_empty());
+        // A repeated wanted address is appended once
+        assert_eq!(helpers::missing_lookup_addresses(&[], &[a, a]), vec![a]);
+    }
+
+    #[test]
+    fn test_claim_many_batch_size() {
+        assert_eq!(helpers::claim_many_batch_size(2), Some(1));
+        assert_eq!(helpers::claim_many_batch_size(16), Some(8));
+        assert_eq!(helpers::claim_many_batch_size(0), None);
+        assert_eq!(helpers::claim_many_batch_size(3), None);
+        assert_eq!(helpers::claim_many_batch_size(18), None);
+    }
+
+    #[test]
+    fn test_needs_migration() {
+        let size = Alarm::SIZE;
+        let current = crate::constants::ALARM_LAYOUT_VERSION;
+        assert!(!helpers::needs_migration(size, size, current, current));
+        // Short accounts are rewritten even if the version byte looks current
+        assert!(helpers::needs_migration(size - 8, size, current, current));
+        // Pre-versioning accounts read version 0 out of the zeroed padding
+        assert!(helpers::needs_migration(size, size, 0, current));
+    }
+
+    #[test]
+    fn test_migrated_baseline_alarm_snoozes_and_claims() {
+        use crate::instructions::migrate_account::backfill_legacy_alarm;
+        use anchor_lang::{AccountDeserialize, Discriminator};
+
+        // A 182-byte alarm as written by the first release: the original
+        // fields, then 64 bytes of zeroed padding
+        let owner = Pubkey::new_unique();
+        let (alarm_time, deadline, deposit) = (1_000_000i64, 1_001_800i64, 100_000_000u64);
+        let mut data = Alarm::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(owner.as_ref());
+        data.extend_from_slice(&7u64.to_le_bytes());
+        data.extend_from_slice(&alarm_time.to_le_bytes());
+        data.extend_from_slice(&deadline.to_le_bytes());
+        data.extend_from_slice(&deposit.to_le_bytes());
+        data.extend_from_slice(&deposit.to_le_bytes());
+        data.push(PenaltyRoute::Burn as u8);
+        data.extend_from_slice(&[1; 33]); // Some(penalty_destination)
+        data.extend_from_slice(&[0, 0, 254, 253]); // snooze_count, status, bumps
+        data.resize(182, 0);
+
+        // `migrate_alarm` zero-fills up to the current size
+        data.resize(Alarm::SIZE, 0);
+        let mut alarm = Alarm::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(alarm.layout_version, 0);
+        assert_eq!(alarm.max_snoozes, 0);
+        backfill_legacy_alarm(&mut alarm);
+        assert_eq!(alarm.payer, owner);
+
+        // Snooze, as `snooze` checks and charges it
+        let params = alarm.snooze_params();
+        assert_eq!(params, helpers::SnoozeParams::default());
+        assert!(!helpers::is_max_snooze_for(
+            alarm.snooze_count,
+            params.max_snoozes
+        ));
+        let model = SnoozeModel::try_from(alarm.snooze_model).unwrap();
+        let cost = helpers::snooze_cost_for_model(model, alarm.remaining_amount, 0, params.percent)
+            .unwrap();
+        assert!(cost > 0);
+        let (new_time, new_deadline) = helpers::snooze_time_extension(
+            alarm.alarm_time,
+            alarm.deadline,
+            params.extension_seconds,
+        )
+        .unwrap();
+        assert!(new_deadline > deadline);
+
+        // Claim inside the grace that follows the (snoozed) deadline
+        assert_eq!(
+            helpers::check_claim_window(
+                AlarmStatus::Acknowledged,
+                new_time,
+                new_deadline,
+                alarm.claim_grace_seconds,
+                new_deadline + CLAIM_GRACE_SECONDS,
+            ),
+            Ok(())
+        );
+
+        // Backfill keeps values a pre-versioning alarm already carries
+        let payer = Pubkey::new_unique();
+        let mut alarm = Alarm {
+            owner,
+            payer,
+            max_snoozes: 2,
+            snooze_extension_seconds: 600,
+            ..Alarm::default()
+        };
+        backfill_legacy_alarm(&mut alarm);
+        assert_eq!((alarm.payer, alarm.max_snoozes), (payer, 2));
+    }
+
+    #[test]
+    fn test_validate_claim_to() {
+        let owner = [1u8; 32];
+        assert!(helpers::validate_claim_to(&owner, &[2u8; 32]));
+        assert!(!helpers::validate_claim_to(&owner, &owner));
+        assert!(!helpers::validate_claim_to(&owner, &[0u8; 32]));
+    }
+
+    #[test]
+    fn test_penalty_destination_mutable_by_route() {
+        let check = helpers::check_penalty_destination_mutable;
+        assert_eq!(check(PenaltyRoute::Donate as u8, 0), Ok(()));
+        assert_eq!(check(PenaltyRoute::Split as u8, 0), Ok(()));
+        assert_eq!(check(PenaltyRoute::Buddy as u8, 0), Ok(()));
+        assert_eq!(check(PenaltyRoute::Buddy as u8, 1), Err("buddy_staked"));
+        assert_eq!(check(PenaltyRoute::Burn as u8, 0), Err("fixed_route"));
+        assert_eq!(check(PenaltyRoute::Savings as u8, 0), Err("fixed_route"));
+        assert_eq!(check(200, 0), Err("fixed_route"));
+    }
+
+    #[test]
+    fn test_forgiveness_earned_every_seventh_streak_day() {
+        assert!(helpers::forgiveness_earned(6, 7));
+        assert!(helpers::forgiveness_earned(13, 14));
+        assert!(!helpers::forgiveness_earned(5, 6));
+        assert!(!helpers::forgiveness_earned(7, 8));
+        // Same-day claims keep the streak and earn nothing twice
+        assert!(!helpers::forgiveness_earned(7, 7));
+        // A restarted streak never lands on a multiple
+        assert!(!helpers::forgiveness_earned(20, 1));
+    }
+
+    #[test]
+    fn test_forgiveness_window() {
+        let deadline = 10_000;
+        assert!(!helpers::is_forgiveness_window(deadline, deadline - 1));
+        assert!(helpers::is_forgiveness_window(deadline, deadline));
+        assert!(helpers::is_forgiveness_window(
+            deadline,
+            deadline + FORGIVENESS_WINDOW_SECONDS - 1
+        ));
+        assert!(!helpers::is_forgiveness_window(
+            deadline,
+            deadline + FORGIVENESS_WINDOW_SECONDS
+        ));
+        assert!(!helpers::is_forgiveness_window(i64::MAX, i64::MAX));
+    }
+
+    #[test]
+    fn test_validate_buddy_link() {
+        let owner = [1u8; 32];
+        assert!(helpers::validate_buddy_link(&owner, &[2u8; 32]));
+        assert!(!helpers::validate_buddy_link(&owner, &owner));
+        assert!(!helpers::validate_buddy_link(&owner, &[0u8; 32]));
+    }
+
+    #[test]
+    fn test_buddy_link_memcmp_offsets() {
+        use anchor_lang::AnchorSerialize;
+        let link = BuddyLink {
+            owner: Pubkey::new_unique(),
+            buddy: Pubkey::new_unique(),
+            active: true,
+            created_at: 0,
+            bump: 255,
+        };
+        // Account data is the 8-byte discriminator followed by these bytes
+        let bytes = link.try_to_vec().unwrap();
+        assert_eq!(&bytes[0..32], link.owner.as_ref());
+        assert_eq!(&bytes[32..64], link.buddy.as_ref());
+        assert_eq!(bytes.len() + 8, BuddyLink::SIZE);
+    }
+
+    #[test]
+    fn test_validate_insurance_bps() {
+        assert!(helpers::validate_insurance_bps(0));
+        assert!(helpers::validate_insurance_bps(
+            crate::constants::MAX_INSURANCE_BPS
+        ));
+        assert!(!helpers::validate_insurance_bps(
+            crate::constants::MAX_INSURANCE_BPS + 1
+        ));
+    }
+
+    #[test]
+    fn test_unclaimed_penalty_bps() {
+        use crate::constants::MAX_UNCLAIMED_PENALTY_BPS;
+        assert!(helpers::validate_unclaimed_penalty_bps(0));
+        assert!(helpers::validate_unclaimed_penalty_bps(
+            MAX_UNCLAIMED_PENALTY_BPS
+        ));
+        assert!(!helpers::validate_unclaimed_penalty_bps(
+            MAX_UNCLAIMED_PENALTY_BPS + 1
+        ));
+        // The owner of a real ack always keeps at least half the deposit
+        let penalty = helpers::bps_of(1_000_000, MAX_UNCLAIMED_PENALTY_BPS).unwrap();
+        assert!(1_000_000 - penalty >= penalty);
+    }
+
+    #[test]
+    fn test_insurance_cooldown() {
+        let cooldown = crate::constants::INSURANCE_CLAIM_COOLDOWN_SECONDS;
+        // First claim is never on cooldown
+        assert!(helpers::is_insurance_cooldown_over(0, 1));
+        assert!(!helpers::is_insurance_cooldown_over(
+            1_000,
+            1_000 + cooldown - 1
+        ));
+        assert!(helpers::is_insurance_cooldown_over(1_000, 1_000 + cooldown));
+    }
+
+    #[test]
+    fn test_settlement_trigger_time() {
+        let deadline = 1_000_000;
+        // Sweep opens strictly after the claim grace
+        assert_eq!(
+            helpers::settlement_trigger_time(deadline, CLAIM_GRACE_SECONDS, false),
+            Some(deadline + CLAIM_GRACE_SECONDS + 1)
+        );
+        // A longer buddy-only window pushes the trigger back
+        assert_eq!(
+            helpers::settlement_trigger_time(deadline, 0, true),
+            Some(deadline + BUDDY_ONLY_SECONDS)
+        );
+        assert_eq!(
+            helpers::settlement_trigger_time(deadline, 0, false),
+            Some(deadline + 1)
+        );
+        assert_eq!(helpers::settlement_trigger_time(i64::MAX, 1, false), None);
+
+        // At the trigger both resolutions are open for everyone
+        let trigger =
+            helpers::settlement_trigger_time(deadline, CLAIM_GRACE_SECONDS, true).unwrap();
+        for status in [AlarmStatus::Created, AlarmStatus::Acknowledged] {
+            assert!(matches!(
+                helpers::post_deadline_resolution(
+                    status,
+                    true,
+                    false,
+                    deadline,
+                    CLAIM_GRACE_SECONDS,
+                    trigger
+                ),
+                Ok(helpers::Resolution::Slash | helpers::Resolution::Sweep)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_treasury_authority() {
+        let admin = [1u8; 32];
+        let (a, b, c) = ([2u8; 32], [3u8; 32], [4u8; 32]);
+        let unset = [[0u8; 32]; 3];
+
+        // No multisig: the admin alone
+        assert_eq!(
+            helpers::check_treasury_authority(&admin, &unset, &[admin]),
+            Ok(())
+        );
+        assert_eq!(
+            helpers::check_treasury_authority(&admin, &unset, &[a, b]),
+            Err("not_admin")
+        );
+
+        // Multisig: 2 of 3, and the admin no longer counts
+        let signers = [a, b, c];
+        assert_eq!(
+            helpers::check_treasury_authority(&admin, &signers, &[a, c]),
+            Ok(())
+        );
+        assert_eq!(
+            helpers::check_treasury_authority(&admin, &signers, &[admin, b]),
+            Err("approvals_missing")
+        );
+        // The same key signing twice is one approval
+        assert_eq!(
+            helpers::check_treasury_authority(&admin, &signers, &[b, b]),
+            Err("approvals_missing")
+        );
+
+        assert!(helpers::validate_treasury_signers(&unset));
+        assert!(helpers::validate_treasury_signers(&signers));
+        assert!(!helpers::validate_treasury_signers(&[a, a, c]));
+        assert!(!helpers::validate_treasury_signers(&[a, b, [0u8; 32]]));
+
+        assert_eq!(helpers::rent_topup(1_000, 1_500), 500);
+        assert_eq!(helpers::rent_topup(2_000, 1_500), 0);
+    }
+
+    #[test]
+    fn test_effective_burn_sink() {
+        use crate::constants::BURN_SINK;
+        let mut config = preflight_config();
+        assert_eq!(config.effective_burn_sink(), BURN_SINK);
+
+        // Regulated deployments point burns at their own sink
+        let sink = Pubkey::new_unique();
+        config.burn_sink = sink;
+        assert_eq!(config.effective_burn_sink(), sink);
+        assert_eq!(
+            helpers::validate_penalty_recipient(
+                PenaltyRoute::Burn as u8,
+                &sink.to_bytes(),
+                &config.effective_burn_sink().to_bytes(),
+                None
+            ),
+            Ok(())
+        );
+        assert!(helpers::validate_penalty_recipient(
+            PenaltyRoute::Burn as u8,
+            &BURN_SINK.to_bytes(),
+            &config.effective_burn_sink().to_bytes(),
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_stats_day_boundaries() {
+        assert_eq!(helpers::stats_day_of(0), Some(0));
+        assert_eq!(helpers::stats_day_of(86_399), Some(0));
+        assert_eq!(helpers::stats_day_of(86_400), Some(1));
+        // Pre-epoch clocks have no stats day
+        assert_eq!(helpers::stats_day_of(-1), None);
+
+        let now = 86_400 * 20_000 + 3_600;
+        assert!(helpers::is_stats_day_open(20_000, now));
+        assert!(helpers::is_stats_day_open(20_001, now));
+        assert!(!helpers::is_stats_day_open(19_999, now));
+        assert!(!helpers::is_stats_day_open(0, -1));
+    }
+
+    #[test]
+    fn test_wake_window_bounds() {
+        use crate::constants::{DEFAULT_MAX_WAKE_WINDOW_SECONDS, DEFAULT_MIN_WAKE_WINDOW_SECONDS};
+        let defaults = (
+            DEFAULT_MIN_WAKE_WINDOW_SECONDS,
+            DEFAULT_MAX_WAKE_WINDOW_SECONDS,
+        );
+        assert_eq!(helpers::wake_window_bounds(0, 0), defaults);
+        assert_eq!(helpers::wake_window_bounds(300, 0).0, 300);
+        assert_eq!(helpers::wake_window_bounds(0, 7_200).1, 7_200);
+
+        assert!(helpers::validate_wake_window_bounds(0, 0));
+        assert!(helpers::validate_wake_window_bounds(600, 600));
+        assert!(!helpers::validate_wake_window_bounds(-1, 0));
+        assert!(!helpers::validate_wake_window_bounds(0, -1));
+        // Effective min above effective max
+        assert!(!helpers::validate_wake_window_bounds(
+            DEFAULT_MAX_WAKE_WINDOW_SECONDS + 1,
+            0
+        ));
+        assert!(!helpers::validate_wake_window_bounds(0, 30));
+
+        let alarm_time = 1_000_000;
+        // A 1-second window can never be won
+        assert_eq!(
+            helpers::validate_wake_window(alarm_time, alarm_time + 1, defaults),
+            Err("wake_window_out_of_bounds")
+        );
+        assert!(helpers::validate_wake_window(
+            alarm_time,
+            alarm_time + DEFAULT_MIN_WAKE_WINDOW_SECONDS,
+            defaults
+        )
+        .is_ok());
+        assert!(helpers::validate_wake_window(
+            alarm_time,
+            alarm_time + DEFAULT_GRACE_PERIOD,
+            defaults
+        )
+        .is_ok());
+        assert!(helpers::validate_wake_window(
+            alarm_time,
+            alarm_time + DEFAULT_MAX_WAKE_WINDOW_SECONDS,
+            defaults
+        )
+        .is_ok());
+        assert_eq!(
+            helpers::validate_wake_window(
+                alarm_time,
+                alarm_time + DEFAULT_MAX_WAKE_WINDOW_SECONDS + 1,
+                defaults
+            ),
+            Err("wake_window_out_of_bounds")
+        );
+        assert!(helpers::validate_wake_window(i64::MIN, i64::MAX, defaults).is_err());
+    }
+
+    #[test]
+    fn test_template_alarm_time() {
+        assert_eq!(helpers::template_alarm_time(1_000, 0), Some(1_000));
+        assert_eq!(helpers::template_alarm_time(1_000, 1), Some(87_400));
+        assert_eq!(
+            helpers::template_alarm_time(0, u16::MAX),
+            Some(i64::from(u16::MAX) * 86_400)
+        );
+        assert_eq!(helpers::template_alarm_time(i64::MAX, 1), None);
+    }
+
+    #[test]
+    fn test_template_keeps_only_grace_and_snooze_options() {
+        let options = AlarmOptions {
+            claim_grace_seconds: Some(600),
+            snooze_percent: Some(20),
+            max_snoozes: Some(3),
+            free_snoozes: Some(1),
+            ..AlarmOptions::default()
+        };
+        assert!(AlarmTemplate::is_templatable(&options));
+        assert!(AlarmTemplate::is_templatable(&AlarmOptions::default()));
+        assert!(!AlarmTemplate::is_templatable(&AlarmOptions {
+            soft_deadline: Some(5_000),
+            ..options.clone()
+        }));
+        assert!(!AlarmTemplate::is_templatable(&AlarmOptions {
+            memo: Some([1; 32]),
+            ..AlarmOptions::default()
+        }));
+
+        let template = AlarmTemplate {
+            owner: Pubkey::new_unique(),
+            template_id: 1,
+            alarm_time: 1_000,
+            window_seconds: 1_800,
+            deposit_amount: 0,
+            penalty_route: PenaltyRoute::Burn as u8,
+            penalty_destination: None,
+            claim_grace_seconds: options.claim_grace_seconds,
+            snooze_percent: options.snooze_percent,
+            snooze_extension_seconds: options.snooze_extension_seconds,
+            max_snoozes: options.max_snoozes,
+            free_snoozes: options.free_snoozes,
+            snooze_model: options.snooze_model,
+            bump: 255,
+        };
+        assert_eq!(template.options(), options);
+    }
+
+    #[test]
+    fn test_validate_weekly_schedule() {
+        assert!(helpers::validate_weekly_schedule(0b001_1111, 7 * 60, 120).is_ok());
+        assert!(helpers::validate_weekly_schedule(0b111_1111, 0, -720).is_ok());
+        assert!(helpers::validate_weekly_schedule(1, 1_439, 840).is_ok());
+        // Nepal: UTC+05:45
+        assert!(helpers::validate_weekly_schedule(1, 360, 345).is_ok());
+
+        assert_eq!(
+            helpers::validate_weekly_schedule(0, 420, 0),
+            Err("invalid_days_of_week")
+        );
+        assert_eq!(
+            helpers::validate_weekly_schedule(0b1000_0000, 420, 0),
+            Err("invalid_days_of_week")
+        );
+        assert_eq!(
+            helpers::validate_weekly_schedule(1, 1_440, 0),
+            Err("invalid_wake_minute")
+        );
+        assert_eq!(
+            helpers::validate_weekly_schedule(1, 420, 850),
+            Err("invalid_utc_offset")
+        );
+        assert_eq!(
+            helpers::validate_weekly_schedule(1, 420, -735),
+            Err("invalid_utc_offset")
+        );
+        assert_eq!(
+            helpers::validate_weekly_schedule(1, 420, 10),
+            Err("invalid_utc_offset")
+        );
+    }
+
+    #[test]
+    fn test_next_weekly_occurrence() {
+        const DAY: i64 = 86_400;
+        const MONDAY: u8 = 1;
+        const WEEKDAYS: u8 = 0b001_1111;
+        // 2024-01-01 00:00 UTC was a Monday
+        let monday = 1_704_067_200;
+        assert_eq!((monday / DAY + 3) % 7, 0);
+
+        // 07:00 local in UTC+2 is 05:00 UTC the same day
+        assert_eq!(
+            helpers::next_weekly_occurrence(MONDAY, 420, 120, monday),
+            Some(monday + 5 * 3_600)
+        );
+        // Strictly after: once the slot passes, the next Monday
+        assert_eq!(
+            helpers::next_weekly_occurrence(MONDAY, 420, 120, monday + 5 * 3_600),
+            Some(monday + 7 * DAY + 5 * 3_600)
+        );
+        // Friday 23:00 UTC is already Saturday in UTC+2: weekdays roll to Monday
+        let friday_late = monday + 4 * DAY + 23 * 3_600;
+        assert_eq!(
+            helpers::next_weekly_occurrence(WEEKDAYS, 420, 120, friday_late),
+            Some(monday + 7 * DAY + 5 * 3_600)
+        );
+        // Negative offset: Sunday 22:00 local (UTC-5) is Monday 03:00 UTC
+        assert_eq!(
+            helpers::next_weekly_occurrence(1 << 6, 22 * 60, -300, monday - DAY),
+            Some(monday + 3 * 3_600)
+        );
+        // Every occurrence falls on a scheduled local weekday at wake time
+        let mut t = monday;
+        for _ in 0..20 {
+            t = helpers::next_weekly_occurrence(0b101_0010, 390, 345, t).unwrap();
+            let local = t + 345 * 60;
+            assert_eq!(local.rem_euclid(DAY), 390 * 60);
+            let weekday = (local.div_euclid(DAY) + 3).rem_euclid(7);
+            assert_ne!(0b101_0010 & (1 << weekday), 0);
+        }
+
+        assert_eq!(helpers::next_weekly_occurrence(0, 420, 0, monday), None);
+        assert_eq!(
+            helpers::next_weekly_occurrence(MONDAY, 420, 0, i64::MAX),
+            None
+        );
+    }
+
+    #[test]
+    fn test_settlement_memo_payload() {
+        assert_eq!(
+            helpers::settlement_memo(
+                "slash",
+                7,
+                100_000_000,
+                helpers::penalty_route_name(PenaltyRoute::Buddy as u8)
+            ),
+            r#"{"solarma":"slash","alarm_id":7,"amount":100000000,"route":"buddy"}"#
+        );
+        assert_eq!(
+            helpers::settlement_memo("sweep", 0, 0, "owner"),
+            r#"{"solarma":"sweep","alarm_id":0,"amount":0,"route":"owner"}"#
+        );
+        for route in 0..=4u8 {
+            assert_ne!(helpers::penalty_route_name(route), "unknown");
+        }
+        assert_eq!(helpers::penalty_route_name(5), "unknown");
+        // Fits comfortably in a transaction alongside the slash itself
+        assert!(helpers::settlement_memo("slash", u64::MAX, u64::MAX, "savings").len() < 128);
+    }
+
+    #[test]
+    fn test_deposit_limits() {
+        const DAY: i64 = 86_400;
+        let now = 10 * DAY + 100;
+        // Unlimited
+        assert_eq!(
+            helpers::apply_deposit_limits(u64::MAX, 0, 0, 0, 0, now),
+            Ok((10, u64::MAX))
+        );
+        // Per-alarm maximum
+        assert_eq!(
+            helpers::apply_deposit_limits(1_001, 1_000, 0, 0, 0, now),
+            Err("deposit_too_large")
+        );
+        // Same day accumulates against the cap
+        assert_eq!(
+            helpers::apply_deposit_limits(400, 0, 1_000, 10, 600, now),
+            Ok((10, 1_000))
+        );
+        assert_eq!(
+            helpers::apply_deposit_limits(401, 0, 1_000, 10, 600, now),
+            Err("daily_cap_exceeded")
+        );
+        // A new UTC day resets the total
+        assert_eq!(
+            helpers::apply_deposit_limits(1_000, 0, 1_000, 9, 1_000, now),
+            Ok((10, 1_000))
+        );
+        assert_eq!(
+            helpers::apply_deposit_limits(1, 0, 0, 10, u64::MAX, now),
+            Err("overflow")
+        );
+    }
+
+    #[test]
+    fn test_duel_accept_and_settle_windows() {
+        // Accept closes at the earlier wake time
+        assert!(helpers::can_accept_duel(false, 1_000, 2_000, 999));
+        assert!(!helpers::can_accept_duel(false, 1_000, 2_000, 1_000));
+        assert!(!helpers::can_accept_duel(true, 1_000, 2_000, 0));
+
+        // Unaccepted: refundable once the accept window closed
+        assert!(!helpers::can_settle_duel(
+            false, false, false, 1_000, 3_000, 999
+        ));
+        assert!(helpers::can_settle_duel(
+            false, false, false, 1_000, 3_000, 1_000
+        ));
+        // Accepted: early once both acked, otherwise after the last deadline
+        assert!(helpers::can_settle_duel(
+            true, true, true, 1_000, 3_000, 1_500
+        ));
+        assert!(!helpers::can_settle_duel(
+            true, true, false, 1_000, 3_000, 2_999
+        ));
+        assert!(helpers::can_settle_duel(
+            true, true, false, 1_000, 3_000, 3_000
+        ));
+    }
+
+    #[test]
+    fn test_duel_payouts() {
+        let stake = 100_000_000;
+        assert_eq!(
+            helpers::duel_payouts(stake, true, true, false),
+            Some((2 * stake, 0))
+        );
+        assert_eq!(
+            helpers::duel_payouts(stake, true, false, true),
+            Some((0, 2 * stake))
+        );
+        assert_eq!(
+            helpers::duel_payouts(stake, true, true, true),
+            Some((stake, stake))
+        );
+        assert_eq!(
+            helpers::duel_payouts(stake, true, false, false),
+            Some((stake, stake))
+        );
+        assert_eq!(
+            helpers::duel_payouts(stake, false, false, false),
+            Some((stake, 0))
+        );
+        assert_eq!(helpers::duel_payouts(u64::MAX, true, true, false), None);
+    }
+
+    #[test]
+    fn test_validate_buddy_reward() {
+        use crate::constants::MAX_BUDDY_REWARD_BPS;
+        let buddy = PenaltyRoute::Buddy as u8;
+        assert_eq!(
+            helpers::validate_buddy_reward(0, PenaltyRoute::Burn as u8),
+            Ok(())
+        );
+        assert_eq!(
+            helpers::validate_buddy_reward(MAX_BUDDY_REWARD_BPS, buddy),
+            Ok(())
+        );
+        assert_eq!(
+            helpers::validate_buddy_reward(MAX_BUDDY_REWARD_BPS + 1, buddy),
+            Err("buddy_reward_too_high")
+        );
+        assert_eq!(
+            helpers::validate_buddy_reward(100, PenaltyRoute::Donate as u8),
+            Err("buddy_route_required")
+        );
+        // 5% of 1 SOL goes to the buddy
+        assert_eq!(helpers::bps_of(1_000_000_000, 500), Some(50_000_000));
+    }
+
+    #[test]
+    fn test_delegate_authorization() {
+        let owner = [1u8; 32];
+        let delegate = [2u8; 32];
+        let now = 1_000;
+
+        assert!(helpers::is_delegate_active(
+            &delegate,
+            &delegate,
+            now + 1,
+            now
+        ));
+        // Expired at exactly expires_at
+        assert!(!helpers::is_delegate_active(&delegate, &delegate, now, now));
+        assert!(!helpers::is_delegate_active(
+            &owner,
+            &delegate,
+            now + 1,
+            now
+        ));
+        // Cleared slot never matches, even for the zero key
+        assert!(!helpers::is_delegate_active(
+            &[0u8; 32],
+            &[0u8; 32],
+            i64::MAX,
+            now
+        ));
+    }
+
+    #[test]
+    fn test_validate_delegate() {
+        use crate::constants::MAX_DELEGATE_TTL_SECONDS;
+        let owner = [1u8; 32];
+        let delegate = [2u8; 32];
+        let now = 1_000;
+
+        assert_eq!(
+            helpers::validate_delegate(&owner, &delegate, now + MAX_DELEGATE_TTL_SECONDS, now),
+            Ok(())
+        );
+        assert_eq!(
+            helpers::validate_delegate(&owner, &delegate, now + MAX_DELEGATE_TTL_SECONDS + 1, now),
+            Err("invalid_expiry")
+        );
+        assert_eq!(
+            helpers::validate_delegate(&owner, &delegate, now, now),
+            Err("invalid_expiry")
+        );
+        assert_eq!(
+            helpers::validate_delegate(&owner, &owner, now + 60, now),
+            Err("delegate_is_owner")
+        );
+        // Clearing ignores the expiry
+        assert_eq!(
+            helpers::validate_delegate(&owner, &[0u8; 32], 0, now),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_leaderboard_epoch_windows() {
+        use crate::constants::LEADERBOARD_EPOCH_SECONDS as WEEK;
+        assert_eq!(helpers::epoch_of(0), Some(0));
+        assert_eq!(helpers::epoch_of(WEEK - 1), Some(0));
+        assert_eq!(helpers::epoch_of(WEEK), Some(1));
+        assert_eq!(helpers::epoch_of(-1), None);
+
+        // Current and future epochs accept entries; past or finalized do not
+        assert!(helpers::is_epoch_open(1, false, WEEK));
+        assert!(helpers::is_epoch_open(2, false, WEEK));
+        assert!(!helpers::is_epoch_open(0, false, WEEK));
+        assert!(!helpers::is_epoch_open(1, true, WEEK));
+
+        // Finalization only after the last second of the epoch
+        assert!(!helpers::can_finalize_epoch(1, false, 2 * WEEK - 1));
+        assert!(helpers::can_finalize_epoch(1, false, 2 * WEEK));
+        assert!(!helpers::can_finalize_epoch(1, true, 2 * WEEK));
+    }
+}
+
+#[cfg(test)]
+mod fuzz_tests {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug)]
+    enum TimeKind {
+        BeforeAlarm,
+        AtAlarm,
+        Between,
+        AtDeadline,
+        AfterDeadline,
+    }
+
+    #[derive(Clone, Debug)]
+    enum Op {
+        Ack,
+        Snooze { expected_snooze_count: u8 },
+        Claim,
+        Slash,
+        Sweep,
+        Refund,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct ModelAlarm {
+        status: AlarmStatus,
+        alarm_time: i64,
+        deadline: i64,
+        initial_amount: u64,
+        remaining_amount: u64,
+        snooze_count: u8,
+        rent_minimum: u64,
+        vault_lamports: u64,
+        vault_closed: bool,
+    }
+
+    impl ModelAlarm {
+        fn new(alarm_time: i64, deadline: i64, deposit: u64, rent_minimum: u64) -> Self {
+            Self {
+                status: AlarmStatus::Created,
+                alarm_time,
+                deadline,
+                initial_amount: deposit,
+                remaining_amount: deposit,
+                snooze_count: 0,
+                rent_minimum,
+                vault_lamports: rent_minimum.saturating_add(deposit),
+                vault_closed: false,
+            }
+        }
+
+        fn is_terminal(&self) -> bool {
+            matches!(self.status, AlarmStatus::Claimed | AlarmStatus::Slashed)
+        }
+
+        fn assert_invariants(&self) {
+            assert!(
+                self.deadline > self.alarm_time,
+                "deadline must be > alarm_time"
+            );
+
+            assert!(
+                self.remaining_amount <= self.initial_amount,
+                "remaining cannot exceed initial"
+            );
+
+            assert!(
+                self.snooze_count <= MAX_SNOOZE_COUNT,
+                "snooze_count must be <= MAX_SNOOZE_COUNT"
+            );
+
+            if !self.vault_closed {
+                assert!(
+                    self.vault_lamports >= self.rent_minimum,
+                    "vault must stay rent-exempt while open"
+                );
+                let available = self.vault_lamports.saturating_sub(self.rent_minimum);
+                assert!(
+                    available >= self.remaining_amount,
+                    "vault available must cover remaining"
+                );
+            }
+
+            if self.is_terminal() {
+                assert!(self.vault_closed, "terminal implies vault closed");
+                assert_eq!(self.remaining_amount, 0, "terminal implies remaining == 0");
+                assert_eq!(
+                    self.vault_lamports, 0,
+                    "closed vault implies 0 lamports in model"
+                );
+            }
+
+            if self.vault_closed {
+                assert!(self.is_terminal(), "vault_closed implies terminal status");
+            }
+        }
+
+        fn apply(&mut self, op: Op, now: i64) -> Result<(), ()> {
+            if self.is_terminal() || self.vault_closed {
+                return Err(());
+            }
+
+            match op {
+                Op::Ack => {
+                    if self.status != AlarmStatus::Created {
+                        return Err(());
+                    }
+                    if !(now >= self.alarm_time && now < self.deadline) {
+                        return Err(());
+                    }
+                    self.status = AlarmStatus::Acknowledged;
+                    Ok(())
+                }
+                Op::Snooze {
+                    expected_snooze_count,
+                } => {
+                    if self.status != AlarmStatus::Created {
+                        return Err(());
+                    }
+                    if !(now >= self.alarm_time && now < self.deadline) {
+                        return Err(());
+                    }
+                    if self.snooze_count >= MAX_SNOOZE_COUNT {
+                        return Err(());
+                    }
+                    if expected_snooze_count != self.snooze_count {
+                        return Err(());
+                    }
+
+                    let cost =
+                        helpers::snooze_cost(self.remaining_amount, self.snooze_count).ok_or(())?;
+                    if cost == 0 {
+                        return Err(());
+                    }
+
+                    let available = self.vault_lamports.saturating_sub(self.rent_minimum);
+                    let final_cost = cost.min(available);
+                    if final_cost == 0 {
+                        return Err(());
+                    }
+
+                    self.vault_lamports = self.vault_lamports.checked_sub(final_cost).ok_or(())?;
+                    self.remaining_amount =
+                        self.remaining_amount.checked_sub(final_cost).ok_or(())?;
+
+                    self.snooze_count = self.snooze_count.checked_add(1).ok_or(())?;
+                    self.alarm_time = self
+                        .alarm_time
+                        .checked_add(DEFAULT_SNOOZE_EXTENSION_SECONDS)
+                        .ok_or(())?;
+                    self.deadline = self
+                        .deadline
+                        .checked_add(DEFAULT_SNOOZE_EXTENSION_SECONDS)
+                        .ok_or(())?;
+
+                    Ok(())
+                }
+                Op::Claim => {
+                    if self.status != AlarmStatus::Acknowledged {
+                        return Err(());
+                    }
+                    if !helpers::is_claim_window_with_grace(self.alarm_time, self.deadline, now) {
+                        return Err(());
+                    }
+                    self.status = AlarmStatus::Claimed;
+                    self.remaining_amount = 0;
+                    self.vault_closed = true;
+                    self.vault_lamports = 0;
+                    Ok(())
+                }
+                Op::Slash => {
+                    if self.status != AlarmStatus::Created {
+                        return Err(());
+                    }
+                    if now < self.deadline {
+                        return Err(());
+                    }
+                    self.status = AlarmStatus::Slashed;
+                    self.remaining_amount = 0;
+                    self.vault_closed = true;
+                    self.vault_lamports = 0;
+                    Ok(())
+                }
+                Op::Sweep => {
+                    if self.status != AlarmStatus::Acknowledged {
+                        return Err(());
+                    }
+                    if !helpers::is_sweep_window(self.deadline, now) {
+                        return Err(());
+                    }
+                    self.status = AlarmStatus::Claimed;
+                    self.remaining_amount = 0;
+                    self.vault_closed = true;
+                    self.vault_lamports = 0;
+                    Ok(())
+                }
+                Op::Refund => {
+                    if self.status != AlarmStatus::Created {
+                        return Err(());
+                    }
+                    if now >= self.alarm_time {
+                        return Err(());
+                    }
+
+                    let penalty = helpers::emergency_penalty(self.remaining_amount).ok_or(())?;
+                    let final_penalty = helpers::cap_at_rent_exempt(
+                        penalty,
+                        self.vault_lamports,
+                        self.rent_minimum,
+                    );
+                    self.vault_lamports =
+                        self.vault_lamports.checked_sub(final_penalty).ok_or(())?;
+
+                    self.status = AlarmStatus::Claimed;
+                    self.remaining_amount = 0;
+                    self.vault_closed = true;
+                    self.vault_lamports = 0;
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct XorShift64 {
+        state: u64,
+    }
+
+    impl XorShift64 {
+        fn new(seed: u64) -> Self {
+            // Avoid the all-zero state.
+            Self {
+                state: if seed == 0 {
+                    0xdead_beef_cafe_f00d
+                } else {
+                    seed
+                },
+            }
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            let mut x = self.state;
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            self.state = x;
+            x
+        }
+
+        fn next_u8(&mut self) -> u8 {
+            (self.next_u64() & 0xff) as u8
+        }
+
+        fn gen_range_u64(&mut self, start: u64, end_inclusive: u64) -> u64 {
+            if start >= end_inclusive {
+                return start;
+            }
+            let span = end_inclusive - start + 1;
+            start + (self.next_u64() % span)
+        }
+
+        fn gen_range_i64(&mut self, start: i64, end_inclusive: i64) -> i64 {
+            if start >= end_inclusive {
+                return start;
+            }
+            let span = (end_inclusive - start + 1) as u64;
+            start + (self.next_u64() % span) as i64
+        }
+
+        fn pick_time_kind(&mut self) -> TimeKind {
+            match self.next_u64() % 5 {
+                0 => TimeKind::BeforeAlarm,
+                1 => TimeKind::AtAlarm,
+                2 => TimeKind::Between,
+                3 => TimeKind::AtDeadline,
+                _ => TimeKind::AfterDeadline,
+            }
+        }
+
+        fn pick_op(&mut self) -> Op {
+            match self.next_u64() % 6 {
+                0 => Op::Ack,
+                1 => Op::Snooze {
+                    expected_snooze_count: self.next_u8(),
+                },
+                2 => Op::Claim,
+                3 => Op::Slash,
+                4 => Op::Sweep,
+                _ => Op::Refund,
+            }
+        }
+    }
+
+    fn pick_now(kind: TimeKind, alarm_time: i64, deadline: i64) -> i64 {
+        match kind {
+            TimeKind::BeforeAlarm => alarm_time.saturating_sub(1),
+            TimeKind::AtAlarm => alarm_time,
+            TimeKind::Between => {
+                let candidate = alarm_time.saturating_add(1);
+                if candidate < deadline {
+                    candidate
+                } else {
+                    alarm_time
+                }
+            }
+            TimeKind::AtDeadline => deadline,
+            TimeKind::AfterDeadline => deadline.saturating_add(1),
+        }
+    }
+
+    #[test]
+    fn fuzz_windows_partition_time_axis() {
+        let mut rng = XorShift64::new(1);
+        for _ in 0..25_000 {
+            let alarm_time = rng.gen_range_i64(10_000, 1_000_000_000);
+            let gap = rng.gen_range_i64(1, 100_000);
+            let deadline = alarm_time.saturating_add(gap);
+            if deadline <= alarm_time {
+                continue;
+            }
+
+            let now = rng.gen_range_i64(0, 1_100_000_000);
+
+            let refund = helpers::is_refund_window(alarm_time, now);
+            let claim = helpers::is_claim_window(alarm_time, deadline, now);
+            let slash = helpers::is_slash_window(deadline, now);
+
+            let sum = (refund as u8) + (claim as u8) + (slash as u8);
+            assert_eq!(sum, 1, "windows must partition time axis");
+            assert_eq!(helpers::is_snooze_window(alarm_time, deadline, now), claim);
+        }
+    }
+
+    #[test]
+    fn fuzz_cap_at_rent_exempt_is_safe() {
+        let mut rng = XorShift64::new(2);
+        for _ in 0..100_000 {
+            let desired = rng.next_u64();
+            let current = rng.next_u64();
+            let min_balance = rng.next_u64();
+
+            let capped = helpers::cap_at_rent_exempt(desired, current, min_balance);
+            assert!(capped <= desired);
+
+            let available = current.saturating_sub(min_balance);
+            assert!(capped <= available);
+
+            if current >= min_balance {
+                assert!(current - capped >= min_balance);
+            }
+        }
+    }
+
+    #[test]
+    fn fuzz_state_machine_preserves_invariants() {
+        for seed in 1u64..=5_000 {
+            let mut rng = XorShift64::new(seed);
+
+            let deposit = if rng.next_u64().is_multiple_of(4) {
+                0u64
+            } else {
+                rng.gen_range_u64(MIN_DEPOSIT_LAMPORTS, 10_000_000_000)
+            };
+            let rent_minimum = rng.gen_range_u64(1, 5_000_000);
+
+            let alarm_time = rng.gen_range_i64(10_000, 1_000_000_000);
+            let gap = rng.gen_range_i64(2, 100_000);
+            let deadline = alarm_time.saturating_add(gap);
+            if deadline <= alarm_time {
+                continue;
+            }
+
+            let mut m = ModelAlarm::new(alarm_time, deadline, deposit, rent_minimum);
+            m.assert_invariants();
+
+            let steps = (rng.next_u64() % 41) as usize;
+            for _ in 0..steps {
+                let op = rng.pick_op();
+                let tk = rng.pick_time_kind();
+                let now = pick_now(tk, m.alarm_time, m.deadline);
+
+                let before = m.clone();
+                let res = m.apply(op, now);
+
+                if res.is_err() {
+                    assert_eq!(m, before, "invalid ops must not mutate state");
+                }
+
+                if matches!(before.status, AlarmStatus::Claimed | AlarmStatus::Slashed) {
+                    assert!(res.is_err());
+                    assert_eq!(m, before);
+                }
+
+                m.assert_invariants();
+            }
+        }
+    }
+
+    #[test]
+    fn model_ack_disables_slash_but_claim_within_grace_is_allowed() {
+        let alarm_time = 1_000i64;
+        let deadline = 2_000i64;
+        let mut m = ModelAlarm::new(alarm_time, deadline, 1_000_000_000, 1_000_000);
+
+        assert!(m.apply(Op::Ack, alarm_time).is_ok());
+        assert_eq!(m.status, AlarmStatus::Acknowledged);
+
+        // Slash must be rejected once acknowledged.
+        assert!(m.apply(Op::Slash, deadline).is_err());
+
+        // Claim is valid exactly at deadline + grace.
+        let at_grace_end = deadline + CLAIM_GRACE_SECONDS;
+        assert!(m.apply(Op::Claim, at_grace_end).is_ok());
+        assert_eq!(m.status, AlarmStatus::Claimed);
+    }
+
+    #[test]
+    fn model_sweep_after_grace_and_claim_after_grace_fails() {
+        let alarm_time = 1_000i64;
+        let deadline = 2_000i64;
+        let mut m = ModelAlarm::new(alarm_time, deadline, 1_000_000_000, 1_000_000);
+
+        assert!(m.apply(Op::Ack, alarm_time).is_ok());
+        assert_eq!(m.status, AlarmStatus::Acknowledged);
+
+        let after_grace = deadline + CLAIM_GRACE_SECONDS + 1;
+        assert!(m.apply(Op::Claim, after_grace).is_err());
+        assert!(m.apply(Op::Sweep, after_grace).is_ok());
+        assert_eq!(m.status, AlarmStatus::Claimed);
+    }
+}
+
+/// Integration test scenarios (require local validator)
+/// Run with: anchor test
+#[cfg(feature = "test-bpf")]
+mod integration_tests {
+    // These are implemented as Anchor TypeScript tests
+    // See tests/solarma_vault.ts (66 tests)
+    // Rust unit tests above: 96+ tests
+}
+
+// =========================================================================
+// Constants tests — verify values match documented behavior
+// =========================================================================
+#[cfg(test)]
+mod constants_tests {
+    use crate::constants::*;
+    use anchor_lang::prelude::Pubkey;
+
+    #[test]
+    fn test_burn_sink_is_solana_incinerator() {
+        // BURN_SINK must be the well-known Solana incinerator address
+        let expected_b58 = "1nc1nerator11111111111111111111111111111111";
+        let expected = Pubkey::try_from(expected_b58).unwrap();
+        assert_eq!(BURN_SINK, expected, "BURN_SINK must be Solana incinerator");
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn test_snooze_percent_within_valid_range() {
+        // Snooze cost % must be > 0 and <= 100
+        assert!(DEFAULT_SNOOZE_PERCENT > 0);
+        assert!(DEFAULT_SNOOZE_PERCENT <= 100);
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn test_max_snooze_count_reasonable() {
+        // Must be > 0 (at least 1 snooze allowed) and < 64 (shift safety)
+        assert!(MAX_SNOOZE_COUNT > 0);
+        assert!(
+            MAX_SNOOZE_COUNT < 64,
+            "Must stay below 64 for bit shift safety"
+        );
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn test_forgiveness_window_shorter_than_late_claims() {
+        // Forgiveness is a short grace, not a second late-claim path
+        assert!(FORGIVENESS_WINDOW_SECONDS > 0);
+        assert!(FORGIVENESS_WINDOW_SECONDS < LATE_CLAIM_EARLY_SECONDS);
+        assert!(FORGIVENESS_STREAK_DAYS > 0 && MAX_FORGIVENESS_TOKENS > 0);
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn test_min_deposit_is_positive() {
+        assert!(MIN_DEPOSIT_LAMPORTS > 0, "Min deposit must be positive");
+        // 0.001 SOL = 1_000_000 lamports
+        assert_eq!(MIN_DEPOSIT_LAMPORTS, 1_000_000);
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn test_emergency_penalty_within_valid_range() {
+        assert!(EMERGENCY_REFUND_PENALTY_PERCENT > 0);
+        assert!(EMERGENCY_REFUND_PENALTY_PERCENT <= 100);
+        assert_eq!(EMERGENCY_REFUND_PENALTY_PERCENT, 5);
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn test_grace_period_is_positive() {
+        assert!(DEFAULT_GRACE_PERIOD > 0);
+        assert_eq!(DEFAULT_GRACE_PERIOD, 1800); // 30 minutes
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn test_snooze_extension_is_positive() {
+        assert!(DEFAULT_SNOOZE_EXTENSION_SECONDS > 0);
+        assert_eq!(DEFAULT_SNOOZE_EXTENSION_SECONDS, 300); // 5 minutes
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn test_claim_grace_is_positive() {
+        assert!(CLAIM_GRACE_SECONDS > 0);
+        assert_eq!(CLAIM_GRACE_SECONDS, 120);
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn test_buddy_only_window_is_positive() {
+        assert!(BUDDY_ONLY_SECONDS > 0);
+        assert_eq!(BUDDY_ONLY_SECONDS, 120);
+    }
+
+    #[test]
+    fn test_cross_platform_android_parity() {
+        // Android AlarmTiming constants must match Rust:
+        // AlarmTiming.GRACE_PERIOD_SECONDS = 1800
+        // AlarmTiming.SNOOZE_MINUTES = 5 → 300 seconds
+        // OnchainParameters.SNOOZE_BASE_PERCENT = 10
+        // OnchainParameters.EMERGENCY_REFUND_PENALTY_PERCENT = 5
+        assert_eq!(
+            DEFAULT_GRACE_PERIOD, 1800,
+            "Must match Android AlarmTiming.GRACE_PERIOD_SECONDS"
+        );
+        assert_eq!(
+            DEFAULT_SNOOZE_EXTENSION_SECONDS, 300,
+            "Must match Android AlarmTiming.SNOOZE_MINUTES * 60"
+        );
+        assert_eq!(
+            DEFAULT_SNOOZE_PERCENT, 10,
+            "Must match Android OnchainParameters.SNOOZE_BASE_PERCENT"
+        );
+        assert_eq!(
+            EMERGENCY_REFUND_PENALTY_PERCENT, 5,
+            "Must match Android OnchainParameters.EMERGENCY_REFUND_PENALTY_PERCENT"
+        );
+    }
+
+    #[test]
+    fn test_protocol_params_mirror_constants() {
+        // Clients read these instead of the constants; nothing may truncate
+        let params = crate::state::ProtocolParams::current(254);
+        assert_eq!(params.min_deposit_lamports, MIN_DEPOSIT_LAMPORTS);
+        assert_eq!(
+            u64::from(params.default_snooze_percent),
+            DEFAULT_SNOOZE_PERCENT
+        );
+        assert_eq!(params.max_snooze_count, MAX_SNOOZE_COUNT);
+        assert_eq!(
+            params.default_snooze_extension_seconds,
+            DEFAULT_SNOOZE_EXTENSION_SECONDS
+        );
+        assert_eq!(params.grace_period_seconds, DEFAULT_GRACE_PERIOD);
+        assert_eq!(params.claim_grace_seconds, CLAIM_GRACE_SECONDS);
+        assert_eq!(params.buddy_only_seconds, BUDDY_ONLY_SECONDS);
+        assert_eq!(
+            u64::from(params.emergency_refund_penalty_percent),
+            EMERGENCY_REFUND_PENALTY_PERCENT
+        );
+        assert_eq!(params.bump, 254);
+    }
+
+    #[test]
+    fn test_snooze_percent_times_max_exceeds_100() {
+        // After MAX_SNOOZE_COUNT iterations, cumulative penalty should exceed 100%
+        // cumulative = 10% * (2^MAX - 1)
+        // At max=10: 10 * (1024 - 1) = 10230% >> 100%
+        let cumulative = DEFAULT_SNOOZE_PERCENT * ((1u64 << MAX_SNOOZE_COUNT) - 1);
+        assert!(cumulative > 100, "Max snoozes must drain the full deposit");
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn test_grace_period_longer_than_snooze_extension() {
+        // Grace period must be >= snooze extension to guarantee at least 1 snooze window
+        assert!(
+            DEFAULT_GRACE_PERIOD >= DEFAULT_SNOOZE_EXTENSION_SECONDS,
+            "Grace period must accommodate at least one snooze"
+        );
+    }
+}
+
+// =========================================================================
+// Error enum tests — verify all error variants are distinct
+// =========================================================================
+#[cfg(test)]
+mod error_tests {
+    use crate::error::SolarmaError;
+
+    #[test]
+    fn test_all_error_variants_exist() {
+        // Ensure all error variants compile and are distinct enum values
+        let variants: Vec<SolarmaError> = vec![
+            SolarmaError::DeadlinePassed,
+            SolarmaError::DeadlineNotPassed,
+            SolarmaError::InvalidAlarmState,
+            SolarmaError::InvalidPenaltyRoute,
+            SolarmaError::InsufficientDeposit,
+            SolarmaError::Overflow,
+            SolarmaError::AlarmTimeInPast,
+            SolarmaError::InvalidDeadline,
+            SolarmaError::DepositTooSmall,
+            SolarmaError::PenaltyDestinationRequired,
+            SolarmaError::InvalidSinkAddress,
+            SolarmaError::MaxSnoozesReached,
+            SolarmaError::InvalidPenaltyRecipient,
+            SolarmaError::PenaltyDestinationNotSet,
+            SolarmaError::TooEarly,
+            SolarmaError::TooLateForRefund,
+            SolarmaError::BuddyOnlyWindow,
+            SolarmaError::UnauthorizedSigner,
+            SolarmaError::TooManyCoOwners,
+            SolarmaError::InvalidCoOwner,
+            SolarmaError::GiftExpired,
+            SolarmaError::GiftNotExpired,
+            SolarmaError::InvalidGiftRecipient,
+            SolarmaError::BetUnavailable,
+            SolarmaError::BetLocked,
+            SolarmaError::InvalidSnoozeConfig,
+            SolarmaError::InvalidSeriesConfig,
+            SolarmaError::SeriesExhausted,
+            SolarmaError::InvalidConfig,
+            SolarmaError::NotUpgradeAuthority,
+            SolarmaError::ProtocolPaused,
+            SolarmaError::InvalidTagHash,
+            SolarmaError::TagAlreadyRegistered,
+            SolarmaError::TagMismatch,
+            SolarmaError::InsufficientTreasury,
+            SolarmaError::InvalidPenaltySplit,
+            SolarmaError::CharityNotVerified,
+            SolarmaError::BuddyAcceptExpired,
+            SolarmaError::ChallengeClosed,
+            SolarmaError::InvalidPartialSlashConfig,
+            SolarmaError::NothingToSlash,
+            SolarmaError::AttestationRequired,
+            SolarmaError::InvalidAttestation,
+            SolarmaError::PermitExpired,
+            SolarmaError::InvalidBadge,
+            SolarmaError::AchievementLocked,
+            SolarmaError::EpochClosed,
+            SolarmaError::EpochNotEnded,
+            SolarmaError::InvalidDelegate,
+            SolarmaError::InvalidBuddyReward,
+            SolarmaError::DuelClosed,
+            SolarmaError::DuelNotSettleable,
+            SolarmaError::InvalidSavingsLock,
+            SolarmaError::SavingsLocked,
+            SolarmaError::DepositLimitExceeded,
+            SolarmaError::UserProfileRequired,
+            SolarmaError::InvalidClaimGrace,
+            SolarmaError::AlarmAcknowledged,
+            SolarmaError::TooManyOpenAlarms,
+            SolarmaError::AlarmIndexFull,
+            SolarmaError::InvalidProofType,
+            SolarmaError::InvalidProofTimestamp,
+            SolarmaError::InvalidSnoozeExtension,
+            SolarmaError::InvalidReferrer,
+            SolarmaError::InvalidArbiter,
+            SolarmaError::DisputeUnavailable,
+            SolarmaError::DisputeActive,
+            SolarmaError::NoActiveDispute,
+            SolarmaError::InvalidGuardians,
+            SolarmaError::NotGuardian,
+            SolarmaError::RecoveryPending,
+            SolarmaError::RecoveryNotReady,
+            SolarmaError::InvalidCrankAccounts,
+            SolarmaError::NothingToSettle,
+            SolarmaError::ScheduleUnavailable,
+            SolarmaError::WakeWindowOutOfBounds,
+            SolarmaError::InvalidSchedule,
+            SolarmaError::SkipUnavailable,
+            SolarmaError::SkipLimitReached,
+            SolarmaError::BuddyStakeUnavailable,
+            SolarmaError::BuddyStakeLocked,
+            SolarmaError::AlarmIndexRequired,
+            SolarmaError::OpenAlarmsMismatch,
+            SolarmaError::TreasuryApprovalsMissing,
+            SolarmaError::InvalidTreasurySigners,
+            SolarmaError::StatsDayClosed,
+            SolarmaError::InvalidProofCommitment,
+            SolarmaError::ProofPreimageMismatch,
+            SolarmaError::InvalidAnchoredProof,
+            SolarmaError::ClaimRequestRequired,
+            SolarmaError::ClaimVetoed,
+            SolarmaError::DestinationBlocked,
+            SolarmaError::InvalidBlockedDestination,
+            SolarmaError::BlocklistFull,
+            SolarmaError::DestinationNotBlocked,
+            SolarmaError::InvalidEarlyAck,
+            SolarmaError::InvalidSoftDeadline,
+            SolarmaError::SoftForfeitAccountsRequired,
+            SolarmaError::InvalidClaimBatch,
+            SolarmaError::LookupTableAlreadySet,
+            SolarmaError::InvalidLookupTable,
+            SolarmaError::LookupTableUpToDate,
+            SolarmaError::InsurancePoolRequired,
+            SolarmaError::NothingToInsure,
+            SolarmaError::InsuranceCooldown,
+            SolarmaError::InsurancePoolEmpty,
+            SolarmaError::CampaignMismatch,
+            SolarmaError::InvalidBuddyLink,
+            SolarmaError::InvalidTemplate,
+            SolarmaError::InvalidClaimDestination,
+            SolarmaError::NoForgivenessToken,
+            SolarmaError::ForgivenessWindowClosed,
+            SolarmaError::PenaltyDestinationFixed,
+            SolarmaError::BuddyConsentRequired,
+            SolarmaError::ProofTypeNotAllowed,
+            SolarmaError::ProofTypeMismatch,
+            SolarmaError::InvalidRequiredProofs,
+            SolarmaError::ProofsIncomplete,
+            SolarmaError::InvalidUnclaimedPenalty,
+            SolarmaError::UnclaimedPenaltyAccountsRequired,
+            SolarmaError::InvalidLeaderboardEntry,
+            SolarmaError::InvalidFunder,
+        ];
+        assert_eq!(variants.len(), 122, "Expected 122 SolarmaError variants");
+    }
+
+    #[test]
+    fn test_error_variant_distinctness() {
+        // All error codes must be unique (Anchor assigns sequential error codes)
+        // This compiles-time verifies each variant is different
+        let a = SolarmaError::DeadlinePassed;
+        let b = SolarmaError::DeadlineNotPassed;
+        let c = SolarmaError::InvalidAlarmState;
+        // These are different enum variants → different discriminants
+        assert!(std::mem::discriminant(&a) != std::mem::discriminant(&b));
+        assert!(std::mem::discriminant(&b) != std::mem::discriminant(&c));
+        assert!(std::mem::discriminant(&a) != std::mem::discriminant(&c));
+    }
+}
+
+// =========================================================================
+// Event struct tests — verify event construction and field types
+// =========================================================================
+#[cfg(test)]
+mod event_tests {
+    use crate::events::*;
+    use crate::state::ProofType;
+    use anchor_lang::prelude::Pubkey;
+
+    #[test]
+    fn test_profile_initialized_event() {
+        let event = ProfileInitialized {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: Pubkey::default(),
+        };
+        assert_eq!(event.owner, Pubkey::default());
+    }
+
+    #[test]
+    fn test_alarm_created_event() {
+        let owner = Pubkey::default();
+        let alarm = Pubkey::new_unique();
+        let event = AlarmCreated {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner,
+            alarm,
+            alarm_id: 42,
+            alarm_time: 1_000_000,
+            deadline: 2_000_000,
+            deposit_amount: 1_000_000_000,
+            penalty_route: 0,
+        };
+        assert_eq!(event.alarm_id, 42);
+        assert_eq!(event.deposit_amount, 1_000_000_000);
+        assert_eq!(event.penalty_route, 0);
+        assert!(event.deadline > event.alarm_time);
+    }
+
+    #[test]
+    fn test_alarm_claimed_event() {
+        let event = AlarmClaimed {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: Pubkey::default(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 1,
+            returned_amount: 500_000_000,
+            buddy_reward: 0,
+        };
+        assert!(event.returned_amount > 0);
+    }
+
+    #[test]
+    fn test_alarm_snoozed_event() {
+        let event = AlarmSnoozed {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: Pubkey::default(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 1,
+            snooze_count: 3,
+            cost: 100_000_000,
+            remaining: 400_000_000,
+            new_alarm_time: 1_001_800,
+            new_deadline: 2_001_800,
+            penalty_recipient: Pubkey::new_unique(),
+        };
+        assert_eq!(event.snooze_count, 3);
+        assert!(event.remaining + event.cost <= 1_000_000_000);
+        assert!(event.new_deadline > event.new_alarm_time);
+    }
+
+    #[test]
+    fn test_snooze_cost_capped_event() {
+        // Vault holds 1.5M lamports above rent, so a 2M quote is capped
+        let min_balance = 1_000_000;
+        let requested = 2_000_000;
+        let charged = crate::helpers::cap_at_rent_exempt(requested, 2_500_000, min_balance);
+        let event = SnoozeCostCapped {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: Pubkey::default(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 1,
+            snooze_count: 4,
+            requested,
+            charged,
+        };
+        assert_eq!(event.charged, 1_500_000);
+        assert!(event.charged < event.requested);
     }
 
     #[test]
-    fn fuzz_state_machine_preserves_invariants() {
-        for seed in 1u64..=5_000 {
-            let mut rng = XorShift64::new(seed);
+    fn test_claim_destination_updated_event() {
+        let owner = Pubkey::new_unique();
+        let event = ClaimDestinationUpdated {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner,
+            alarm: Pubkey::new_unique(),
+            alarm_id: 1,
+            claim_to: Some(Pubkey::new_unique()),
+        };
+        assert_ne!(event.claim_to, Some(owner));
+    }
 
-            let deposit = if rng.next_u64().is_multiple_of(4) {
-                0u64
-            } else {
-                rng.gen_range_u64(MIN_DEPOSIT_LAMPORTS, 10_000_000_000)
-            };
-            let rent_minimum = rng.gen_range_u64(1, 5_000_000);
+    #[test]
+    fn test_alarm_rolled_event() {
+        let event = AlarmRolled {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            owner: Pubkey::new_unique(),
+            from_alarm: Pubkey::new_unique(),
+            to_alarm: Pubkey::new_unique(),
+            amount: 10_000_000,
+        };
+        assert_ne!(event.from_alarm, event.to_alarm);
+        assert_eq!(event.amount, 10_000_000);
+    }
 
-            let alarm_time = rng.gen_range_i64(10_000, 1_000_000_000);
-            let gap = rng.gen_range_i64(2, 100_000);
-            let deadline = alarm_time.saturating_add(gap);
-            if deadline <= alarm_time {
-                continue;
-            }
+    #[test]
+    fn test_slash_forgiven_event() {
+        let event = SlashForgiven {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            owner: Pubkey::new_unique(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 4,
+            returned_amount: 5_000_000,
+            balance: 2,
+        };
+        let earned = ForgivenessEarned {
+            version: EVENT_VERSION,
+            timestamp: 900,
+            owner: event.owner,
+            streak: 7,
+            balance: event.balance + 1,
+        };
+        assert_eq!(earned.balance - 1, event.balance);
+        assert!(earned
+            .streak
+            .is_multiple_of(crate::constants::FORGIVENESS_STREAK_DAYS));
+    }
 
-            let mut m = ModelAlarm::new(alarm_time, deadline, deposit, rent_minimum);
-            m.assert_invariants();
+    #[test]
+    fn test_proof_stage_completed_event() {
+        let event = ProofStageCompleted {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            owner: Pubkey::new_unique(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 5,
+            proof_type: ProofType::Nfc as u8,
+            completed_proofs: 1 << ProofType::Nfc as u8,
+            required_proofs: 1 << ProofType::Nfc as u8 | 1 << ProofType::Steps as u8,
+        };
+        assert_ne!(event.completed_proofs, event.required_proofs);
+        assert_eq!(
+            event.completed_proofs & event.required_proofs,
+            event.completed_proofs
+        );
+    }
 
-            let steps = (rng.next_u64() % 41) as usize;
-            for _ in 0..steps {
-                let op = rng.pick_op();
-                let tk = rng.pick_time_kind();
-                let now = pick_now(tk, m.alarm_time, m.deadline);
+    #[test]
+    fn test_unclaimed_deposit_penalized_event() {
+        let sink = Pubkey::new_unique();
+        let event = UnclaimedDepositPenalized {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            owner: Pubkey::new_unique(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 9,
+            penalty: 25_000_000,
+            penalty_recipient: sink,
+        };
+        assert_eq!(event.penalty_recipient, sink);
+        assert_eq!(event.penalty, 25_000_000);
+    }
 
-                let before = m.clone();
-                let res = m.apply(op, now);
+    #[test]
+    fn test_penalty_destination_updated_event() {
+        let old = Pubkey::new_unique();
+        let event = PenaltyDestinationUpdated {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            owner: Pubkey::new_unique(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 3,
+            old_destination: Some(old),
+            new_destination: Pubkey::new_unique(),
+        };
+        assert_ne!(event.old_destination, Some(event.new_destination));
+    }
 
-                if res.is_err() {
-                    assert_eq!(m, before, "invalid ops must not mutate state");
-                }
+    #[test]
+    fn test_alarm_memo_updated_event() {
+        let mut memo = [0u8; 32];
+        memo[..3].copy_from_slice(b"gym");
+        let event = AlarmMemoUpdated {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            alarm: Pubkey::new_unique(),
+            alarm_id: 7,
+            memo,
+        };
+        assert_eq!(&event.memo[..3], b"gym");
+        assert!(event.memo[3..].iter().all(|b| *b == 0));
+    }
 
-                if matches!(before.status, AlarmStatus::Claimed | AlarmStatus::Slashed) {
-                    assert!(res.is_err());
-                    assert_eq!(m, before);
-                }
+    #[test]
+    fn test_event_version_prefix() {
+        use anchor_lang::AnchorSerialize;
+        let event = ProfileInitialized {
+            version: EVENT_VERSION,
+            timestamp: 1_700_000_000,
+            owner: Pubkey::default(),
+        };
+        // Decoders branch on the first byte before parsing the rest
+        let bytes = event.try_to_vec().unwrap();
+        assert_eq!(bytes[0], EVENT_VERSION);
+        assert_eq!(bytes[1..9], 1_700_000_000i64.to_le_bytes());
+    }
 
-                m.assert_invariants();
-            }
-        }
+    #[test]
+    fn test_alarm_slashed_event() {
+        let event = AlarmSlashed {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            alarm: Pubkey::new_unique(),
+            alarm_id: 1,
+            penalty_recipient: Pubkey::default(),
+            slashed_amount: 1_000_000_000,
+            caller: Pubkey::new_unique(),
+            caller_bounty: 10_000_000,
+            protocol_fee: 20_000_000,
+            penalty_route: 0,
+        };
+        assert!(event.slashed_amount > 0);
+        assert!(event.caller_bounty + event.protocol_fee < event.slashed_amount);
+        assert_ne!(event.alarm, event.caller);
     }
 
     #[test]
-    fn model_ack_disables_slash_but_claim_within_grace_is_allowed() {
-        let alarm_time = 1_000i64;
-        let deadline = 2_000i64;
-        let mut m = ModelAlarm::new(alarm_time, deadline, 1_000_000_000, 1_000_000);
+    fn test_emergency_refund_event() {
+        let event = EmergencyRefundExecuted {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: Pubkey::default(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 1,
+            penalty_amount: 50_000_000,
+            returned_amount: 950_000_000,
+        };
+        // penalty + returned should not exceed original deposit
+        assert!(event.penalty_amount + event.returned_amount <= 1_000_000_000);
+    }
 
-        assert!(m.apply(Op::Ack, alarm_time).is_ok());
-        assert_eq!(m.status, AlarmStatus::Acknowledged);
+    #[test]
+    fn test_referral_events() {
+        let owner = Pubkey::new_unique();
+        let referrer = Pubkey::new_unique();
+        let registered = ReferralRegistered {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner,
+            referrer,
+        };
+        let paid = ReferralRewardPaid {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            referrer,
+            referee: owner,
+            alarm: Pubkey::new_unique(),
+            amount: 2_000_000,
+        };
+        assert_eq!(registered.referrer, paid.referrer);
+        assert_eq!(registered.owner, paid.referee);
+    }
 
-        // Slash must be rejected once acknowledged.
-        assert!(m.apply(Op::Slash, deadline).is_err());
+    #[test]
+    fn test_dispute_events() {
+        let alarm = Pubkey::new_unique();
+        let arbiter = Pubkey::new_unique();
+        let raised = DisputeRaised {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            owner: Pubkey::new_unique(),
+            alarm,
+            alarm_id: 7,
+            arbiter,
+            dispute_until: 1_000 + crate::constants::DISPUTE_WINDOW_SECONDS,
+        };
+        let resolved = DisputeResolved {
+            version: EVENT_VERSION,
+            timestamp: 2_000,
+            arbiter,
+            alarm,
+            alarm_id: 7,
+            refunded_owner: false,
+            refunded_amount: 0,
+        };
+        assert!(resolved.timestamp < raised.dispute_until);
+        assert_eq!(raised.arbiter, resolved.arbiter);
+    }
 
-        // Claim is valid exactly at deadline + grace.
-        let at_grace_end = deadline + CLAIM_GRACE_SECONDS;
-        assert!(m.apply(Op::Claim, at_grace_end).is_ok());
-        assert_eq!(m.status, AlarmStatus::Claimed);
+    #[test]
+    fn test_recovery_events() {
+        let owner = Pubkey::new_unique();
+        let alarm = Pubkey::new_unique();
+        let approved = RecoveryApproved {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            owner,
+            alarm,
+            alarm_id: 3,
+            guardian: Pubkey::new_unique(),
+            approvals: 2,
+            unlocks_at: 1_000 + crate::constants::RECOVERY_TIMELOCK_SECONDS,
+        };
+        let recovered = AlarmRecovered {
+            version: EVENT_VERSION,
+            timestamp: approved.unlocks_at,
+            owner,
+            alarm,
+            alarm_id: 3,
+            recovery_address: Pubkey::new_unique(),
+            amount: 1_000_000_000,
+        };
+        assert!(recovered.timestamp >= approved.unlocks_at);
+        assert_ne!(recovered.recovery_address, owner);
     }
 
     #[test]
-    fn model_sweep_after_grace_and_claim_after_grace_fails() {
-        let alarm_time = 1_000i64;
-        let deadline = 2_000i64;
-        let mut m = ModelAlarm::new(alarm_time, deadline, 1_000_000_000, 1_000_000);
+    fn test_crank_settled_event() {
+        let event = CrankSettled {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            keeper: Pubkey::new_unique(),
+            slashed: 6,
+            swept: 3,
+            skipped: 1,
+            total_bounty: 600_000,
+        };
+        let groups = usize::from(event.slashed + event.swept + event.skipped);
+        assert!(groups <= crate::constants::MAX_CRANK_ALARMS);
+    }
 
-        assert!(m.apply(Op::Ack, alarm_time).is_ok());
-        assert_eq!(m.status, AlarmStatus::Acknowledged);
+    #[test]
+    fn test_buddy_stake_events() {
+        let alarm = Pubkey::new_unique();
+        let buddy = Pubkey::new_unique();
+        let staked = BuddyStaked {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            alarm,
+            buddy,
+            amount: 50_000_000,
+        };
+        let released = BuddyStakeReleased {
+            version: EVENT_VERSION,
+            timestamp: 9_000,
+            alarm,
+            buddy,
+            amount: staked.amount,
+            owner_slashed: true,
+        };
+        assert_eq!(released.amount, staked.amount);
+        assert_eq!(released.buddy, staked.buddy);
+    }
 
-        let after_grace = deadline + CLAIM_GRACE_SECONDS + 1;
-        assert!(m.apply(Op::Claim, after_grace).is_err());
-        assert!(m.apply(Op::Sweep, after_grace).is_ok());
-        assert_eq!(m.status, AlarmStatus::Claimed);
+    #[test]
+    fn test_occurrence_skipped_event() {
+        let event = OccurrenceSkipped {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            owner: Pubkey::new_unique(),
+            series: Pubkey::new_unique(),
+            occurrence_time: 86_400,
+            skips_in_period: 1,
+        };
+        assert!(event.occurrence_time > event.timestamp);
+        assert!(event.skips_in_period <= crate::constants::MAX_SERIES_SKIPS_PER_PERIOD);
     }
-}
 
-/// Integration test scenarios (require local validator)
-/// Run with: anchor test
-#[cfg(feature = "test-bpf")]
-mod integration_tests {
-    // These are implemented as Anchor TypeScript tests
-    // See tests/solarma_vault.ts (66 tests)
-    // Rust unit tests above: 96+ tests
-}
+    #[test]
+    fn test_schedule_materialized_event() {
+        let event = ScheduleMaterialized {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            owner: Pubkey::new_unique(),
+            schedule: Pubkey::new_unique(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 3,
+            alarm_time: 5_000,
+            deadline: 6_800,
+            deposit_amount: 10_000_000,
+        };
+        assert!(event.alarm_time > event.timestamp);
+        assert!(event.deadline > event.alarm_time);
+    }
 
-// =========================================================================
-// Constants tests — verify values match documented behavior
-// =========================================================================
-#[cfg(test)]
-mod constants_tests {
-    use crate::constants::*;
-    use anchor_lang::prelude::Pubkey;
+    #[test]
+    fn test_template_used_event() {
+        let event = TemplateUsed {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            owner: Pubkey::new_unique(),
+            template: Pubkey::new_unique(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 8,
+            alarm_time: 87_400,
+            deadline: 89_200,
+            deposit_amount: 10_000_000,
+        };
+        assert!(event.alarm_time > event.timestamp);
+        assert_ne!(event.alarm, event.template);
+    }
 
     #[test]
-    fn test_burn_sink_is_solana_incinerator() {
-        // BURN_SINK must be the well-known Solana incinerator address
-        let expected_b58 = "1nc1nerator11111111111111111111111111111111";
-        let expected = Pubkey::try_from(expected_b58).unwrap();
-        assert_eq!(BURN_SINK, expected, "BURN_SINK must be Solana incinerator");
+    fn test_settlement_scheduled_event() {
+        let event = SettlementScheduled {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            alarm: Pubkey::new_unique(),
+            schedule: Pubkey::new_unique(),
+            penalty_recipient: Pubkey::new_unique(),
+            trigger_at: 2_000,
+        };
+        assert!(event.trigger_at > event.timestamp);
+        assert_ne!(event.alarm, event.schedule);
+    }
+
+    #[test]
+    fn test_relayed_submission_event() {
+        use anchor_lang::ToAccountMetas;
+        let authority = Pubkey::new_unique();
+        let relayer = Pubkey::new_unique();
+        let metas = crate::accounts::AckAwakeRelayed {
+            ack: crate::accounts::AckAwake {
+                alarm: Pubkey::new_unique(),
+                user_profile: None,
+                authority,
+                vault: None,
+                penalty_recipient: None,
+                config: None,
+            },
+            relayer,
+        }
+        .to_account_metas(None);
+        // `ack_awake` metas first, then the fee-paying relayer; both sign
+        assert_eq!(metas.len(), 7);
+        assert!(metas[2].pubkey == authority && metas[2].is_signer);
+        assert!(metas[6].pubkey == relayer && metas[6].is_signer && metas[6].is_writable);
+
+        let event = RelayedSubmission {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            alarm: Pubkey::new_unique(),
+            authority,
+            relayer,
+            action: crate::constants::PERMIT_ACTION_CLAIM,
+        };
+        assert_ne!(event.authority, event.relayer);
     }
 
     #[test]
-    #[allow(clippy::assertions_on_constants)]
-    fn test_snooze_percent_within_valid_range() {
-        // Snooze cost % must be > 0 and <= 100
-        assert!(DEFAULT_SNOOZE_PERCENT > 0);
-        assert!(DEFAULT_SNOOZE_PERCENT <= 100);
+    fn test_wake_acknowledged_event() {
+        let event = WakeAcknowledged {
+            version: EVENT_VERSION,
+            timestamp: 1_000_500,
+            owner: Pubkey::default(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 1,
+            proof_completed_at: 1_000_480,
+            proof_type: ProofType::Nfc as u8,
+        };
+        assert!(event.timestamp > 0);
+        assert!(event.proof_completed_at <= event.timestamp);
     }
 
     #[test]
-    #[allow(clippy::assertions_on_constants)]
-    fn test_max_snooze_count_reasonable() {
-        // Must be > 0 (at least 1 snooze allowed) and < 64 (shift safety)
-        assert!(MAX_SNOOZE_COUNT > 0);
-        assert!(
-            MAX_SNOOZE_COUNT < 64,
-            "Must stay below 64 for bit shift safety"
-        );
+    fn test_co_owners_updated_event() {
+        let event = CoOwnersUpdated {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: Pubkey::new_unique(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 7,
+            co_owners: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+        };
+        assert_eq!(event.co_owners.len(), 2);
+        assert!(!event.co_owners.contains(&event.owner));
     }
 
     #[test]
-    #[allow(clippy::assertions_on_constants)]
-    fn test_min_deposit_is_positive() {
-        assert!(MIN_DEPOSIT_LAMPORTS > 0, "Min deposit must be positive");
-        // 0.001 SOL = 1_000_000 lamports
-        assert_eq!(MIN_DEPOSIT_LAMPORTS, 1_000_000);
+    fn test_gift_events() {
+        let gifter = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let alarm = Pubkey::new_unique();
+        let created = GiftCreated {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            gifter,
+            recipient,
+            alarm,
+            alarm_id: 3,
+            alarm_time: 1_000_000,
+            deadline: 1_001_800,
+            deposit_amount: 500_000_000,
+        };
+        assert_ne!(created.gifter, created.recipient);
+
+        let accepted = GiftAccepted {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: recipient,
+            gifter,
+            alarm,
+            alarm_id: 3,
+        };
+        assert_eq!(accepted.owner, created.recipient);
+
+        let returned = GiftReturned {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            gifter,
+            recipient,
+            alarm,
+            alarm_id: 3,
+            returned_amount: 500_000_000,
+            expired: true,
+        };
+        assert!(returned.returned_amount >= created.deposit_amount);
     }
 
     #[test]
-    #[allow(clippy::assertions_on_constants)]
-    fn test_emergency_penalty_within_valid_range() {
-        assert!(EMERGENCY_REFUND_PENALTY_PERCENT > 0);
-        assert!(EMERGENCY_REFUND_PENALTY_PERCENT <= 100);
-        assert_eq!(EMERGENCY_REFUND_PENALTY_PERCENT, 5);
+    fn test_bet_events() {
+        let offered = BetOffered {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: Pubkey::new_unique(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 9,
+            challenger: Pubkey::new_unique(),
+        };
+        assert_ne!(offered.owner, offered.challenger);
+
+        let matched = BetMatched {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            challenger: offered.challenger,
+            alarm: offered.alarm,
+            alarm_id: 9,
+            stake: 1_000_000_000,
+            pot: 2_000_000_000,
+        };
+        assert_eq!(matched.pot, matched.stake * 2);
     }
 
     #[test]
-    #[allow(clippy::assertions_on_constants)]
-    fn test_grace_period_is_positive() {
-        assert!(DEFAULT_GRACE_PERIOD > 0);
-        assert_eq!(DEFAULT_GRACE_PERIOD, 1800); // 30 minutes
+    fn test_series_events() {
+        let owner = Pubkey::new_unique();
+        let series = Pubkey::new_unique();
+        let created = SeriesCreated {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner,
+            series,
+            series_id: 1,
+            first_alarm_time: 1_000_000,
+            interval_seconds: 86_400,
+            occurrences: 30,
+            deposit_per_occurrence: 10_000_000,
+        };
+        let spawned = OccurrenceSpawned {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner,
+            series,
+            alarm: Pubkey::new_unique(),
+            alarm_id: 100,
+            alarm_time: created.first_alarm_time,
+            deadline: created.first_alarm_time + 1800,
+            deposit_amount: created.deposit_per_occurrence,
+            remaining_occurrences: created.occurrences - 1,
+        };
+        assert!(spawned.deadline > spawned.alarm_time);
+        let cancelled = SeriesCancelled {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner,
+            series,
+            series_id: 1,
+            refunded_amount: 290_000_000,
+        };
+        assert_eq!(cancelled.series, spawned.series);
     }
 
     #[test]
-    #[allow(clippy::assertions_on_constants)]
-    fn test_snooze_extension_is_positive() {
-        assert!(DEFAULT_SNOOZE_EXTENSION_SECONDS > 0);
-        assert_eq!(DEFAULT_SNOOZE_EXTENSION_SECONDS, 300); // 5 minutes
+    fn test_config_events() {
+        let admin = Pubkey::new_unique();
+        let config = Pubkey::new_unique();
+        let initialized = ConfigInitialized {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            admin,
+            config,
+        };
+        let updated = ConfigUpdated {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            admin,
+            config,
+            protocol_fee_bps: 250,
+            paused: true,
+        };
+        assert_eq!(initialized.config, updated.config);
+        assert!(updated.paused);
     }
 
     #[test]
-    #[allow(clippy::assertions_on_constants)]
-    fn test_claim_grace_is_positive() {
-        assert!(CLAIM_GRACE_SECONDS > 0);
-        assert_eq!(CLAIM_GRACE_SECONDS, 120);
+    fn test_tag_events() {
+        let owner = Pubkey::new_unique();
+        let registered = TagRegistered {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner,
+            tag_hash: [1u8; 32],
+        };
+        let rotated = TagRotated {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner,
+            old_hash: registered.tag_hash,
+            new_hash: [2u8; 32],
+        };
+        assert_ne!(rotated.old_hash, rotated.new_hash);
+    }
+
+    #[test]
+    fn test_treasury_signers_updated_event() {
+        let event = TreasurySignersUpdated {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            signers: [
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            ],
+        };
+        assert_eq!(event.signers.len(), crate::constants::TREASURY_SIGNER_COUNT);
+        let migrated = ConfigMigrated {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            config_size: crate::state::GlobalConfig::SIZE as u32,
+            treasury_size: crate::state::Treasury::SIZE as u32,
+        };
+        assert!(migrated.config_size > migrated.treasury_size);
+    }
+
+    #[test]
+    fn test_lookup_table_updated_event() {
+        let config = crate::state::GlobalConfig {
+            admin: Pubkey::new_unique(),
+            protocol_fee_bps: 0,
+            slasher_bounty_bps: 0,
+            default_snooze_percent: 10,
+            default_snooze_extension_seconds: 300,
+            default_max_snoozes: 10,
+            paused: false,
+            require_verified_charity: false,
+            bump: 255,
+            max_deposit_lamports: 0,
+            daily_deposit_cap_lamports: 0,
+            max_open_alarms: 0,
+            referral_fee_bps: 0,
+            min_wake_window_seconds: 0,
+            max_wake_window_seconds: 0,
+            treasury_signers: Default::default(),
+            burn_sink: Pubkey::default(),
+            two_phase_claim_threshold: 0,
+            lookup_table: Pubkey::default(),
+            insurance_bps: 0,
+            proof_type_registry: 0,
+            default_unclaimed_penalty_bps: 0,
+        };
+        let hot = crate::instructions::extend_lookup_table::hot_accounts(&config);
+        assert_eq!(hot[0], crate::constants::BURN_SINK);
+        assert_eq!(hot[1], crate::pda::config_address().0);
+        let event = LookupTableUpdated {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            lookup_table: Pubkey::new_unique(),
+            added: hot.len() as u8,
+        };
+        assert_eq!(event.added, 4);
     }
 
     #[test]
-    #[allow(clippy::assertions_on_constants)]
-    fn test_buddy_only_window_is_positive() {
-        assert!(BUDDY_ONLY_SECONDS > 0);
-        assert_eq!(BUDDY_ONLY_SECONDS, 120);
+    fn test_insurance_claimed_event() {
+        let event = InsuranceClaimed {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: Pubkey::new_unique(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 7,
+            amount: 400_000,
+            remaining_loss: 600_000,
+            incident_hash: [9u8; 32],
+        };
+        assert_eq!(event.amount + event.remaining_loss, 1_000_000);
+        assert_ne!(event.owner, event.alarm);
     }
 
     #[test]
-    fn test_cross_platform_android_parity() {
-        // Android AlarmTiming constants must match Rust:
-        // AlarmTiming.GRACE_PERIOD_SECONDS = 1800
-        // AlarmTiming.SNOOZE_MINUTES = 5 → 300 seconds
-        // OnchainParameters.SNOOZE_BASE_PERCENT = 10
-        // OnchainParameters.EMERGENCY_REFUND_PENALTY_PERCENT = 5
-        assert_eq!(
-            DEFAULT_GRACE_PERIOD, 1800,
-            "Must match Android AlarmTiming.GRACE_PERIOD_SECONDS"
-        );
-        assert_eq!(
-            DEFAULT_SNOOZE_EXTENSION_SECONDS, 300,
-            "Must match Android AlarmTiming.SNOOZE_MINUTES * 60"
-        );
-        assert_eq!(
-            DEFAULT_SNOOZE_PERCENT, 10,
-            "Must match Android OnchainParameters.SNOOZE_BASE_PERCENT"
-        );
+    fn test_donation_matched_event() {
+        let charity = Pubkey::new_unique();
+        let (campaign, _) = crate::state::Campaign::find_address(&Pubkey::new_unique(), 1);
+        let event = DonationMatched {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            campaign,
+            alarm: Pubkey::new_unique(),
+            charity,
+            amount: 500_000,
+            total_matched: 1_500_000,
+        };
+        assert!(event.amount <= event.total_matched);
+        // A drained campaign stops matching instead of failing the slash
         assert_eq!(
-            EMERGENCY_REFUND_PENALTY_PERCENT, 5,
-            "Must match Android OnchainParameters.EMERGENCY_REFUND_PENALTY_PERCENT"
+            crate::helpers::cap_at_rent_exempt(event.amount, 1_000, 1_000),
+            0
         );
     }
 
     #[test]
-    fn test_snooze_percent_times_max_exceeds_100() {
-        // After MAX_SNOOZE_COUNT iterations, cumulative penalty should exceed 100%
-        // cumulative = 10% * (2^MAX - 1)
-        // At max=10: 10 * (1024 - 1) = 10230% >> 100%
-        let cumulative = DEFAULT_SNOOZE_PERCENT * ((1u64 << MAX_SNOOZE_COUNT) - 1);
-        assert!(cumulative > 100, "Max snoozes must drain the full deposit");
+    fn test_destination_blocklist_updated_event() {
+        let destination = Pubkey::new_unique();
+        let blocked = DestinationBlocklistUpdated {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: Pubkey::new_unique(),
+            destination,
+            blocked: true,
+        };
+        let unblocked = DestinationBlocklistUpdated {
+            version: EVENT_VERSION,
+            timestamp: 1,
+            owner: blocked.owner,
+            destination,
+            blocked: false,
+        };
+        assert_eq!(blocked.destination, unblocked.destination);
+        assert_ne!(blocked.blocked, unblocked.blocked);
     }
 
     #[test]
-    #[allow(clippy::assertions_on_constants)]
-    fn test_grace_period_longer_than_snooze_extension() {
-        // Grace period must be >= snooze extension to guarantee at least 1 snooze window
-        assert!(
-            DEFAULT_GRACE_PERIOD >= DEFAULT_SNOOZE_EXTENSION_SECONDS,
-            "Grace period must accommodate at least one snooze"
-        );
+    fn test_two_phase_claim_events() {
+        let alarm = Pubkey::new_unique();
+        let requested = ClaimRequested {
+            version: EVENT_VERSION,
+            timestamp: 1_000,
+            alarm,
+            alarm_id: 1,
+            authority: Pubkey::new_unique(),
+            finalize_after: 1_000 + crate::constants::CLAIM_FINALIZE_DELAY_SECONDS,
+        };
+        assert!(requested.finalize_after > requested.timestamp);
+        let flagged = ClaimFlagged {
+            version: EVENT_VERSION,
+            timestamp: 1_030,
+            alarm,
+            alarm_id: requested.alarm_id,
+            flagger: Pubkey::new_unique(),
+        };
+        assert!(flagged.timestamp < requested.finalize_after);
+        assert_ne!(flagged.flagger, requested.authority);
     }
-}
 
-// =========================================================================
-// Error enum tests — verify all error variants are distinct
-// =========================================================================
-#[cfg(test)]
-mod error_tests {
-    use crate::error::SolarmaError;
+    #[test]
+    fn test_proof_anchored_event() {
+        let event = ProofAnchored {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            alarm: Pubkey::new_unique(),
+            alarm_id: 3,
+            proof_hash: [5u8; 32],
+            proof_type: ProofType::Photo as u8,
+        };
+        assert!(ProofType::try_from(event.proof_type).is_ok());
+        assert_ne!(event.proof_hash, [0u8; 32]);
+    }
 
     #[test]
-    fn test_all_error_variants_exist() {
-        // Ensure all 14 error variants compile and are distinct enum values
-        let variants: Vec<SolarmaError> = vec![
-            SolarmaError::DeadlinePassed,
-            SolarmaError::DeadlineNotPassed,
-            SolarmaError::InvalidAlarmState,
-            SolarmaError::InvalidPenaltyRoute,
-            SolarmaError::InsufficientDeposit,
-            SolarmaError::Overflow,
-            SolarmaError::AlarmTimeInPast,
-            SolarmaError::InvalidDeadline,
-            SolarmaError::DepositTooSmall,
-            SolarmaError::PenaltyDestinationRequired,
-            SolarmaError::InvalidSinkAddress,
-            SolarmaError::MaxSnoozesReached,
-            SolarmaError::InvalidPenaltyRecipient,
-            SolarmaError::PenaltyDestinationNotSet,
-            SolarmaError::TooEarly,
-            SolarmaError::TooLateForRefund,
-        ];
-        assert_eq!(variants.len(), 16, "Expected 16 SolarmaError variants");
+    fn test_daily_stats_opened_event() {
+        let event = DailyStatsOpened {
+            version: EVENT_VERSION,
+            timestamp: 86_400 * 20_000 + 5,
+            day: 20_000,
+        };
+        assert_eq!(
+            crate::helpers::stats_day_of(event.timestamp),
+            Some(event.day)
+        );
     }
 
     #[test]
-    fn test_error_variant_distinctness() {
-        // All error codes must be unique (Anchor assigns sequential error codes)
-        // This compiles-time verifies each variant is different
-        let a = SolarmaError::DeadlinePassed;
-        let b = SolarmaError::DeadlineNotPassed;
-        let c = SolarmaError::InvalidAlarmState;
-        // These are different enum variants → different discriminants
-        assert!(std::mem::discriminant(&a) != std::mem::discriminant(&b));
-        assert!(std::mem::discriminant(&b) != std::mem::discriminant(&c));
-        assert!(std::mem::discriminant(&a) != std::mem::discriminant(&c));
+    fn test_treasury_withdrawn_event() {
+        let event = TreasuryWithdrawn {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            admin: Pubkey::new_unique(),
+            destination: Pubkey::new_unique(),
+            amount: 5_000_000,
+        };
+        assert!(event.amount > 0);
+        assert_ne!(event.admin, event.destination);
     }
-}
 
-// =========================================================================
-// Event struct tests — verify event construction and field types
-// =========================================================================
-#[cfg(test)]
-mod event_tests {
-    use crate::events::*;
-    use anchor_lang::prelude::Pubkey;
+    #[test]
+    fn test_alarm_closed_event() {
+        let event = AlarmClosed {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: Pubkey::new_unique(),
+            alarm: Pubkey::new_unique(),
+            alarm_id: 42,
+        };
+        assert_ne!(event.owner, event.alarm);
+    }
 
     #[test]
-    fn test_profile_initialized_event() {
-        let event = ProfileInitialized {
-            owner: Pubkey::default(),
+    fn test_charity_events() {
+        let charity = Pubkey::new_unique();
+        let address = Pubkey::new_unique();
+        let added = CharityAdded {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            charity,
+            address,
+            name_hash: [3u8; 32],
         };
-        assert_eq!(event.owner, Pubkey::default());
+        let removed = CharityRemoved {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            charity,
+            address,
+        };
+        assert_eq!(added.address, removed.address);
     }
 
     #[test]
-    fn test_alarm_created_event() {
-        let owner = Pubkey::default();
+    fn test_buddy_events() {
+        let owner = Pubkey::new_unique();
         let alarm = Pubkey::new_unique();
-        let event = AlarmCreated {
+        let accepted = BuddyAccepted {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            buddy: Pubkey::new_unique(),
             owner,
             alarm,
-            alarm_id: 42,
-            alarm_time: 1_000_000,
-            deadline: 2_000_000,
-            deposit_amount: 1_000_000_000,
-            penalty_route: 0,
+            alarm_id: 3,
         };
-        assert_eq!(event.alarm_id, 42);
-        assert_eq!(event.deposit_amount, 1_000_000_000);
-        assert_eq!(event.penalty_route, 0);
-        assert!(event.deadline > event.alarm_time);
+        let cancelled = BuddyRequestCancelled {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner,
+            alarm,
+            alarm_id: 3,
+            returned_amount: 100_000_000,
+        };
+        assert_ne!(accepted.buddy, accepted.owner);
+        assert_eq!(accepted.alarm_id, cancelled.alarm_id);
+    }
+
+    #[test]
+    fn test_challenge_events() {
+        let challenge = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let created = ChallengeCreated {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            creator: user,
+            challenge,
+            challenge_id: 1,
+            wake_time: 1_000,
+            deadline: 2_000,
+            deposit_amount: 100_000_000,
+        };
+        let joined = ChallengeJoined {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            user,
+            challenge,
+            participant_count: 1,
+            total_pot: created.deposit_amount,
+        };
+        let acked = ChallengeAcked {
+            version: EVENT_VERSION,
+            timestamp: 1_500,
+            user,
+            challenge,
+        };
+        let settled = ChallengeSettled {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            user,
+            challenge,
+            acked: true,
+            payout: joined.total_pot,
+            burned: 0,
+        };
+        assert!(acked.timestamp < created.deadline);
+        assert_eq!(settled.payout, joined.total_pot);
     }
 
     #[test]
-    fn test_alarm_claimed_event() {
-        let event = AlarmClaimed {
-            owner: Pubkey::default(),
+    fn test_alarm_rescheduled_event() {
+        let event = AlarmRescheduled {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: Pubkey::new_unique(),
             alarm: Pubkey::new_unique(),
-            alarm_id: 1,
-            returned_amount: 500_000_000,
+            alarm_id: 7,
+            old_alarm_time: 1_000,
+            old_deadline: 1_600,
+            new_alarm_time: 2_000,
+            new_deadline: 2_900,
         };
-        assert!(event.returned_amount > 0);
+        assert_eq!(event.alarm_id, 7);
+        assert!(event.new_deadline > event.new_alarm_time);
+        assert_ne!(event.old_alarm_time, event.new_alarm_time);
     }
 
     #[test]
-    fn test_alarm_snoozed_event() {
-        let event = AlarmSnoozed {
-            owner: Pubkey::default(),
+    fn test_deadline_extended_event() {
+        let fee = crate::helpers::deadline_extension_fee(1_000_000_000, 1_800).unwrap();
+        let event = DeadlineExtended {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: Pubkey::new_unique(),
             alarm: Pubkey::new_unique(),
-            alarm_id: 1,
-            snooze_count: 3,
-            cost: 100_000_000,
-            remaining: 400_000_000,
-            new_alarm_time: 1_001_800,
-            new_deadline: 2_001_800,
+            alarm_id: 7,
+            old_deadline: 2_000,
+            new_deadline: 3_800,
+            fee,
+            remaining: 1_000_000_000 - fee,
         };
-        assert_eq!(event.snooze_count, 3);
-        assert!(event.remaining + event.cost <= 1_000_000_000);
-        assert!(event.new_deadline > event.new_alarm_time);
+        assert_eq!(event.fee, 2_500_000);
+        assert_eq!(event.new_deadline - event.old_deadline, 1_800);
     }
 
     #[test]
-    fn test_alarm_slashed_event() {
-        let event = AlarmSlashed {
-            alarm: Pubkey::new_unique(),
-            alarm_id: 1,
-            penalty_recipient: Pubkey::default(),
-            slashed_amount: 1_000_000_000,
-            caller: Pubkey::new_unique(),
+    fn test_delegate_updated_event() {
+        let event = DelegateUpdated {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: Pubkey::new_unique(),
+            delegate: Pubkey::new_unique(),
+            expires_at: 86_400,
         };
-        assert!(event.slashed_amount > 0);
-        assert_ne!(event.alarm, event.caller);
+        assert_ne!(event.owner, event.delegate);
+        assert_eq!(event.expires_at, 86_400);
     }
 
     #[test]
-    fn test_emergency_refund_event() {
-        let event = EmergencyRefundExecuted {
-            owner: Pubkey::default(),
+    fn test_auto_claim_authorized_event() {
+        let owner = Pubkey::new_unique();
+        let event = AutoClaimAuthorized {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner,
             alarm: Pubkey::new_unique(),
-            alarm_id: 1,
-            penalty_amount: 50_000_000,
-            returned_amount: 950_000_000,
+            alarm_id: 3,
+            authorized_by: owner,
         };
-        // penalty + returned should not exceed original deposit
-        assert!(event.penalty_amount + event.returned_amount <= 1_000_000_000);
+        assert_eq!(event.authorized_by, event.owner);
+        assert_eq!(event.alarm_id, 3);
     }
 
     #[test]
-    fn test_wake_acknowledged_event() {
-        let event = WakeAcknowledged {
-            owner: Pubkey::default(),
-            alarm: Pubkey::new_unique(),
-            alarm_id: 1,
-            timestamp: 1_000_500,
+    fn test_savings_credited_event() {
+        let event = SavingsCredited {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            owner: Pubkey::new_unique(),
+            savings_vault: Pubkey::new_unique(),
+            amount: 90_000_000,
+            unlock_at: 604_800,
         };
-        assert!(event.timestamp > 0);
+        assert_eq!(event.amount, 90_000_000);
+        assert!(event.unlock_at > 0);
+    }
+
+    #[test]
+    fn test_duel_settled_event() {
+        let event = DuelSettled {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            duel: Pubkey::new_unique(),
+            creator_payout: 200,
+            opponent_payout: 0,
+        };
+        assert_eq!(event.creator_payout + event.opponent_payout, 200);
+    }
+
+    #[test]
+    fn test_epoch_finalized_event() {
+        let event = EpochFinalized {
+            version: EVENT_VERSION,
+            timestamp: 0,
+            epoch: 2_900,
+            participants: 3,
+            total_claims: 12,
+            total_slashes: 2,
+            finalized_by: Pubkey::new_unique(),
+        };
+        assert_eq!(event.epoch, 2_900);
+        assert!(event.total_claims > event.total_slashes);
     }
 }
 
@@ -2360,8 +6964,8 @@ mod protocol_invariants {
 
     // =====================================================================
     // INV-5: PENALTY ROUTING IS EXHAUSTIVE AND CORRECT
-    // Every penalty route (Burn=0, Donate=1, Buddy=2) must route
-    // funds to the correct recipient. Invalid routes (3+) must fail.
+    // Every penalty route (Burn=0, Donate=1, Buddy=2, Split=3, Savings=4)
+    // must route funds to the correct recipient. Invalid routes (5+) must fail.
     // This directly maps to the slash instruction (slash.rs:54-71).
     // =====================================================================
 
@@ -2396,8 +7000,26 @@ mod protocol_invariants {
         );
         assert!(helpers::validate_penalty_recipient(2, &some_dest, &burn_sink, None).is_err());
 
-        // Invalid routes (3-255): must always fail
-        for route in 3..=255u8 {
+        // Split route: close target is the primary leg (penalty_destination)
+        assert!(
+            helpers::validate_penalty_recipient(3, &some_dest, &burn_sink, Some(&some_dest))
+                .is_ok()
+        );
+        assert!(helpers::validate_penalty_recipient(3, &some_dest, &burn_sink, None).is_err());
+
+        // Savings route: must go to penalty_destination (the owner's savings PDA)
+        assert!(
+            helpers::validate_penalty_recipient(4, &some_dest, &burn_sink, Some(&some_dest))
+                .is_ok()
+        );
+        assert!(
+            helpers::validate_penalty_recipient(4, &burn_sink, &burn_sink, Some(&some_dest))
+                .is_err()
+        );
+        assert!(helpers::validate_penalty_recipient(4, &some_dest, &burn_sink, None).is_err());
+
+        // Invalid routes (5-255): must always fail
+        for route in 5..=255u8 {
             assert!(
                 helpers::validate_penalty_recipient(route, &burn_sink, &burn_sink, None).is_err(),
                 "Route {} must be rejected",
@@ -2634,7 +7256,7 @@ mod protocol_invariants {
                 now + 100,
                 now + 200,
                 MIN_DEPOSIT_LAMPORTS,
-                3,
+                5,
                 false,
                 Err("invalid_penalty_route"),
             ),
@@ -2678,6 +7300,7 @@ mod protocol_invariants {
                 *deposit,
                 *route,
                 *has_dest,
+                &helpers::SnoozeParams::default(),
             );
             match expected {
                 Ok(()) => assert!(
@@ -2711,6 +7334,8 @@ mod protocol_invariants {
             (0u8, PenaltyRoute::Burn),
             (1u8, PenaltyRoute::Donate),
             (2u8, PenaltyRoute::Buddy),
+            (3u8, PenaltyRoute::Split),
+            (4u8, PenaltyRoute::Savings),
         ];
 
         for (byte, expected) in &routes {
@@ -2722,8 +7347,8 @@ mod protocol_invariants {
             );
         }
 
-        // All values 3-255 must fail
-        for byte in 3..=255u8 {
+        // All values 5-255 must fail
+        for byte in 5..=255u8 {
             assert!(
                 PenaltyRoute::try_from(byte).is_err(),
                 "PenaltyRoute::try_from({}) should fail",
@@ -2743,11 +7368,35 @@ mod protocol_invariants {
         // Alarm::SIZE breakdown (from state.rs):
         // 8 disc + 32 owner + 8 id + 8 time + 8 deadline + 8 initial +
         // 8 remaining + 1 route + (1+32) dest + 1 snooze + 1 status +
-        // 1 bump + 1 vault_bump + 64 padding = 182
-        assert_eq!(Alarm::SIZE, 182, "Alarm::SIZE constant is wrong");
-
-        // UserProfile::SIZE: 8 + 32 + (1+32) + 1 = 74
-        assert_eq!(UserProfile::SIZE, 74, "UserProfile::SIZE constant is wrong");
+        // 1 bump + 1 vault_bump + 3*32 co_owners + (1+32) gifter +
+        // (1+32) challenger + 8 challenger_stake + 1 snooze_percent +
+        // 8 snooze_extension_seconds + 1 max_snoozes + (1+32) series +
+        // 3*(32+2) penalty_splits + 2 partial_slash_bps +
+        // 8 partial_slash_interval_seconds + 8 partial_slashed +
+        // 1 require_attestation + 1 auto_claim_authorized +
+        // 2 buddy_reward_bps + 32 memo + 8 claim_grace_seconds +
+        // 8 proof_completed_at + 1 proof_type + 1 free_snoozes +
+        // 1 snooze_model + (1+32) arbiter + 8 dispute_until +
+        // 1 recovery_approvals + 8 recovery_unlocks_at + 8 buddy_stake +
+        // 32 payer + 1 deposit_from_payer + 32 proof_commitment +
+        // 32 anchored_proof_hash + 1 anchored_proof_type + 1 two_phase_claim +
+        // 8 claim_requested_at + 1 claim_flagged + 8 early_ack_seconds +
+        // 8 insured_loss + 8 soft_deadline + 1 layout_version +
+        // 63 padding = 760
+        assert_eq!(Alarm::SIZE, 760, "Alarm::SIZE constant is wrong");
+
+        // UserProfile::SIZE: 8 + 32 + (1+32) + 1 + 4 total_claims +
+        // 4*2 streak counters + 8 last_claim_day + 32 delegate +
+        // 8 delegate_expires_at + 8 deposit_day + 8 deposited_today +
+        // 2 open_alarm_count + 32 referrer + 3*32 guardians +
+        // 32 recovery_address + 8*32 blocked_destinations +
+        // 8 last_insurance_claim_at + 8 lifetime_snooze_lamports +
+        // 4 lifetime_snoozes + 1 layout_version = 589
+        assert_eq!(
+            UserProfile::SIZE,
+            589,
+            "UserProfile::SIZE constant is wrong"
+        );
 
         // Vault::SIZE: 8 + 32 + 1 = 41
         assert_eq!(Vault::SIZE, 41, "Vault::SIZE constant is wrong");
@@ -2809,4 +7458,132 @@ mod protocol_invariants {
             );
         }
     }
+
+    // =====================================================================
+    // INV-15: ALARM ID ROUND-TRIPS AND MATCHES THE PDA SEED
+    // Events read `alarm.alarm_id`, and clients locate alarms by
+    // (owner, alarm_id). The stored id must survive serialization and
+    // re-derive the same address the account was created at.
+    // =====================================================================
+
+    #[test]
+    fn inv15_alarm_id_persists_and_derives_pda() {
+        use anchor_lang::prelude::Pubkey;
+        use anchor_lang::{AccountDeserialize, AccountSerialize};
+
+        let owner = Pubkey::new_unique();
+        for alarm_id in [0u64, 1, 42, u64::MAX] {
+            let alarm = Alarm {
+                owner,
+                alarm_id,
+                ..Alarm::default()
+            };
+            let mut data = Vec::new();
+            alarm.try_serialize(&mut data).unwrap();
+            assert!(data.len() <= Alarm::SIZE);
+            let decoded = Alarm::try_deserialize(&mut data.as_slice()).unwrap();
+            assert_eq!(decoded.alarm_id, alarm_id);
+
+            let expected = Pubkey::find_program_address(
+                &[b"alarm", owner.as_ref(), &alarm_id.to_le_bytes()],
+                &crate::ID,
+            );
+            assert_eq!(
+                Alarm::find_address(&decoded.owner, decoded.alarm_id),
+                expected
+            );
+        }
+        assert_ne!(
+            Alarm::find_address(&owner, 1).0,
+            Alarm::find_address(&owner, 2).0
+        );
+    }
+
+    // =====================================================================
+    // INV-16: EXPORTED PDA HELPERS MATCH THE ACCOUNT CONSTRAINT SEEDS
+    // SDKs derive every address through `crate::pda`; a drift from the
+    // `seeds = [...]` constraints would make every client call fail.
+    // =====================================================================
+
+    #[test]
+    fn inv16_pda_helpers_match_constraint_seeds() {
+        use crate::pda;
+        use anchor_lang::prelude::Pubkey;
+
+        let owner = Pubkey::new_unique();
+        let alarm = Pubkey::new_unique();
+        let find = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &crate::ID);
+
+        assert_eq!(
+            pda::alarm_address(&owner, 7),
+            find(&[b"alarm", owner.as_ref(), &7u64.to_le_bytes()])
+        );
+        assert_eq!(
+            pda::vault_address(&alarm),
+            find(&[b"vault", alarm.as_ref()])
+        );
+        assert_eq!(
+            pda::profile_address(&owner),
+            find(&[b"user-profile", owner.as_ref()])
+        );
+        assert_eq!(
+            pda::permit_nonce_address(&alarm, 9),
+            find(&[b"permit", alarm.as_ref(), &9u64.to_le_bytes()])
+        );
+        assert_eq!(pda::config_address(), find(&[b"config"]));
+        assert_eq!(pda::treasury_address(), find(&[b"treasury"]));
+        assert_eq!(pda::attestation_config_address(), find(&[b"attestation"]));
+        assert_eq!(pda::insurance_pool_address(), find(&[b"insurance"]));
+        assert_eq!(pda::protocol_params_address(), find(&[b"protocol-params"]));
+        assert_eq!(
+            pda::event_authority_address(),
+            find(&[b"__event_authority"])
+        );
+        assert_eq!(
+            pda::savings_vault_address(&owner),
+            find(&[b"savings", owner.as_ref()])
+        );
+        assert_eq!(
+            pda::alarm_index_address(&owner),
+            find(&[b"alarm-index", owner.as_ref()])
+        );
+        assert_eq!(
+            pda::schedule_address(&owner, 4),
+            find(&[b"wake-schedule", owner.as_ref(), &4u64.to_le_bytes()])
+        );
+        assert_eq!(
+            pda::alarm_template_address(&owner, 2),
+            find(&[b"alarm-template", owner.as_ref(), &2u64.to_le_bytes()])
+        );
+        assert_eq!(
+            pda::forgiveness_token_address(&owner),
+            find(&[b"forgiveness", owner.as_ref()])
+        );
+        assert_eq!(
+            pda::buddy_stake_address(&alarm),
+            find(&[b"buddy-stake", alarm.as_ref()])
+        );
+        assert_eq!(
+            pda::settlement_schedule_address(&alarm),
+            find(&[b"schedule", alarm.as_ref()])
+        );
+        assert_eq!(
+            pda::buddy_link_address(&owner, &alarm),
+            find(&[b"buddy-link", owner.as_ref(), alarm.as_ref()])
+        );
+        assert_eq!(
+            pda::campaign_address(&owner, 3),
+            find(&[b"campaign", owner.as_ref(), &3u64.to_le_bytes()])
+        );
+        assert_eq!(
+            pda::daily_stats_address(20_000),
+            find(&[b"daily-stats", &20_000u64.to_le_bytes()])
+        );
+        // The account-type helpers delegate here
+        assert_eq!(Vault::find_address(&alarm), pda::vault_address(&alarm));
+        assert_eq!(
+            UserProfile::find_address(&owner),
+            pda::profile_address(&owner)
+        );
+    }
 }
@@ -13,6 +13,7 @@ describe("solarma_vault", () => {
 
     // Burn sink address (must match constants.rs)
     const BURN_SINK = new PublicKey("1nc1nerator11111111111111111111111111111111");
+    const BPF_LOADER_UPGRADEABLE = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
 
     // Test constants
     const MIN_DEPOSIT = 1_000_000; // 0.001 SOL in lamports
@@ -51,6 +52,14 @@ describe("solarma_vault", () => {
         );
     }
 
+    // Helper to derive the user profile PDA
+    function deriveUserProfilePda(ownerPubkey: PublicKey): [PublicKey, number] {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("user-profile"), ownerPubkey.toBuffer()],
+            program.programId
+        );
+    }
+
     // Helper to get current unix timestamp
     async function getCurrentTimestamp(): Promise<number> {
         const slot = await provider.connection.getSlot();
@@ -58,6 +67,42 @@ describe("solarma_vault", () => {
         return timestamp || Math.floor(Date.now() / 1000);
     }
 
+    // Helper: a proof_completed_at that ack_awake accepts — the start of the
+    // alarm's wake window, which is never after a valid ack
+    async function proofCompletedAt(alarmPubkey: PublicKey): Promise<anchor.BN> {
+        const alarm = await program.account.alarm.fetchNullable(alarmPubkey);
+        if (!alarm) return new anchor.BN(0);
+        return alarm.alarmTime.sub(alarm.earlyAckSeconds);
+    }
+
+    // Helper: AlarmOptions that only take the deposit from the payer
+    function sponsoredOptions() {
+        return {
+            snoozePercent: null,
+            snoozeExtensionSeconds: null,
+            maxSnoozes: null,
+            penaltySplits: null,
+            partialSlashBps: null,
+            partialSlashIntervalSeconds: null,
+            requireAttestation: null,
+            buddyRewardBps: null,
+            memo: null,
+            claimGraceSeconds: null,
+            freeSnoozes: null,
+            snoozeModel: null,
+            arbiter: null,
+            expectedOpenAlarms: null,
+            depositFromPayer: true,
+            proofCommitment: null,
+            earlyAckSeconds: null,
+            softDeadline: null,
+            claimTo: null,
+            requiredProofType: null,
+            requiredProofs: null,
+            unclaimedPenaltyBps: null,
+        };
+    }
+
     // Helper to fund a keypair via SOL transfer (avoids airdrop rate limits)
     async function fundKeypair(kp: Keypair, lamports: number = 0.01 * LAMPORTS_PER_SOL): Promise<void> {
         const tx = new Transaction().add(
@@ -83,6 +128,25 @@ describe("solarma_vault", () => {
             await provider.connection.confirmTransaction(sig);
             console.log("Airdrop successful");
         }
+
+        // create_alarm, snooze and slash read the global config; the test
+        // wallet deploys the program, so it is the upgrade authority.
+        const [config] = PublicKey.findProgramAddressSync([Buffer.from("config")], program.programId);
+        if (await provider.connection.getAccountInfo(config)) {
+            console.log("Config already exists, skipping init_config");
+            return;
+        }
+        const [programData] = PublicKey.findProgramAddressSync(
+            [program.programId.toBuffer()],
+            BPF_LOADER_UPGRADEABLE
+        );
+        await program.methods
+            .initConfig(owner.publicKey)
+            .accounts({
+                programData,
+                authority: owner.publicKey,
+            })
+            .rpc();
     });
 
     // =========================================================================
@@ -138,12 +202,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(0), // no deposit
                     0, // Burn route
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -170,12 +236,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0, // Burn route
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -203,12 +271,15 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     2, // Buddy route
-                    buddy
+                    buddy,
+                    null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
+                    userProfile: deriveUserProfilePda(owner.publicKey)[0],
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -235,12 +306,14 @@ describe("solarma_vault", () => {
                         new anchor.BN(deadline),
                         new anchor.BN(DEPOSIT_AMOUNT),
                         2, // Buddy route
-                        null // Missing buddy address!
+                        null, // Missing buddy address!
+                        null
                     )
                     .accounts({
                         alarm,
                         vault,
                         owner: owner.publicKey,
+                        payer: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -267,12 +340,14 @@ describe("solarma_vault", () => {
                         new anchor.BN(deadline),
                         new anchor.BN(MIN_DEPOSIT - 1), // Below minimum
                         0,
+                        null,
                         null
                     )
                     .accounts({
                         alarm,
                         vault,
                         owner: owner.publicKey,
+                        payer: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -299,12 +374,14 @@ describe("solarma_vault", () => {
                         new anchor.BN(deadline),
                         new anchor.BN(0),
                         0,
+                        null,
                         null
                     )
                     .accounts({
                         alarm,
                         vault,
                         owner: owner.publicKey,
+                        payer: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -331,12 +408,14 @@ describe("solarma_vault", () => {
                         new anchor.BN(deadline),
                         new anchor.BN(0),
                         0,
+                        null,
                         null
                     )
                     .accounts({
                         alarm,
                         vault,
                         owner: owner.publicKey,
+                        payer: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -369,12 +448,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -384,10 +465,10 @@ describe("solarma_vault", () => {
 
             // Claim now requires ack_awake first
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -400,6 +481,7 @@ describe("solarma_vault", () => {
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -429,12 +511,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -446,6 +530,7 @@ describe("solarma_vault", () => {
                         alarm,
                         vault,
                         owner: owner.publicKey,
+                        authority: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -476,12 +561,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -526,12 +613,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -573,12 +662,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -599,6 +690,126 @@ describe("solarma_vault", () => {
                 expect(err.message).to.include("InvalidSinkAddress");
             }
         });
+
+        it("Refunds a sponsored deposit to the sponsor", async () => {
+            const sponsor = Keypair.generate();
+            await fundKeypair(sponsor, 0.05 * LAMPORTS_PER_SOL);
+            const alarmId = uniqueAlarmId();
+            const now = await getCurrentTimestamp();
+            const alarmTime = now + 3600;
+            const deadline = alarmTime + 1800;
+
+            const [alarm] = deriveAlarmPda(owner.publicKey, alarmId);
+            const [vault] = deriveVaultPda(alarm);
+
+            await program.methods
+                .createAlarm(
+                    alarmId,
+                    new anchor.BN(alarmTime),
+                    new anchor.BN(deadline),
+                    new anchor.BN(DEPOSIT_AMOUNT),
+                    0,
+                    null,
+                    sponsoredOptions()
+                )
+                .accounts({
+                    alarm,
+                    vault,
+                    owner: owner.publicKey,
+                    payer: sponsor.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([sponsor])
+                .rpc();
+
+            const alarmAccount = await program.account.alarm.fetch(alarm);
+            expect(alarmAccount.payer.toBase58()).to.equal(sponsor.publicKey.toBase58());
+            expect(alarmAccount.depositFromPayer).to.equal(true);
+
+            const sponsorBefore = await provider.connection.getBalance(sponsor.publicKey);
+            await program.methods
+                .emergencyRefund()
+                .accounts({
+                    alarm,
+                    vault,
+                    sink: BURN_SINK,
+                    owner: owner.publicKey,
+                    systemProgram: SystemProgram.programId,
+                    funder: sponsor.publicKey,
+                })
+                .rpc();
+
+            // The sponsor gets the deposit minus the 5% penalty; the owner only the vault rent
+            const sponsorAfter = await provider.connection.getBalance(sponsor.publicKey);
+            const expectedRefund = DEPOSIT_AMOUNT * (100 - EMERGENCY_PENALTY_PERCENT) / 100;
+            expect(sponsorAfter - sponsorBefore).to.equal(expectedRefund);
+        });
+
+        it("FAILS: Emergency refund of a sponsored deposit without the sponsor", async () => {
+            const sponsor = Keypair.generate();
+            await fundKeypair(sponsor, 0.05 * LAMPORTS_PER_SOL);
+            const alarmId = uniqueAlarmId();
+            const now = await getCurrentTimestamp();
+            const alarmTime = now + 3600;
+            const deadline = alarmTime + 1800;
+
+            const [alarm] = deriveAlarmPda(owner.publicKey, alarmId);
+            const [vault] = deriveVaultPda(alarm);
+
+            await program.methods
+                .createAlarm(
+                    alarmId,
+                    new anchor.BN(alarmTime),
+                    new anchor.BN(deadline),
+                    new anchor.BN(DEPOSIT_AMOUNT),
+                    0,
+                    null,
+                    sponsoredOptions()
+                )
+                .accounts({
+                    alarm,
+                    vault,
+                    owner: owner.publicKey,
+                    payer: sponsor.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([sponsor])
+                .rpc();
+
+            try {
+                await program.methods
+                    .emergencyRefund()
+                    .accounts({
+                        alarm,
+                        vault,
+                        sink: BURN_SINK,
+                        owner: owner.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+                expect.fail("Should have thrown InvalidFunder error");
+            } catch (err: any) {
+                expect(err.message).to.include("InvalidFunder");
+            }
+
+            // Passing the owner in the sponsor's place is refused too
+            try {
+                await program.methods
+                    .emergencyRefund()
+                    .accounts({
+                        alarm,
+                        vault,
+                        sink: BURN_SINK,
+                        owner: owner.publicKey,
+                        systemProgram: SystemProgram.programId,
+                        funder: owner.publicKey,
+                    })
+                    .rpc();
+                expect.fail("Should have thrown InvalidFunder error");
+            } catch (err: any) {
+                expect(err.message).to.include("InvalidFunder");
+            }
+        });
     });
 
     // =========================================================================
@@ -621,12 +832,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -635,12 +848,12 @@ describe("solarma_vault", () => {
             await new Promise(resolve => setTimeout(resolve, 3000));
 
             await program.methods
-                .snooze(0) // H1: expected_snooze_count = 0 (first snooze)
+                .snooze(0, null) // H1: expected_snooze_count = 0 (first snooze)
                 .accounts({
                     alarm,
                     vault,
                     sink: BURN_SINK,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -674,12 +887,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -688,12 +903,12 @@ describe("solarma_vault", () => {
 
             // First snooze succeeds
             await program.methods
-                .snooze(0)
+                .snooze(0, null)
                 .accounts({
                     alarm,
                     vault,
                     sink: BURN_SINK,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -705,12 +920,12 @@ describe("solarma_vault", () => {
             // Retry with same expected_snooze_count=0 should fail (H1 idempotency)
             try {
                 await program.methods
-                    .snooze(0) // Wrong! Actual snooze_count is now 1
+                    .snooze(0, null) // Wrong! Actual snooze_count is now 1
                     .accounts({
                         alarm,
                         vault,
                         sink: BURN_SINK,
-                        owner: owner.publicKey,
+                        authority: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -738,12 +953,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -752,12 +969,12 @@ describe("solarma_vault", () => {
 
             // First snooze: 10%
             await program.methods
-                .snooze(0) // H1: expected_snooze_count = 0
+                .snooze(0, null) // H1: expected_snooze_count = 0
                 .accounts({
                     alarm,
                     vault,
                     sink: BURN_SINK,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -771,12 +988,12 @@ describe("solarma_vault", () => {
 
             // Second snooze: 10% * 2 = 20% of remaining
             await program.methods
-                .snooze(1) // H1: expected_snooze_count = 1 (second snooze)
+                .snooze(1, null) // H1: expected_snooze_count = 1 (second snooze)
                 .accounts({
                     alarm,
                     vault,
                     sink: BURN_SINK,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -806,24 +1023,26 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
 
             try {
                 await program.methods
-                    .snooze(0) // H1: expected_snooze_count = 0
+                    .snooze(0, null) // H1: expected_snooze_count = 0
                     .accounts({
                         alarm,
                         vault,
                         sink: BURN_SINK,
-                        owner: owner.publicKey,
+                        authority: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -853,12 +1072,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0, // Burn route
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -899,12 +1120,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -943,12 +1166,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0, // Burn route
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -998,12 +1223,15 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     2, // Buddy route
-                    buddy.publicKey
+                    buddy.publicKey,
+                    null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
+                    userProfile: deriveUserProfilePda(owner.publicKey)[0],
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1051,12 +1279,15 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     2, // Buddy route
-                    buddy.publicKey
+                    buddy.publicKey,
+                    null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
+                    userProfile: deriveUserProfilePda(owner.publicKey)[0],
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1102,12 +1333,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1116,10 +1349,10 @@ describe("solarma_vault", () => {
             await new Promise(resolve => setTimeout(resolve, 3000));
 
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -1143,22 +1376,24 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
 
             try {
                 await program.methods
-                    .ackAwake()
+                    .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                     .accounts({
                         alarm,
-                        owner: owner.publicKey,
+                        authority: owner.publicKey,
                     })
                     .rpc();
                 expect.fail("Should have thrown TooEarly error");
@@ -1183,12 +1418,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1200,19 +1437,18 @@ describe("solarma_vault", () => {
 
             try {
                 await program.methods
-                    .ackAwake()
+                    .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                     .accounts({
                         alarm,
-                        owner: impostor.publicKey,
+                        authority: impostor.publicKey,
                     })
                     .signers([impostor])
                     .rpc();
                 expect.fail("Should have thrown — non-owner");
             } catch (err: any) {
-                // has_one = owner constraint violation
+                // Not the owner or a co-owner
                 expect(err.message.toLowerCase()).to.satisfy((msg: string) =>
-                    msg.includes("hasone") ||
-                    msg.includes("has_one") ||
+                    msg.includes("unauthorizedsigner") ||
                     msg.includes("constraint") ||
                     msg.includes("2001") ||
                     msg.includes("error")
@@ -1236,12 +1472,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1250,20 +1488,20 @@ describe("solarma_vault", () => {
 
             // First ack succeeds
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
             // Second ack should fail
             try {
                 await program.methods
-                    .ackAwake()
+                    .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                     .accounts({
                         alarm,
-                        owner: owner.publicKey,
+                        authority: owner.publicKey,
                     })
                     .rpc();
                 expect.fail("Should have thrown InvalidAlarmState");
@@ -1292,12 +1530,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1307,12 +1547,12 @@ describe("solarma_vault", () => {
 
             // Step 1: Snooze
             await program.methods
-                .snooze(0) // H1: expected_snooze_count = 0
+                .snooze(0, null) // H1: expected_snooze_count = 0
                 .accounts({
                     alarm,
                     vault,
                     sink: BURN_SINK,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1327,10 +1567,10 @@ describe("solarma_vault", () => {
 
             // Step 2: Ack Awake (H3)
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -1346,6 +1586,7 @@ describe("solarma_vault", () => {
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1374,12 +1615,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1394,6 +1637,7 @@ describe("solarma_vault", () => {
                         alarm,
                         vault,
                         owner: owner.publicKey,
+                        authority: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -1424,12 +1668,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1437,10 +1683,10 @@ describe("solarma_vault", () => {
             await new Promise(resolve => setTimeout(resolve, 2000));
 
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -1451,6 +1697,7 @@ describe("solarma_vault", () => {
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1463,6 +1710,7 @@ describe("solarma_vault", () => {
                         alarm,
                         vault, // Vault no longer exists
                         owner: owner.publicKey,
+                        authority: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -1501,12 +1749,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1519,14 +1769,15 @@ describe("solarma_vault", () => {
             await fundKeypair(impostor);
         });
 
-        it("FAILS: Claim by non-owner (has_one constraint)", async () => {
+        it("FAILS: Claim by non-owner (UnauthorizedSigner)", async () => {
             try {
                 await program.methods
                     .claim()
                     .accounts({
                         alarm: sharedAlarmPda,
                         vault: sharedVaultPda,
-                        owner: impostor.publicKey,
+                        owner: owner.publicKey,
+                        authority: impostor.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .signers([impostor])
@@ -1534,8 +1785,7 @@ describe("solarma_vault", () => {
                 expect.fail("Should have thrown — non-owner");
             } catch (err: any) {
                 expect(err.message.toLowerCase()).to.satisfy((msg: string) =>
-                    msg.includes("hasone") ||
-                    msg.includes("has_one") ||
+                    msg.includes("unauthorizedsigner") ||
                     msg.includes("constraint") ||
                     msg.includes("2001") ||
                     msg.includes("error")
@@ -1570,12 +1820,12 @@ describe("solarma_vault", () => {
         it("FAILS: Snooze by non-owner", async () => {
             try {
                 await program.methods
-                    .snooze(0)
+                    .snooze(0, null)
                     .accounts({
                         alarm: sharedAlarmPda,
                         vault: sharedVaultPda,
                         sink: BURN_SINK,
-                        owner: impostor.publicKey,
+                        authority: impostor.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .signers([impostor])
@@ -1583,8 +1833,7 @@ describe("solarma_vault", () => {
                 expect.fail("Should have thrown — non-owner");
             } catch (err: any) {
                 expect(err.message.toLowerCase()).to.satisfy((msg: string) =>
-                    msg.includes("hasone") ||
-                    msg.includes("has_one") ||
+                    msg.includes("unauthorizedsigner") ||
                     msg.includes("constraint") ||
                     msg.includes("error")
                 );
@@ -1612,12 +1861,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1625,10 +1876,10 @@ describe("solarma_vault", () => {
             await new Promise(resolve => setTimeout(resolve, 2000));
 
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -1639,6 +1890,7 @@ describe("solarma_vault", () => {
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1646,12 +1898,12 @@ describe("solarma_vault", () => {
             // Try to snooze claimed alarm
             try {
                 await program.methods
-                    .snooze(0)
+                    .snooze(0, null)
                     .accounts({
                         alarm,
                         vault,
                         sink: BURN_SINK,
-                        owner: owner.publicKey,
+                        authority: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -1677,12 +1929,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1733,12 +1987,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1792,12 +2048,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1805,10 +2063,10 @@ describe("solarma_vault", () => {
             await new Promise(resolve => setTimeout(resolve, 2000));
 
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -1819,6 +2077,7 @@ describe("solarma_vault", () => {
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1826,10 +2085,10 @@ describe("solarma_vault", () => {
             // Try to ack on claimed alarm
             try {
                 await program.methods
-                    .ackAwake()
+                    .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                     .accounts({
                         alarm,
-                        owner: owner.publicKey,
+                        authority: owner.publicKey,
                     })
                     .rpc();
                 expect.fail("Should have thrown — alarm already claimed");
@@ -1859,12 +2118,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(0), // Zero deposit
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1873,10 +2134,10 @@ describe("solarma_vault", () => {
             await new Promise(resolve => setTimeout(resolve, 2000));
 
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -1887,6 +2148,7 @@ describe("solarma_vault", () => {
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1912,12 +2174,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -1957,12 +2221,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(0), // Zero deposit
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2004,12 +2270,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2017,10 +2285,10 @@ describe("solarma_vault", () => {
             // Wait for alarm_time, then ACK.
             await new Promise(resolve => setTimeout(resolve, 5000));
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -2033,6 +2301,7 @@ describe("solarma_vault", () => {
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2059,22 +2328,24 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
 
             await new Promise(resolve => setTimeout(resolve, 3000));
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -2088,6 +2359,7 @@ describe("solarma_vault", () => {
                         alarm,
                         vault,
                         owner: owner.publicKey,
+                        authority: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -2127,12 +2399,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2142,12 +2416,12 @@ describe("solarma_vault", () => {
 
             try {
                 await program.methods
-                    .snooze(0)
+                    .snooze(0, null)
                     .accounts({
                         alarm,
                         vault,
                         sink: BURN_SINK,
-                        owner: owner.publicKey,
+                        authority: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -2173,12 +2447,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2188,10 +2464,10 @@ describe("solarma_vault", () => {
 
             try {
                 await program.methods
-                    .ackAwake()
+                    .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                     .accounts({
                         alarm,
-                        owner: owner.publicKey,
+                        authority: owner.publicKey,
                     })
                     .rpc();
                 expect.fail("Should have thrown DeadlinePassed");
@@ -2221,12 +2497,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2237,12 +2515,12 @@ describe("solarma_vault", () => {
 
             try {
                 await program.methods
-                    .snooze(0)
+                    .snooze(0, null)
                     .accounts({
                         alarm,
                         vault,
                         sink: wrongSink, // Wrong sink!
-                        owner: owner.publicKey,
+                        authority: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -2273,12 +2551,14 @@ describe("solarma_vault", () => {
                         new anchor.BN(deadline),
                         new anchor.BN(DEPOSIT_AMOUNT),
                         99, // Invalid route!
+                        null,
                         null
                     )
                     .accounts({
                         alarm,
                         vault,
                         owner: owner.publicKey,
+                        payer: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -2305,12 +2585,14 @@ describe("solarma_vault", () => {
                         new anchor.BN(deadline),
                         new anchor.BN(DEPOSIT_AMOUNT),
                         1, // Donate route
-                        null // No destination!
+                        null, // No destination!
+                        null
                     )
                     .accounts({
                         alarm,
                         vault,
                         owner: owner.publicKey,
+                        payer: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -2341,12 +2623,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2356,10 +2640,10 @@ describe("solarma_vault", () => {
 
             // First, ack_awake
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -2406,12 +2690,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2457,12 +2743,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2476,12 +2764,12 @@ describe("solarma_vault", () => {
             await new Promise(resolve => setTimeout(resolve, 2000));
 
             await program.methods
-                .snooze(0)
+                .snooze(0, null)
                 .accounts({
                     alarm,
                     vault,
                     sink: BURN_SINK,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2520,12 +2808,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2536,10 +2826,10 @@ describe("solarma_vault", () => {
             await new Promise(resolve => setTimeout(resolve, 2000));
 
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -2549,6 +2839,7 @@ describe("solarma_vault", () => {
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2584,12 +2875,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2643,12 +2936,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2659,12 +2954,12 @@ describe("solarma_vault", () => {
             await new Promise(resolve => setTimeout(resolve, 2000));
 
             await program.methods
-                .snooze(0)
+                .snooze(0, null)
                 .accounts({
                     alarm,
                     vault,
                     sink: BURN_SINK,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2696,12 +2991,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2741,12 +3038,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(MIN_DEPOSIT), // Exact minimum
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2772,12 +3071,14 @@ describe("solarma_vault", () => {
                         new anchor.BN(deadline),
                         new anchor.BN(MIN_DEPOSIT - 1), // 1 lamport below minimum
                         0,
+                        null,
                         null
                     )
                     .accounts({
                         alarm,
                         vault,
                         owner: owner.publicKey,
+                        payer: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -2804,12 +3105,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2823,12 +3126,14 @@ describe("solarma_vault", () => {
                         new anchor.BN(deadline + 100),
                         new anchor.BN(DEPOSIT_AMOUNT),
                         0,
+                        null,
                         null
                     )
                     .accounts({
                         alarm,
                         vault,
                         owner: owner.publicKey,
+                        payer: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -2857,12 +3162,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(now + 2100),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm: alarm1,
                     vault: vault1,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2874,12 +3181,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(now + 2400),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm: alarm2,
                     vault: vault2,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2912,12 +3221,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(now + 2100),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     1, // Donate route
-                    donateAddr
+                    donateAddr,
+                    null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2943,12 +3254,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -2957,10 +3270,10 @@ describe("solarma_vault", () => {
 
             // Acknowledge first
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -2974,6 +3287,7 @@ describe("solarma_vault", () => {
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -3027,12 +3341,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -3041,10 +3357,10 @@ describe("solarma_vault", () => {
 
             // Move to Acknowledged
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -3054,12 +3370,12 @@ describe("solarma_vault", () => {
             // Try to snooze — should fail, snooze requires Created
             try {
                 await program.methods
-                    .snooze(0)
+                    .snooze(0, null)
                     .accounts({
                         alarm,
                         vault,
                         sink: BURN_SINK,
-                        owner: owner.publicKey,
+                        authority: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -3085,12 +3401,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -3099,10 +3417,10 @@ describe("solarma_vault", () => {
 
             // Move to Acknowledged
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -3140,12 +3458,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -3168,10 +3488,10 @@ describe("solarma_vault", () => {
             // Try to ack_awake on slashed alarm
             try {
                 await program.methods
-                    .ackAwake()
+                    .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                     .accounts({
                         alarm,
-                        owner: owner.publicKey,
+                        authority: owner.publicKey,
                     })
                     .rpc();
                 expect.fail("Should have thrown — alarm is slashed");
@@ -3205,12 +3525,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     1, // Donate route
-                    donationWallet.publicKey
+                    donationWallet.publicKey,
+                    null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -3257,12 +3579,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -3270,12 +3594,12 @@ describe("solarma_vault", () => {
             await new Promise(resolve => setTimeout(resolve, 3000));
 
             await program.methods
-                .snooze(0)
+                .snooze(0, null)
                 .accounts({
                     alarm,
                     vault,
                     sink: BURN_SINK,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -3310,12 +3634,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -3325,12 +3651,12 @@ describe("solarma_vault", () => {
             // Pass expected_snooze_count = 1, but actual is 0
             try {
                 await program.methods
-                    .snooze(1) // WRONG: should be 0
+                    .snooze(1, null) // WRONG: should be 0
                     .accounts({
                         alarm,
                         vault,
                         sink: BURN_SINK,
-                        owner: owner.publicKey,
+                        authority: owner.publicKey,
                         systemProgram: SystemProgram.programId,
                     })
                     .rpc();
@@ -3360,12 +3686,15 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     2, // Buddy route
-                    buddyWallet.publicKey
+                    buddyWallet.publicKey,
+                    null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
+                    userProfile: deriveUserProfilePda(owner.publicKey)[0],
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -3411,12 +3740,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0, // Burn route
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -3425,10 +3756,10 @@ describe("solarma_vault", () => {
             await new Promise(resolve => setTimeout(resolve, 3000));
 
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -3472,12 +3803,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(0), // Zero deposit
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -3491,10 +3824,10 @@ describe("solarma_vault", () => {
             await new Promise(resolve => setTimeout(resolve, 3000));
 
             await program.methods
-                .ackAwake()
+                .ackAwake(null, await proofCompletedAt(alarm), 0, null)
                 .accounts({
                     alarm,
-                    owner: owner.publicKey,
+                    authority: owner.publicKey,
                 })
                 .rpc();
 
@@ -3504,6 +3837,7 @@ describe("solarma_vault", () => {
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    authority: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -3528,12 +3862,14 @@ describe("solarma_vault", () => {
                     new anchor.BN(deadline),
                     new anchor.BN(DEPOSIT_AMOUNT),
                     0,
+                    null,
                     null
                 )
                 .accounts({
                     alarm,
                     vault,
                     owner: owner.publicKey,
+                    payer: owner.publicKey,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
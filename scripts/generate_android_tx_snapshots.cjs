@@ -74,6 +74,24 @@ function deriveVaultPda(alarmPda) {
   return { pda, bump };
 }
 
+function deriveConfigPda() {
+  const [pda] = PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+  return pda;
+}
+
+function deriveUserProfilePda(owner) {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("user-profile"), owner.toBuffer()],
+    PROGRAM_ID
+  );
+  return pda;
+}
+
+function deriveTreasuryPda() {
+  const [pda] = PublicKey.findProgramAddressSync([Buffer.from("treasury")], PROGRAM_ID);
+  return pda;
+}
+
 function writeCompactU16(value) {
   // Matches Kotlin writeCompactU16 implementation (shortvec).
   let rem = value >>> 0;
@@ -198,6 +216,11 @@ function meta(pubkey, isSigner, isWritable) {
   return { pubkey, isSigner, isWritable };
 }
 
+// Anchor reads the program ID in an optional account slot as None.
+function none() {
+  return meta(PROGRAM_ID, false, false);
+}
+
 function buildCreateAlarmIx({
   owner,
   alarmId,
@@ -218,13 +241,24 @@ function buildCreateAlarmIx({
     u64le(depositLamports),
     Buffer.from([penaltyRoute & 0xff]),
     optionPubkey(penaltyDestination),
+    Buffer.from([0]), // options: None
   ]);
 
   const accounts = [
     meta(alarmPda, false, true),
     meta(vaultPda, false, true),
+    meta(deriveConfigPda(), false, false),
+    none(), // charity
+    none(), // savings_vault
+    penaltyRoute === 2 && penaltyDestination
+      ? meta(deriveUserProfilePda(owner), false, true)
+      : none(), // user_profile
+    none(), // alarm_index
     meta(owner, true, true),
+    meta(owner, true, true), // payer
     meta(SYSTEM_PROGRAM_ID, false, false),
+    none(), // daily_stats
+    none(), // payer_profile
   ];
 
   return { alarmPda, vaultPda, instruction: instr(PROGRAM_ID, accounts, data) };
@@ -238,20 +272,40 @@ function buildClaimIx({ owner, alarmId }) {
   const accounts = [
     meta(alarmPda, false, true),
     meta(vaultPda, false, true),
-    meta(owner, true, true),
+    meta(owner, false, true),
+    none(), // user_profile
+    none(), // leaderboard_entry
+    none(), // leaderboard_epoch
+    none(), // buddy
+    none(), // alarm_index
+    meta(owner, true, false), // authority
     meta(SYSTEM_PROGRAM_ID, false, false),
+    none(), // daily_stats
+    none(), // claim_to
+    none(), // forgiveness_token
+    none(), // funder
   ];
 
   return { alarmPda, vaultPda, instruction: instr(PROGRAM_ID, accounts, data) };
 }
 
-function buildAckAwakeIx({ owner, alarmId }) {
+function buildAckAwakeIx({ owner, alarmId, proofCompletedAt }) {
   const { pda: alarmPda } = deriveAlarmPda(owner, alarmId);
-  const data = Buffer.from(discriminator("ack_awake"));
+  const data = Buffer.concat([
+    discriminator("ack_awake"),
+    Buffer.from([0]), // proof_hash: None
+    i64le(proofCompletedAt),
+    Buffer.from([0]), // proof_type: not reported
+    Buffer.from([0]), // proof_preimage: None
+  ]);
 
   const accounts = [
     meta(alarmPda, false, true),
-    meta(owner, true, true),
+    none(), // user_profile
+    meta(owner, true, true), // authority
+    none(), // vault
+    none(), // penalty_recipient
+    none(), // config
   ];
 
   return { alarmPda, instruction: instr(PROGRAM_ID, accounts, data) };
@@ -263,13 +317,16 @@ function buildSnoozeIx({ owner, alarmId, expectedSnoozeCount }) {
   const data = Buffer.concat([
     discriminator("snooze"),
     Buffer.from([expectedSnoozeCount & 0xff]),
+    Buffer.from([0]), // extension_minutes: None
   ]);
 
   const accounts = [
     meta(alarmPda, false, true),
     meta(vaultPda, false, true),
     meta(BURN_SINK, false, true),
-    meta(owner, true, true),
+    meta(deriveConfigPda(), false, false),
+    none(), // user_profile
+    meta(owner, true, true), // authority
     meta(SYSTEM_PROGRAM_ID, false, false),
   ];
 
@@ -284,9 +341,12 @@ function buildEmergencyRefundIx({ owner, alarmId }) {
   const accounts = [
     meta(alarmPda, false, true),
     meta(vaultPda, false, true),
+    meta(deriveConfigPda(), false, false),
     meta(BURN_SINK, false, true),
+    none(), // alarm_index
     meta(owner, true, true),
     meta(SYSTEM_PROGRAM_ID, false, false),
+    none(), // funder
   ];
 
   return { alarmPda, vaultPda, instruction: instr(PROGRAM_ID, accounts, data) };
@@ -301,8 +361,19 @@ function buildSlashIx({ caller, alarmId, penaltyRecipient }) {
     meta(alarmPda, false, true),
     meta(vaultPda, false, true),
     meta(penaltyRecipient, false, true),
+    meta(deriveConfigPda(), false, false),
+    meta(deriveTreasuryPda(), false, true),
+    none(), // leaderboard_entry
+    none(), // leaderboard_epoch
+    none(), // alarm_index
+    none(), // user_profile
+    none(), // referrer
     meta(caller, true, true),
     meta(SYSTEM_PROGRAM_ID, false, false),
+    none(), // memo_program
+    none(), // daily_stats
+    none(), // insurance_pool
+    none(), // campaign
   ];
 
   return { alarmPda, vaultPda, instruction: instr(PROGRAM_ID, accounts, data) };
@@ -414,8 +485,9 @@ function main() {
         op: "ack_awake",
         owner: owner.toBase58(),
         alarmId,
+        proofCompletedAt: alarmTime + 60,
       },
-      () => buildAckAwakeIx({ owner, alarmId })
+      () => buildAckAwakeIx({ owner, alarmId, proofCompletedAt: alarmTime + 60 })
     )
   );
   cases.push(